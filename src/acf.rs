@@ -0,0 +1,71 @@
+//! Endpoints for the ACF to REST API plugin.
+//!
+//! The endpoints use the `acf/v3` namespace, which reads and writes the
+//! Advanced Custom Fields of an object on their own. ACF also adds the fields
+//! to core responses, where they are read from the `acf` field of
+//! [`Post`](crate::types::Post) and the other models.
+//!
+//! The endpoints respond with the fields under an `acf` key, which can be
+//! read with [`pointer`](fn@crate::pointer) as
+//! [`AcfFields`](crate::types::AcfFields).
+
+use std::fmt;
+
+pub use retrieve::{RetrieveAcfField, RetrieveAcfFields};
+pub use update::UpdateAcfFields;
+
+pub mod retrieve;
+pub mod update;
+
+/// An object with custom fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AcfObject {
+    /// A post.
+    Post(u32),
+    /// A page.
+    Page(u32),
+    /// A media item.
+    Media(u32),
+    /// A comment.
+    Comment(u32),
+    /// A user.
+    User(u32),
+    /// A term of the taxonomy with the given REST base, such as `categories`.
+    Term { rest_base: String, id: u32 },
+    /// A post of the custom post type with the given REST base.
+    Custom { rest_base: String, id: u32 },
+    /// An options page, such as `options`.
+    Options(String),
+}
+
+impl fmt::Display for AcfObject {
+    /// The route of the object, relative to the namespace.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AcfObject::Post(id) => write!(f, "posts/{}", id),
+            AcfObject::Page(id) => write!(f, "pages/{}", id),
+            AcfObject::Media(id) => write!(f, "media/{}", id),
+            AcfObject::Comment(id) => write!(f, "comments/{}", id),
+            AcfObject::User(id) => write!(f, "users/{}", id),
+            AcfObject::Term { rest_base, id } | AcfObject::Custom { rest_base, id } => {
+                write!(f, "{}/{}", rest_base, id)
+            }
+            AcfObject::Options(id) => write!(f, "options/{}", id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(AcfObject::Post(1) => "posts/1")]
+    #[test_case(AcfObject::User(2) => "users/2")]
+    #[test_case(AcfObject::Term { rest_base: "categories".into(), id: 3 } => "categories/3")]
+    #[test_case(AcfObject::Options("options".into()) => "options/options")]
+    fn route(object: AcfObject) -> String {
+        object.to_string()
+    }
+}
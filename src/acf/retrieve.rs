@@ -0,0 +1,111 @@
+use derive_builder::Builder;
+
+use super::AcfObject;
+use crate::endpoint::prelude::*;
+
+/// Retrieve the custom fields of an object.
+#[derive(Builder)]
+pub struct RetrieveAcfFields {
+    /// The object with the fields.
+    object: AcfObject,
+}
+
+impl RetrieveAcfFields {
+    pub fn builder() -> RetrieveAcfFieldsBuilder {
+        RetrieveAcfFieldsBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveAcfFields {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/acf/v3/{}", self.object).into()
+    }
+}
+
+/// Retrieve a single custom field of an object.
+///
+/// The response holds the value under the name of the field.
+#[derive(Builder)]
+pub struct RetrieveAcfField {
+    /// The object with the field.
+    object: AcfObject,
+
+    /// The name of the field.
+    #[builder(setter(into))]
+    name: String,
+}
+
+impl RetrieveAcfField {
+    pub fn builder() -> RetrieveAcfFieldBuilder {
+        RetrieveAcfFieldBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveAcfField {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/acf/v3/{}/{}", self.object, self.name).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        pointer,
+        test::{MockClient, MockResponse},
+        types::AcfFields,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn fields() {
+        let endpoint = RetrieveAcfFields::builder()
+            .object(AcfObject::Post(1))
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/acf/v3/posts/1")
+            .json(json!({ "acf": { "subtitle": "Hello", "hero_image": false } }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let fields: AcfFields = pointer(endpoint, "/acf").query(&client).await.unwrap();
+
+        assert_eq!(fields.str("subtitle"), Some("Hello"));
+        assert_eq!(fields.get::<String>("hero_image").unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn field() {
+        let endpoint = RetrieveAcfField::builder()
+            .object(AcfObject::Term {
+                rest_base: "categories".into(),
+                id: 3,
+            })
+            .name("color")
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/acf/v3/categories/3/color")
+            .json(json!({ "color": "#ff0000" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let color: String = pointer(endpoint, "/color").query(&client).await.unwrap();
+
+        assert_eq!(color, "#ff0000");
+    }
+}
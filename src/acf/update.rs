@@ -0,0 +1,90 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use super::AcfObject;
+use crate::{endpoint::prelude::*, types::AcfFields};
+
+/// Update the custom fields of an object.
+///
+/// Only the given fields are changed. The response holds all the fields of
+/// the object.
+#[derive(Builder, Serialize)]
+pub struct UpdateAcfFields {
+    /// The object with the fields.
+    #[serde(skip)]
+    object: AcfObject,
+
+    /// The values of the fields, by name.
+    #[builder(setter(into))]
+    fields: AcfFields,
+}
+
+impl UpdateAcfFields {
+    pub fn builder() -> UpdateAcfFieldsBuilder {
+        UpdateAcfFieldsBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateAcfFields {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/acf/v3/{}", self.object).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        pointer,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = UpdateAcfFields::builder()
+            .object(AcfObject::User(2))
+            .fields(AcfFields::from_iter([("twitter", "@example")]))
+            .build()
+            .unwrap();
+
+        let (_, body) = endpoint.body().unwrap().unwrap();
+        let body: Json = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, json!({ "fields": { "twitter": "@example" } }));
+    }
+
+    #[tokio::test]
+    async fn update() {
+        let mut fields = AcfFields::new();
+        fields.set("related", [3, 4]).unwrap();
+        let endpoint = UpdateAcfFields::builder()
+            .object(AcfObject::Post(1))
+            .fields(fields)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route("/acf/v3/posts/1")
+            .json(json!({ "acf": { "subtitle": "Hello", "related": [3, 4] } }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let fields: AcfFields = pointer(endpoint, "/acf").query(&client).await.unwrap();
+
+        assert_eq!(fields.get::<Vec<u32>>("related").unwrap(), Some(vec![3, 4]));
+        assert_eq!(fields.len(), 2);
+    }
+}
@@ -0,0 +1,503 @@
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bytes::Bytes;
+use http::{
+    header::{self, HeaderName, HeaderValue},
+    Request, Response,
+};
+use url::Url;
+
+#[cfg(feature = "edd")]
+pub use self::edd::EddAuth;
+use self::oauth1::OAuth1;
+#[cfg(feature = "woocommerce")]
+pub use self::woocommerce::WooCommerceAuth;
+pub use self::{
+    bearer::{BearerAuth, RefreshToken},
+    cookie::{CookieAuth, CookieJar},
+};
+#[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+use crate::request;
+use crate::{
+    client::{BodyStream, Client, RequestBodyStream},
+    error::{ApiError, AuthError},
+    secret::SecretString,
+};
+
+mod bearer;
+mod cookie;
+#[cfg(feature = "edd")]
+mod edd;
+mod oauth1;
+#[cfg(feature = "woocommerce")]
+mod woocommerce;
+
+/// A trait for adding credentials to outgoing requests.
+#[async_trait]
+pub trait Authenticate: Send + Sync {
+    /// Add credentials to a request before it is sent.
+    async fn authenticate(&self, request: &mut Request<Vec<u8>>) -> Result<(), AuthError>;
+}
+
+/// Credentials used to authenticate requests.
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum Auth {
+    /// HTTP Basic authentication using an application password.
+    ///
+    /// Application passwords can be created from the user's profile page and
+    /// may be given with or without the spaces WordPress displays them with.
+    Basic {
+        /// The user's login name.
+        username: String,
+        /// The application password.
+        app_password: SecretString,
+    },
+
+    /// OAuth 1.0a, as implemented by the WordPress OAuth1 plugin.
+    ///
+    /// Requests are signed using HMAC-SHA1 with a fresh nonce and timestamp.
+    OAuth1 {
+        /// The client key.
+        consumer_key: String,
+        /// The client secret.
+        consumer_secret: SecretString,
+        /// The access token.
+        token: String,
+        /// The access token secret.
+        token_secret: SecretString,
+    },
+
+    /// OAuth2 bearer token authentication, as used by WordPress.com and
+    /// Jetpack connected sites.
+    Bearer(BearerAuth),
+
+    /// Cookie authentication with a REST API nonce.
+    ///
+    /// See [`CookieAuth::login`].
+    Cookie(CookieAuth),
+
+    /// Send a fixed header with every request.
+    ///
+    /// Any existing value for the header is replaced. The value is treated as
+    /// sensitive.
+    Header {
+        /// The header name.
+        name: HeaderName,
+        /// The header value.
+        value: HeaderValue,
+    },
+
+    /// WooCommerce REST API keys.
+    #[cfg(feature = "woocommerce")]
+    WooCommerce(WooCommerceAuth),
+
+    /// Easy Digital Downloads API keys.
+    #[cfg(feature = "edd")]
+    Edd(EddAuth),
+
+    /// A custom authentication scheme.
+    Custom(Arc<dyn Authenticate>),
+}
+
+impl Auth {
+    /// Authenticate using an OAuth2 access token.
+    pub fn bearer(token: impl Into<SecretString>) -> Self {
+        Auth::Bearer(BearerAuth::new(token))
+    }
+
+    /// Authenticate using WooCommerce REST API keys.
+    #[cfg(feature = "woocommerce")]
+    pub fn woocommerce(
+        consumer_key: impl Into<String>,
+        consumer_secret: impl Into<SecretString>,
+    ) -> Self {
+        Auth::WooCommerce(WooCommerceAuth::new(consumer_key, consumer_secret))
+    }
+
+    /// Authenticate using Easy Digital Downloads API keys.
+    #[cfg(feature = "edd")]
+    pub fn edd(public_key: impl Into<String>, token: impl Into<SecretString>) -> Self {
+        Auth::Edd(EddAuth::new(public_key, token))
+    }
+
+    /// Authenticate using a custom scheme.
+    pub fn custom(auth: impl Authenticate + 'static) -> Self {
+        Auth::Custom(Arc::new(auth))
+    }
+
+    /// Whether the credentials can be refreshed after being rejected.
+    #[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+    pub(crate) fn can_refresh(&self) -> bool {
+        match self {
+            Auth::Bearer(auth) => auth.can_refresh(),
+            _ => false,
+        }
+    }
+
+    /// Refresh the credentials, returning `false` if they cannot be.
+    #[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+    pub(crate) async fn refresh(&self) -> Result<bool, AuthError> {
+        match self {
+            Auth::Bearer(auth) => auth.refresh().await,
+            _ => Ok(false),
+        }
+    }
+}
+
+#[async_trait]
+impl Authenticate for Auth {
+    async fn authenticate(&self, request: &mut Request<Vec<u8>>) -> Result<(), AuthError> {
+        match self {
+            Auth::Basic {
+                username,
+                app_password,
+            } => basic(request, username, app_password.expose()),
+            Auth::OAuth1 {
+                consumer_key,
+                consumer_secret,
+                token,
+                token_secret,
+            } => OAuth1 {
+                consumer_key,
+                consumer_secret: consumer_secret.expose(),
+                token,
+                token_secret: token_secret.expose(),
+            }
+            .sign(request),
+            Auth::Bearer(auth) => auth.apply(request),
+            Auth::Cookie(auth) => auth.apply(request),
+            Auth::Header { name, value } => {
+                let mut value = value.clone();
+                value.set_sensitive(true);
+                request.headers_mut().insert(name.clone(), value);
+                Ok(())
+            }
+            #[cfg(feature = "woocommerce")]
+            Auth::WooCommerce(auth) => auth.apply(request),
+            #[cfg(feature = "edd")]
+            Auth::Edd(auth) => auth.apply(request),
+            Auth::Custom(auth) => auth.authenticate(request).await,
+        }
+    }
+}
+
+/// Add HTTP Basic credentials to a request.
+fn basic(request: &mut Request<Vec<u8>>, username: &str, password: &str) -> Result<(), AuthError> {
+    let credentials = STANDARD.encode(format!("{}:{}", username, password));
+    let mut value = HeaderValue::try_from(format!("Basic {}", credentials))?;
+    value.set_sensitive(true);
+    request.headers_mut().insert(header::AUTHORIZATION, value);
+    Ok(())
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Auth::Basic {
+                username,
+                app_password,
+            } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("app_password", app_password)
+                .finish(),
+            Auth::OAuth1 {
+                consumer_key,
+                consumer_secret,
+                token,
+                token_secret,
+            } => f
+                .debug_struct("OAuth1")
+                .field("consumer_key", consumer_key)
+                .field("consumer_secret", consumer_secret)
+                .field("token", token)
+                .field("token_secret", token_secret)
+                .finish(),
+            Auth::Bearer(auth) => f.debug_tuple("Bearer").field(auth).finish(),
+            Auth::Cookie(auth) => f.debug_tuple("Cookie").field(auth).finish(),
+            Auth::Header { name, .. } => f
+                .debug_struct("Header")
+                .field("name", name)
+                .field("value", &SecretString::default())
+                .finish(),
+            #[cfg(feature = "woocommerce")]
+            Auth::WooCommerce(auth) => f.debug_tuple("WooCommerce").field(auth).finish(),
+            #[cfg(feature = "edd")]
+            Auth::Edd(auth) => f.debug_tuple("Edd").field(auth).finish(),
+            Auth::Custom(_) => f.debug_tuple("Custom").finish_non_exhaustive(),
+        }
+    }
+}
+
+/// A client which sends requests using credentials other than its defaults.
+///
+/// The credentials are attached to each request as an extension. Clients which
+/// support authentication, such as [`WordPress`](crate::WordPress), use them in
+/// place of their own.
+pub struct Authenticated<'a, C> {
+    client: &'a C,
+    auth: &'a Auth,
+}
+
+impl<'a, C> Authenticated<'a, C> {
+    /// Send requests through a client using the given credentials.
+    pub fn new(client: &'a C, auth: &'a Auth) -> Self {
+        Self { client, auth }
+    }
+}
+
+#[async_trait]
+impl<C> Client for Authenticated<'_, C>
+where
+    C: Client + Sync,
+{
+    type Error = C::Error;
+
+    async fn route_url(&self, route: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.route_url(route).await
+    }
+
+    async fn send_request(
+        &self,
+        mut request: Request<Vec<u8>>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        request.extensions_mut().insert(self.auth.clone());
+        self.client.send_request(request).await
+    }
+
+    async fn send_request_streamed(
+        &self,
+        mut request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        request.extensions_mut().insert(self.auth.clone());
+        self.client.send_request_streamed(request).await
+    }
+
+    async fn send_streaming_request(
+        &self,
+        mut request: Request<RequestBodyStream>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        request.extensions_mut().insert(self.auth.clone());
+        self.client.send_streaming_request(request).await
+    }
+
+    async fn send_external_request(
+        &self,
+        mut request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        request.extensions_mut().insert(self.auth.clone());
+        self.client.send_external_request(request).await
+    }
+}
+
+/// Authenticate and send a request.
+///
+/// Credentials attached to the request by [`Authenticated`] take precedence
+/// over the client's defaults. If the credentials can be refreshed, a request
+/// rejected with `401 Unauthorized` is sent once more after refreshing them.
+#[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+pub(crate) async fn send<F, Fut, B, E>(
+    default: Option<&Auth>,
+    mut request: Request<Vec<u8>>,
+    execute: F,
+) -> Result<Response<B>, ApiError<E>>
+where
+    F: Fn(Request<Vec<u8>>) -> Fut,
+    Fut: std::future::Future<Output = Result<Response<B>, E>>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let query_auth = request.extensions_mut().remove::<Auth>();
+    let auth = match query_auth.as_ref().or(default) {
+        Some(auth) => auth,
+        None => return execute(request).await.map_err(ApiError::client),
+    };
+
+    let retry = auth.can_refresh().then(|| request::copy(&request));
+    auth.authenticate(&mut request).await?;
+    let resp = execute(request).await.map_err(ApiError::client)?;
+
+    if let Some(mut request) = retry {
+        if resp.status() == http::StatusCode::UNAUTHORIZED && auth.refresh().await? {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("retrying with refreshed credentials");
+            auth.authenticate(&mut request).await?;
+            return execute(request).await.map_err(ApiError::client);
+        }
+    }
+    Ok(resp)
+}
+
+/// Authenticate and send a request whose body is streamed.
+///
+/// Credentials are added without reading the body, and the request is not
+/// sent again after refreshing them, as the body can only be read once.
+#[cfg(any(feature = "client", feature = "hyper"))]
+pub(crate) async fn send_streaming<F, Fut, E>(
+    default: Option<&Auth>,
+    request: Request<RequestBodyStream>,
+    execute: F,
+) -> Result<Response<Bytes>, ApiError<E>>
+where
+    F: FnOnce(Request<RequestBodyStream>) -> Fut,
+    Fut: std::future::Future<Output = Result<Response<Bytes>, E>>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let (parts, body) = request.into_parts();
+    let mut head = Request::from_parts(parts, Vec::new());
+    let query_auth = head.extensions_mut().remove::<Auth>();
+    if let Some(auth) = query_auth.as_ref().or(default) {
+        auth.authenticate(&mut head).await?;
+    }
+    let (parts, _) = head.into_parts();
+    execute(Request::from_parts(parts, body))
+        .await
+        .map_err(ApiError::client)
+}
+
+/// Query parameters which hold credentials, such as the WooCommerce keys.
+#[cfg(feature = "client")]
+const SECRET_PARAMS: [&str; 3] = ["consumer_key", "consumer_secret", "oauth_signature"];
+
+/// Mask the credentials in the query of a URL, so that it can be shown to
+/// observers or in errors.
+#[cfg(feature = "client")]
+pub(crate) fn redact_url(url: &mut Url) {
+    if !url
+        .query_pairs()
+        .any(|(name, _)| SECRET_PARAMS.contains(&&*name))
+    {
+        return;
+    }
+    let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    let mut query = url.query_pairs_mut();
+    query.clear();
+    for (name, value) in &pairs {
+        if SECRET_PARAMS.contains(&name.as_str()) {
+            query.append_pair(name, "REDACTED");
+        } else {
+            query.append_pair(name, value);
+        }
+    }
+}
+
+/// Mask the credentials in the query of a URI, see [`redact_url`].
+#[cfg(feature = "client")]
+pub(crate) fn redact_uri(uri: &http::Uri) -> http::Uri {
+    let secret = uri.query().is_some_and(|query| {
+        SECRET_PARAMS
+            .iter()
+            .any(|name| query.contains(&format!("{}=", name)))
+    });
+    let Some(mut url) = secret.then(|| Url::parse(&uri.to_string()).ok()).flatten() else {
+        return uri.clone();
+    };
+    redact_url(&mut url);
+    url.as_str().parse().unwrap_or_else(|_| uri.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn redact() {
+        let uri: http::Uri =
+            "http://example.com/wp-json/?page=2&oauth_nonce=n&oauth_signature=s%3D"
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            redact_uri(&uri),
+            "http://example.com/wp-json/?page=2&oauth_nonce=n&oauth_signature=REDACTED"
+        );
+        let plain: http::Uri = "http://example.com/wp-json/?page=2".parse().unwrap();
+        assert_eq!(redact_uri(&plain), plain);
+    }
+
+    fn request() -> Request<Vec<u8>> {
+        Request::builder()
+            .uri("http://example.com/wp-json/")
+            .body(Vec::new())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let auth = Auth::Basic {
+            username: "admin".into(),
+            app_password: "abcd EFGH 1234".into(),
+        };
+        let mut request = request();
+
+        auth.authenticate(&mut request).await.unwrap();
+
+        assert_eq!(
+            request.headers()[header::AUTHORIZATION],
+            "Basic YWRtaW46YWJjZCBFRkdIIDEyMzQ="
+        );
+    }
+
+    #[tokio::test]
+    async fn bearer() {
+        let auth = Auth::bearer("abc123");
+        let mut request = request();
+
+        auth.authenticate(&mut request).await.unwrap();
+
+        assert_eq!(request.headers()[header::AUTHORIZATION], "Bearer abc123");
+    }
+
+    #[test]
+    fn debug() {
+        let auth = Auth::Basic {
+            username: "admin".into(),
+            app_password: "abcd EFGH 1234".into(),
+        };
+
+        assert_eq!(
+            format!("{:?}", auth),
+            "Basic { username: \"admin\", app_password: [REDACTED] }"
+        );
+    }
+
+    #[tokio::test]
+    async fn header() {
+        let auth = Auth::Header {
+            name: HeaderName::from_static("x-api-key"),
+            value: HeaderValue::from_static("secret"),
+        };
+        let mut request = request();
+
+        auth.authenticate(&mut request).await.unwrap();
+
+        assert_eq!(request.headers()["x-api-key"], "secret");
+    }
+
+    struct Token;
+
+    #[async_trait]
+    impl Authenticate for Token {
+        async fn authenticate(&self, request: &mut Request<Vec<u8>>) -> Result<(), AuthError> {
+            request
+                .headers_mut()
+                .insert(header::AUTHORIZATION, HeaderValue::from_static("Token abc"));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn custom() {
+        let auth = Auth::custom(Token);
+        let mut request = request();
+
+        auth.authenticate(&mut request).await.unwrap();
+
+        assert_eq!(request.headers()[header::AUTHORIZATION], "Token abc");
+    }
+}
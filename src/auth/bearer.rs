@@ -0,0 +1,123 @@
+use std::{
+    fmt,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use http::{
+    header::{self, HeaderValue},
+    Request,
+};
+
+use crate::{error::AuthError, secret::SecretString};
+
+/// A hook for fetching a new OAuth2 access token once the current one has
+/// expired.
+#[async_trait]
+pub trait RefreshToken: Send + Sync {
+    /// Fetch a new access token.
+    async fn refresh(&self) -> Result<SecretString, AuthError>;
+}
+
+/// OAuth2 bearer token authentication.
+///
+/// This is the scheme used by WordPress.com and Jetpack connected sites. If a
+/// refresh hook is given, requests rejected with `401 Unauthorized` are sent
+/// once more using a freshly fetched token.
+#[derive(Clone)]
+pub struct BearerAuth {
+    token: Arc<RwLock<SecretString>>,
+    refresh: Option<Arc<dyn RefreshToken>>,
+}
+
+impl BearerAuth {
+    /// Authenticate using an access token.
+    pub fn new(token: impl Into<SecretString>) -> Self {
+        Self {
+            token: Arc::new(RwLock::new(token.into())),
+            refresh: None,
+        }
+    }
+
+    /// Use a hook to refresh the access token once it has expired.
+    pub fn with_refresh(mut self, refresh: impl RefreshToken + 'static) -> Self {
+        self.refresh = Some(Arc::new(refresh));
+        self
+    }
+
+    /// The current access token.
+    pub fn token(&self) -> SecretString {
+        self.token
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    pub(super) fn can_refresh(&self) -> bool {
+        self.refresh.is_some()
+    }
+
+    /// Replace the access token using the refresh hook.
+    ///
+    /// Returns `false` if no refresh hook was given.
+    pub async fn refresh(&self) -> Result<bool, AuthError> {
+        let refresh = match &self.refresh {
+            Some(refresh) => refresh,
+            None => return Ok(false),
+        };
+        let token = refresh.refresh().await?;
+        *self.token.write().unwrap_or_else(|err| err.into_inner()) = token;
+        Ok(true)
+    }
+
+    pub(super) fn apply(&self, request: &mut Request<Vec<u8>>) -> Result<(), AuthError> {
+        let mut value = HeaderValue::try_from(format!("Bearer {}", self.token().expose()))?;
+        value.set_sensitive(true);
+        request.headers_mut().insert(header::AUTHORIZATION, value);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for BearerAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BearerAuth")
+            .field("token", &self.token())
+            .field("refresh", &self.can_refresh())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    struct Refresh;
+
+    #[async_trait]
+    impl RefreshToken for Refresh {
+        async fn refresh(&self) -> Result<SecretString, AuthError> {
+            Ok("fresh".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn refresh() {
+        let auth = BearerAuth::new("stale").with_refresh(Refresh);
+        let copy = auth.clone();
+
+        assert!(auth.refresh().await.unwrap());
+
+        assert_eq!(copy.token().expose(), "fresh");
+    }
+
+    #[tokio::test]
+    async fn no_refresh() {
+        let auth = BearerAuth::new("stale");
+
+        assert!(!auth.refresh().await.unwrap());
+
+        assert_eq!(auth.token().expose(), "stale");
+    }
+}
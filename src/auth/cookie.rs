@@ -0,0 +1,357 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt,
+    sync::{Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{
+    header::{self, HeaderName, HeaderValue},
+    Method, Request, Response,
+};
+use serde::{Deserialize, Serialize};
+use url::{form_urlencoded, Url};
+
+use crate::{
+    client::Client,
+    error::AuthError,
+    middleware::{Middleware, Next},
+    secret::SecretString,
+    ApiError,
+};
+
+const TEST_COOKIE: &str = "wordpress_test_cookie";
+const LOGGED_IN_COOKIE_PREFIX: &str = "wordpress_logged_in_";
+
+/// Cookie authentication using a logged in session.
+///
+/// This is the scheme used by the WordPress admin itself. The session cookies
+/// are sent along with a REST API nonce, which WordPress requires to protect
+/// against CSRF. This is useful when application passwords are disabled.
+///
+/// The cookie jar is deliberately simple: cookies are scoped to the site that
+/// was logged in to and are never expired by the client.
+///
+/// The session can be serialized to survive process restarts. The serialized
+/// form contains the session cookies and must be stored securely.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CookieAuth {
+    cookies: BTreeMap<String, String>,
+    nonce: String,
+}
+
+impl CookieAuth {
+    /// Log in through `wp-login.php` and fetch a REST API nonce.
+    ///
+    /// The client must not follow redirects from `wp-login.php`, since the
+    /// session cookies are set on the redirect response.
+    pub async fn login<C>(
+        client: &C,
+        site_url: &Url,
+        username: &str,
+        password: &str,
+    ) -> Result<Self, ApiError<C::Error>>
+    where
+        C: Client + Sync,
+    {
+        let mut cookies = BTreeMap::new();
+        cookies.insert(TEST_COOKIE.to_string(), "WP%20Cookie%20check".to_string());
+
+        // Make sure relative URLs are resolved under the site directory.
+        let mut site_url = site_url.clone();
+        if !site_url.path().ends_with('/') {
+            let path = format!("{}/", site_url.path());
+            site_url.set_path(&path);
+        }
+
+        let admin_url = site_url.join("wp-admin/")?;
+        let body = form_urlencoded::Serializer::new(String::new())
+            .append_pair("log", username)
+            .append_pair("pwd", password)
+            .append_pair("rememberme", "forever")
+            .append_pair("redirect_to", admin_url.as_str())
+            .append_pair("testcookie", "1")
+            .finish();
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri(site_url.join("wp-login.php")?.as_str())
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(header::COOKIE, cookie_header(&cookies)?)
+            .body(body.into_bytes())
+            .map_err(ApiError::request)?;
+        let resp = client.send_request(req).await?;
+        store_cookies(&mut cookies, &resp);
+
+        if !cookies
+            .keys()
+            .any(|name| name.starts_with(LOGGED_IN_COOKIE_PREFIX))
+        {
+            return Err(AuthError::LoginFailed.into());
+        }
+
+        let mut nonce_url = site_url.join("wp-admin/admin-ajax.php")?;
+        nonce_url
+            .query_pairs_mut()
+            .append_pair("action", "rest-nonce");
+        let req = Request::builder()
+            .method(Method::GET)
+            .uri(nonce_url.as_str())
+            .header(header::COOKIE, cookie_header(&cookies)?)
+            .body(Vec::new())
+            .map_err(ApiError::request)?;
+        let resp = client.send_request(req).await?;
+        store_cookies(&mut cookies, &resp);
+
+        let nonce = String::from_utf8_lossy(resp.body()).trim().to_string();
+        if !resp.status().is_success() || nonce.is_empty() || nonce == "0" {
+            return Err(AuthError::Nonce.into());
+        }
+
+        Ok(Self { cookies, nonce })
+    }
+
+    /// The REST API nonce.
+    pub fn nonce(&self) -> &str {
+        &self.nonce
+    }
+
+    pub(super) fn apply(&self, request: &mut Request<Vec<u8>>) -> Result<(), AuthError> {
+        let mut cookie = cookie_header(&self.cookies)?;
+        cookie.set_sensitive(true);
+        let mut nonce = HeaderValue::try_from(self.nonce.as_str())?;
+        nonce.set_sensitive(true);
+        let headers = request.headers_mut();
+        headers.insert(header::COOKIE, cookie);
+        headers.insert(HeaderName::from_static("x-wp-nonce"), nonce);
+        Ok(())
+    }
+}
+
+/// Middleware which stores cookies set by WordPress and sends them with later
+/// requests.
+///
+/// Like [`CookieAuth`], the jar is scoped to a single site and cookies are
+/// never expired by the client. The jar can be serialized so that a session
+/// survives process restarts. Requests which already have a `Cookie` header,
+/// such as those using cookie authentication, are sent unchanged.
+#[derive(Default)]
+pub struct CookieJar {
+    cookies: Mutex<BTreeMap<String, String>>,
+}
+
+impl CookieJar {
+    /// Create an empty cookie jar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value of a cookie.
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.cookies().get(name).cloned()
+    }
+
+    /// Add a cookie, replacing any cookie with the same name.
+    pub fn insert(&self, name: impl Into<String>, value: impl Into<String>) {
+        self.cookies().insert(name.into(), value.into());
+    }
+
+    /// Remove all cookies.
+    pub fn clear(&self) {
+        self.cookies().clear();
+    }
+
+    fn cookies(&self) -> MutexGuard<'_, BTreeMap<String, String>> {
+        self.cookies.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl fmt::Debug for CookieJar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CookieJar")
+            .field("cookies", &self.cookies().keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl Serialize for CookieJar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.cookies().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CookieJar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Self {
+            cookies: Mutex::new(BTreeMap::deserialize(deserializer)?),
+        })
+    }
+}
+
+#[async_trait]
+impl<E> Middleware<E> for CookieJar
+where
+    E: Error + Send + Sync + 'static,
+{
+    async fn handle(
+        &self,
+        mut request: Request<Vec<u8>>,
+        next: Next<'_, E>,
+    ) -> Result<Response<Bytes>, ApiError<E>> {
+        let cookies = self.cookies().clone();
+        if !cookies.is_empty() && !request.headers().contains_key(header::COOKIE) {
+            let mut cookie = cookie_header(&cookies)?;
+            cookie.set_sensitive(true);
+            request.headers_mut().insert(header::COOKIE, cookie);
+        }
+        let resp = next.run(request).await?;
+        store_cookies(&mut self.cookies(), &resp);
+        Ok(resp)
+    }
+}
+
+fn cookie_header(cookies: &BTreeMap<String, String>) -> Result<HeaderValue, AuthError> {
+    let value = cookies
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect::<Vec<_>>()
+        .join("; ");
+    Ok(HeaderValue::try_from(value)?)
+}
+
+fn store_cookies<B>(cookies: &mut BTreeMap<String, String>, resp: &Response<B>) {
+    let set_cookies = resp
+        .headers()
+        .get_all(header::SET_COOKIE)
+        .into_iter()
+        .filter_map(|value| value.to_str().ok());
+    for set_cookie in set_cookies {
+        let pair = set_cookie.split(';').next().unwrap_or_default();
+        if let Some((name, value)) = pair.split_once('=') {
+            let (name, value) = (name.trim(), value.trim());
+            // WordPress clears cookies by setting them to a single (encoded)
+            // space with an expiry in the past.
+            if matches!(value, "" | "+" | "%20" | "deleted") {
+                cookies.remove(name);
+            } else {
+                cookies.insert(name.to_string(), value.to_string());
+            }
+        }
+    }
+}
+
+impl fmt::Debug for CookieAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CookieAuth")
+            .field("cookies", &self.cookies.keys().collect::<Vec<_>>())
+            .field("nonce", &SecretString::default())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{middleware::BoxFuture, test::MockClientError};
+
+    #[test]
+    fn store() {
+        let mut cookies = BTreeMap::new();
+        cookies.insert("expired".to_string(), "1".to_string());
+        let resp = Response::builder()
+            .header(
+                header::SET_COOKIE,
+                "wordpress_logged_in_abc=admin%7C123; path=/; HttpOnly",
+            )
+            .header(
+                header::SET_COOKIE,
+                "expired=+; expires=Thu, 01-Jan-1970 00:00:01 GMT",
+            )
+            .body(())
+            .unwrap();
+
+        store_cookies(&mut cookies, &resp);
+
+        assert_eq!(cookies.len(), 1);
+        assert_eq!(cookies["wordpress_logged_in_abc"], "admin%7C123");
+    }
+
+    #[tokio::test]
+    async fn jar() {
+        let jar = Arc::new(CookieJar::new());
+        jar.insert("a", "1");
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> = vec![jar.clone()];
+        let endpoint = |request: Request<Vec<u8>>| -> BoxFuture<'_, _> {
+            Box::pin(async move {
+                assert_eq!(request.headers()[header::COOKIE], "a=1");
+                Ok(Response::builder()
+                    .header(header::SET_COOKIE, "b=2; path=/")
+                    .body(Bytes::new())
+                    .unwrap())
+            })
+        };
+
+        Next::new(&middleware, &endpoint)
+            .run(Request::new(Vec::new()))
+            .await
+            .unwrap();
+
+        let json = serde_json::to_value(&*jar).unwrap();
+        let jar: CookieJar = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(json, json!({ "a": "1", "b": "2" }));
+        assert_eq!(jar.get("b").as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn serde() {
+        let mut cookies = BTreeMap::new();
+        cookies.insert("a".to_string(), "1".to_string());
+        let auth = CookieAuth {
+            cookies,
+            nonce: "abc123".into(),
+        };
+
+        let json = serde_json::to_value(&auth).unwrap();
+        let auth: CookieAuth = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(json, json!({ "cookies": { "a": "1" }, "nonce": "abc123" }));
+        assert_eq!(auth.nonce(), "abc123");
+    }
+
+    #[test]
+    fn apply() {
+        let mut cookies = BTreeMap::new();
+        cookies.insert("a".to_string(), "1".to_string());
+        cookies.insert("b".to_string(), "2".to_string());
+        let auth = CookieAuth {
+            cookies,
+            nonce: "abc123".into(),
+        };
+        let mut request = Request::builder()
+            .uri("http://example.com/wp-json/")
+            .body(Vec::new())
+            .unwrap();
+
+        auth.apply(&mut request).unwrap();
+
+        assert_eq!(request.headers()[header::COOKIE], "a=1; b=2");
+        assert_eq!(request.headers()["x-wp-nonce"], "abc123");
+        assert_eq!(
+            format!("{:?}", auth),
+            "CookieAuth { cookies: [\"a\", \"b\"], nonce: [REDACTED] }"
+        );
+    }
+}
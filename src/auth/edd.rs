@@ -0,0 +1,112 @@
+use std::fmt;
+
+use http::Request;
+use url::Url;
+
+use crate::{error::AuthError, secret::SecretString};
+
+/// Easy Digital Downloads API keys, as shown on a user's profile once API
+/// access is enabled.
+///
+/// The public key and the token are sent as the `key` and `token` query
+/// parameters. They are only added to requests for the EDD API, so they are
+/// not sent to the REST API when the same client is used for both.
+#[derive(Clone)]
+pub struct EddAuth {
+    public_key: String,
+    token: SecretString,
+}
+
+impl EddAuth {
+    /// Authenticate using a public key and token.
+    ///
+    /// The token is the one EDD shows next to the keys, not the secret key.
+    pub fn new(public_key: impl Into<String>, token: impl Into<SecretString>) -> Self {
+        Self {
+            public_key: public_key.into(),
+            token: token.into(),
+        }
+    }
+
+    pub(super) fn apply(&self, request: &mut Request<Vec<u8>>) -> Result<(), AuthError> {
+        let mut url = Url::parse(&request.uri().to_string()).map_err(AuthError::custom)?;
+        if !is_edd_api(&url) {
+            return Ok(());
+        }
+        url.query_pairs_mut()
+            .append_pair("key", &self.public_key)
+            .append_pair("token", self.token.expose());
+        *request.uri_mut() = url.as_str().parse().map_err(AuthError::custom)?;
+        Ok(())
+    }
+}
+
+/// Whether a URL is for the EDD API, with or without pretty permalinks.
+fn is_edd_api(url: &Url) -> bool {
+    url.path_segments()
+        .is_some_and(|mut segments| segments.any(|s| s == "edd-api"))
+        || url.query_pairs().any(|(key, _)| key == "edd-api")
+}
+
+impl fmt::Debug for EddAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EddAuth")
+            .field("public_key", &self.public_key)
+            .field("token", &self.token)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn request(url: &str) -> Request<Vec<u8>> {
+        Request::builder().uri(url).body(Vec::new()).unwrap()
+    }
+
+    #[test]
+    fn edd_api() {
+        let auth = EddAuth::new("public", "token");
+        let mut request = request("https://example.com/edd-api/v2/sales/?number=5");
+
+        auth.apply(&mut request).unwrap();
+
+        assert_eq!(
+            request.uri().query(),
+            Some("number=5&key=public&token=token")
+        );
+    }
+
+    #[test]
+    fn default_permalinks() {
+        let auth = EddAuth::new("public", "token");
+        let mut request = request("https://example.com/?edd-api=v2%2Fproducts");
+
+        auth.apply(&mut request).unwrap();
+
+        assert_eq!(
+            request.uri().query(),
+            Some("edd-api=v2%2Fproducts&key=public&token=token")
+        );
+    }
+
+    #[test]
+    fn rest_api() {
+        let auth = EddAuth::new("public", "token");
+        let mut request = request("https://example.com/wp-json/wp/v2/posts");
+
+        auth.apply(&mut request).unwrap();
+
+        assert_eq!(request.uri().query(), None);
+    }
+
+    #[test]
+    fn debug_redacts_token() {
+        let auth = EddAuth::new("public", "s3cr3t");
+
+        assert!(!format!("{:?}", auth).contains("s3cr3t"));
+    }
+}
@@ -0,0 +1,288 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hmac::{Hmac, KeyInit, Mac};
+use http::{
+    header::{self, HeaderValue},
+    Request,
+};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use sha1::Sha1;
+use url::{form_urlencoded, Url};
+
+use crate::{error::AuthError, random::random_hex};
+
+/// Characters which must be percent encoded, per RFC 5849 section 3.6.
+const ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+fn encode(value: &str) -> String {
+    utf8_percent_encode(value, ENCODE_SET).to_string()
+}
+
+/// OAuth 1.0a credentials used to sign a request.
+pub(super) struct OAuth1<'a> {
+    pub consumer_key: &'a str,
+    pub consumer_secret: &'a str,
+    pub token: &'a str,
+    pub token_secret: &'a str,
+}
+
+impl OAuth1<'_> {
+    /// Sign a request using a fresh nonce and the current time.
+    pub(super) fn sign(&self, request: &mut Request<Vec<u8>>) -> Result<(), AuthError> {
+        self.sign_with(request, &random_hex(), now())
+    }
+
+    /// Sign a request with the protocol parameters in the query string, as
+    /// WooCommerce expects over plain HTTP.
+    #[cfg(feature = "woocommerce")]
+    pub(super) fn sign_query(&self, request: &mut Request<Vec<u8>>) -> Result<(), AuthError> {
+        self.sign_query_with(request, &random_hex(), now())
+    }
+
+    fn sign_with(
+        &self,
+        request: &mut Request<Vec<u8>>,
+        nonce: &str,
+        timestamp: u64,
+    ) -> Result<(), AuthError> {
+        let timestamp = timestamp.to_string();
+        let oauth_params = self.oauth_params(nonce, &timestamp);
+        let signature = self.signature(request, &oauth_params)?;
+
+        let header = oauth_params
+            .iter()
+            .copied()
+            .chain([("oauth_signature", signature.as_str())])
+            .map(|(key, value)| format!("{}=\"{}\"", encode(key), encode(value)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut value = HeaderValue::try_from(format!("OAuth {}", header))?;
+        value.set_sensitive(true);
+        request.headers_mut().insert(header::AUTHORIZATION, value);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "woocommerce")]
+    fn sign_query_with(
+        &self,
+        request: &mut Request<Vec<u8>>,
+        nonce: &str,
+        timestamp: u64,
+    ) -> Result<(), AuthError> {
+        let timestamp = timestamp.to_string();
+        let oauth_params = self.oauth_params(nonce, &timestamp);
+        let signature = self.signature(request, &oauth_params)?;
+
+        let mut url = Url::parse(&request.uri().to_string()).map_err(AuthError::custom)?;
+        url.query_pairs_mut()
+            .extend_pairs(oauth_params)
+            .append_pair("oauth_signature", &signature);
+        *request.uri_mut() = url.as_str().parse().map_err(AuthError::custom)?;
+
+        Ok(())
+    }
+
+    /// The protocol parameters, leaving out the token for one-legged
+    /// requests.
+    fn oauth_params<'a>(&'a self, nonce: &'a str, timestamp: &'a str) -> Vec<(&'a str, &'a str)> {
+        let mut params = vec![
+            ("oauth_consumer_key", self.consumer_key),
+            ("oauth_nonce", nonce),
+            ("oauth_signature_method", "HMAC-SHA1"),
+            ("oauth_timestamp", timestamp),
+        ];
+        if !self.token.is_empty() {
+            params.push(("oauth_token", self.token));
+        }
+        params.push(("oauth_version", "1.0"));
+        params
+    }
+
+    fn signature(
+        &self,
+        request: &Request<Vec<u8>>,
+        oauth_params: &[(&str, &str)],
+    ) -> Result<String, AuthError> {
+        let url = Url::parse(&request.uri().to_string()).map_err(AuthError::custom)?;
+
+        // Collect the query string, body (if form encoded) and protocol
+        // parameters, then sort them by encoded name and value.
+        let mut params: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(key, value)| (encode(&key), encode(&value)))
+            .collect();
+        let form_encoded = request
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"));
+        if form_encoded {
+            params.extend(
+                form_urlencoded::parse(request.body())
+                    .map(|(key, value)| (encode(&key), encode(&value))),
+            );
+        }
+        params.extend(
+            oauth_params
+                .iter()
+                .map(|(key, value)| (encode(key), encode(value))),
+        );
+        params.sort();
+        let params = params
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let mut base_url = url;
+        base_url.set_query(None);
+        base_url.set_fragment(None);
+        let base_string = format!(
+            "{}&{}&{}",
+            request.method().as_str().to_ascii_uppercase(),
+            encode(base_url.as_str()),
+            encode(&params)
+        );
+
+        let key = format!(
+            "{}&{}",
+            encode(self.consumer_secret),
+            encode(self.token_secret)
+        );
+        let mut mac = Hmac::<Sha1>::new_from_slice(key.as_bytes()).map_err(AuthError::custom)?;
+        mac.update(base_string.as_bytes());
+        Ok(STANDARD.encode(mac.finalize().into_bytes()))
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// The example request from Twitter's documentation on creating
+    /// signatures.
+    #[test]
+    fn signature() {
+        let oauth = OAuth1 {
+            consumer_key: "xvz1evFS4wEEPTGEFPHBog",
+            consumer_secret: "kAcSOqF21Fu85e7zjz7ZN2U4ZRhfV3WpwPAoE3Z7kBw",
+            token: "370773112-GmHxMAgYyLbNEtIKZeRNFsMKPR9EyMZeS9weJAEb",
+            token_secret: "LswwdoUaIvS8ltyTt5jkRh4J50vUPVVHtR2YPi5kE",
+        };
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("https://api.twitter.com/1.1/statuses/update.json?include_entities=true")
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(
+                "status=Hello%20Ladies%20%2b%20Gentlemen%2c%20a%20signed%20OAuth%20request%21"
+                    .into(),
+            )
+            .unwrap();
+
+        oauth
+            .sign_with(
+                &mut request,
+                "kYjzVBB8Y0ZFabxSWbWovY3uYSQ2pTgmZeNu2VS4cg",
+                1318622958,
+            )
+            .unwrap();
+
+        assert_eq!(
+            request.headers()[header::AUTHORIZATION],
+            "OAuth oauth_consumer_key=\"xvz1evFS4wEEPTGEFPHBog\", \
+             oauth_nonce=\"kYjzVBB8Y0ZFabxSWbWovY3uYSQ2pTgmZeNu2VS4cg\", \
+             oauth_signature_method=\"HMAC-SHA1\", \
+             oauth_timestamp=\"1318622958\", \
+             oauth_token=\"370773112-GmHxMAgYyLbNEtIKZeRNFsMKPR9EyMZeS9weJAEb\", \
+             oauth_version=\"1.0\", \
+             oauth_signature=\"hCtSmYh%2BiHYCEqBWrE7C7hYmtUk%3D\""
+        );
+    }
+
+    #[test]
+    fn fresh_nonce() {
+        let oauth = OAuth1 {
+            consumer_key: "key",
+            consumer_secret: "secret",
+            token: "token",
+            token_secret: "token-secret",
+        };
+        let mut first = Request::builder()
+            .uri("http://example.com/wp-json/wp/v2/posts")
+            .body(Vec::new())
+            .unwrap();
+        let mut second = Request::builder()
+            .uri("http://example.com/wp-json/wp/v2/posts")
+            .body(Vec::new())
+            .unwrap();
+
+        oauth.sign(&mut first).unwrap();
+        oauth.sign(&mut second).unwrap();
+
+        assert_ne!(
+            first.headers()[header::AUTHORIZATION],
+            second.headers()[header::AUTHORIZATION]
+        );
+    }
+
+    #[cfg(feature = "woocommerce")]
+    #[test]
+    fn query_one_legged() {
+        let oauth = OAuth1 {
+            consumer_key: "ck_key",
+            consumer_secret: "cs_secret",
+            token: "",
+            token_secret: "",
+        };
+        let mut request = Request::builder()
+            .uri("http://example.com/wp-json/wc/v3/products?per_page=10")
+            .body(Vec::new())
+            .unwrap();
+
+        oauth
+            .sign_query_with(&mut request, "nonce", 1318622958)
+            .unwrap();
+
+        let url = Url::parse(&request.uri().to_string()).unwrap();
+        let params: Vec<_> = url.query_pairs().map(|(key, _)| key.into_owned()).collect();
+        assert_eq!(
+            params,
+            [
+                "per_page",
+                "oauth_consumer_key",
+                "oauth_nonce",
+                "oauth_signature_method",
+                "oauth_timestamp",
+                "oauth_version",
+                "oauth_signature",
+            ]
+        );
+        assert!(!request.headers().contains_key(header::AUTHORIZATION));
+
+        // The signature covers the original query and the protocol
+        // parameters.
+        let unsigned = Request::builder()
+            .uri("http://example.com/wp-json/wc/v3/products?per_page=10")
+            .body(Vec::new())
+            .unwrap();
+        let expected = oauth
+            .signature(&unsigned, &oauth.oauth_params("nonce", "1318622958"))
+            .unwrap();
+        assert_eq!(url.query_pairs().last().unwrap().1, expected.as_str());
+    }
+}
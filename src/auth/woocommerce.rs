@@ -0,0 +1,149 @@
+use std::fmt;
+
+use http::{uri::Scheme as UriScheme, Request};
+use url::Url;
+
+use super::{basic, oauth1::OAuth1};
+use crate::{error::AuthError, secret::SecretString};
+
+/// How the keys are sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scheme {
+    Auto,
+    Basic,
+    QueryString,
+}
+
+/// WooCommerce REST API keys, as created under WooCommerce > Settings >
+/// Advanced > REST API.
+///
+/// By default, the keys are sent using HTTP Basic authentication over HTTPS.
+/// Over plain HTTP, where WooCommerce does not accept the keys themselves,
+/// requests are signed using one-legged OAuth 1.0a instead.
+#[derive(Clone)]
+pub struct WooCommerceAuth {
+    consumer_key: String,
+    consumer_secret: SecretString,
+    scheme: Scheme,
+}
+
+impl WooCommerceAuth {
+    /// Authenticate using a consumer key and secret.
+    pub fn new(consumer_key: impl Into<String>, consumer_secret: impl Into<SecretString>) -> Self {
+        Self {
+            consumer_key: consumer_key.into(),
+            consumer_secret: consumer_secret.into(),
+            scheme: Scheme::Auto,
+        }
+    }
+
+    /// Always send the keys using HTTP Basic authentication.
+    pub fn basic(mut self) -> Self {
+        self.scheme = Scheme::Basic;
+        self
+    }
+
+    /// Send the keys as the `consumer_key` and `consumer_secret` query
+    /// parameters, for servers which do not pass the `Authorization` header
+    /// on to PHP.
+    ///
+    /// WooCommerce only accepts this over HTTPS. The keys are masked in the
+    /// URLs given to [observers](crate::MetricsObserver) and in errors.
+    pub fn query_string(mut self) -> Self {
+        self.scheme = Scheme::QueryString;
+        self
+    }
+
+    pub(super) fn apply(&self, request: &mut Request<Vec<u8>>) -> Result<(), AuthError> {
+        let secure = request.uri().scheme() == Some(&UriScheme::HTTPS);
+        match self.scheme {
+            Scheme::Auto if !secure => OAuth1 {
+                consumer_key: &self.consumer_key,
+                consumer_secret: self.consumer_secret.expose(),
+                token: "",
+                token_secret: "",
+            }
+            .sign_query(request),
+            Scheme::Auto | Scheme::Basic => {
+                basic(request, &self.consumer_key, self.consumer_secret.expose())
+            }
+            Scheme::QueryString => {
+                let mut url = Url::parse(&request.uri().to_string()).map_err(AuthError::custom)?;
+                url.query_pairs_mut()
+                    .append_pair("consumer_key", &self.consumer_key)
+                    .append_pair("consumer_secret", self.consumer_secret.expose());
+                *request.uri_mut() = url.as_str().parse().map_err(AuthError::custom)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Debug for WooCommerceAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WooCommerceAuth")
+            .field("consumer_key", &self.consumer_key)
+            .field("consumer_secret", &self.consumer_secret)
+            .field("scheme", &self.scheme)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn request(url: &str) -> Request<Vec<u8>> {
+        Request::builder().uri(url).body(Vec::new()).unwrap()
+    }
+
+    #[test]
+    fn basic_over_https() {
+        let auth = WooCommerceAuth::new("ck_key", "cs_secret");
+        let mut request = request("https://example.com/wp-json/wc/v3/products");
+
+        auth.apply(&mut request).unwrap();
+
+        assert_eq!(
+            request.headers()[header::AUTHORIZATION],
+            "Basic Y2tfa2V5OmNzX3NlY3JldA=="
+        );
+        assert_eq!(request.uri().query(), None);
+    }
+
+    #[test]
+    fn oauth1_over_http() {
+        let auth = WooCommerceAuth::new("ck_key", "cs_secret");
+        let mut request = request("http://example.com/wp-json/wc/v3/products");
+
+        auth.apply(&mut request).unwrap();
+
+        let query = request.uri().query().unwrap();
+        assert!(query.starts_with("oauth_consumer_key=ck_key&oauth_nonce="));
+        assert!(query.contains("&oauth_signature="));
+        assert!(!request.headers().contains_key(header::AUTHORIZATION));
+    }
+
+    #[test]
+    fn query_string() {
+        let auth = WooCommerceAuth::new("ck_key", "cs_secret").query_string();
+        let mut request = request("https://example.com/wp-json/wc/v3/products?per_page=10");
+
+        auth.apply(&mut request).unwrap();
+
+        assert_eq!(
+            request.uri().query(),
+            Some("per_page=10&consumer_key=ck_key&consumer_secret=cs_secret")
+        );
+    }
+
+    #[test]
+    fn debug_redacts_secret() {
+        let auth = WooCommerceAuth::new("ck_key", "cs_secret");
+
+        assert!(!format!("{:?}", auth).contains("cs_secret"));
+    }
+}
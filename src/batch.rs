@@ -0,0 +1,274 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use http::{HeaderValue, Method};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    client::Client,
+    endpoint::{ContentType, Endpoint},
+    envelope::{Enveloped, RawEnvelope},
+    error::BodyError,
+    query::Query,
+    request::RequestBuilder,
+    ApiError,
+};
+
+/// The maximum number of requests WordPress accepts in a batch.
+pub(crate) const MAX_REQUESTS: usize = 25;
+
+/// The results of a batch, one for each request in order.
+pub type BatchResults<E> = Vec<Result<Enveloped<Value>, ApiError<E>>>;
+
+/// A batch of write requests sent to the `/batch/v1` route.
+///
+/// Only endpoints which create, update or delete resources with a JSON body
+/// can be batched, and a batch holds at most 25 requests. Querying a batch
+/// returns the result of each request in order, so one failing request does
+/// not fail the others.
+#[derive(Default)]
+pub struct Batch {
+    requests: Vec<Box<dyn Endpoint + Send + Sync>>,
+    require_all_validate: bool,
+}
+
+impl Batch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a request to the batch.
+    pub fn push<E>(&mut self, endpoint: E) -> &mut Self
+    where
+        E: Endpoint + Send + Sync + 'static,
+    {
+        self.requests.push(Box::new(endpoint));
+        self
+    }
+
+    /// Only perform the requests if all of them pass validation.
+    ///
+    /// Otherwise the requests which failed validation return their errors
+    /// and the others return [`ApiError::BatchSkipped`].
+    pub fn require_all_validate(&mut self, require_all_validate: bool) -> &mut Self {
+        self.require_all_validate = require_all_validate;
+        self
+    }
+
+    /// The number of requests in the batch.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Whether the batch holds no requests.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    fn body<E>(&self) -> Result<Value, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        if self.requests.len() > MAX_REQUESTS {
+            return Err(ApiError::batch_too_large(self.requests.len(), MAX_REQUESTS));
+        }
+
+        let mut requests = Vec::with_capacity(self.requests.len());
+        for (index, endpoint) in self.requests.iter().enumerate() {
+            let method = endpoint.method();
+            if method == Method::GET || method == Method::HEAD {
+                return Err(ApiError::unbatchable(index));
+            }
+
+            let mut path = endpoint.route().into_owned();
+            if let Some(query) = endpoint.parameters().to_query_string() {
+                path.push('?');
+                path.push_str(&query);
+            }
+            let mut request = json!({ "method": method.as_str(), "path": path });
+            match endpoint.body()? {
+                Some((ContentType::Json, body)) => {
+                    request["body"] = serde_json::from_slice(&body).map_err(BodyError::from)?;
+                }
+                Some(_) => return Err(ApiError::unbatchable(index)),
+                None => {}
+            }
+            requests.push(request);
+        }
+
+        let validation = if self.require_all_validate {
+            "require-all-validate"
+        } else {
+            "normal"
+        };
+        Ok(json!({ "validation": validation, "requests": requests }))
+    }
+}
+
+#[derive(Deserialize)]
+struct RawBatch {
+    responses: Vec<Option<RawEnvelope>>,
+}
+
+#[async_trait]
+impl<C> Query<BatchResults<C::Error>, C> for Batch
+where
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<BatchResults<C::Error>, ApiError<C::Error>> {
+        let body = serde_json::to_vec(&self.body()?).map_err(BodyError::from)?;
+        let url = client.route_url("/batch/v1").await?;
+        let (_, batch): (_, RawBatch) = RequestBuilder::new()
+            .method(Method::POST)
+            .url(url)
+            .content_type(HeaderValue::from_static("application/json"))
+            .body(body)
+            .send_as(client)
+            .await?;
+
+        Ok(batch
+            .responses
+            .into_iter()
+            .map(|response| match response {
+                Some(response) => response.into_enveloped(),
+                None => Err(ApiError::BatchSkipped),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        endpoints::{CreatePost, DeletePost, RetrievePost},
+        test::{MockClient, MockClientError, MockResponse},
+    };
+
+    #[test]
+    fn body() {
+        let mut batch = Batch::new();
+        batch
+            .push(CreatePost::builder().title("Hello").build().unwrap())
+            .push(DeletePost::builder().id(2).force(true).build().unwrap())
+            .require_all_validate(true);
+
+        let body = batch.body::<MockClientError>().unwrap();
+
+        assert_eq!(
+            body,
+            json!({
+                "validation": "require-all-validate",
+                "requests": [
+                    { "method": "POST", "path": "/wp/v2/posts", "body": { "title": "Hello" } },
+                    { "method": "DELETE", "path": "/wp/v2/posts/2?force=true" },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn unbatchable() {
+        let mut batch = Batch::new();
+        batch.push(RetrievePost::builder().id(1).build().unwrap());
+
+        let err = batch.body::<MockClientError>().unwrap_err();
+
+        assert!(matches!(err, ApiError::Unbatchable { index: 0 }));
+    }
+
+    #[test]
+    fn too_large() {
+        let mut batch = Batch::new();
+        for id in 0..26 {
+            batch.push(DeletePost::builder().id(id).build().unwrap());
+        }
+
+        let err = batch.body::<MockClientError>().unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::BatchTooLarge {
+                count: 26,
+                limit: 25
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn results() {
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route("/batch/v1")
+            .json(json!({
+                "responses": [
+                    { "body": { "id": 1 }, "status": 201, "headers": {} },
+                    {
+                        "body": {
+                            "code": "rest_post_invalid_id",
+                            "message": "Invalid post ID.",
+                            "data": { "status": 404 },
+                        },
+                        "status": 404,
+                        "headers": {},
+                    },
+                ],
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let mut batch = Batch::new();
+        batch
+            .push(CreatePost::builder().title("Hello").build().unwrap())
+            .push(DeletePost::builder().id(2).build().unwrap());
+
+        let results = batch.query(&client).await.unwrap();
+
+        let created = results[0].as_ref().unwrap();
+        assert_eq!(created.status, StatusCode::CREATED);
+        assert_eq!(created.body, json!({ "id": 1 }));
+        assert!(
+            matches!(&results[1], Err(ApiError::WordPress { code, .. }) if code == "rest_post_invalid_id")
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_validation() {
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route("/batch/v1")
+            .status(StatusCode::MULTI_STATUS)
+            .json(json!({
+                "failed": "validation",
+                "responses": [
+                    null,
+                    {
+                        "body": {
+                            "code": "rest_invalid_param",
+                            "message": "Invalid parameter(s): status",
+                            "data": { "status": 400 },
+                        },
+                        "status": 400,
+                        "headers": {},
+                    },
+                ],
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let mut batch = Batch::new();
+        batch
+            .push(DeletePost::builder().id(1).build().unwrap())
+            .push(DeletePost::builder().id(2).build().unwrap())
+            .require_all_validate(true);
+
+        let results = batch.query(&client).await.unwrap();
+
+        assert!(matches!(results[0], Err(ApiError::BatchSkipped)));
+        assert!(matches!(&results[1], Err(ApiError::WordPress { .. })));
+    }
+}
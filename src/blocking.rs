@@ -0,0 +1,153 @@
+//! A synchronous client for use outside of an async runtime.
+//!
+//! Requests are driven by a single threaded runtime owned by the client, so
+//! the client must not be used from within another async runtime.
+
+use std::future::Future;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{auth::Auth, error::ApiError, root::RootRoute, wordpress::WordPressError};
+
+/// Synchronous WordPress client.
+#[derive(Debug)]
+pub struct WordPress {
+    inner: crate::WordPress,
+    runtime: Runtime,
+}
+
+impl WordPress {
+    /// Create a new WordPress client using the default settings.
+    pub fn new(site_url: impl AsRef<str>) -> Result<Self, WordPressError> {
+        Self::from_async(crate::WordPress::new(site_url)?)
+    }
+
+    /// Create a synchronous client from a configured asynchronous client.
+    ///
+    /// See [`WordPress::builder`](crate::WordPress::builder).
+    pub fn from_async(inner: crate::WordPress) -> Result<Self, WordPressError> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Authenticate every request sent by the client.
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.inner = self.inner.with_auth(auth);
+        self
+    }
+
+    /// Log in with a username and password and authenticate every request
+    /// sent by the client using cookies.
+    ///
+    /// See [`CookieAuth`](crate::CookieAuth).
+    pub fn login(self, username: &str, password: &str) -> Result<Self, ApiError<WordPressError>> {
+        let Self { inner, runtime } = self;
+        let inner = runtime.block_on(inner.login(username, password))?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// The root route for the WordPress instance.
+    pub fn root_route(&self) -> Result<RootRoute, ApiError<WordPressError>> {
+        self.block_on(self.inner.root_route())
+    }
+
+    /// Use a previously discovered root route instead of discovering it on the
+    /// first request.
+    pub fn with_root_route(mut self, root_route: RootRoute) -> Self {
+        self.inner = self.inner.with_root_route(root_route);
+        self
+    }
+
+    /// Forget the root route, so that it is discovered again on the next
+    /// request.
+    pub fn invalidate_root_route(&self) {
+        self.inner.invalidate_root_route();
+    }
+
+    /// The asynchronous client used to send requests.
+    pub fn inner(&self) -> &crate::WordPress {
+        &self.inner
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+/// A trait which represents a synchronous query.
+///
+/// This is implemented for every type which can be queried asynchronously
+/// using [`WordPress`](crate::WordPress).
+pub trait Query<T> {
+    /// Perform the query using the passed client.
+    fn query(&self, client: &WordPress) -> Result<T, ApiError<WordPressError>>;
+
+    /// Perform the query using the passed client and credentials.
+    fn query_as(&self, client: &WordPress, auth: &Auth) -> Result<T, ApiError<WordPressError>>;
+}
+
+impl<Q, T> Query<T> for Q
+where
+    Q: crate::Query<T, crate::WordPress> + crate::QueryAs<T, crate::WordPress>,
+{
+    fn query(&self, client: &WordPress) -> Result<T, ApiError<WordPressError>> {
+        client.block_on(crate::Query::query(self, &client.inner))
+    }
+
+    fn query_as(&self, client: &WordPress, auth: &Auth) -> Result<T, ApiError<WordPressError>> {
+        client.block_on(crate::QueryAs::query_as(self, &client.inner, auth))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::endpoints::RawEndpoint;
+
+    #[test]
+    fn query() {
+        let runtime = Builder::new_current_thread().enable_all().build().unwrap();
+        let mock_server = runtime.block_on(async {
+            let mock_server = MockServer::start().await;
+            Mock::given(method("HEAD"))
+                .respond_with(
+                    ResponseTemplate::new(200).insert_header(
+                        "link",
+                        format!(
+                            "<{}/wp-json/>; rel=\"https://api.w.org/\"",
+                            mock_server.uri()
+                        )
+                        .as_str(),
+                    ),
+                )
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/wp-json/wp/v2/users/me"))
+                .and(header("authorization", "Bearer abc123"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 1 })))
+                .mount(&mock_server)
+                .await;
+            mock_server
+        });
+
+        let wordpress = WordPress::new(mock_server.uri()).unwrap();
+        let endpoint = RawEndpoint::builder()
+            .route("/wp/v2/users/me")
+            .build()
+            .unwrap();
+
+        let user: Value = endpoint
+            .query_as(&wordpress, &Auth::bearer("abc123"))
+            .unwrap();
+
+        assert_eq!(user, json!({ "id": 1 }));
+    }
+}
@@ -0,0 +1,455 @@
+//! Parsing and serializing the blocks of post content.
+//!
+//! The block editor stores blocks in the raw content of posts as HTML
+//! comments delimiting each block, such as
+//! `<!-- wp:heading {"level":3} --><h3>Hi</h3><!-- /wp:heading -->`. The raw
+//! content is only returned in the `edit` context:
+//!
+//! ```no_run
+//! # async fn example(wp: &wordprs::WordPress) -> Result<(), Box<dyn std::error::Error>> {
+//! use wordprs::{blocks, endpoints::Context};
+//!
+//! let post = wp
+//!     .posts()
+//!     .list()
+//!     .context(Context::Edit)
+//!     .send()
+//!     .await?
+//!     .remove(0);
+//! let mut content = blocks::parse(post.content.raw().unwrap_or_default());
+//! for block in content.iter_mut().filter_map(blocks::Node::as_block_mut) {
+//!     if block.name == "core/heading" {
+//!         block.attrs.insert("level".into(), 2.into());
+//!     }
+//! }
+//! let raw = blocks::serialize(&content);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Parsing follows the parser of WordPress: it never fails, so HTML outside
+//! of blocks, malformed delimiters and closing delimiters without an opening
+//! one are kept as HTML, and blocks left open end with the content.
+
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+/// A part of the content: either HTML or a block.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// HTML between blocks, kept as it is.
+    Html(String),
+
+    /// A block delimited by comments, with its own content.
+    Block(Block),
+}
+
+impl Node {
+    /// The block, if this is a block.
+    pub fn as_block(&self) -> Option<&Block> {
+        match self {
+            Self::Block(block) => Some(block),
+            Self::Html(_) => None,
+        }
+    }
+
+    /// The block, if this is a block.
+    pub fn as_block_mut(&mut self) -> Option<&mut Block> {
+        match self {
+            Self::Block(block) => Some(block),
+            Self::Html(_) => None,
+        }
+    }
+}
+
+impl From<Block> for Node {
+    fn from(block: Block) -> Self {
+        Self::Block(block)
+    }
+}
+
+impl From<String> for Node {
+    fn from(html: String) -> Self {
+        Self::Html(html)
+    }
+}
+
+impl From<&str> for Node {
+    fn from(html: &str) -> Self {
+        Self::Html(html.to_owned())
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Html(html) => f.write_str(html),
+            Self::Block(block) => block.fmt(f),
+        }
+    }
+}
+
+/// A block and its content.
+///
+/// Displaying a block serializes it as it is stored in the content.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Block {
+    /// The name of the block including its namespace, such as
+    /// `core/paragraph`. Names without a namespace are in the `core` one.
+    pub name: String,
+
+    /// The attributes stored in the delimiter. Attributes sourced from the
+    /// HTML of the block are not included.
+    pub attrs: Map<String, Value>,
+
+    /// The HTML and inner blocks within the block. A block without any is
+    /// serialized as a void block, such as `<!-- wp:spacer /-->`.
+    pub inner: Vec<Node>,
+}
+
+impl Block {
+    /// A block without attributes or content.
+    pub fn new(name: impl Into<String>) -> Self {
+        let mut name = name.into();
+        if !name.contains('/') {
+            name.insert_str(0, "core/");
+        }
+        Self {
+            name,
+            ..Self::default()
+        }
+    }
+
+    /// Set an attribute.
+    pub fn attr(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.attrs.insert(key.into(), value.into());
+        self
+    }
+
+    /// Add HTML or an inner block to the content.
+    pub fn push(mut self, node: impl Into<Node>) -> Self {
+        self.inner.push(node.into());
+        self
+    }
+
+    /// The inner blocks, without the HTML around them.
+    pub fn inner_blocks(&self) -> impl Iterator<Item = &Block> {
+        self.inner.iter().filter_map(Node::as_block)
+    }
+
+    /// The HTML of the block, without its inner blocks.
+    pub fn inner_html(&self) -> String {
+        self.inner
+            .iter()
+            .filter_map(|node| match node {
+                Node::Html(html) => Some(html.as_str()),
+                Node::Block(_) => None,
+            })
+            .collect()
+    }
+
+    /// The name as written in the delimiter, without the `core` namespace.
+    fn short_name(&self) -> &str {
+        self.name.strip_prefix("core/").unwrap_or(&self.name)
+    }
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<!-- wp:{} ", self.short_name())?;
+        if !self.attrs.is_empty() {
+            write!(f, "{} ", serialize_attrs(&self.attrs))?;
+        }
+        if self.inner.is_empty() {
+            return f.write_str("/-->");
+        }
+        f.write_str("-->")?;
+        for node in &self.inner {
+            node.fmt(f)?;
+        }
+        write!(f, "<!-- /wp:{} -->", self.short_name())
+    }
+}
+
+/// Parse raw content into its blocks and the HTML between them.
+pub fn parse(content: &str) -> Vec<Node> {
+    let mut top = Vec::new();
+    let mut open: Vec<Block> = Vec::new();
+    let mut rest = content;
+
+    while let Some(start) = rest.find("<!--") {
+        let Some((delimiter, len)) = delimiter(&rest[start..]) else {
+            let skipped = start + "<!--".len();
+            push_html(current(&mut top, &mut open), &rest[..skipped]);
+            rest = &rest[skipped..];
+            continue;
+        };
+        if delimiter.kind == Kind::Closer && open.is_empty() {
+            let skipped = start + len;
+            push_html(&mut top, &rest[..skipped]);
+            rest = &rest[skipped..];
+            continue;
+        }
+
+        push_html(current(&mut top, &mut open), &rest[..start]);
+        rest = &rest[start + len..];
+        match delimiter.kind {
+            Kind::Opener => open.push(delimiter.block),
+            Kind::Void => current(&mut top, &mut open).push(Node::Block(delimiter.block)),
+            Kind::Closer => {
+                // As in WordPress, the name of a closer is not checked.
+                let block = open.pop().expect("a block is open");
+                current(&mut top, &mut open).push(Node::Block(block));
+            }
+        }
+    }
+    push_html(current(&mut top, &mut open), rest);
+
+    while let Some(block) = open.pop() {
+        current(&mut top, &mut open).push(Node::Block(block));
+    }
+    top
+}
+
+/// Serialize blocks and HTML back into raw content.
+///
+/// Content which parsed without malformed delimiters is serialized as it
+/// was, except that attributes are written without whitespace and with their
+/// keys sorted, and that blocks without content are written with a void
+/// delimiter, as WordPress does: `<!-- wp:spacer --><!-- /wp:spacer -->`
+/// becomes `<!-- wp:spacer /-->`.
+pub fn serialize(nodes: &[Node]) -> String {
+    nodes.iter().map(Node::to_string).collect()
+}
+
+/// The nodes the next node is added to.
+fn current<'a>(top: &'a mut Vec<Node>, open: &'a mut [Block]) -> &'a mut Vec<Node> {
+    match open.last_mut() {
+        Some(block) => &mut block.inner,
+        None => top,
+    }
+}
+
+/// Add HTML to nodes, joining it with any HTML they end with.
+fn push_html(nodes: &mut Vec<Node>, html: &str) {
+    if html.is_empty() {
+        return;
+    }
+    match nodes.last_mut() {
+        Some(Node::Html(last)) => last.push_str(html),
+        _ => nodes.push(Node::Html(html.to_owned())),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Kind {
+    Opener,
+    Closer,
+    Void,
+}
+
+struct Delimiter {
+    kind: Kind,
+    block: Block,
+}
+
+/// Read a block delimiter at the start of the input, along with its length.
+fn delimiter(input: &str) -> Option<(Delimiter, usize)> {
+    let mut rest = input.strip_prefix("<!--")?;
+    rest = skip_whitespace(rest)?;
+    let closer = match rest.strip_prefix('/') {
+        Some(after) => {
+            rest = after;
+            true
+        }
+        None => false,
+    };
+    rest = rest.strip_prefix("wp:")?;
+
+    let start = rest;
+    rest = name_part(rest)?;
+    let namespaced = match rest.strip_prefix('/').and_then(name_part) {
+        Some(after) => {
+            rest = after;
+            true
+        }
+        None => false,
+    };
+    let name = &start[..start.len() - rest.len()];
+    rest = skip_whitespace(rest)?;
+
+    let mut attrs = Map::new();
+    if rest.starts_with('{') {
+        let (json, after) = attrs_json(rest)?;
+        if let Ok(Value::Object(map)) = serde_json::from_str(json) {
+            attrs = map;
+        }
+        rest = after;
+    }
+
+    let void = match rest.strip_prefix('/') {
+        Some(after) => {
+            rest = after;
+            true
+        }
+        None => false,
+    };
+    rest = rest.strip_prefix("-->")?;
+
+    let kind = match (closer, void) {
+        (true, _) => Kind::Closer,
+        (false, true) => Kind::Void,
+        (false, false) => Kind::Opener,
+    };
+    let name = if namespaced {
+        name.to_owned()
+    } else {
+        format!("core/{}", name)
+    };
+    let block = Block {
+        name,
+        attrs,
+        inner: Vec::new(),
+    };
+    Some((Delimiter { kind, block }, input.len() - rest.len()))
+}
+
+/// Skip a part of a block name, `[a-z][a-z0-9_-]*`.
+fn name_part(input: &str) -> Option<&str> {
+    if !input.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return None;
+    }
+    let end = input
+        .find(|c: char| !matches!(c, 'a'..='z' | '0'..='9' | '_' | '-'))
+        .unwrap_or(input.len());
+    Some(&input[end..])
+}
+
+/// Skip at least one whitespace character.
+fn skip_whitespace(input: &str) -> Option<&str> {
+    let rest = input.trim_start();
+    (rest.len() < input.len()).then_some(rest)
+}
+
+/// Read the JSON attributes of a delimiter, which end at the first `}`
+/// followed by whitespace and the end of the delimiter, returning the rest
+/// after that whitespace.
+fn attrs_json(input: &str) -> Option<(&str, &str)> {
+    input.match_indices('}').find_map(|(end, _)| {
+        let after = skip_whitespace(&input[end + 1..])?;
+        let closes = after.starts_with("-->") || after.starts_with("/-->");
+        closes.then(|| (&input[..=end], after))
+    })
+}
+
+/// Serialize attributes as WordPress does, escaping what could end the
+/// comment or be mangled by HTML filters.
+fn serialize_attrs(attrs: &Map<String, Value>) -> String {
+    serde_json::to_string(attrs)
+        .expect("JSON values serialize")
+        .replace("--", "\\u002d\\u002d")
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+        .replace("\\\"", "\\u0022")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    const CONTENT: &str = "<!-- wp:heading {\"level\":3} -->\n<h3>Hi</h3>\n<!-- /wp:heading -->\n\n\
+        <!-- wp:columns -->\n<div class=\"wp-block-columns\">\
+        <!-- wp:column -->\n<div class=\"wp-block-column\"><!-- wp:acme/chart {\"id\":7} /--></div>\n<!-- /wp:column -->\
+        </div>\n<!-- /wp:columns -->";
+
+    #[test]
+    fn blocks() {
+        let nodes = parse(CONTENT);
+
+        assert_eq!(nodes.len(), 3);
+        let heading = nodes[0].as_block().unwrap();
+        assert_eq!(heading.name, "core/heading");
+        assert_eq!(Value::Object(heading.attrs.clone()), json!({ "level": 3 }));
+        assert_eq!(heading.inner_html(), "\n<h3>Hi</h3>\n");
+        assert_eq!(nodes[1], Node::Html("\n\n".into()));
+
+        let columns = nodes[2].as_block().unwrap();
+        let column = columns.inner_blocks().next().unwrap();
+        assert_eq!(column.name, "core/column");
+        let chart = column.inner_blocks().next().unwrap();
+        assert_eq!(chart, &Block::new("acme/chart").attr("id", 7),);
+        assert_eq!(
+            columns.inner_html(),
+            "\n<div class=\"wp-block-columns\"></div>\n"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(serialize(&parse(CONTENT)), CONTENT);
+    }
+
+    #[test]
+    fn empty_block() {
+        let content = "<!-- wp:spacer {\"height\":\"10px\"} --><!-- /wp:spacer -->";
+
+        let serialized = serialize(&parse(content));
+
+        assert_eq!(serialized, "<!-- wp:spacer {\"height\":\"10px\"} /-->");
+        assert_eq!(parse(&serialized), parse(content));
+    }
+
+    #[test]
+    fn build() {
+        let block = Block::new("paragraph")
+            .attr("className", "x--y")
+            .push("<p>Hi & bye</p>");
+
+        assert_eq!(
+            block.to_string(),
+            "<!-- wp:paragraph {\"className\":\"x\\u002d\\u002dy\"} --><p>Hi & bye</p><!-- /wp:paragraph -->"
+        );
+        assert_eq!(Block::new("spacer").to_string(), "<!-- wp:spacer /-->");
+    }
+
+    #[test]
+    fn escaped_attrs() {
+        let content =
+            r#"<!-- wp:html {"a":"\u003cb\u003e \u0022x\u0022 } \u002d\u002d\u003e"} /-->"#;
+
+        let nodes = parse(content);
+
+        let block = nodes[0].as_block().unwrap();
+        assert_eq!(block.attrs["a"], "<b> \"x\" } -->");
+        assert_eq!(block.to_string(), content);
+    }
+
+    #[test]
+    fn malformed() {
+        let content = "<!-- a comment --><p>A</p><!-- /wp:paragraph --><!-- wp:Bad -->\
+            <!-- wp:quote --><p>B";
+
+        let nodes = parse(content);
+
+        assert_eq!(
+            nodes,
+            vec![
+                Node::Html(
+                    "<!-- a comment --><p>A</p><!-- /wp:paragraph --><!-- wp:Bad -->".into()
+                ),
+                Node::Block(Block::new("quote").push("<p>B")),
+            ]
+        );
+    }
+
+    #[test]
+    fn freeform() {
+        assert_eq!(parse("<p>Classic</p>"), vec![Node::from("<p>Classic</p>")]);
+        assert!(parse("").is_empty());
+    }
+}
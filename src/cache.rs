@@ -0,0 +1,462 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bytes::Bytes;
+use http::{
+    header::{self, HeaderName, HeaderValue},
+    HeaderMap, Method, Request, Response, StatusCode, Uri,
+};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use url::Url;
+
+use crate::{
+    auth::Auth,
+    error::ApiError,
+    middleware::{Middleware, Next},
+};
+
+struct Entry {
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+/// An entry as it is stored on disk.
+#[derive(Serialize, Deserialize)]
+struct StoredEntry {
+    /// The key of the entry, to tell apart keys with the same hash.
+    key: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    headers: Vec<(String, String)>,
+    /// The base64 encoded body.
+    body: String,
+}
+
+/// Response headers which are not written to disk, as they hold credentials.
+const SENSITIVE_HEADERS: [HeaderName; 4] = [
+    header::SET_COOKIE,
+    header::WWW_AUTHENTICATE,
+    header::PROXY_AUTHENTICATE,
+    HeaderName::from_static("x-wp-nonce"),
+];
+
+impl Entry {
+    fn store(&self, key: &str) -> StoredEntry {
+        let text = |value: &HeaderValue| value.to_str().ok().map(str::to_owned);
+        StoredEntry {
+            key: key.to_owned(),
+            etag: self.etag.as_ref().and_then(text),
+            last_modified: self.last_modified.as_ref().and_then(text),
+            headers: self
+                .headers
+                .iter()
+                .filter(|(name, value)| !value.is_sensitive() && !SENSITIVE_HEADERS.contains(name))
+                .filter_map(|(name, value)| Some((name.to_string(), text(value)?)))
+                .collect(),
+            body: STANDARD.encode(&self.body),
+        }
+    }
+
+    fn load(stored: StoredEntry) -> Option<Self> {
+        let value = |text: String| HeaderValue::try_from(text).ok();
+        let mut headers = HeaderMap::new();
+        for (name, text) in stored.headers {
+            headers.append(HeaderName::try_from(name).ok()?, value(text)?);
+        }
+        Some(Self {
+            etag: stored.etag.and_then(value),
+            last_modified: stored.last_modified.and_then(value),
+            headers,
+            body: STANDARD.decode(stored.body).ok()?.into(),
+        })
+    }
+}
+
+/// Middleware which caches responses in memory and revalidates them using
+/// conditional requests.
+///
+/// Successful `GET` responses with an `ETag` or `Last-Modified` header are
+/// stored per URL. Later requests for the same URL send `If-None-Match` or
+/// `If-Modified-Since`, and the stored response is returned when WordPress
+/// answers with `304 Not Modified`.
+///
+/// Responses are cached by URL alone, with the query parameters in any
+/// order, so parameters such as `_fields` and `context` are part of the key.
+/// A cache should not be shared between clients using different credentials.
+/// Requests using credentials other than the client's defaults are never
+/// cached.
+///
+/// A [persistent](Self::persistent) cache also stores the responses in a
+/// directory, so that tools which are run again against the same site only
+/// receive the resources which changed since the last run.
+#[derive(Default)]
+pub struct ConditionalCache {
+    entries: Mutex<HashMap<String, Entry>>,
+    dir: Option<PathBuf>,
+}
+
+impl ConditionalCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a cache which stores responses in a directory, creating the
+    /// directory if needed.
+    ///
+    /// Responses stored by earlier caches using the same directory are loaded
+    /// as they are requested. Reading and writing the directory is best
+    /// effort: a response which cannot be stored is only cached in memory.
+    /// Headers holding credentials, such as `Set-Cookie` and `X-WP-Nonce`,
+    /// are not stored.
+    pub fn persistent(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            entries: Mutex::default(),
+            dir: Some(dir),
+        })
+    }
+
+    /// The number of cached responses in memory, which for a persistent cache
+    /// does not include responses on disk which have not been requested yet.
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+
+    /// Remove all cached responses, including those stored on disk.
+    pub fn clear(&self) {
+        self.entries().clear();
+        if let Some(dir) = &self.dir {
+            if let Ok(files) = fs::read_dir(dir) {
+                for file in files.flatten() {
+                    let path = file.path();
+                    if path.extension().is_some_and(|ext| ext == "json") {
+                        let _ = fs::remove_file(path);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Load an entry from disk into memory, unless it is already there.
+    fn load(&self, key: &str) {
+        let Some(path) = self.path(key) else {
+            return;
+        };
+        if self.entries().contains_key(key) {
+            return;
+        }
+        let entry = fs::read(path)
+            .ok()
+            .and_then(|data| serde_json::from_slice::<StoredEntry>(&data).ok())
+            .filter(|stored| stored.key == key)
+            .and_then(Entry::load);
+        if let Some(entry) = entry {
+            self.entries().entry(key.to_owned()).or_insert(entry);
+        }
+    }
+
+    /// Write an entry to disk, replacing the stored entry at once so that it
+    /// is never read partially written.
+    fn store(&self, key: &str, entry: &Entry) {
+        let Some(path) = self.path(key) else {
+            return;
+        };
+        let write = |path: &Path| -> io::Result<()> {
+            let data = serde_json::to_vec(&entry.store(key))?;
+            let tmp = path.with_extension(format!("{}.tmp", crate::random::random_hex()));
+            fs::write(&tmp, data)?;
+            fs::rename(&tmp, path).inspect_err(|_| {
+                let _ = fs::remove_file(&tmp);
+            })
+        };
+        let _ = write(&path);
+    }
+
+    fn remove(&self, key: &str) {
+        if let Some(path) = self.path(key) {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    /// The file storing the entry for a key.
+    fn path(&self, key: &str) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+        let hash = Sha1::digest(key.as_bytes());
+        let name: String = hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+        Some(dir.join(name).with_extension("json"))
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>> {
+        self.entries.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[async_trait]
+impl<E> Middleware<E> for ConditionalCache
+where
+    E: Error + Send + Sync + 'static,
+{
+    async fn handle(
+        &self,
+        mut request: Request<Vec<u8>>,
+        next: Next<'_, E>,
+    ) -> Result<Response<Bytes>, ApiError<E>> {
+        let conditional = request.headers().contains_key(header::IF_NONE_MATCH)
+            || request.headers().contains_key(header::IF_MODIFIED_SINCE);
+        if request.method() != Method::GET
+            || conditional
+            || request.extensions().get::<Auth>().is_some()
+        {
+            return next.run(request).await;
+        }
+
+        let key = cache_key(request.uri());
+        self.load(&key);
+        if let Some(entry) = self.entries().get(&key) {
+            let headers = request.headers_mut();
+            if let Some(etag) = &entry.etag {
+                headers.insert(header::IF_NONE_MATCH, etag.clone());
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                headers.insert(header::IF_MODIFIED_SINCE, last_modified.clone());
+            }
+        }
+
+        let resp = next.run(request).await?;
+
+        let mut entries = self.entries();
+        if resp.status() == StatusCode::NOT_MODIFIED {
+            if let Some(entry) = entries.get(&key) {
+                let mut cached = Response::new(entry.body.clone());
+                *cached.headers_mut() = entry.headers.clone();
+                return Ok(cached);
+            }
+        } else if resp.status() == StatusCode::OK {
+            let etag = resp.headers().get(header::ETAG).cloned();
+            let last_modified = resp.headers().get(header::LAST_MODIFIED).cloned();
+            if etag.is_some() || last_modified.is_some() {
+                let entry = Entry {
+                    etag,
+                    last_modified,
+                    headers: resp.headers().clone(),
+                    body: resp.body().clone(),
+                };
+                drop(entries);
+                self.store(&key, &entry);
+                self.entries().insert(key, entry);
+            } else if entries.remove(&key).is_some() {
+                drop(entries);
+                self.remove(&key);
+            }
+        }
+        Ok(resp)
+    }
+}
+
+/// The key of the responses for a URL, which does not depend on the order of
+/// its query parameters.
+fn cache_key(uri: &Uri) -> String {
+    let Ok(mut url) = Url::parse(&uri.to_string()) else {
+        return uri.to_string();
+    };
+    let mut pairs: Vec<_> = url.query_pairs().into_owned().collect();
+    if pairs.is_empty() {
+        return url.into();
+    }
+    pairs.sort();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    url.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{middleware::BoxFuture, test::MockClientError};
+
+    fn request() -> Request<Vec<u8>> {
+        Request::builder()
+            .uri("http://example.com/wp-json/wp/v2/posts")
+            .body(Vec::new())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn revalidate() {
+        let cache = Arc::new(ConditionalCache::new());
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> = vec![cache.clone()];
+        let endpoint = |request: Request<Vec<u8>>| -> BoxFuture<'_, _> {
+            Box::pin(async move {
+                let resp = match request.headers().get(header::IF_NONE_MATCH) {
+                    Some(etag) => {
+                        assert_eq!(etag, "\"v1\"");
+                        Response::builder()
+                            .status(StatusCode::NOT_MODIFIED)
+                            .body(Bytes::new())
+                    }
+                    None => Response::builder()
+                        .header(header::ETAG, "\"v1\"")
+                        .body(Bytes::from_static(b"[]")),
+                };
+                Ok(resp.unwrap())
+            })
+        };
+
+        let first = Next::new(&middleware, &endpoint)
+            .run(request())
+            .await
+            .unwrap();
+        let second = Next::new(&middleware, &endpoint)
+            .run(request())
+            .await
+            .unwrap();
+
+        assert_eq!(first.body(), "[]");
+        assert_eq!(second.status(), StatusCode::OK);
+        assert_eq!(second.body(), "[]");
+        assert_eq!(second.headers()[header::ETAG], "\"v1\"");
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn query_auth() {
+        let cache = Arc::new(ConditionalCache::new());
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> = vec![cache.clone()];
+        let endpoint = |request: Request<Vec<u8>>| -> BoxFuture<'_, _> {
+            Box::pin(async move {
+                assert!(!request.headers().contains_key(header::IF_NONE_MATCH));
+                Ok(Response::builder()
+                    .header(header::ETAG, "\"v1\"")
+                    .body(Bytes::from_static(b"[]"))
+                    .unwrap())
+            })
+        };
+        let authenticated = || {
+            let mut request = request();
+            request.extensions_mut().insert(Auth::bearer("secret"));
+            request
+        };
+
+        for _ in 0..2 {
+            Next::new(&middleware, &endpoint)
+                .run(authenticated())
+                .await
+                .unwrap();
+        }
+
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn uncacheable() {
+        let cache = Arc::new(ConditionalCache::new());
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> = vec![cache.clone()];
+        let endpoint = |request: Request<Vec<u8>>| -> BoxFuture<'_, _> {
+            Box::pin(async move {
+                assert!(!request.headers().contains_key(header::IF_MODIFIED_SINCE));
+                Ok(Response::new(Bytes::from_static(b"[]")))
+            })
+        };
+
+        for _ in 0..2 {
+            Next::new(&middleware, &endpoint)
+                .run(request())
+                .await
+                .unwrap();
+        }
+
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn persistent() {
+        let dir =
+            std::env::temp_dir().join(format!("wordprs-cache-{}", crate::random::random_hex()));
+        let endpoint = |request: Request<Vec<u8>>| -> BoxFuture<'_, _> {
+            Box::pin(async move {
+                let resp = match request.headers().get(header::IF_NONE_MATCH) {
+                    Some(etag) => {
+                        assert_eq!(etag, "\"v1\"");
+                        Response::builder()
+                            .status(StatusCode::NOT_MODIFIED)
+                            .body(Bytes::new())
+                    }
+                    None => Response::builder()
+                        .header(header::ETAG, "\"v1\"")
+                        .header("x-wp-total", "1")
+                        .header(header::SET_COOKIE, "wordpress_logged_in=secret")
+                        .header("x-wp-nonce", "abc123")
+                        .body(Bytes::from_static(b"[{\"id\":1}]")),
+                };
+                Ok(resp.unwrap())
+            })
+        };
+
+        let first = Arc::new(ConditionalCache::persistent(&dir).unwrap());
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> = vec![first.clone()];
+        Next::new(&middleware, &endpoint)
+            .run(request())
+            .await
+            .unwrap();
+
+        let stored = fs::read_dir(&dir).unwrap().next().unwrap().unwrap().path();
+        let stored = fs::read_to_string(stored).unwrap();
+        assert!(!stored.contains("secret") && !stored.contains("abc123"));
+
+        let second = Arc::new(ConditionalCache::persistent(&dir).unwrap());
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> = vec![second.clone()];
+        assert!(second.is_empty());
+        let resp = Next::new(&middleware, &endpoint)
+            .run(request())
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.body(), "[{\"id\":1}]");
+        assert_eq!(resp.headers()["x-wp-total"], "1");
+        assert!(!resp.headers().contains_key(header::SET_COOKIE));
+        assert_eq!(second.len(), 1);
+
+        second.clear();
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn key_ignores_parameter_order() {
+        let key = |uri: &str| cache_key(&uri.parse().unwrap());
+
+        assert_eq!(
+            key("http://example.com/wp-json/wp/v2/posts?context=edit&_fields=id%2Ctitle"),
+            key("http://example.com/wp-json/wp/v2/posts?_fields=id%2Ctitle&context=edit")
+        );
+        assert_ne!(
+            key("http://example.com/wp-json/wp/v2/posts?context=edit"),
+            key("http://example.com/wp-json/wp/v2/posts?context=view")
+        );
+        assert_eq!(
+            key("http://example.com/wp-json/wp/v2/posts"),
+            "http://example.com/wp-json/wp/v2/posts"
+        );
+    }
+}
@@ -2,10 +2,17 @@ use std::error::Error;
 
 use async_trait::async_trait;
 use bytes::Bytes;
-use http::{Method, Request, Response};
+use futures_util::{
+    stream::{self, BoxStream, StreamExt},
+    TryStreamExt,
+};
+use http::{header, Method, Request, Response};
 use url::Url;
 
-use crate::{error::ApiError, root::RootRoute};
+use crate::{
+    error::{ApiError, BodyError},
+    root::RootRoute,
+};
 
 macro_rules! regex {
     ($re:literal $(,)?) => {{
@@ -14,6 +21,12 @@ macro_rules! regex {
     }};
 }
 
+/// The body of a response, received as a stream of chunks.
+pub type BodyStream<E> = BoxStream<'static, Result<Bytes, ApiError<E>>>;
+
+/// The body of a request, sent as a stream of chunks.
+pub type RequestBodyStream = BoxStream<'static, Result<Bytes, std::io::Error>>;
+
 /// A trait representing a client that can communicate with a WordPress
 /// instance.
 #[async_trait]
@@ -33,8 +46,59 @@ pub trait Client {
         request: Request<Vec<u8>>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
 
+    /// Send an HTTP request, returning the body as it is received.
+    ///
+    /// Clients which can only return the full body, which is the default,
+    /// return it as a single chunk.
+    async fn send_request_streamed(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        let resp = self.send_request(request).await?;
+        Ok(resp.map(|body| stream::once(async { Ok(body) }).boxed()))
+    }
+
+    /// Send an HTTP request, sending the body as it is read.
+    ///
+    /// Clients which can only send the full body, which is the default, read
+    /// it into memory first.
+    async fn send_streaming_request(
+        &self,
+        request: Request<RequestBodyStream>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let (parts, body) = request.into_parts();
+        let body: Vec<u8> = body
+            .map_ok(|chunk| chunk.to_vec())
+            .try_concat()
+            .await
+            .map_err(BodyError::from)?;
+        self.send_request(Request::from_parts(parts, body)).await
+    }
+
+    /// Send a request to a URL which may be outside the site, such as a file
+    /// to download, returning the body as it is received.
+    ///
+    /// Requests to other hosts are sent without the client's credentials or
+    /// middleware. Clients which cannot leave them out, which is the default,
+    /// only send requests to the site itself and fail with
+    /// [`ApiError::ForeignLink`] otherwise.
+    async fn send_external_request(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        if !is_site_request(self, &request).await? {
+            return Err(ApiError::foreign_link(Url::parse(
+                &request.uri().to_string(),
+            )?));
+        }
+        self.send_request_streamed(request).await
+    }
+
     /// Discover the API root route for a WordPress instance.
-    #[tracing::instrument(level = "debug", skip(self), fields(url = %url.as_ref()))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(url = %url.as_ref()))
+    )]
     async fn discover_root_route(
         &self,
         url: impl AsRef<str> + Send + 'async_trait,
@@ -47,8 +111,16 @@ pub trait Client {
             .map(|url| url.into())
     }
 
-    /// Resource discovery.
-    #[tracing::instrument(level = "debug", skip(self), fields(url = %url.as_ref()))]
+    /// Discover the API URL of the resource displayed on a web page.
+    ///
+    /// Redirects within the site are followed. Fails with
+    /// [`ApiError::ResourceDiscovery`] if the page does not link to a
+    /// resource, and with [`ApiError::DiscoveryStatus`] if the page responds
+    /// with an error.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(url = %url.as_ref()))
+    )]
     async fn discover_resource(
         &self,
         url: impl AsRef<str> + Send + 'async_trait,
@@ -61,6 +133,34 @@ pub trait Client {
     }
 }
 
+/// Whether two URLs have the same scheme, host and port.
+pub(crate) fn same_origin(url: &Url, root: &Url) -> bool {
+    url.scheme() == root.scheme()
+        && url.host_str() == root.host_str()
+        && url.port_or_known_default() == root.port_or_known_default()
+}
+
+/// Whether a request is sent to the site itself rather than another host.
+pub(crate) async fn is_site_request<C, B>(
+    client: &C,
+    request: &Request<B>,
+) -> Result<bool, ApiError<C::Error>>
+where
+    C: Client + ?Sized,
+{
+    let url = Url::parse(&request.uri().to_string())?;
+    Ok(same_origin(&url, &client.route_url("/").await?))
+}
+
+/// The maximum number of redirects followed during discovery.
+const MAX_REDIRECTS: usize = 5;
+
+/// Whether a redirect may be followed without sending credentials elsewhere.
+fn same_site(from: &Url, to: &Url) -> bool {
+    from.host_str() == to.host_str()
+        && (from.scheme() == to.scheme() || (from.scheme() == "http" && to.scheme() == "https"))
+}
+
 async fn link_header_discovery<C>(
     client: &C,
     url: &Url,
@@ -69,13 +169,36 @@ async fn link_header_discovery<C>(
 where
     C: Client + ?Sized,
 {
-    let req = Request::builder()
-        .method(Method::HEAD)
-        .uri(url.as_str())
-        .body(Vec::new())
-        .map_err(ApiError::request)?;
+    let mut url = url.clone();
+    let mut redirects = 0;
+    let resp = loop {
+        let req = Request::builder()
+            .method(Method::HEAD)
+            .uri(url.as_str())
+            .body(Vec::new())
+            .map_err(ApiError::request)?;
 
-    let resp = client.send_request(req).await?;
+        let resp = client.send_request(req).await?;
+        let location = resp
+            .headers()
+            .get(header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| url.join(location).ok());
+        match location {
+            Some(location)
+                if resp.status().is_redirection()
+                    && redirects < MAX_REDIRECTS
+                    && same_site(&url, &location) =>
+            {
+                url = location;
+                redirects += 1;
+            }
+            _ => break resp,
+        }
+    };
+    if !resp.status().is_success() {
+        return Err(ApiError::discovery_status(url, resp.status()));
+    }
 
     for header in resp.headers().get_all("link") {
         let header = if let Ok(header) = header.to_str() {
@@ -94,7 +217,7 @@ where
 
         let link = captures.get(1).expect("missing capture group").as_str();
 
-        return Ok(Some(Url::parse(link)?.into()));
+        return Ok(Some(Url::parse(link)?));
     }
 
     Ok(None)
@@ -0,0 +1,229 @@
+use std::{error::Error, sync::Arc};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{
+    header::{self, HeaderValue},
+    Request, Response,
+};
+
+use crate::{
+    error::ApiError,
+    middleware::{Middleware, Next},
+};
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+/// An HTTP content coding, such as `gzip` or `br`.
+///
+/// With the `gzip` and `brotli` features, [`WordPress`](crate::WordPress)
+/// requests and decompresses those codings itself. Implement this trait with
+/// a compression library for other codings, other clients or to compress
+/// request bodies.
+pub trait Codec: Send + Sync {
+    /// The content coding token used in `Accept-Encoding` and
+    /// `Content-Encoding` headers.
+    fn encoding(&self) -> &str;
+
+    /// Compress data.
+    fn encode(&self, data: &[u8]) -> Result<Vec<u8>, BoxError>;
+
+    /// Decompress data.
+    fn decode(&self, data: &[u8]) -> Result<Vec<u8>, BoxError>;
+}
+
+/// Middleware which requests compressed responses and decompresses them
+/// transparently.
+///
+/// Request bodies may also be compressed, although many servers do not accept
+/// compressed request bodies so this must be enabled explicitly.
+#[derive(Clone, Default)]
+pub struct Compression {
+    codecs: Vec<Arc<dyn Codec>>,
+    min_upload_size: Option<usize>,
+}
+
+impl Compression {
+    /// Create a middleware without any codecs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept responses using a codec. Codecs are listed in
+    /// `Accept-Encoding` in the order they are added.
+    pub fn codec(mut self, codec: impl Codec + 'static) -> Self {
+        self.codecs.push(Arc::new(codec));
+        self
+    }
+
+    /// Compress request bodies of at least `min_size` bytes using the first
+    /// codec.
+    pub fn compress_uploads(mut self, min_size: usize) -> Self {
+        self.min_upload_size = Some(min_size);
+        self
+    }
+
+    fn find(&self, encoding: &str) -> Option<&dyn Codec> {
+        self.codecs
+            .iter()
+            .find(|codec| codec.encoding().eq_ignore_ascii_case(encoding))
+            .map(|codec| codec.as_ref())
+    }
+
+    fn compress(&self, request: &mut Request<Vec<u8>>) -> Result<(), BoxError> {
+        let (min_size, codec) = match (self.min_upload_size, self.codecs.first()) {
+            (Some(min_size), Some(codec)) => (min_size, codec),
+            _ => return Ok(()),
+        };
+        if request.body().len() < min_size
+            || request.headers().contains_key(header::CONTENT_ENCODING)
+        {
+            return Ok(());
+        }
+        let body = codec.encode(request.body())?;
+        *request.body_mut() = body;
+        request.headers_mut().insert(
+            header::CONTENT_ENCODING,
+            HeaderValue::from_str(codec.encoding())?,
+        );
+        Ok(())
+    }
+
+    fn decompress(&self, resp: &mut Response<Bytes>) -> Result<(), BoxError> {
+        let encodings = match resp.headers().get(header::CONTENT_ENCODING) {
+            Some(value) => value.to_str()?.to_string(),
+            None => return Ok(()),
+        };
+        // Codings are listed in the order they were applied.
+        let mut codecs = Vec::new();
+        for encoding in encodings
+            .split(',')
+            .map(str::trim)
+            .filter(|e| !e.is_empty())
+        {
+            if encoding.eq_ignore_ascii_case("identity") {
+                continue;
+            }
+            match self.find(encoding) {
+                Some(codec) => codecs.push(codec),
+                // Leave responses in unknown codings untouched.
+                None => return Ok(()),
+            }
+        }
+        let mut body = resp.body().to_vec();
+        for codec in codecs.iter().rev() {
+            body = codec.decode(&body)?;
+        }
+        *resp.body_mut() = body.into();
+        let headers = resp.headers_mut();
+        headers.remove(header::CONTENT_ENCODING);
+        headers.remove(header::CONTENT_LENGTH);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<E> Middleware<E> for Compression
+where
+    E: Error + Send + Sync + 'static,
+{
+    async fn handle(
+        &self,
+        mut request: Request<Vec<u8>>,
+        next: Next<'_, E>,
+    ) -> Result<Response<Bytes>, ApiError<E>> {
+        if !self.codecs.is_empty() && !request.headers().contains_key(header::ACCEPT_ENCODING) {
+            let accept = self
+                .codecs
+                .iter()
+                .map(|codec| codec.encoding())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let accept = HeaderValue::from_str(&accept).map_err(ApiError::compression)?;
+            request
+                .headers_mut()
+                .insert(header::ACCEPT_ENCODING, accept);
+        }
+        self.compress(&mut request).map_err(ApiError::compression)?;
+
+        let mut resp = next.run(request).await?;
+        self.decompress(&mut resp).map_err(ApiError::compression)?;
+        Ok(resp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{middleware::BoxFuture, test::MockClientError};
+
+    /// Reverses the data, which is enough to tell whether it was coded.
+    struct Reverse;
+
+    impl Codec for Reverse {
+        fn encoding(&self) -> &str {
+            "x-reverse"
+        }
+
+        fn encode(&self, data: &[u8]) -> Result<Vec<u8>, BoxError> {
+            Ok(data.iter().rev().copied().collect())
+        }
+
+        fn decode(&self, data: &[u8]) -> Result<Vec<u8>, BoxError> {
+            self.encode(data)
+        }
+    }
+
+    async fn run(
+        compression: Compression,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<Bytes>, ApiError<MockClientError>> {
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> = vec![Arc::new(compression)];
+        let endpoint = |request: Request<Vec<u8>>| -> BoxFuture<'_, _> {
+            Box::pin(async move {
+                assert_eq!(request.headers()[header::ACCEPT_ENCODING], "x-reverse");
+                let compressed = request.headers().contains_key(header::CONTENT_ENCODING);
+                let body = if compressed {
+                    Reverse.decode(request.body()).unwrap()
+                } else {
+                    request.body().clone()
+                };
+                Ok(Response::builder()
+                    .header(header::CONTENT_ENCODING, "x-reverse")
+                    .header("x-compressed", compressed.to_string())
+                    .body(Reverse.encode(&body).unwrap().into())
+                    .unwrap())
+            })
+        };
+        Next::new(&middleware, &endpoint).run(request).await
+    }
+
+    #[tokio::test]
+    async fn decompress() {
+        let resp = run(
+            Compression::new().codec(Reverse),
+            Request::new(b"{\"id\":1}".to_vec()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(resp.body(), "{\"id\":1}");
+        assert_eq!(resp.headers()["x-compressed"], "false");
+        assert!(!resp.headers().contains_key(header::CONTENT_ENCODING));
+    }
+
+    #[tokio::test]
+    async fn compress_uploads() {
+        let resp = run(
+            Compression::new().codec(Reverse).compress_uploads(4),
+            Request::new(b"{\"id\":1}".to_vec()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(resp.body(), "{\"id\":1}");
+        assert_eq!(resp.headers()["x-compressed"], "true");
+    }
+}
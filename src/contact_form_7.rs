@@ -0,0 +1,15 @@
+//! Endpoints and models for Contact Form 7.
+//!
+//! The endpoints use the `contact-form-7/v1` namespace. Managing contact
+//! forms needs the capabilities to edit them, while feedback is submitted
+//! anonymously in the same way as the form on the site.
+
+pub use contact_forms::{
+    CreateContactForm, DeleteContactForm, ListContactForms, RetrieveContactForm, UpdateContactForm,
+};
+pub use feedback::SubmitFeedback;
+pub use types::{ContactForm, Feedback, InvalidField};
+
+pub mod contact_forms;
+pub mod feedback;
+pub mod types;
@@ -0,0 +1,11 @@
+pub use create::CreateContactForm;
+pub use delete::DeleteContactForm;
+pub use list::ListContactForms;
+pub use retrieve::RetrieveContactForm;
+pub use update::UpdateContactForm;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod retrieve;
+pub mod update;
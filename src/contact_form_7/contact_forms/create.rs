@@ -0,0 +1,97 @@
+use derive_builder::Builder;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::endpoint::prelude::*;
+
+/// Create a contact form.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateContactForm {
+    /// The title of the contact form.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+
+    /// The locale of the contact form, such as `en_US`.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+
+    /// The form template, such as `[email* your-email] [submit "Send"]`.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    form: Option<String>,
+
+    /// The mail settings, with fields such as `recipient`, `subject` and
+    /// `body`.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail: Option<Value>,
+
+    /// The settings of the second mail, with the same fields as `mail` and
+    /// `active`.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail_2: Option<Value>,
+
+    /// The messages shown to the sender, by name.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    messages: Option<Value>,
+
+    /// Additional settings, one per line.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    additional_settings: Option<String>,
+}
+
+impl CreateContactForm {
+    pub fn builder() -> CreateContactFormBuilder {
+        CreateContactFormBuilder::default()
+    }
+}
+
+impl Endpoint for CreateContactForm {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/contact-form-7/v1/contact-forms".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+
+    #[test]
+    fn body() {
+        let endpoint = CreateContactForm::builder()
+            .title("Contact")
+            .form("[email* your-email] [submit \"Send\"]")
+            .mail(json!({ "recipient": "admin@example.com", "subject": "Hello" }))
+            .build()
+            .unwrap();
+
+        let (_, body) = endpoint.body().unwrap().unwrap();
+        let body: Json = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            body,
+            json!({
+                "title": "Contact",
+                "form": "[email* your-email] [submit \"Send\"]",
+                "mail": { "recipient": "admin@example.com", "subject": "Hello" },
+            })
+        );
+    }
+}
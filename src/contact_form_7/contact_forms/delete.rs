@@ -0,0 +1,55 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Delete a contact form.
+///
+/// Contact forms cannot be trashed, so they are deleted permanently.
+#[derive(Builder)]
+pub struct DeleteContactForm {
+    /// Unique identifier for the contact form.
+    id: u32,
+}
+
+impl DeleteContactForm {
+    pub fn builder() -> DeleteContactFormBuilder {
+        DeleteContactFormBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteContactForm {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/contact-form-7/v1/contact-forms/{}", self.id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = DeleteContactForm::builder().id(10).build().unwrap();
+        let response = MockResponse::builder()
+            .method(Method::DELETE)
+            .route("/contact-form-7/v1/contact-forms/10")
+            .json(json!({ "deleted": true }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Value = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response["deleted"], true);
+    }
+}
@@ -0,0 +1,95 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List contact forms.
+#[derive(Default, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListContactForms {
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Offset the result set by a specific number of items.
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    order: Option<Order>,
+
+    /// Sort collection by object attribute. Defaults to the title.
+    #[builder(setter(into))]
+    orderby: Option<OrderBy>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Limit result set to contact forms in a locale, such as `en_US`.
+    #[builder(setter(into))]
+    locale: Option<String>,
+}
+
+impl ListContactForms {
+    pub fn builder() -> ListContactFormsBuilder {
+        ListContactFormsBuilder::default()
+    }
+}
+
+impl Endpoint for ListContactForms {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/contact-form-7/v1/contact-forms".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("per_page", self.per_page)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_opt("search", self.search.as_ref())
+            .push_opt("locale", self.locale.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        contact_form_7::ContactForm,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn search() {
+        let endpoint = ListContactForms::builder()
+            .search("contact")
+            .per_page(10)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/contact-form-7/v1/contact-forms")
+            .query("per_page=10&search=contact")
+            .json(json!([{
+                "id": 10,
+                "slug": "contact-form-1",
+                "title": "Contact form 1",
+                "locale": "en_US",
+            }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let forms: Vec<ContactForm> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(forms[0].title, "Contact form 1");
+        assert!(forms[0].properties.is_empty());
+    }
+}
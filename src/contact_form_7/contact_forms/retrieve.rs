@@ -0,0 +1,64 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a contact form.
+#[derive(Builder)]
+pub struct RetrieveContactForm {
+    /// Unique identifier for the contact form.
+    id: u32,
+}
+
+impl RetrieveContactForm {
+    pub fn builder() -> RetrieveContactFormBuilder {
+        RetrieveContactFormBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveContactForm {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/contact-form-7/v1/contact-forms/{}", self.id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        contact_form_7::ContactForm,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn typed() {
+        let endpoint = RetrieveContactForm::builder().id(10).build().unwrap();
+        let response = MockResponse::builder()
+            .route("/contact-form-7/v1/contact-forms/10")
+            .json(json!({
+                "id": 10,
+                "slug": "contact-form-1",
+                "title": "Contact form 1",
+                "locale": "en_US",
+                "properties": {
+                    "form": { "content": "[email* your-email]", "fields": [] },
+                    "additional_settings": { "content": "", "settings": [] },
+                },
+                "config_errors": [],
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let form: ContactForm = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(form.properties["form"]["content"], "[email* your-email]");
+    }
+}
@@ -0,0 +1,108 @@
+use derive_builder::Builder;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::endpoint::prelude::*;
+
+/// Update a contact form.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct UpdateContactForm {
+    /// Unique identifier for the contact form.
+    #[serde(skip)]
+    id: u32,
+
+    /// The title of the contact form.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+
+    /// The locale of the contact form, such as `en_US`.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+
+    /// The form template, such as `[email* your-email] [submit "Send"]`.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    form: Option<String>,
+
+    /// The mail settings, with fields such as `recipient`, `subject` and
+    /// `body`.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail: Option<Value>,
+
+    /// The settings of the second mail, with the same fields as `mail` and
+    /// `active`.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail_2: Option<Value>,
+
+    /// The messages shown to the sender, by name.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    messages: Option<Value>,
+
+    /// Additional settings, one per line.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    additional_settings: Option<String>,
+}
+
+impl UpdateContactForm {
+    pub fn builder() -> UpdateContactFormBuilder {
+        UpdateContactFormBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateContactForm {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/contact-form-7/v1/contact-forms/{}", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        contact_form_7::ContactForm,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn title() {
+        let endpoint = UpdateContactForm::builder()
+            .id(10)
+            .title("Support")
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route("/contact-form-7/v1/contact-forms/10")
+            .json(json!({ "id": 10, "title": "Support" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let form: ContactForm = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(form.title, "Support");
+        assert_eq!(
+            endpoint.body().unwrap().unwrap().1,
+            br#"{"title":"Support"}"#
+        );
+    }
+}
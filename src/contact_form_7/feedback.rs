@@ -0,0 +1,215 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, multipart::Form};
+
+/// A file attached to a submission.
+#[derive(Clone)]
+struct FeedbackFile {
+    name: String,
+    filename: String,
+    content_type: String,
+    data: Vec<u8>,
+}
+
+/// Submit a contact form.
+///
+/// The fields are sent as a `multipart/form-data` request, as the form on the
+/// site does. Validation errors, spam and mail failures are not HTTP errors;
+/// they are reported by the status of the [`Feedback`](super::Feedback).
+#[derive(Builder)]
+pub struct SubmitFeedback {
+    /// Unique identifier for the contact form.
+    id: u32,
+
+    /// The unit tag identifying the form on its page, which Contact Form 7
+    /// requires. Defaults to the tag of the first form on a page, such as
+    /// `wpcf7-f10-o1`, or `wpcf7-f10-p2-o1` with a container post.
+    #[builder(setter(into, strip_option), default)]
+    unit_tag: Option<String>,
+
+    /// The ID of the post showing the form.
+    #[builder(setter(strip_option), default)]
+    container_post: Option<u32>,
+
+    /// The locale of the sender, such as `en_US`.
+    #[builder(setter(into, strip_option), default)]
+    locale: Option<String>,
+
+    /// Field values, in order.
+    #[builder(setter(custom), default)]
+    fields: Vec<(String, String)>,
+
+    /// Files for file upload fields.
+    #[builder(setter(custom), default)]
+    files: Vec<FeedbackFile>,
+}
+
+impl SubmitFeedback {
+    pub fn builder() -> SubmitFeedbackBuilder {
+        SubmitFeedbackBuilder::default()
+    }
+
+    fn unit_tag(&self) -> String {
+        if let Some(unit_tag) = &self.unit_tag {
+            return unit_tag.clone();
+        }
+        match self.container_post {
+            Some(post) => format!("wpcf7-f{}-p{}-o1", self.id, post),
+            None => format!("wpcf7-f{}-o1", self.id),
+        }
+    }
+
+    fn encode(&self, form: &mut Form) {
+        form.text("_wpcf7", &self.id.to_string())
+            .text("_wpcf7_unit_tag", &self.unit_tag());
+        if let Some(post) = self.container_post {
+            form.text("_wpcf7_container_post", &post.to_string());
+        }
+        if let Some(locale) = &self.locale {
+            form.text("_wpcf7_locale", locale);
+        }
+        for (name, value) in &self.fields {
+            form.text(name, value);
+        }
+        for file in &self.files {
+            form.file(&file.name, &file.filename, &file.content_type, &file.data);
+        }
+    }
+}
+
+impl SubmitFeedbackBuilder {
+    /// Set the value of a field, such as `your-email`.
+    ///
+    /// Use the same name several times for the options of a checkbox, with
+    /// `[]` after the name.
+    pub fn field(&mut self, name: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.fields
+            .get_or_insert_with(Vec::new)
+            .push((name.into(), value.into()));
+        self
+    }
+
+    /// Attach a file to a file upload field.
+    pub fn file(
+        &mut self,
+        name: impl Into<String>,
+        filename: impl Into<String>,
+        content_type: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+    ) -> &mut Self {
+        self.files.get_or_insert_with(Vec::new).push(FeedbackFile {
+            name: name.into(),
+            filename: filename.into(),
+            content_type: content_type.into(),
+            data: data.into(),
+        });
+        self
+    }
+}
+
+impl Endpoint for SubmitFeedback {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/contact-form-7/v1/contact-forms/{}/feedback", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        let mut form = Form::new();
+        self.encode(&mut form);
+        Ok(Some((form.content_type(), form.finish())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        contact_form_7::Feedback,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    fn endpoint() -> SubmitFeedback {
+        SubmitFeedback::builder()
+            .id(10)
+            .container_post(2)
+            .field("your-email", "john@example.com")
+            .file("your-file", "cv.txt", "text/plain", "hello")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn body() {
+        let mut form = Form::with_boundary("boundary");
+        endpoint().encode(&mut form);
+
+        assert_eq!(
+            String::from_utf8(form.finish()).unwrap(),
+            "--boundary\r\n\
+             Content-Disposition: form-data; name=\"_wpcf7\"\r\n\
+             \r\n\
+             10\r\n\
+             --boundary\r\n\
+             Content-Disposition: form-data; name=\"_wpcf7_unit_tag\"\r\n\
+             \r\n\
+             wpcf7-f10-p2-o1\r\n\
+             --boundary\r\n\
+             Content-Disposition: form-data; name=\"_wpcf7_container_post\"\r\n\
+             \r\n\
+             2\r\n\
+             --boundary\r\n\
+             Content-Disposition: form-data; name=\"your-email\"\r\n\
+             \r\n\
+             john@example.com\r\n\
+             --boundary\r\n\
+             Content-Disposition: form-data; name=\"your-file\"; filename=\"cv.txt\"\r\n\
+             Content-Type: text/plain\r\n\
+             \r\n\
+             hello\r\n\
+             --boundary--\r\n"
+        );
+    }
+
+    #[test]
+    fn unit_tag() {
+        let endpoint = SubmitFeedback::builder().id(10).build().unwrap();
+        assert_eq!(endpoint.unit_tag(), "wpcf7-f10-o1");
+
+        let endpoint = SubmitFeedback::builder()
+            .id(10)
+            .unit_tag("wpcf7-f10-o2")
+            .build()
+            .unwrap();
+        assert_eq!(endpoint.unit_tag(), "wpcf7-f10-o2");
+    }
+
+    #[tokio::test]
+    async fn sent() {
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route("/contact-form-7/v1/contact-forms/10/feedback")
+            .json(json!({
+                "contact_form_id": 10,
+                "status": "mail_sent",
+                "message": "Thank you for your message. It has been sent.",
+                "posted_data_hash": "d52f9f9de995287195409fe6dcde0c50",
+                "into": "#wpcf7-f10-p2-o1",
+                "invalid_fields": [],
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let feedback: Feedback = endpoint().query(&client).await.unwrap();
+
+        assert!(feedback.is_sent());
+        assert_eq!(feedback.into, "#wpcf7-f10-p2-o1");
+    }
+}
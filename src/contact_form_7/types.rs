@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::de::php_map;
+
+/// A contact form.
+///
+/// Contact forms are listed without their properties.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContactForm {
+    /// Unique identifier for the contact form.
+    #[serde(default)]
+    pub id: u32,
+
+    /// An alphanumeric identifier for the contact form.
+    #[serde(default)]
+    pub slug: String,
+
+    /// The title of the contact form.
+    #[serde(default)]
+    pub title: String,
+
+    /// The locale of the contact form, such as `en_US`.
+    #[serde(default)]
+    pub locale: String,
+
+    /// The properties of the contact form by name, such as `form`, `mail`
+    /// and `messages`.
+    #[serde(
+        default,
+        deserialize_with = "php_map",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub properties: BTreeMap<String, Value>,
+
+    /// Errors in the configuration of the contact form, by property.
+    #[serde(
+        default,
+        deserialize_with = "php_map",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub config_errors: BTreeMap<String, Value>,
+}
+
+/// The result of submitting a contact form.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Feedback {
+    /// The ID of the contact form.
+    #[serde(default)]
+    pub contact_form_id: u32,
+
+    /// The status of the submission, such as `mail_sent`,
+    /// `validation_failed` or `spam`.
+    #[serde(default)]
+    pub status: String,
+
+    /// The message to show to the sender.
+    #[serde(default)]
+    pub message: String,
+
+    /// The fields which failed validation.
+    #[serde(default)]
+    pub invalid_fields: Vec<InvalidField>,
+
+    /// A hash of the submitted data.
+    #[serde(default)]
+    pub posted_data_hash: String,
+
+    /// A selector for the element showing the form, such as
+    /// `#wpcf7-f10-o1`.
+    #[serde(default)]
+    pub into: String,
+}
+
+impl Feedback {
+    /// Whether the mail was sent.
+    pub fn is_sent(&self) -> bool {
+        self.status == "mail_sent"
+    }
+}
+
+/// A field which failed validation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvalidField {
+    /// The name of the field.
+    #[serde(default)]
+    pub field: String,
+
+    /// The validation error.
+    #[serde(default)]
+    pub message: String,
+
+    /// The ID of the field's element, if it has one.
+    #[serde(default)]
+    pub idref: Option<String>,
+
+    /// An identifier for the validation error.
+    #[serde(default)]
+    pub error_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn validation_failed() {
+        let feedback: Feedback = serde_json::from_value(json!({
+            "contact_form_id": 10,
+            "status": "validation_failed",
+            "message": "One or more fields have an error.",
+            "posted_data_hash": "",
+            "into": "#wpcf7-f10-o1",
+            "invalid_fields": [{
+                "field": "your-email",
+                "message": "Please fill out this field.",
+                "idref": null,
+                "error_id": "wpcf7-f10-o1-ve-your-email",
+            }],
+        }))
+        .unwrap();
+
+        assert!(!feedback.is_sent());
+        assert_eq!(feedback.invalid_fields[0].field, "your-email");
+        assert_eq!(feedback.invalid_fields[0].idref, None);
+    }
+}
@@ -0,0 +1,250 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    error::Error,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard,
+    },
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{HeaderMap, Method, Request, Response, StatusCode, Version};
+use tokio::sync::watch;
+
+use crate::{
+    auth::Auth,
+    error::ApiError,
+    middleware::{Middleware, Next},
+};
+
+#[derive(Clone)]
+struct Shared {
+    status: StatusCode,
+    version: Version,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl Shared {
+    fn new(resp: &Response<Bytes>) -> Self {
+        Self {
+            status: resp.status(),
+            version: resp.version(),
+            headers: resp.headers().clone(),
+            body: resp.body().clone(),
+        }
+    }
+
+    fn into_response(self) -> Response<Bytes> {
+        let mut resp = Response::new(self.body);
+        *resp.status_mut() = self.status;
+        *resp.version_mut() = self.version;
+        *resp.headers_mut() = self.headers;
+        resp
+    }
+}
+
+/// The in-flight requests by URL, with an ID telling apart successive requests
+/// for the same URL.
+type InFlight = HashMap<String, (u64, watch::Receiver<Option<Shared>>)>;
+
+/// Middleware which shares a single response between identical `GET` requests
+/// sent at the same time.
+///
+/// While a `GET` request for a URL is in flight, other requests for the same
+/// URL wait for its response instead of being sent. If the first request fails,
+/// the waiting requests are sent as usual, since errors cannot be shared.
+///
+/// Requests are matched by URL alone, so the middleware should not be shared
+/// between clients using different credentials. Requests using credentials
+/// other than the client's defaults are never shared.
+#[derive(Default)]
+pub struct Deduplicate {
+    in_flight: Mutex<InFlight>,
+    next_id: AtomicU64,
+}
+
+impl Deduplicate {
+    /// Create the middleware.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn in_flight(&self) -> MutexGuard<'_, InFlight> {
+        self.in_flight.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// Removes the in-flight entry, even if the request is cancelled, unless it
+/// was replaced by a later request.
+struct Guard<'a> {
+    dedup: &'a Deduplicate,
+    key: String,
+    id: u64,
+}
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self.dedup.in_flight();
+        if let Entry::Occupied(entry) = in_flight.entry(std::mem::take(&mut self.key)) {
+            if entry.get().0 == self.id {
+                entry.remove();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<E> Middleware<E> for Deduplicate
+where
+    E: Error + Send + Sync + 'static,
+{
+    async fn handle(
+        &self,
+        request: Request<Vec<u8>>,
+        next: Next<'_, E>,
+    ) -> Result<Response<Bytes>, ApiError<E>> {
+        if request.method() != Method::GET || request.extensions().get::<Auth>().is_some() {
+            return next.run(request).await;
+        }
+
+        let key = request.uri().to_string();
+        // The lookup and insertion happen under one lock, so that only one of
+        // several identical requests is sent.
+        let sender = match self.in_flight().entry(key.clone()) {
+            Entry::Occupied(entry) => Err(entry.get().1.clone()),
+            Entry::Vacant(entry) => {
+                let (tx, rx) = watch::channel(None);
+                let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+                entry.insert((id, rx));
+                Ok((tx, id))
+            }
+        };
+        let (tx, id) = match sender {
+            Ok(sender) => sender,
+            Err(mut rx) => {
+                if rx.changed().await.is_ok() {
+                    if let Some(shared) = rx.borrow().clone() {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(url = %key, "sharing in-flight response");
+                        return Ok(shared.into_response());
+                    }
+                }
+                return next.run(request).await;
+            }
+        };
+        let guard = Guard {
+            dedup: self,
+            key,
+            id,
+        };
+        let resp = next.run(request).await;
+        drop(guard);
+        if let Ok(resp) = &resp {
+            let _ = tx.send(Some(Shared::new(resp)));
+        }
+        resp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{middleware::BoxFuture, test::MockClientError};
+
+    fn get(uri: &str) -> Request<Vec<u8>> {
+        Request::builder().uri(uri).body(Vec::new()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn coalesce() {
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> =
+            vec![Arc::new(Deduplicate::new())];
+        let calls = AtomicUsize::new(0);
+        let endpoint = |request: Request<Vec<u8>>| -> BoxFuture<'_, _> {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(Response::builder()
+                    .header("x-uri", request.uri().to_string())
+                    .body(Bytes::from_static(b"{}"))
+                    .unwrap())
+            })
+        };
+        let next = Next::new(&middleware, &endpoint);
+
+        let (a, b, c) = tokio::join!(
+            next.run(get("http://example.com/wp-json/wp/v2/posts")),
+            next.run(get("http://example.com/wp-json/wp/v2/posts")),
+            next.run(get("http://example.com/wp-json/wp/v2/pages")),
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(a.unwrap().body(), b.unwrap().body());
+        assert_eq!(
+            c.unwrap().headers()["x-uri"],
+            "http://example.com/wp-json/wp/v2/pages"
+        );
+    }
+
+    #[tokio::test]
+    async fn error() {
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> =
+            vec![Arc::new(Deduplicate::new())];
+        let calls = AtomicUsize::new(0);
+        let endpoint = |_request| -> BoxFuture<'_, _> {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                if call == 0 {
+                    Err(ApiError::client(MockClientError))
+                } else {
+                    Ok(Response::new(Bytes::new()))
+                }
+            })
+        };
+        let next = Next::new(&middleware, &endpoint);
+
+        let (a, b) = tokio::join!(
+            next.run(get("http://example.com/wp-json/")),
+            next.run(get("http://example.com/wp-json/")),
+        );
+
+        assert!(a.is_err());
+        assert_eq!(b.unwrap().status(), StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn guard_keeps_later_request() {
+        let dedup = Deduplicate::new();
+        let key = "http://example.com/wp-json/";
+        let (_tx, rx) = watch::channel(None);
+        dedup.in_flight().insert(key.into(), (1, rx));
+
+        drop(Guard {
+            dedup: &dedup,
+            key: key.into(),
+            id: 0,
+        });
+        assert!(dedup.in_flight().contains_key(key));
+
+        drop(Guard {
+            dedup: &dedup,
+            key: key.into(),
+            id: 1,
+        });
+        assert!(!dedup.in_flight().contains_key(key));
+    }
+}
@@ -3,11 +3,40 @@ use http::Method;
 use serde::de::DeserializeOwned;
 use url::Url;
 
-use crate::{client::Client, error::ApiError, query::Query, request::RequestBuilder};
+use crate::{
+    client::Client, error::ApiError, query::Query, request::RequestBuilder, root::RootRoute,
+};
 
 /// URL to the web page for a resource
+///
+/// Querying a document discovers the API URL of the resource from the page,
+/// see [`Client::discover_resource`].
 pub struct Document(Url);
 
+impl Document {
+    pub fn new(url: Url) -> Self {
+        Document(url)
+    }
+
+    /// The document for a permalink relative to the site, such as
+    /// `/2024/05/hello-world/`.
+    ///
+    /// The site URL is derived from the root route of the client.
+    pub async fn from_permalink<C>(client: &C, permalink: &str) -> Result<Self, ApiError<C::Error>>
+    where
+        C: Client + Sync,
+    {
+        let root = RootRoute::from(client.route_url("/").await?);
+        let url = root.site_url().join(permalink.trim_start_matches('/'))?;
+        Ok(Document(url))
+    }
+
+    /// The URL of the web page.
+    pub fn url(&self) -> &Url {
+        &self.0
+    }
+}
+
 impl From<Url> for Document {
     fn from(url: Url) -> Self {
         Document(url)
@@ -29,3 +58,80 @@ where
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::test::{MockClient, MockResponse};
+
+    #[tokio::test]
+    async fn from_permalink() {
+        let client = MockClient::with_response(MockResponse::builder().build().unwrap());
+
+        let document = Document::from_permalink(&client, "/2024/05/hello-world/")
+            .await
+            .unwrap();
+
+        assert_eq!(document.url().path(), "/2024/05/hello-world/");
+    }
+
+    #[tokio::test]
+    async fn redirect() {
+        let old = MockResponse::builder()
+            .method(Method::HEAD)
+            .route("/")
+            .query("p=1")
+            .status(StatusCode::MOVED_PERMANENTLY)
+            .header("location", "/hello-world/")
+            .build()
+            .unwrap();
+        let page = MockResponse::builder()
+            .method(Method::HEAD)
+            .route("/hello-world/")
+            .header(
+                "link",
+                "<test://test/wp/v2/posts/1>; rel=\"alternate\"; type=\"application/json\"",
+            )
+            .build()
+            .unwrap();
+        let post = MockResponse::builder()
+            .route("/wp/v2/posts/1")
+            .json(json!({ "id": 1 }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_responses([old, page, post]);
+        let document = Document::new("test://test/?p=1".parse().unwrap());
+
+        let response: Value = document.query(&client).await.unwrap();
+
+        assert_eq!(response, json!({ "id": 1 }));
+    }
+
+    #[tokio::test]
+    async fn not_found() {
+        let response = MockResponse::builder()
+            .method(Method::HEAD)
+            .route("/missing/")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let document = Document::new("test://test/missing/".parse().unwrap());
+
+        let err = Query::<Value, _>::query(&document, &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::DiscoveryStatus {
+                status: StatusCode::NOT_FOUND,
+                ..
+            }
+        ));
+    }
+}
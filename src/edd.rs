@@ -0,0 +1,145 @@
+//! Endpoints and models for the Easy Digital Downloads API.
+//!
+//! Version 2 of the EDD API is served at `edd-api/v2/` of the site rather
+//! than under the REST API root, so its endpoints are queries of their own
+//! instead of [`Endpoint`](crate::Endpoint)s. They are queried with the same
+//! clients as the core endpoints, using [`Auth::edd`](crate::auth::Auth::edd)
+//! for the keys. Each query returns the list EDD responds with, such as the
+//! `products` of the response.
+//!
+//! Amounts are sent back as strings in the format of the store.
+
+use async_trait::async_trait;
+use http::Method;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use url::Url;
+
+pub use self::{
+    customers::ListCustomers,
+    products::ListProducts,
+    sales::ListSales,
+    types::{Customer, CustomerInfo, CustomerStats, Product, ProductInfo, Sale, SaleItem},
+};
+use crate::{
+    client::Client, params::QueryParams, query::Query, request::RequestBuilder, root::RootRoute,
+    ApiError,
+};
+
+pub mod customers;
+pub mod products;
+pub mod sales;
+pub mod types;
+
+/// A query of the EDD API.
+trait EddQuery {
+    /// The queried resource, such as `products`, which is also the key of the
+    /// results in the response.
+    fn resource(&self) -> &'static str;
+
+    fn parameters(&self) -> QueryParams<'_>;
+}
+
+/// Implement [`Query`] for queries of the EDD API.
+macro_rules! impl_query {
+    ($ty:ty) => {
+        #[async_trait]
+        impl<T, C> Query<T, C> for $ty
+        where
+            T: DeserializeOwned + 'static,
+            C: Client + Sync,
+        {
+            async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+                query(self, client).await
+            }
+        }
+    };
+}
+
+impl_query!(ListCustomers);
+impl_query!(ListProducts);
+impl_query!(ListSales);
+
+async fn query<Q, T, C>(query: &Q, client: &C) -> Result<T, ApiError<C::Error>>
+where
+    Q: EddQuery + Sync,
+    T: DeserializeOwned + 'static,
+    C: Client + Sync,
+{
+    let root: RootRoute = client.route_url("/").await?.into();
+    let resource = query.resource();
+    let mut url = api_url(&root, resource)?;
+    query.parameters().add_to_url(&mut url);
+
+    let mut builder = RequestBuilder::new();
+    builder.method(Method::GET).url(url);
+    let (_, mut json) = builder.send(client).await?;
+    if json.get("error").is_some() {
+        return Err(ApiError::from_json(json));
+    }
+    let results = json
+        .get_mut(resource)
+        .map(Value::take)
+        .ok_or_else(|| ApiError::missing_pointer(format!("/{}", resource)))?;
+    serde_json::from_value(results).map_err(ApiError::data_type::<T>)
+}
+
+/// The URL of a resource of the EDD API, which is a query variable of the
+/// site without pretty permalinks.
+fn api_url(root: &RootRoute, resource: &str) -> Result<Url, url::ParseError> {
+    let site_url = root.site_url();
+    match root {
+        RootRoute::Default(_) => {
+            let mut url = site_url;
+            url.query_pairs_mut()
+                .append_pair("edd-api", &format!("v2/{}", resource));
+            Ok(url)
+        }
+        _ => site_url.join(&format!("edd-api/v2/{}/", resource)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::test::{MockClient, MockResponse};
+
+    #[test]
+    fn pretty_permalinks() {
+        let root: RootRoute = "http://example.com/wp-json/".parse().unwrap();
+
+        assert_eq!(
+            api_url(&root, "products").unwrap().as_str(),
+            "http://example.com/edd-api/v2/products/"
+        );
+    }
+
+    #[test]
+    fn default_permalinks() {
+        let root: RootRoute = "http://example.com/?rest_route=/".parse().unwrap();
+
+        assert_eq!(
+            api_url(&root, "sales").unwrap().as_str(),
+            "http://example.com/?edd-api=v2%2Fsales"
+        );
+    }
+
+    #[tokio::test]
+    async fn error() {
+        let response = MockResponse::builder()
+            .route("/edd-api/v2/sales/")
+            .json(json!({ "error": "You must specify both a token and API key!" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let err = Query::<Vec<Sale>, _>::query(&ListSales::default(), &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::WordPressUnrecognized { .. }));
+    }
+}
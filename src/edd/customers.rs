@@ -0,0 +1,99 @@
+use derive_builder::Builder;
+
+use super::EddQuery;
+use crate::params::QueryParams;
+
+/// List customers, or retrieve one.
+///
+/// Listing customers needs the `view_shop_sensitive_data` capability.
+#[derive(Debug, Default, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListCustomers {
+    /// Retrieve the customer with this ID or email address only.
+    #[builder(setter(into))]
+    customer: Option<String>,
+
+    /// Limit result set to customers created on or after a date, as
+    /// `YYYYMMDD`.
+    #[builder(setter(into))]
+    startdate: Option<String>,
+
+    /// Limit result set to customers created on or before a date, as
+    /// `YYYYMMDD`.
+    #[builder(setter(into))]
+    enddate: Option<String>,
+
+    /// Maximum number of items to be returned in result set, or -1 for all.
+    number: Option<i32>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+}
+
+impl ListCustomers {
+    pub fn builder() -> ListCustomersBuilder {
+        ListCustomersBuilder::default()
+    }
+
+    /// EDD only filters by dates when `date` is `range`.
+    fn date_range(&self) -> Option<&'static str> {
+        (self.startdate.is_some() || self.enddate.is_some()).then_some("range")
+    }
+}
+
+impl EddQuery for ListCustomers {
+    fn resource(&self) -> &'static str {
+        "customers"
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("customer", self.customer.as_ref())
+            .push_opt("date", self.date_range())
+            .push_opt("startdate", self.startdate.as_ref())
+            .push_opt("enddate", self.enddate.as_ref())
+            .push_opt("number", self.number)
+            .push_opt("page", self.page);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        edd::Customer,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn date_range() {
+        let query = ListCustomers::builder()
+            .startdate("20240101")
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/edd-api/v2/customers/")
+            .query("date=range&startdate=20240101")
+            .json(json!({
+                "customers": [{
+                    "info": { "customer_id": "7", "user_id": "", "email": "jane@example.com" },
+                    "stats": { "total_purchases": 2, "total_spent": "20.00", "total_downloads": 3 },
+                }],
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let customers: Vec<Customer> = query.query(&client).await.unwrap();
+
+        assert_eq!(customers[0].info.customer_id, 7);
+        assert_eq!(customers[0].info.user_id, 0);
+        assert_eq!(customers[0].stats.total_spent, "20.00");
+    }
+}
@@ -0,0 +1,89 @@
+use derive_builder::Builder;
+
+use super::EddQuery;
+use crate::params::QueryParams;
+
+/// List products, or retrieve one.
+#[derive(Debug, Default, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListProducts {
+    /// Retrieve the product with this ID only.
+    product: Option<u32>,
+
+    /// Limit result set to products in a category, by ID or slug.
+    #[builder(setter(into))]
+    category: Option<String>,
+
+    /// Limit result set to products with a tag, by ID or slug.
+    #[builder(setter(into))]
+    tag: Option<String>,
+
+    /// Maximum number of items to be returned in result set, or -1 for all.
+    number: Option<i32>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+}
+
+impl ListProducts {
+    pub fn builder() -> ListProductsBuilder {
+        ListProductsBuilder::default()
+    }
+}
+
+impl EddQuery for ListProducts {
+    fn resource(&self) -> &'static str {
+        "products"
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("product", self.product)
+            .push_opt("category", self.category.as_ref())
+            .push_opt("tag", self.tag.as_ref())
+            .push_opt("number", self.number)
+            .push_opt("page", self.page);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        edd::Product,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn category() {
+        let query = ListProducts::builder()
+            .category("ebooks")
+            .number(10)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/edd-api/v2/products/")
+            .query("category=ebooks&number=10")
+            .json(json!({
+                "products": [{
+                    "info": { "id": 12, "title": "Ebook" },
+                    "pricing": { "amount": "9.99" },
+                }],
+                "request_speed": 0.02,
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let products: Vec<Product> = query.query(&client).await.unwrap();
+
+        assert_eq!(products[0].info.title, "Ebook");
+        assert_eq!(products[0].pricing["amount"], "9.99");
+    }
+}
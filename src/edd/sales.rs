@@ -0,0 +1,83 @@
+use derive_builder::Builder;
+
+use super::EddQuery;
+use crate::params::QueryParams;
+
+/// List recent sales, or retrieve one.
+///
+/// Listing sales needs the `view_shop_reports` capability.
+#[derive(Debug, Default, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListSales {
+    /// Retrieve the sale with this ID only.
+    id: Option<u32>,
+
+    /// Retrieve the sale with this purchase key only.
+    #[builder(setter(into))]
+    purchasekey: Option<String>,
+
+    /// Limit result set to sales to a customer, by email address.
+    #[builder(setter(into))]
+    email: Option<String>,
+
+    /// Maximum number of items to be returned in result set, or -1 for all.
+    number: Option<i32>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+}
+
+impl ListSales {
+    pub fn builder() -> ListSalesBuilder {
+        ListSalesBuilder::default()
+    }
+}
+
+impl EddQuery for ListSales {
+    fn resource(&self) -> &'static str {
+        "sales"
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("id", self.id)
+            .push_opt("purchasekey", self.purchasekey.as_ref())
+            .push_opt("email", self.email.as_ref())
+            .push_opt("number", self.number)
+            .push_opt("page", self.page);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        edd::Sale,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn email() {
+        let query = ListSales::builder()
+            .email("jane@example.com")
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/edd-api/v2/sales/")
+            .query("email=jane%40example.com")
+            .json(json!({ "sales": [{ "ID": 105, "total": "20.00" }] }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let sales: Vec<Sale> = query.query(&client).await.unwrap();
+
+        assert_eq!(sales[0].id, 105);
+    }
+}
@@ -0,0 +1,281 @@
+use std::collections::BTreeMap;
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// A downloadable product.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Product {
+    /// Details of the product.
+    #[serde(default)]
+    pub info: ProductInfo,
+
+    /// Prices of the product. Products with variable pricing have a price
+    /// for each option, by name, and others have a single `amount`.
+    #[serde(default, deserialize_with = "prices")]
+    pub pricing: BTreeMap<String, String>,
+
+    /// Sales and earnings of the product, which are only included for users
+    /// who can view shop reports.
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub stats: Value,
+}
+
+/// Details of a product.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductInfo {
+    /// Unique identifier for the product.
+    #[serde(default, deserialize_with = "integer")]
+    pub id: u32,
+
+    /// An alphanumeric identifier for the product.
+    #[serde(default)]
+    pub slug: String,
+
+    /// Product name.
+    #[serde(default)]
+    pub title: String,
+
+    /// The date the product was created, in the site's timezone.
+    #[serde(default)]
+    pub create_date: String,
+
+    /// The date the product was last modified, in the site's timezone.
+    #[serde(default)]
+    pub modified_date: String,
+
+    /// Product status, such as `publish`.
+    #[serde(default)]
+    pub status: String,
+
+    /// Product URL.
+    #[serde(default)]
+    pub link: String,
+
+    /// HTML description of the product.
+    #[serde(default)]
+    pub content: String,
+
+    /// Excerpt of the product.
+    #[serde(default)]
+    pub excerpt: String,
+}
+
+/// A customer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Customer {
+    /// Details of the customer.
+    #[serde(default)]
+    pub info: CustomerInfo,
+
+    /// Purchases of the customer.
+    #[serde(default)]
+    pub stats: CustomerStats,
+}
+
+/// Details of a customer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomerInfo {
+    /// Unique identifier for the customer.
+    #[serde(default, deserialize_with = "integer")]
+    pub customer_id: u32,
+
+    /// The ID of the user account of the customer, or 0 for guests.
+    #[serde(default, deserialize_with = "integer")]
+    pub user_id: u32,
+
+    /// The login name of the user account of the customer.
+    #[serde(default)]
+    pub username: String,
+
+    /// The display name of the customer.
+    #[serde(default)]
+    pub display_name: String,
+
+    /// Customer first name.
+    #[serde(default)]
+    pub first_name: String,
+
+    /// Customer last name.
+    #[serde(default)]
+    pub last_name: String,
+
+    /// The email address for the customer.
+    #[serde(default)]
+    pub email: String,
+
+    /// The date the customer was created, in the site's timezone.
+    #[serde(default)]
+    pub date_created: String,
+}
+
+/// Purchases of a customer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomerStats {
+    /// Number of purchases.
+    #[serde(default, deserialize_with = "integer")]
+    pub total_purchases: u32,
+
+    /// Amount spent.
+    #[serde(default, deserialize_with = "amount")]
+    pub total_spent: String,
+
+    /// Number of file downloads.
+    #[serde(default, deserialize_with = "integer")]
+    pub total_downloads: u32,
+}
+
+/// A sale.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sale {
+    /// Unique identifier for the sale.
+    #[serde(rename = "ID", default, deserialize_with = "integer")]
+    pub id: u32,
+
+    /// Transaction ID of the payment gateway.
+    #[serde(default)]
+    pub transaction_id: String,
+
+    /// Purchase key.
+    #[serde(default)]
+    pub key: String,
+
+    /// Subtotal, before discounts and tax.
+    #[serde(default, deserialize_with = "amount")]
+    pub subtotal: String,
+
+    /// Tax amount.
+    #[serde(default, deserialize_with = "amount")]
+    pub tax: String,
+
+    /// Total amount.
+    #[serde(default, deserialize_with = "amount")]
+    pub total: String,
+
+    /// Payment gateway, such as `stripe`.
+    #[serde(default)]
+    pub gateway: String,
+
+    /// The ID of the customer.
+    #[serde(default, deserialize_with = "integer")]
+    pub customer_id: u32,
+
+    /// The email address of the customer.
+    #[serde(default)]
+    pub email: String,
+
+    /// The date of the sale, in the site's timezone.
+    #[serde(default)]
+    pub date: String,
+
+    /// Products sold.
+    #[serde(default)]
+    pub products: Vec<SaleItem>,
+}
+
+/// A product sold.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaleItem {
+    /// The ID of the product.
+    #[serde(default, deserialize_with = "integer")]
+    pub id: u32,
+
+    /// Quantity sold.
+    #[serde(default, deserialize_with = "integer")]
+    pub quantity: u32,
+
+    /// Product name.
+    #[serde(default)]
+    pub name: String,
+
+    /// Price paid.
+    #[serde(default, deserialize_with = "amount")]
+    pub price: String,
+
+    /// The name of the price option, for products with variable pricing.
+    #[serde(default)]
+    pub price_name: String,
+}
+
+/// Deserialize a number which EDD may send as a string.
+fn integer<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(0),
+        Value::String(s) if s.is_empty() => Ok(0),
+        Value::String(s) => s.parse().map_err(de::Error::custom),
+        value => u32::deserialize(value).map_err(de::Error::custom),
+    }
+}
+
+/// Deserialize an amount, which EDD may send as a number.
+fn amount<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(String::new()),
+        Value::String(s) => Ok(s),
+        Value::Number(n) => Ok(n.to_string()),
+        other => Err(de::Error::custom(format!("expected an amount: {}", other))),
+    }
+}
+
+/// Deserialize prices, whose amounts EDD may send as numbers.
+fn prices<'de, D>(deserializer: D) -> Result<BTreeMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(name, price)| Ok((name, amount(price).map_err(de::Error::custom)?)))
+            .collect(),
+        Value::Null => Ok(BTreeMap::new()),
+        Value::Array(array) if array.is_empty() => Ok(BTreeMap::new()),
+        other => Err(de::Error::custom(format!("expected prices: {}", other))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn variable_pricing() {
+        let product: Product = serde_json::from_value(json!({
+            "info": { "id": 12, "slug": "ebook", "title": "Ebook", "status": "publish" },
+            "pricing": { "personal": "9.99", "business": 49 },
+        }))
+        .unwrap();
+
+        assert_eq!(product.info.id, 12);
+        assert_eq!(product.pricing["personal"], "9.99");
+        assert_eq!(product.pricing["business"], "49");
+        assert!(product.stats.is_null());
+    }
+
+    #[test]
+    fn sale() {
+        let sale: Sale = serde_json::from_value(json!({
+            "ID": 105,
+            "key": "f2c2d4bd",
+            "subtotal": 20,
+            "tax": "0.00",
+            "total": "20.00",
+            "customer_id": "7",
+            "products": [{ "id": 12, "quantity": 2, "name": "Ebook", "price": 10 }],
+        }))
+        .unwrap();
+
+        assert_eq!(sale.id, 105);
+        assert_eq!(sale.subtotal, "20");
+        assert_eq!(sale.customer_id, 7);
+        assert_eq!(sale.products[0].price, "10");
+    }
+}
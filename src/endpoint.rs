@@ -1,10 +1,38 @@
-use std::borrow::Cow;
+//! Building blocks for endpoints.
+//!
+//! Endpoints of plugins and other namespaces can be defined outside of this
+//! crate in the same way as the core endpoints, and are queried with the same
+//! [`Client`]s, [`Query`] combinators and authentication. An endpoint is a
+//! type implementing [`Endpoint`], usually with a builder:
+//!
+//! - [`Endpoint::route`] gives the route relative to the REST API root, such as
+//!   `/my-plugin/v1/items`, which the client resolves for the site's permalink
+//!   structure.
+//! - [`Endpoint::parameters`] adds query parameters using [`QueryParams`].
+//!   Values implement [`ParamValue`](crate::ParamValue), which can be
+//!   implemented for enums and other types of parameters.
+//! - [`Endpoint::body`] encodes the request body, such as JSON using
+//!   `serde_json::to_vec`, whose errors convert into [`BodyError`], or
+//!   `multipart/form-data` using a [`Form`].
+//! - Collections which follow the `page` and `per_page` conventions of the core
+//!   endpoints, and report `X-WP-TotalPages`, implement
+//!   [`Pageable`](crate::Pageable) to be collected with
+//!   [`paged`](crate::paged).
+//!
+//! Every endpoint is then a [`Query`] for any type it deserializes into. The
+//! [`prelude`] imports what endpoint definitions commonly need.
+
+use std::{borrow::Cow, fmt};
 
 use async_trait::async_trait;
-use http::Method;
+use http::{header::HeaderValue, Method};
 use serde::de::DeserializeOwned;
 
-use crate::{client::Client, query::Query, request::RequestBuilder, ApiError};
+pub use crate::multipart::Form;
+use crate::{
+    client::Client, error::BodyError, params::QueryParams, query::Query, request::RequestBuilder,
+    ApiError,
+};
 
 /// A trait for providing the necessary information for a single REST API
 /// endpoint.
@@ -14,6 +42,74 @@ pub trait Endpoint {
 
     /// Route for the endpoint.
     fn route(&self) -> Cow<'static, str>;
+
+    /// Query parameters for the endpoint.
+    fn parameters(&self) -> QueryParams<'_> {
+        QueryParams::default()
+    }
+
+    /// The body for the endpoint.
+    ///
+    /// Returns the content type of the body along with the data.
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(None)
+    }
+}
+
+/// The content type of a request body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContentType {
+    /// `application/json`
+    Json,
+
+    /// `application/x-www-form-urlencoded`
+    FormUrlEncoded,
+
+    /// `multipart/form-data`, with the boundary separating the parts.
+    Multipart {
+        /// The boundary.
+        boundary: String,
+    },
+
+    /// Any other content type, given as the full `Content-Type` header value.
+    Other(Cow<'static, str>),
+}
+
+impl ContentType {
+    /// The `Content-Type` header value.
+    pub fn as_str(&self) -> Cow<'_, str> {
+        match self {
+            ContentType::Json => "application/json".into(),
+            ContentType::FormUrlEncoded => "application/x-www-form-urlencoded".into(),
+            ContentType::Multipart { boundary } => {
+                format!("multipart/form-data; boundary={}", boundary).into()
+            }
+            ContentType::Other(content_type) => content_type.as_ref().into(),
+        }
+    }
+
+    pub(crate) fn header_value(&self) -> Result<HeaderValue, http::Error> {
+        Ok(HeaderValue::try_from(self.as_str().as_ref())?)
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.as_str())
+    }
+}
+
+impl PartialEq<str> for ContentType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ContentType {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
 }
 
 #[async_trait]
@@ -23,20 +119,150 @@ where
     T: DeserializeOwned + 'static,
     C: Client + Sync,
 {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(method = %self.method(), route = %self.route()),
+        )
+    )]
     async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
-        let url = client.route_url(&self.route()).await?;
-        RequestBuilder::new()
-            .method(self.method())
-            .url(url)
-            .query(client)
-            .await
+        request(self, client).await?.query(client).await
     }
 }
 
+/// Build the request for an endpoint.
+pub(crate) async fn request<E, C>(
+    endpoint: &E,
+    client: &C,
+) -> Result<RequestBuilder, ApiError<C::Error>>
+where
+    E: Endpoint + ?Sized,
+    C: Client + Sync,
+{
+    let mut url = client.route_url(&endpoint.route()).await?;
+    endpoint.parameters().add_to_url(&mut url);
+    let mut builder = RequestBuilder::new();
+    builder.method(endpoint.method()).url(url);
+    if let Some((content_type, body)) = endpoint.body()? {
+        let content_type = content_type.header_value().map_err(ApiError::request)?;
+        builder.content_type(content_type).body(body);
+    }
+    Ok(builder)
+}
+
+/// Common imports for defining endpoints.
 pub mod prelude {
     pub use std::borrow::Cow;
 
     pub use http::Method;
 
-    pub use super::Endpoint;
+    pub use super::{ContentType, Endpoint};
+    pub use crate::{
+        endpoints::common::{Context, Order, OrderBy, PostStatus},
+        error::BodyError,
+        paged::Pageable,
+        params::{ParamValue, QueryParams, Timestamp},
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn content_type() {
+        let multipart = ContentType::Multipart {
+            boundary: "abc".into(),
+        };
+
+        assert_eq!(ContentType::Json, "application/json");
+        assert_eq!(multipart.to_string(), "multipart/form-data; boundary=abc");
+        assert_eq!(ContentType::Other("image/png".into()), "image/png");
+    }
+
+    /// An endpoint of a plugin, defined using only the public API.
+    mod plugin {
+        use crate::endpoint::prelude::*;
+
+        pub enum Visibility {
+            Public,
+            Private,
+        }
+
+        impl ParamValue<'static> for Visibility {
+            fn as_value(&self) -> Cow<'static, str> {
+                match self {
+                    Self::Public => "public",
+                    Self::Private => "private",
+                }
+                .into()
+            }
+        }
+
+        pub struct ListItems {
+            pub visibility: Option<Visibility>,
+            pub tags: Vec<u32>,
+        }
+
+        impl Endpoint for ListItems {
+            fn method(&self) -> Method {
+                Method::GET
+            }
+
+            fn route(&self) -> Cow<'static, str> {
+                "/my-plugin/v1/items".into()
+            }
+
+            fn parameters(&self) -> QueryParams<'_> {
+                let mut params = QueryParams::default();
+                params
+                    .push_opt("visibility", self.visibility.as_ref())
+                    .push_list("tags", &self.tags);
+                params
+            }
+        }
+
+        impl Pageable for ListItems {}
+    }
+
+    #[tokio::test]
+    async fn third_party_endpoint() {
+        use serde_json::{json, Value};
+
+        use crate::{
+            paged,
+            test::{MockClient, MockResponse},
+            ParamValue, Query,
+        };
+
+        let page = |page: u32, items: Value| {
+            MockResponse::builder()
+                .route("/my-plugin/v1/items")
+                .query(format!(
+                    "visibility=private&tags=1%2C2&page={}&per_page=1",
+                    page
+                ))
+                .header("x-wp-totalpages", "2")
+                .json(items)
+                .build()
+                .unwrap()
+        };
+        let client = MockClient::with_responses([
+            page(1, json!([{ "id": 1 }])),
+            page(2, json!([{ "id": 2 }])),
+        ]);
+        let endpoint = plugin::ListItems {
+            visibility: Some(plugin::Visibility::Private),
+            tags: vec![1, 2],
+        };
+
+        let items: Vec<Value> = paged(endpoint).per_page(1).query(&client).await.unwrap();
+
+        assert_eq!(items, vec![json!({ "id": 1 }), json!({ "id": 2 })]);
+        assert_eq!(plugin::Visibility::Public.as_value(), "public");
+    }
 }
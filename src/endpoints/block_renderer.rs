@@ -0,0 +1,3 @@
+pub use render::{RenderBlock, RenderedBlock};
+
+pub mod render;
@@ -0,0 +1,210 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::endpoint::prelude::*;
+
+/// Render a dynamic block on the server.
+///
+/// Attributes are sent as a JSON body when using `POST` (the default) and as
+/// query parameters when using `GET`.
+#[derive(Builder, Serialize)]
+pub struct RenderBlock {
+    /// Unique registered name for the block, e.g. `core/archives`.
+    #[builder(setter(into))]
+    #[serde(skip)]
+    name: String,
+
+    /// HTTP method used for the request.
+    ///
+    /// Must be either `GET` or `POST`.
+    #[builder(default = "Method::POST")]
+    #[serde(skip)]
+    method: Method,
+
+    /// Attributes for the block.
+    #[builder(setter(custom), default)]
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    attributes: Map<String, Value>,
+
+    /// ID of the post context.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_id: Option<u32>,
+}
+
+impl RenderBlock {
+    pub fn builder() -> RenderBlockBuilder {
+        RenderBlockBuilder::default()
+    }
+}
+
+impl RenderBlockBuilder {
+    /// Set a block attribute.
+    pub fn attribute(&mut self, key: impl Into<String>, value: impl Into<Value>) -> &mut Self {
+        self.attributes
+            .get_or_insert_with(Map::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Set multiple block attributes.
+    pub fn attributes<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        self.attributes
+            .get_or_insert_with(Map::new)
+            .extend(iter.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+}
+
+/// Push a JSON value using the bracket syntax understood by PHP.
+fn push_value(params: &mut QueryParams<'_>, key: String, value: &Value) {
+    match value {
+        Value::Null => {}
+        Value::String(value) => {
+            params.push(key, value.clone());
+        }
+        Value::Bool(value) => {
+            params.push(key, *value);
+        }
+        Value::Number(value) => {
+            params.push(key, value.to_string());
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                push_value(params, format!("{}[{}]", key, index), value);
+            }
+        }
+        Value::Object(values) => {
+            for (name, value) in values {
+                push_value(params, format!("{}[{}]", key, name), value);
+            }
+        }
+    }
+}
+
+impl Endpoint for RenderBlock {
+    fn method(&self) -> Method {
+        self.method.clone()
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/block-renderer/{}", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("context", Context::Edit);
+        if self.method == Method::GET {
+            for (name, value) in &self.attributes {
+                push_value(&mut params, format!("attributes[{}]", name), value);
+            }
+            params.push_opt("post_id", self.post_id);
+        }
+        params
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        if self.method == Method::GET {
+            return Ok(None);
+        }
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+/// A block rendered by the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderedBlock {
+    /// The rendered block.
+    pub rendered: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = RenderBlock::builder()
+            .name("core/archives")
+            .attribute("displayAsDropdown", true)
+            .post_id(1)
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "attributes": { "displayAsDropdown": true },
+                "post_id": 1,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn post() {
+        let endpoint = RenderBlock::builder()
+            .name("core/archives")
+            .attribute("showPostCounts", true)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route("/wp/v2/block-renderer/core/archives")
+            .query("context=edit")
+            .json(json!({ "rendered": "<ul></ul>" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: RenderedBlock = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.rendered, "<ul></ul>");
+    }
+
+    #[tokio::test]
+    async fn get() {
+        let endpoint = RenderBlock::builder()
+            .name("core/latest-comments")
+            .method(Method::GET)
+            .attribute("commentsToShow", 5)
+            .attribute("className", json!(["a", "b"]))
+            .post_id(1)
+            .build()
+            .unwrap();
+        assert!(endpoint.body().unwrap().is_none());
+        let response = MockResponse::builder()
+            .method(Method::GET)
+            .route("/wp/v2/block-renderer/core/latest-comments")
+            .query(
+                "context=edit\
+                 &attributes%5BclassName%5D%5B0%5D=a\
+                 &attributes%5BclassName%5D%5B1%5D=b\
+                 &attributes%5BcommentsToShow%5D=5\
+                 &post_id=1",
+            )
+            .json(json!({ "rendered": "<ol></ol>" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: RenderedBlock = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.rendered, "<ol></ol>");
+    }
+}
@@ -0,0 +1,82 @@
+use std::borrow::Cow;
+
+pub use create::CreateComment;
+pub use delete::{DeleteComment, DeletedComment};
+pub use list::ListComments;
+pub use retrieve::RetrieveComment;
+use serde::{Deserialize, Serialize};
+pub use update::UpdateComment;
+
+use crate::params::ParamValue;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod retrieve;
+pub mod update;
+
+/// The status of a comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentStatus {
+    /// The comment is approved.
+    #[serde(alias = "approved")]
+    Approve,
+
+    /// The comment is awaiting moderation.
+    Hold,
+
+    /// The comment is marked as spam.
+    Spam,
+
+    /// The comment is in the trash.
+    Trash,
+
+    /// Comments of any status.
+    ///
+    /// Only valid as a filter when listing comments.
+    All,
+}
+
+impl CommentStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            CommentStatus::Approve => "approve",
+            CommentStatus::Hold => "hold",
+            CommentStatus::Spam => "spam",
+            CommentStatus::Trash => "trash",
+            CommentStatus::All => "all",
+        }
+    }
+}
+
+impl ParamValue<'static> for CommentStatus {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_value(CommentStatus::Approve).unwrap(),
+            json!("approve")
+        );
+        assert_eq!(CommentStatus::Hold.as_value(), "hold");
+    }
+
+    #[test]
+    fn deserialize_response_status() {
+        let status: CommentStatus = serde_json::from_value(json!("approved")).unwrap();
+        assert_eq!(status, CommentStatus::Approve);
+        let status: CommentStatus = serde_json::from_value(json!("hold")).unwrap();
+        assert_eq!(status, CommentStatus::Hold);
+    }
+}
@@ -0,0 +1,150 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, endpoints::comments::CommentStatus, types::Meta};
+
+/// Create a comment.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateComment {
+    /// The ID of the associated post object.
+    post: u32,
+
+    /// The content for the comment.
+    #[builder(setter(into))]
+    content: String,
+
+    /// The ID of the user object, if author was a user.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<u32>,
+
+    /// Email address for the comment author.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author_email: Option<String>,
+
+    /// IP address for the comment author.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author_ip: Option<String>,
+
+    /// Display name for the comment author.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author_name: Option<String>,
+
+    /// URL for the comment author.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author_url: Option<String>,
+
+    /// User agent for the comment author.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author_user_agent: Option<String>,
+
+    /// The date the comment was published, in the site's timezone.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+
+    /// The date the comment was published, as GMT.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_gmt: Option<String>,
+
+    /// The ID for the parent of the comment.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<u32>,
+
+    /// State of the comment.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<CommentStatus>,
+
+    /// Meta fields.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<Meta>,
+}
+
+impl CreateComment {
+    pub fn builder() -> CreateCommentBuilder {
+        CreateCommentBuilder::default()
+    }
+}
+
+impl Endpoint for CreateComment {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/comments".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = CreateComment::builder()
+            .post(1)
+            .parent(2)
+            .content("Nice post!")
+            .author_name("Bob")
+            .author_email("bob@example.com")
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "post": 1,
+                "parent": 2,
+                "content": "Nice post!",
+                "author_name": "Bob",
+                "author_email": "bob@example.com",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = CreateComment::builder()
+            .post(1)
+            .content("Nice post!")
+            .build()
+            .unwrap();
+        let body = json!({ "id": 1, "post": 1 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,182 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::comments::CommentStatus};
+
+/// List comments.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListComments {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Limit response to comments published after a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    after: Option<Timestamp>,
+
+    /// Limit result set to comments assigned to specific user IDs.
+    #[builder(setter(into, strip_option = false))]
+    author: Vec<u32>,
+
+    /// Ensure result set excludes comments assigned to specific user IDs.
+    #[builder(setter(into, strip_option = false))]
+    author_exclude: Vec<u32>,
+
+    /// Limit result set to that from a specific author email.
+    #[builder(setter(into))]
+    author_email: Option<String>,
+
+    /// Limit response to comments published before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    before: Option<Timestamp>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    #[builder(setter(into))]
+    order: Option<String>,
+
+    /// Sort collection by comment attribute.
+    #[builder(setter(into))]
+    orderby: Option<String>,
+
+    /// Limit result set to comments of specific parent IDs.
+    #[builder(setter(into, strip_option = false))]
+    parent: Vec<u32>,
+
+    /// Ensure result set excludes specific parent IDs.
+    #[builder(setter(into, strip_option = false))]
+    parent_exclude: Vec<u32>,
+
+    /// Limit result set to comments assigned to specific post IDs.
+    #[builder(setter(into, strip_option = false))]
+    post: Vec<u32>,
+
+    /// Limit result set to comments assigned a specific status.
+    status: Option<CommentStatus>,
+
+    /// Limit result set to comments assigned a specific type.
+    #[builder(setter(into))]
+    comment_type: Option<String>,
+
+    /// The password for the post if it is password protected.
+    #[builder(setter(into))]
+    password: Option<String>,
+}
+
+impl ListComments {
+    pub fn builder() -> ListCommentsBuilder {
+        ListCommentsBuilder::default()
+    }
+}
+
+impl Endpoint for ListComments {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/comments".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("after", self.after.as_ref())
+            .push_list("author", &self.author)
+            .push_list("author_exclude", &self.author_exclude)
+            .push_opt("author_email", self.author_email.as_ref())
+            .push_opt("before", self.before.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_list("parent", &self.parent)
+            .push_list("parent_exclude", &self.parent_exclude)
+            .push_list("post", &self.post)
+            .push_opt("status", self.status)
+            .push_opt("type", self.comment_type.as_ref())
+            .push_opt("password", self.password.as_ref());
+        params
+    }
+}
+
+impl Pageable for ListComments {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListComments::builder().build().unwrap();
+        let body = json!([{ "id": 1 }, { "id": 2 }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+
+    #[tokio::test]
+    async fn filters() {
+        let endpoint = ListComments::builder()
+            .post(vec![1])
+            .parent(vec![0])
+            .status(CommentStatus::Hold)
+            .author_email("bob@example.com")
+            .build()
+            .unwrap();
+        let body = json!([]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("author_email=bob%40example.com&parent=0&post=1&status=hold")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
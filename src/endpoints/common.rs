@@ -0,0 +1,272 @@
+use std::{borrow::Cow, error::Error};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{error::ApiError, params::ParamValue};
+
+/// The scope under which a request is made, which determines the fields
+/// present in the response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Context {
+    /// The fields shown publicly. This is the default for most endpoints.
+    View,
+
+    /// A reduced set of fields, as used when embedding resources.
+    Embed,
+
+    /// All fields, including raw values for editing. Requires the user to be
+    /// able to edit the resource.
+    Edit,
+}
+
+impl Context {
+    fn as_str(self) -> &'static str {
+        match self {
+            Context::View => "view",
+            Context::Embed => "embed",
+            Context::Edit => "edit",
+        }
+    }
+}
+
+impl ParamValue<'static> for Context {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Implement conversions for an enum of known string values with an
+/// `Unknown(String)` variant for values added in later versions of WordPress.
+macro_rules! impl_string_enum {
+    ($ty:ident { $($variant:ident => $value:literal),* $(,)? }) => {
+        impl $ty {
+            /// The value as sent to WordPress.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($ty::$variant => $value,)*
+                    $ty::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $ty {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $ty::$variant,)*
+                    value => $ty::Unknown(value.into()),
+                }
+            }
+        }
+
+        impl From<String> for $ty {
+            fn from(value: String) -> Self {
+                value.as_str().into()
+            }
+        }
+
+        impl $crate::params::ParamValue<'static> for $ty {
+            fn as_value(&self) -> ::std::borrow::Cow<'static, str> {
+                self.as_str().to_string().into()
+            }
+        }
+
+        impl ::serde::Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                Ok(<String as ::serde::Deserialize>::deserialize(deserializer)?.into())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "woocommerce")]
+pub(crate) use impl_string_enum;
+
+/// The direction to sort a collection in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Order {
+    /// Ascending order.
+    Asc,
+
+    /// Descending order.
+    Desc,
+}
+
+impl ParamValue<'static> for Order {
+    fn as_value(&self) -> Cow<'static, str> {
+        match self {
+            Order::Asc => "asc".into(),
+            Order::Desc => "desc".into(),
+        }
+    }
+}
+
+/// The attribute to sort a collection of posts by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OrderBy {
+    /// The author ID.
+    Author,
+
+    /// The publication date. This is the default.
+    Date,
+
+    /// The post ID.
+    Id,
+
+    /// The order of the IDs given with `include`.
+    Include,
+
+    /// The last modified date.
+    Modified,
+
+    /// The parent ID.
+    Parent,
+
+    /// Relevance to the search term.
+    Relevance,
+
+    /// The slug.
+    Slug,
+
+    /// The order of the slugs given with `slug`.
+    IncludeSlugs,
+
+    /// The title.
+    Title,
+
+    /// The menu order, for hierarchical post types.
+    MenuOrder,
+
+    /// A value not known to this crate.
+    Unknown(String),
+}
+
+impl_string_enum!(OrderBy {
+    Author => "author",
+    Date => "date",
+    Id => "id",
+    Include => "include",
+    Modified => "modified",
+    Parent => "parent",
+    Relevance => "relevance",
+    Slug => "slug",
+    IncludeSlugs => "include_slugs",
+    Title => "title",
+    MenuOrder => "menu_order",
+});
+
+/// The status of a post.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PostStatus {
+    /// Published and publicly visible.
+    Publish,
+
+    /// Scheduled to be published in the future.
+    Future,
+
+    /// A draft.
+    Draft,
+
+    /// Awaiting review.
+    Pending,
+
+    /// Only visible to users who can read private posts.
+    Private,
+
+    /// In the trash.
+    Trash,
+
+    /// Created automatically when starting a new post.
+    AutoDraft,
+
+    /// Inherits the status of its parent, as used by revisions and
+    /// attachments.
+    Inherit,
+
+    /// Posts of any status.
+    ///
+    /// Only valid as a filter when listing posts.
+    Any,
+
+    /// A status not known to this crate, such as one registered by a plugin.
+    Unknown(String),
+}
+
+impl_string_enum!(PostStatus {
+    Publish => "publish",
+    Future => "future",
+    Draft => "draft",
+    Pending => "pending",
+    Private => "private",
+    Trash => "trash",
+    AutoDraft => "auto-draft",
+    Inherit => "inherit",
+    Any => "any",
+});
+
+/// Deserialize the only item matching a slug or path.
+pub(crate) fn single<T, E>(slug: &str, mut items: Vec<Value>) -> Result<T, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: Error + Send + Sync + 'static,
+{
+    match items.len() {
+        0 => Err(ApiError::not_found(slug)),
+        1 => serde_json::from_value(items.remove(0)).map_err(ApiError::data_type::<T>),
+        count => Err(ApiError::multiple_matches(slug, count)),
+    }
+}
+
+/// The result of permanently deleting a resource.
+#[derive(Debug, Deserialize)]
+pub struct Deleted<T> {
+    /// Whether the resource was deleted.
+    pub deleted: bool,
+
+    /// The resource as it was before deletion.
+    pub previous: T,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn post_status() {
+        assert_eq!(
+            serde_json::to_value(PostStatus::AutoDraft).unwrap(),
+            json!("auto-draft")
+        );
+        assert_eq!(
+            serde_json::from_value::<PostStatus>(json!("wc-completed")).unwrap(),
+            PostStatus::Unknown("wc-completed".into())
+        );
+        assert_eq!(PostStatus::from("draft"), PostStatus::Draft);
+    }
+
+    #[test]
+    fn order_by() {
+        assert_eq!(OrderBy::IncludeSlugs.as_value(), "include_slugs");
+        assert_eq!(OrderBy::from("rand").as_value(), "rand");
+        assert_eq!(Order::Desc.as_value(), "desc");
+    }
+}
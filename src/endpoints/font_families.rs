@@ -0,0 +1,26 @@
+pub use create::CreateFontFamily;
+pub use delete::{DeleteFontFamily, DeletedFontFamily};
+pub use font_faces::{
+    CreateFontFace, DeleteFontFace, DeletedFontFace, ListFontFaces, RetrieveFontFace,
+};
+pub use list::ListFontFamilies;
+pub use retrieve::RetrieveFontFamily;
+use serde::{ser::Error, Serialize, Serializer};
+pub use update::UpdateFontFamily;
+
+pub mod create;
+pub mod delete;
+pub mod font_faces;
+pub mod list;
+pub mod retrieve;
+pub mod update;
+
+/// Font settings are sent as JSON-encoded strings rather than objects.
+fn serialize_settings<T, S>(settings: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    let settings = serde_json::to_string(settings).map_err(S::Error::custom)?;
+    serializer.serialize_str(&settings)
+}
@@ -0,0 +1,105 @@
+use derive_builder::Builder;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{endpoint::prelude::*, endpoints::font_families::serialize_settings};
+
+/// Install a font family.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateFontFamily {
+    /// Version of the theme.json schema used for the typography settings.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme_json_version: Option<u32>,
+
+    /// The font family declaration, in theme.json format.
+    ///
+    /// Must include `name`, `slug` and `fontFamily`.
+    #[serde(serialize_with = "serialize_settings")]
+    font_family_settings: Value,
+}
+
+impl CreateFontFamily {
+    pub fn builder() -> CreateFontFamilyBuilder {
+        CreateFontFamilyBuilder::default()
+    }
+}
+
+impl Endpoint for CreateFontFamily {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/font-families".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn settings_required() {
+        let err = CreateFontFamily::builder().build();
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn body() {
+        let endpoint = CreateFontFamily::builder()
+            .font_family_settings(json!({
+                "name": "Inter",
+                "slug": "inter",
+                "fontFamily": "Inter, sans-serif",
+            }))
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        let body = serde_json::from_slice::<Json>(&body).unwrap();
+        let settings = body["font_family_settings"].as_str().unwrap();
+        assert_eq!(
+            serde_json::from_str::<Json>(settings).unwrap(),
+            json!({
+                "name": "Inter",
+                "slug": "inter",
+                "fontFamily": "Inter, sans-serif",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = CreateFontFamily::builder()
+            .font_family_settings(json!({ "name": "Inter", "slug": "inter" }))
+            .build()
+            .unwrap();
+        let body = json!({ "id": 1 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
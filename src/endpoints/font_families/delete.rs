@@ -0,0 +1,71 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::common::Deleted};
+
+/// Delete a font family along with its font faces and files.
+///
+/// Font families do not support trashing, so the deletion is always forced.
+#[derive(Builder)]
+pub struct DeleteFontFamily {
+    /// Unique identifier for the font family.
+    id: u32,
+}
+
+impl DeleteFontFamily {
+    pub fn builder() -> DeleteFontFamilyBuilder {
+        DeleteFontFamilyBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteFontFamily {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/font-families/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("force", true);
+        params
+    }
+}
+
+/// The result of deleting a font family.
+pub type DeletedFontFamily<T> = Deleted<T>;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = DeleteFontFamily::builder().id(1).build().unwrap();
+        let previous = json!({ "id": 1 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedFontFamily<Json> = endpoint.query(&client).await.unwrap();
+
+        assert!(response.deleted);
+        assert_eq!(response.previous, previous);
+    }
+}
@@ -0,0 +1,9 @@
+pub use create::CreateFontFace;
+pub use delete::{DeleteFontFace, DeletedFontFace};
+pub use list::ListFontFaces;
+pub use retrieve::RetrieveFontFace;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod retrieve;
@@ -0,0 +1,184 @@
+use std::path::Path;
+
+use derive_builder::Builder;
+use serde_json::Value;
+
+use crate::{endpoint::prelude::*, multipart::Form};
+
+/// A font file uploaded along with a font face.
+#[derive(Clone)]
+struct FontFile {
+    name: String,
+    filename: String,
+    data: Vec<u8>,
+}
+
+impl FontFile {
+    fn content_type(&self) -> &'static str {
+        let extension = Path::new(&self.filename)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("woff2") => "font/woff2",
+            Some("woff") => "font/woff",
+            Some("ttf") => "font/ttf",
+            Some("otf") => "font/otf",
+            _ => "application/octet-stream",
+        }
+    }
+}
+
+/// Install a font face into a font family.
+///
+/// Font files are uploaded as a `multipart/form-data` request. Each file is
+/// sent under the field name given to [`CreateFontFaceBuilder::file`], which
+/// must be referenced by the `src` of the font face settings.
+#[derive(Builder)]
+pub struct CreateFontFace {
+    /// The ID for the parent font family of the font face.
+    font_family_id: u32,
+
+    /// Version of the theme.json schema used for the typography settings.
+    #[builder(setter(strip_option), default)]
+    theme_json_version: Option<u32>,
+
+    /// The font face declaration, in theme.json format.
+    ///
+    /// Must include `fontFamily` and `src`.
+    font_face_settings: Value,
+
+    /// Font files to upload.
+    #[builder(setter(custom), default)]
+    files: Vec<FontFile>,
+}
+
+impl CreateFontFace {
+    pub fn builder() -> CreateFontFaceBuilder {
+        CreateFontFaceBuilder::default()
+    }
+
+    fn encode(&self, form: &mut Form) -> Result<(), BodyError> {
+        if let Some(version) = self.theme_json_version {
+            form.text("theme_json_version", &version.to_string());
+        }
+        form.text(
+            "font_face_settings",
+            &serde_json::to_string(&self.font_face_settings)?,
+        );
+        for file in &self.files {
+            form.file(&file.name, &file.filename, file.content_type(), &file.data);
+        }
+        Ok(())
+    }
+}
+
+impl CreateFontFaceBuilder {
+    /// Upload a font file under the given field name.
+    pub fn file(
+        &mut self,
+        name: impl Into<String>,
+        filename: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+    ) -> &mut Self {
+        self.files.get_or_insert_with(Vec::new).push(FontFile {
+            name: name.into(),
+            filename: filename.into(),
+            data: data.into(),
+        });
+        self
+    }
+}
+
+impl Endpoint for CreateFontFace {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/font-families/{}/font-faces", self.font_family_id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        let mut form = Form::new();
+        self.encode(&mut form)?;
+        Ok(Some((form.content_type(), form.finish())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    fn endpoint() -> CreateFontFace {
+        CreateFontFace::builder()
+            .font_family_id(1)
+            .font_face_settings(json!({
+                "fontFamily": "Inter",
+                "fontWeight": "400",
+                "src": "file-0",
+            }))
+            .file("file-0", "inter.woff2", "woff2")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn body() {
+        let mut form = Form::with_boundary("boundary");
+        endpoint().encode(&mut form).unwrap();
+
+        assert_eq!(
+            String::from_utf8(form.finish()).unwrap(),
+            "--boundary\r\n\
+             Content-Disposition: form-data; name=\"font_face_settings\"\r\n\
+             \r\n\
+             {\"fontFamily\":\"Inter\",\"fontWeight\":\"400\",\"src\":\"file-0\"}\r\n\
+             --boundary\r\n\
+             Content-Disposition: form-data; name=\"file-0\"; filename=\"inter.woff2\"\r\n\
+             Content-Type: font/woff2\r\n\
+             \r\n\
+             woff2\r\n\
+             --boundary--\r\n"
+        );
+    }
+
+    #[test]
+    fn content_type() {
+        let (content_type, _) = endpoint().body().unwrap().unwrap();
+
+        assert!(content_type
+            .to_string()
+            .starts_with("multipart/form-data; boundary="));
+    }
+
+    #[test]
+    fn settings_required() {
+        let err = CreateFontFace::builder().font_family_id(1).build();
+        assert!(err.is_err());
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = endpoint();
+        let body = json!({ "id": 2, "parent": 1 });
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,82 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::common::Deleted};
+
+/// Delete a font face along with its font files.
+///
+/// Font faces do not support trashing, so the deletion is always forced.
+#[derive(Builder)]
+pub struct DeleteFontFace {
+    /// The ID for the parent font family of the font face.
+    font_family_id: u32,
+
+    /// Unique identifier for the font face.
+    id: u32,
+}
+
+impl DeleteFontFace {
+    pub fn builder() -> DeleteFontFaceBuilder {
+        DeleteFontFaceBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteFontFace {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!(
+            "/wp/v2/font-families/{}/font-faces/{}",
+            self.font_family_id, self.id
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("force", true);
+        params
+    }
+}
+
+/// The result of deleting a font face.
+pub type DeletedFontFace<T> = Deleted<T>;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = DeleteFontFace::builder()
+            .font_family_id(1)
+            .id(2)
+            .build()
+            .unwrap();
+        let previous = json!({ "id": 2, "parent": 1 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedFontFace<Json> = endpoint.query(&client).await.unwrap();
+
+        assert!(response.deleted);
+        assert_eq!(response.previous, previous);
+    }
+}
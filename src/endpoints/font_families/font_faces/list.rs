@@ -0,0 +1,104 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List the font faces of a font family.
+#[derive(Builder)]
+#[builder(setter(strip_option))]
+pub struct ListFontFaces {
+    /// The ID for the parent font family of the font faces.
+    font_family_id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into), default)]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    #[builder(default)]
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    #[builder(default)]
+    per_page: Option<u32>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false), default)]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false), default)]
+    include: Vec<u32>,
+
+    /// Order sort attribute ascending or descending.
+    #[builder(setter(into), default)]
+    order: Option<String>,
+
+    /// Sort collection by font face attribute.
+    #[builder(setter(into), default)]
+    orderby: Option<String>,
+}
+
+impl ListFontFaces {
+    pub fn builder() -> ListFontFacesBuilder {
+        ListFontFacesBuilder::default()
+    }
+}
+
+impl Endpoint for ListFontFaces {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/font-families/{}/font-faces", self.font_family_id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref());
+        params
+    }
+}
+
+impl Pageable for ListFontFaces {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn font_family_required() {
+        let err = ListFontFaces::builder().build();
+        assert!(err.is_err());
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListFontFaces::builder().font_family_id(1).build().unwrap();
+        let body = json!([{ "id": 2, "parent": 1 }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,75 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a font face.
+#[derive(Builder)]
+pub struct RetrieveFontFace {
+    /// The ID for the parent font family of the font face.
+    font_family_id: u32,
+
+    /// Unique identifier for the font face.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveFontFace {
+    pub fn builder() -> RetrieveFontFaceBuilder {
+        RetrieveFontFaceBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveFontFace {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!(
+            "/wp/v2/font-families/{}/font-faces/{}",
+            self.font_family_id, self.id
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveFontFace::builder()
+            .font_family_id(1)
+            .id(2)
+            .build()
+            .unwrap();
+        let body = json!({ "id": 2, "parent": 1 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
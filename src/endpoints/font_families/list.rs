@@ -0,0 +1,102 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List installed font families.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListFontFamilies {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Order sort attribute ascending or descending.
+    #[builder(setter(into))]
+    order: Option<String>,
+
+    /// Sort collection by font family attribute.
+    #[builder(setter(into))]
+    orderby: Option<String>,
+
+    /// Limit result set to font families with one or more specific slugs.
+    #[builder(setter(into, strip_option = false))]
+    slug: Vec<String>,
+}
+
+impl ListFontFamilies {
+    pub fn builder() -> ListFontFamiliesBuilder {
+        ListFontFamiliesBuilder::default()
+    }
+}
+
+impl Endpoint for ListFontFamilies {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/font-families".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_list("slug", &self.slug);
+        params
+    }
+}
+
+impl Pageable for ListFontFamilies {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListFontFamilies::builder()
+            .slug(vec!["inter".into()])
+            .build()
+            .unwrap();
+        let body = json!([{ "id": 1, "font_family_settings": { "slug": "inter" } }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("slug=inter")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
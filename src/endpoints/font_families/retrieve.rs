@@ -0,0 +1,64 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a font family.
+#[derive(Builder)]
+pub struct RetrieveFontFamily {
+    /// Unique identifier for the font family.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveFontFamily {
+    pub fn builder() -> RetrieveFontFamilyBuilder {
+        RetrieveFontFamilyBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveFontFamily {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/font-families/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveFontFamily::builder().id(1).build().unwrap();
+        let body = json!({ "id": 1, "font_faces": [2, 3] });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,97 @@
+use derive_builder::Builder;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{endpoint::prelude::*, endpoints::font_families::serialize_settings};
+
+/// Update a font family.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct UpdateFontFamily {
+    /// Unique identifier for the font family.
+    #[serde(skip)]
+    id: u32,
+
+    /// Version of the theme.json schema used for the typography settings.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme_json_version: Option<u32>,
+
+    /// The font family declaration, in theme.json format.
+    #[builder(default)]
+    #[serde(
+        serialize_with = "serialize_settings",
+        skip_serializing_if = "Option::is_none"
+    )]
+    font_family_settings: Option<Value>,
+}
+
+impl UpdateFontFamily {
+    pub fn builder() -> UpdateFontFamilyBuilder {
+        UpdateFontFamilyBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateFontFamily {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/font-families/{}", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = UpdateFontFamily::builder()
+            .id(1)
+            .font_family_settings(json!({ "name": "Inter Variable" }))
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "font_family_settings": "{\"name\":\"Inter Variable\"}" })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = UpdateFontFamily::builder()
+            .id(1)
+            .theme_json_version(3)
+            .build()
+            .unwrap();
+        let body = json!({ "id": 1 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,3 @@
+pub use retrieve::RetrieveIndex;
+
+pub mod retrieve;
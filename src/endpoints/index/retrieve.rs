@@ -0,0 +1,76 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve the API index.
+///
+/// The index describes the site and lists the namespaces and authentication
+/// methods supported by the API.
+#[derive(Default, Builder)]
+pub struct RetrieveIndex {}
+
+impl RetrieveIndex {
+    pub fn builder() -> RetrieveIndexBuilder {
+        RetrieveIndexBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveIndex {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::SiteIndex,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveIndex::builder().build().unwrap();
+        let body = json!({
+            "name": "My Site",
+            "description": "Just another WordPress site",
+            "url": "https://example.com",
+            "home": "https://example.com",
+            "gmt_offset": "-5",
+            "timezone_string": "America/New_York",
+            "namespaces": ["oembed/1.0", "wp/v2", "wp-site-health/v1"],
+            "authentication": {
+                "application-passwords": {
+                    "endpoints": {
+                        "authorization": "https://example.com/wp-admin/authorize-application.php",
+                    },
+                },
+            },
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: SiteIndex = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.name, "My Site");
+        assert_eq!(response.gmt_offset, -5.0);
+        assert!(response.supports_namespace("wp/v2"));
+        assert!(response
+            .authentication
+            .contains_key("application-passwords"));
+    }
+}
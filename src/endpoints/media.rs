@@ -0,0 +1,11 @@
+pub use create::CreateMedia;
+pub use edit::{EditMedia, Modifier};
+pub use retrieve::RetrieveMedia;
+pub use sideload::SideloadMedia;
+pub use upload::UploadMedia;
+
+pub mod create;
+pub mod edit;
+pub mod retrieve;
+pub mod sideload;
+pub mod upload;
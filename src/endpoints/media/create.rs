@@ -0,0 +1,176 @@
+use std::path::Path;
+
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, multipart::Form};
+
+/// Upload a file as an attachment.
+///
+/// The file is sent as a `multipart/form-data` request along with the other
+/// fields. Its content type is guessed from the extension of the filename
+/// unless set with [`CreateMediaBuilder::mime_type`]. Use
+/// [`UploadMedia`](super::UploadMedia) to upload large files without loading
+/// them into memory.
+#[derive(Builder)]
+pub struct CreateMedia {
+    /// Name of the uploaded file.
+    #[builder(setter(into))]
+    filename: String,
+
+    /// Contents of the uploaded file.
+    #[builder(setter(into))]
+    data: Vec<u8>,
+
+    /// The content type of the uploaded file.
+    #[builder(setter(into, strip_option), default)]
+    mime_type: Option<String>,
+
+    /// The title for the attachment.
+    #[builder(setter(into, strip_option), default)]
+    title: Option<String>,
+
+    /// Alternative text to display when the attachment is not displayed.
+    #[builder(setter(into, strip_option), default)]
+    alt_text: Option<String>,
+
+    /// The attachment caption.
+    #[builder(setter(into, strip_option), default)]
+    caption: Option<String>,
+
+    /// The attachment description.
+    #[builder(setter(into, strip_option), default)]
+    description: Option<String>,
+
+    /// The ID for the associated post of the attachment.
+    #[builder(setter(strip_option), default)]
+    post: Option<u32>,
+}
+
+impl CreateMedia {
+    pub fn builder() -> CreateMediaBuilder {
+        CreateMediaBuilder::default()
+    }
+
+    fn mime_type(&self) -> &str {
+        match &self.mime_type {
+            Some(mime_type) => mime_type,
+            None => guess_mime_type(&self.filename),
+        }
+    }
+
+    fn encode(&self, form: &mut Form) {
+        for (name, value) in [
+            ("title", &self.title),
+            ("alt_text", &self.alt_text),
+            ("caption", &self.caption),
+            ("description", &self.description),
+        ] {
+            if let Some(value) = value {
+                form.text(name, value);
+            }
+        }
+        if let Some(post) = self.post {
+            form.text("post", &post.to_string());
+        }
+        form.file("file", &self.filename, self.mime_type(), &self.data);
+    }
+}
+
+/// Guess the content type of a file from the extension of its name.
+pub(super) fn guess_mime_type(filename: &str) -> &'static str {
+    let extension = Path::new(filename)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("png") => "image/png",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("avif") => "image/avif",
+        Some("svg") => "image/svg+xml",
+        Some("pdf") => "application/pdf",
+        Some("mp3") => "audio/mpeg",
+        Some("mp4") => "video/mp4",
+        _ => "application/octet-stream",
+    }
+}
+
+impl Endpoint for CreateMedia {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/media".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        let mut form = Form::new();
+        self.encode(&mut form);
+        Ok(Some((form.content_type(), form.finish())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    fn endpoint() -> CreateMedia {
+        CreateMedia::builder()
+            .filename("photo.PNG")
+            .data(b"png".to_vec())
+            .alt_text("A photo")
+            .post(3)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn body() {
+        let mut form = Form::with_boundary("boundary");
+        endpoint().encode(&mut form);
+
+        assert_eq!(
+            String::from_utf8(form.finish()).unwrap(),
+            "--boundary\r\n\
+             Content-Disposition: form-data; name=\"alt_text\"\r\n\
+             \r\n\
+             A photo\r\n\
+             --boundary\r\n\
+             Content-Disposition: form-data; name=\"post\"\r\n\
+             \r\n\
+             3\r\n\
+             --boundary\r\n\
+             Content-Disposition: form-data; name=\"file\"; filename=\"photo.PNG\"\r\n\
+             Content-Type: image/png\r\n\
+             \r\n\
+             png\r\n\
+             --boundary--\r\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = endpoint();
+        let body = json!({ "id": 7, "media_type": "image" });
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,160 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::endpoint::prelude::*;
+
+/// A modification to apply to an image.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", content = "args", rename_all = "lowercase")]
+pub enum Modifier {
+    /// Rotate the image clockwise.
+    Rotate {
+        /// Angle to rotate clockwise in degrees.
+        angle: f64,
+    },
+
+    /// Crop the image.
+    ///
+    /// All values are percentages of the image dimensions.
+    Crop {
+        /// Horizontal position from the left to begin the crop.
+        left: f64,
+        /// Vertical position from the top to begin the crop.
+        top: f64,
+        /// Width of the crop.
+        width: f64,
+        /// Height of the crop.
+        height: f64,
+    },
+}
+
+/// Edit an image attachment.
+///
+/// The edited image is saved as a new attachment.
+#[derive(Builder, Serialize)]
+pub struct EditMedia {
+    /// Unique identifier for the attachment.
+    #[serde(skip)]
+    id: u32,
+
+    /// URL to the edited image file.
+    #[builder(setter(into))]
+    src: String,
+
+    /// Array of image edits.
+    #[builder(setter(custom))]
+    modifiers: Vec<Modifier>,
+}
+
+impl EditMedia {
+    pub fn builder() -> EditMediaBuilder {
+        EditMediaBuilder::default()
+    }
+}
+
+impl EditMediaBuilder {
+    /// Add a modifier.
+    pub fn modifier(&mut self, modifier: Modifier) -> &mut Self {
+        self.modifiers.get_or_insert_with(Vec::new).push(modifier);
+        self
+    }
+
+    /// Add multiple modifiers.
+    pub fn modifiers<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Modifier>,
+    {
+        self.modifiers.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+}
+
+impl Endpoint for EditMedia {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/media/{}/edit", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn modifiers_required() {
+        let err = EditMedia::builder()
+            .id(1)
+            .src("http://example.com/image.png")
+            .build();
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn body() {
+        let endpoint = EditMedia::builder()
+            .id(1)
+            .src("http://example.com/image.png")
+            .modifier(Modifier::Rotate { angle: 90.0 })
+            .modifier(Modifier::Crop {
+                left: 0.0,
+                top: 10.0,
+                width: 50.0,
+                height: 50.0,
+            })
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "src": "http://example.com/image.png",
+                "modifiers": [
+                    { "type": "rotate", "args": { "angle": 90.0 } },
+                    {
+                        "type": "crop",
+                        "args": { "left": 0.0, "top": 10.0, "width": 50.0, "height": 50.0 },
+                    },
+                ],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = EditMedia::builder()
+            .id(1)
+            .src("http://example.com/image.png")
+            .modifier(Modifier::Rotate { angle: 90.0 })
+            .build()
+            .unwrap();
+        let body = json!({ "id": 2 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
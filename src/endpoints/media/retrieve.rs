@@ -0,0 +1,68 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+#[derive(Builder)]
+pub struct RetrieveMedia {
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveMedia {
+    pub fn builder() -> RetrieveMediaBuilder {
+        RetrieveMediaBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveMedia {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/media/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::Attachment,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveMedia::builder().id(7).build().unwrap();
+        let response = MockResponse::builder()
+            .route("/wp/v2/media/7")
+            .json(json!({
+                "id": 7,
+                "source_url": "http://example.com/wp-content/uploads/photo.png",
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let attachment: Attachment = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(
+            attachment.source_url,
+            "http://example.com/wp-content/uploads/photo.png"
+        );
+    }
+}
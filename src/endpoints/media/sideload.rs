@@ -0,0 +1,255 @@
+use async_trait::async_trait;
+use derive_builder::Builder;
+use futures_util::TryStreamExt;
+use http::{
+    header::{self, HeaderMap, HeaderValue},
+    Method, Request,
+};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::{
+    client::Client, error::ApiError, params::QueryParams, query::Query, request::RequestBuilder,
+};
+
+/// Download media from a remote URL and upload it as an attachment.
+///
+/// The remote media is downloaded with [`Client::send_external_request`], so
+/// the client's credentials are not sent to other hosts.
+#[derive(Builder)]
+pub struct SideloadMedia {
+    /// URL of the remote media.
+    url: Url,
+
+    /// Name of the uploaded file.
+    ///
+    /// Defaults to the last path segment of the remote URL.
+    #[builder(setter(into, strip_option), default)]
+    filename: Option<String>,
+
+    /// Alternative text to display when the attachment is not displayed.
+    #[builder(setter(into, strip_option), default)]
+    alt_text: Option<String>,
+
+    /// The attachment caption.
+    #[builder(setter(into, strip_option), default)]
+    caption: Option<String>,
+
+    /// Maximum size of the remote media in bytes.
+    ///
+    /// The download is aborted as soon as it is exceeded.
+    #[builder(setter(strip_option), default)]
+    max_size: Option<usize>,
+}
+
+impl SideloadMedia {
+    pub fn builder() -> SideloadMediaBuilder {
+        SideloadMediaBuilder::default()
+    }
+
+    fn filename(&self) -> &str {
+        match &self.filename {
+            Some(filename) => filename,
+            None => filename(&self.url),
+        }
+    }
+}
+
+/// The last path segment of a URL, or "file" if it has none.
+pub(crate) fn filename(url: &Url) -> &str {
+    url.path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|segment| !segment.is_empty())
+        .unwrap_or("file")
+}
+
+/// Download a file with [`Client::send_external_request`], returning its
+/// headers and body.
+///
+/// The download is aborted as soon as the file exceeds `max_size` bytes.
+pub(crate) async fn download<C>(
+    client: &C,
+    url: &Url,
+    max_size: Option<usize>,
+) -> Result<(HeaderMap, Vec<u8>), ApiError<C::Error>>
+where
+    C: Client + Sync,
+{
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(url.as_str())
+        .body(Vec::new())
+        .map_err(ApiError::request)?;
+    let resp = client.send_external_request(req).await?;
+
+    if !resp.status().is_success() {
+        return Err(ApiError::media_download(url.clone(), resp.status()));
+    }
+
+    let (parts, mut stream) = resp.into_parts();
+    let too_large = |len: usize| max_size.filter(|&limit| len > limit);
+    let content_length = parts
+        .headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if let Some(limit) = content_length.and_then(too_large) {
+        return Err(ApiError::media_too_large(url.clone(), limit));
+    }
+    let mut body = Vec::new();
+    while let Some(chunk) = stream.try_next().await? {
+        if let Some(limit) = too_large(body.len() + chunk.len()) {
+            return Err(ApiError::media_too_large(url.clone(), limit));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok((parts.headers, body))
+}
+
+#[async_trait]
+impl<T, C> Query<T, C> for SideloadMedia
+where
+    T: DeserializeOwned + 'static,
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let (headers, body) = download(client, &self.url, self.max_size).await?;
+
+        let content_type = headers
+            .get(header::CONTENT_TYPE)
+            .cloned()
+            .unwrap_or_else(|| HeaderValue::from_static("application/octet-stream"));
+        let disposition = format!(
+            "attachment; filename=\"{}\"",
+            self.filename().replace('"', "")
+        );
+        let disposition =
+            HeaderValue::from_str(&disposition).map_err(|err| ApiError::request(err.into()))?;
+
+        let mut url = client.route_url("/wp/v2/media").await?;
+        let mut params = QueryParams::default();
+        params
+            .push_opt("alt_text", self.alt_text.as_ref())
+            .push_opt("caption", self.caption.as_ref());
+        params.add_to_url(&mut url);
+
+        RequestBuilder::new()
+            .method(Method::POST)
+            .url(url)
+            .header(header::CONTENT_TYPE, content_type)
+            .header(header::CONTENT_DISPOSITION, disposition)
+            .body(body)
+            .query(client)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::test::{MockClient, MockResponse};
+
+    fn remote(status: StatusCode) -> MockResponse {
+        MockResponse::builder()
+            .route("/images/photo.png")
+            .status(status)
+            .header("content-type", "image/png")
+            .body("png")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = SideloadMedia::builder()
+            .url(Url::parse("http://remote.example/images/photo.png").unwrap())
+            .alt_text("A photo")
+            .build()
+            .unwrap();
+        let body = json!({ "id": 1 });
+        let mut client = MockClient::with_response(remote(StatusCode::OK));
+        client.insert(
+            MockResponse::builder()
+                .method(Method::POST)
+                .route("/wp/v2/media")
+                .query("alt_text=A+photo")
+                .expect_header("content-type", "image/png")
+                .expect_header("content-disposition", "attachment; filename=\"photo.png\"")
+                .json(body.clone())
+                .build()
+                .unwrap(),
+        );
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+
+    #[tokio::test]
+    async fn download_failed() {
+        let endpoint = SideloadMedia::builder()
+            .url(Url::parse("http://remote.example/images/photo.png").unwrap())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(remote(StatusCode::NOT_FOUND));
+
+        let result: Result<Json, _> = endpoint.query(&client).await;
+
+        let err = result.expect_err("expected ApiError::MediaDownload");
+        if let ApiError::MediaDownload { status, .. } = err {
+            assert_eq!(status, StatusCode::NOT_FOUND);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn too_large_without_content_length() {
+        let endpoint = SideloadMedia::builder()
+            .url(Url::parse("http://remote.example/images/photo.png").unwrap())
+            .max_size(10)
+            .build()
+            .unwrap();
+        // The body is streamed in several chunks without a Content-Length.
+        let client = MockClient::with_response(
+            MockResponse::builder()
+                .route("/images/photo.png")
+                .header("content-type", "image/png")
+                .body("0123456789abcdef")
+                .build()
+                .unwrap(),
+        );
+
+        let result: Result<Json, _> = endpoint.query(&client).await;
+
+        let err = result.expect_err("expected ApiError::MediaTooLarge");
+        if let ApiError::MediaTooLarge { limit, .. } = err {
+            assert_eq!(limit, 10);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn too_large() {
+        let endpoint = SideloadMedia::builder()
+            .url(Url::parse("http://remote.example/images/photo.png").unwrap())
+            .max_size(2)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(remote(StatusCode::OK));
+
+        let result: Result<Json, _> = endpoint.query(&client).await;
+
+        let err = result.expect_err("expected ApiError::MediaTooLarge");
+        if let ApiError::MediaTooLarge { limit, .. } = err {
+            assert_eq!(limit, 2);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
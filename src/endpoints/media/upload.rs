@@ -0,0 +1,267 @@
+use std::io;
+
+use bytes::Bytes;
+use derive_builder::Builder;
+use futures_util::{io::AsyncRead, stream, AsyncReadExt, Stream, StreamExt, TryStreamExt};
+use http::{
+    header::{self, HeaderValue},
+    Method, Request,
+};
+use serde::de::DeserializeOwned;
+
+use super::create::guess_mime_type;
+use crate::{
+    client::{Client, RequestBodyStream},
+    error::ApiError,
+    params::QueryParams,
+    request,
+};
+
+/// The size of the chunks read from a reader.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+type Progress = Box<dyn FnMut(u64, Option<u64>) + Send>;
+
+/// Upload a file as an attachment as it is read, without loading it into
+/// memory.
+///
+/// The file is sent as the body of the request, with the other fields as
+/// query parameters, so clients which support it send each chunk as soon as
+/// it is read. As the file can only be read once, the upload is sent with
+/// [`UploadMedia::send`] rather than as a [`Query`](crate::Query).
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+pub struct UploadMedia {
+    /// Name of the uploaded file.
+    #[builder(setter(into))]
+    filename: String,
+
+    /// Contents of the uploaded file.
+    #[builder(setter(custom))]
+    file: RequestBodyStream,
+
+    /// Size of the file in bytes, which is sent as the `Content-Length` and
+    /// reported along with the progress.
+    #[builder(setter(strip_option), default)]
+    length: Option<u64>,
+
+    /// The content type of the uploaded file.
+    ///
+    /// Guessed from the extension of the filename by default.
+    #[builder(setter(into, strip_option), default)]
+    mime_type: Option<String>,
+
+    /// The title for the attachment.
+    #[builder(setter(into, strip_option), default)]
+    title: Option<String>,
+
+    /// Alternative text to display when the attachment is not displayed.
+    #[builder(setter(into, strip_option), default)]
+    alt_text: Option<String>,
+
+    /// The attachment caption.
+    #[builder(setter(into, strip_option), default)]
+    caption: Option<String>,
+
+    /// The attachment description.
+    #[builder(setter(into, strip_option), default)]
+    description: Option<String>,
+
+    /// The ID for the associated post of the attachment.
+    #[builder(setter(strip_option), default)]
+    post: Option<u32>,
+
+    #[builder(setter(custom), default)]
+    progress: Option<Progress>,
+}
+
+impl UploadMediaBuilder {
+    /// Read the file from a stream of chunks.
+    pub fn stream(
+        mut self,
+        stream: impl Stream<Item = io::Result<Bytes>> + Send + 'static,
+    ) -> Self {
+        self.file = Some(stream.boxed());
+        self
+    }
+
+    /// Read the file from a reader, such as an open file.
+    pub fn reader(self, reader: impl AsyncRead + Unpin + Send + 'static) -> Self {
+        self.stream(read_chunks(reader))
+    }
+
+    /// Report progress as the file is read, with the number of bytes read so
+    /// far and the [`length`](Self::length) when set.
+    pub fn progress(mut self, progress: impl FnMut(u64, Option<u64>) + Send + 'static) -> Self {
+        self.progress = Some(Some(Box::new(progress)));
+        self
+    }
+}
+
+impl UploadMedia {
+    pub fn builder() -> UploadMediaBuilder {
+        UploadMediaBuilder::default()
+    }
+
+    /// Upload the file, returning the created attachment.
+    pub async fn send<T, C>(self, client: &C) -> Result<T, ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+        C: Client + Sync,
+    {
+        let mut url = client.route_url("/wp/v2/media").await?;
+        let mut params = QueryParams::default();
+        params
+            .push_opt("title", self.title.as_ref())
+            .push_opt("alt_text", self.alt_text.as_ref())
+            .push_opt("caption", self.caption.as_ref())
+            .push_opt("description", self.description.as_ref())
+            .push_opt("post", self.post);
+        params.add_to_url(&mut url);
+
+        let content_type = self
+            .mime_type
+            .as_deref()
+            .unwrap_or_else(|| guess_mime_type(&self.filename));
+        let disposition = format!(
+            "attachment; filename=\"{}\"",
+            self.filename.replace('"', "")
+        );
+        let mut builder = Request::builder()
+            .method(Method::POST)
+            .uri(url.as_str())
+            .header(
+                header::CONTENT_TYPE,
+                HeaderValue::from_str(content_type).map_err(|err| ApiError::request(err.into()))?,
+            )
+            .header(
+                header::CONTENT_DISPOSITION,
+                HeaderValue::from_str(&disposition).map_err(|err| ApiError::request(err.into()))?,
+            );
+        if let Some(length) = self.length {
+            builder = builder.header(header::CONTENT_LENGTH, length);
+        }
+
+        let body = match self.progress {
+            Some(mut progress) => {
+                let length = self.length;
+                let mut read = 0;
+                self.file
+                    .inspect_ok(move |chunk| {
+                        read += chunk.len() as u64;
+                        progress(read, length);
+                    })
+                    .boxed()
+            }
+            None => self.file,
+        };
+        let request = builder.body(body).map_err(ApiError::request)?;
+
+        let resp = client.send_streaming_request(request).await?;
+        let (_, attachment) = request::json_response(resp)?;
+        Ok(attachment)
+    }
+}
+
+/// Read chunks of a reader until it is exhausted.
+fn read_chunks(
+    reader: impl AsyncRead + Unpin + Send + 'static,
+) -> impl Stream<Item = io::Result<Bytes>> + Send {
+    stream::try_unfold(reader, |mut reader| async move {
+        let mut chunk = vec![0; CHUNK_SIZE];
+        let read = reader.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        chunk.truncate(read);
+        Ok(Some((Bytes::from(chunk), reader)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures_util::io::Cursor;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::Attachment,
+    };
+
+    #[tokio::test]
+    async fn progress() {
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route("/wp/v2/media")
+            .query("title=Trailer&post=3")
+            .expect_header("content-type", "video/mp4")
+            .expect_header(
+                "content-disposition",
+                "attachment; filename=\"trailer.mp4\"",
+            )
+            .expect_header("content-length", "150000")
+            .json(json!({ "id": 7, "mime_type": "video/mp4" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let progress = Arc::new(Mutex::new(Vec::new()));
+
+        let attachment: Attachment = UploadMedia::builder()
+            .filename("trailer.mp4")
+            .reader(Cursor::new(vec![0; 150_000]))
+            .length(150_000)
+            .title("Trailer")
+            .post(3)
+            .progress({
+                let progress = progress.clone();
+                move |read, length| progress.lock().unwrap().push((read, length))
+            })
+            .build()
+            .unwrap()
+            .send(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(attachment.id, 7);
+        assert_eq!(
+            *progress.lock().unwrap(),
+            vec![
+                (65_536, Some(150_000)),
+                (131_072, Some(150_000)),
+                (150_000, Some(150_000)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn read_error() {
+        let client = MockClient::new();
+        let file = stream::iter([
+            Ok(Bytes::from_static(b"abc")),
+            Err(io::Error::other("disk removed")),
+        ]);
+
+        let err = UploadMedia::builder()
+            .filename("photo.png")
+            .stream(file)
+            .build()
+            .unwrap()
+            .send::<Attachment, _>(&client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::Body { .. }));
+    }
+
+    #[test]
+    fn file_required() {
+        assert!(UploadMedia::builder()
+            .filename("photo.png")
+            .build()
+            .is_err());
+    }
+}
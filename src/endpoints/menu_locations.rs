@@ -0,0 +1,5 @@
+pub use list::ListMenuLocations;
+pub use retrieve::RetrieveMenuLocation;
+
+pub mod list;
+pub mod retrieve;
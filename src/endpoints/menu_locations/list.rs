@@ -0,0 +1,72 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List navigation menu locations.
+///
+/// The response is an object keyed by the location name.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListMenuLocations {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+}
+
+impl ListMenuLocations {
+    pub fn builder() -> ListMenuLocationsBuilder {
+        ListMenuLocationsBuilder::default()
+    }
+}
+
+impl Endpoint for ListMenuLocations {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/menu-locations".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::MenuLocation,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListMenuLocations::builder().build().unwrap();
+        let body = json!({
+            "primary": { "name": "primary", "description": "Primary Menu", "menu": 3 },
+            "footer": { "name": "footer", "description": "Footer Menu", "menu": 0 },
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: BTreeMap<String, MenuLocation> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response["primary"].menu(), Some(3));
+        assert_eq!(response["footer"].menu(), None);
+    }
+}
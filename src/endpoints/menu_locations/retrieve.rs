@@ -0,0 +1,70 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a navigation menu location.
+#[derive(Builder)]
+pub struct RetrieveMenuLocation {
+    /// An alphanumeric identifier for the menu location.
+    #[builder(setter(into))]
+    location: String,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveMenuLocation {
+    pub fn builder() -> RetrieveMenuLocationBuilder {
+        RetrieveMenuLocationBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveMenuLocation {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/menu-locations/{}", self.location).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::MenuLocation,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveMenuLocation::builder()
+            .location("primary")
+            .build()
+            .unwrap();
+        let body = json!({ "name": "primary", "description": "Primary Menu", "menu": 3 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: MenuLocation = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.menu(), Some(3));
+    }
+}
@@ -0,0 +1,11 @@
+pub use create::CreateMenu;
+pub use delete::{DeleteMenu, DeletedMenu};
+pub use list::ListMenus;
+pub use retrieve::RetrieveMenu;
+pub use update::UpdateMenu;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod retrieve;
+pub mod update;
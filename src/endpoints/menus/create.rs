@@ -0,0 +1,105 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, types::Meta};
+
+/// Create a navigation menu.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateMenu {
+    /// Name of the menu.
+    #[builder(setter(into))]
+    name: String,
+
+    /// Description of the menu.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// An alphanumeric identifier for the menu unique to its type.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// The locations assigned to the menu.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locations: Option<Vec<String>>,
+
+    /// Whether to automatically add top level pages to this menu.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_add: Option<bool>,
+
+    /// Meta fields.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<Meta>,
+}
+
+impl CreateMenu {
+    pub fn builder() -> CreateMenuBuilder {
+        CreateMenuBuilder::default()
+    }
+}
+
+impl Endpoint for CreateMenu {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/menus".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = CreateMenu::builder()
+            .name("Primary")
+            .locations(vec!["primary".to_string()])
+            .auto_add(true)
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "name": "Primary", "locations": ["primary"], "auto_add": true })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = CreateMenu::builder().name("Primary").build().unwrap();
+        let body = json!({ "id": 1, "name": "Primary" });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
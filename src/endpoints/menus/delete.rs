@@ -0,0 +1,71 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::common::Deleted};
+
+/// Delete a navigation menu.
+///
+/// Menus do not support trashing, so the deletion is always forced.
+#[derive(Builder)]
+pub struct DeleteMenu {
+    /// Unique identifier for the menu.
+    id: u32,
+}
+
+impl DeleteMenu {
+    pub fn builder() -> DeleteMenuBuilder {
+        DeleteMenuBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteMenu {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/menus/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("force", true);
+        params
+    }
+}
+
+/// The result of deleting a navigation menu.
+pub type DeletedMenu<T> = Deleted<T>;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = DeleteMenu::builder().id(2).build().unwrap();
+        let previous = json!({ "id": 2 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedMenu<Json> = endpoint.query(&client).await.unwrap();
+
+        assert!(response.deleted);
+        assert_eq!(response.previous, previous);
+    }
+}
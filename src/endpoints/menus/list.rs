@@ -0,0 +1,137 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List navigation menus.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListMenus {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    #[builder(setter(into))]
+    order: Option<String>,
+
+    /// Sort collection by term attribute.
+    #[builder(setter(into))]
+    orderby: Option<String>,
+
+    /// Whether to hide terms not assigned to any posts.
+    hide_empty: Option<bool>,
+
+    /// Limit result set to terms assigned to a specific post.
+    post: Option<u32>,
+
+    /// Limit result set to terms with one or more specific slugs.
+    #[builder(setter(into, strip_option = false))]
+    slug: Vec<String>,
+}
+
+impl ListMenus {
+    pub fn builder() -> ListMenusBuilder {
+        ListMenusBuilder::default()
+    }
+}
+
+impl Endpoint for ListMenus {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/menus".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_opt("hide_empty", self.hide_empty)
+            .push_opt("post", self.post)
+            .push_list("slug", &self.slug);
+        params
+    }
+}
+
+impl Pageable for ListMenus {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListMenus::builder().build().unwrap();
+        let body = json!([{ "id": 1 }, { "id": 2 }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+
+    #[tokio::test]
+    async fn filters() {
+        let endpoint = ListMenus::builder()
+            .hide_empty(true)
+            .post(1)
+            .build()
+            .unwrap();
+        let body = json!([]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("hide_empty=true&post=1")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,109 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, types::Meta};
+
+/// Update a navigation menu.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct UpdateMenu {
+    /// Unique identifier for the menu.
+    #[serde(skip)]
+    id: u32,
+
+    /// Name of the menu.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    /// Description of the menu.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// An alphanumeric identifier for the menu unique to its type.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// The locations assigned to the menu.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locations: Option<Vec<String>>,
+
+    /// Whether to automatically add top level pages to this menu.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_add: Option<bool>,
+
+    /// Meta fields.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<Meta>,
+}
+
+impl UpdateMenu {
+    pub fn builder() -> UpdateMenuBuilder {
+        UpdateMenuBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateMenu {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/menus/{}", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = UpdateMenu::builder()
+            .id(1)
+            .locations(vec!["footer".to_string()])
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "locations": ["footer"] })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = UpdateMenu::builder().id(1).name("Primary").build().unwrap();
+        let body = json!({ "id": 1, "name": "Primary" });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
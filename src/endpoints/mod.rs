@@ -1,3 +1,73 @@
-pub use posts::RetrievePost;
+pub use block_renderer::{RenderBlock, RenderedBlock};
+pub use comments::{
+    CommentStatus, CreateComment, DeleteComment, DeletedComment, ListComments, RetrieveComment,
+    UpdateComment,
+};
+pub use common::{Context, Deleted, Order, OrderBy, PostStatus};
+pub use font_families::{
+    CreateFontFace, CreateFontFamily, DeleteFontFace, DeleteFontFamily, DeletedFontFace,
+    DeletedFontFamily, ListFontFaces, ListFontFamilies, RetrieveFontFace, RetrieveFontFamily,
+    UpdateFontFamily,
+};
+pub use index::RetrieveIndex;
+pub use media::{CreateMedia, EditMedia, Modifier, RetrieveMedia, SideloadMedia, UploadMedia};
+pub use menu_locations::{ListMenuLocations, RetrieveMenuLocation};
+pub use menus::{CreateMenu, DeleteMenu, DeletedMenu, ListMenus, RetrieveMenu, UpdateMenu};
+pub use namespaces::RetrieveNamespace;
+pub use navigation::{
+    CreateNavigation, CreateNavigationAutosave, DeleteNavigation, DeleteNavigationRevision,
+    DeletedNavigation, DeletedNavigationRevision, ListNavigationAutosaves, ListNavigationRevisions,
+    ListNavigations, RetrieveNavigation, RetrieveNavigationAutosave, RetrieveNavigationRevision,
+    UpdateNavigation,
+};
+pub use oembed::{ProxyOEmbed, RetrieveOEmbed};
+pub use pages::{
+    CreatePage, DeletePage, DeletedPage, ListPages, RetrievePage, RetrievePageByPath, UpdatePage,
+};
+pub use posts::{
+    CreatePost, DeletePost, DeletedPost, ListPosts, RetrievePost, RetrievePostBySlug, StickyFirst,
+};
+pub use raw::RawEndpoint;
+pub use search::{Search, SearchType};
+pub use settings::{RetrieveSettings, UpdateSettings};
+pub use sidebars::{ListSidebars, RetrieveSidebar};
+pub use tags::{CreateTag, DeleteTag, DeletedTag, ListTags, RetrieveTag, UpdateTag};
+pub use taxonomies::{ListTaxonomies, RetrieveTaxonomy};
+pub use terms::{
+    CreateTerm, DeleteTerm, DeletedTerm, ListTerms, RetrieveTerm, TermTree, UpdateTerm,
+};
+pub use types::{ListTypes, RetrieveType};
+pub use users::{CreateUser, DeleteUser, DeletedUser, Reassign, UpdateUser};
+pub use widget_types::{
+    EncodeWidgetType, EncodedWidget, ListWidgetTypes, RenderWidgetType, RenderedWidget,
+    RetrieveWidgetType,
+};
+pub use widgets::{
+    CreateWidget, DeleteWidget, DeletedWidget, ListWidgets, RetrieveWidget, UpdateWidget,
+    WidgetInstance,
+};
 
+pub mod block_renderer;
+pub mod comments;
+pub mod common;
+pub mod font_families;
+pub mod index;
+pub mod media;
+pub mod menu_locations;
+pub mod menus;
+pub mod namespaces;
+pub mod navigation;
+pub mod oembed;
+pub mod pages;
 pub mod posts;
+pub mod raw;
+pub mod search;
+pub mod settings;
+pub mod sidebars;
+pub mod tags;
+pub mod taxonomies;
+pub mod terms;
+pub mod types;
+pub mod users;
+pub mod widget_types;
+pub mod widgets;
@@ -0,0 +1,3 @@
+pub use retrieve::RetrieveNamespace;
+
+pub mod retrieve;
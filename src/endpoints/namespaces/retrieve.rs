@@ -0,0 +1,106 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve the routes registered under an API namespace.
+#[derive(Builder)]
+pub struct RetrieveNamespace {
+    /// The namespace name, such as `wp/v2`.
+    #[builder(setter(into))]
+    namespace: String,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveNamespace {
+    pub fn builder() -> RetrieveNamespaceBuilder {
+        RetrieveNamespaceBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveNamespace {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/{}", self.namespace.trim_matches('/')).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::Namespace,
+        Query,
+    };
+
+    #[test]
+    fn namespace_required() {
+        let err = RetrieveNamespace::builder().build();
+        assert!(err.is_err());
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveNamespace::builder()
+            .namespace("wp/v2")
+            .build()
+            .unwrap();
+        let body = json!({
+            "namespace": "wp/v2",
+            "routes": {
+                "/wp/v2/posts": {
+                    "namespace": "wp/v2",
+                    "methods": ["GET", "POST"],
+                    "endpoints": [
+                        {
+                            "methods": ["GET"],
+                            "args": {
+                                "per_page": {
+                                    "description": "Maximum number of items to be returned in result set.",
+                                    "type": "integer",
+                                    "default": 10,
+                                    "minimum": 1,
+                                    "maximum": 100,
+                                    "required": false,
+                                },
+                            },
+                        },
+                        { "methods": ["POST"], "args": [] },
+                    ],
+                },
+            },
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route("/wp/v2")
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Namespace = endpoint.query(&client).await.unwrap();
+
+        let route = response.route("/wp/v2/posts").unwrap();
+        assert!(route.supports(&Method::POST));
+        assert!(!route.supports(&Method::DELETE));
+        let per_page = &route.endpoints[0].args["per_page"];
+        assert_eq!(per_page.default, Some(json!(10)));
+        assert_eq!(per_page.schema["maximum"], json!(100));
+        assert!(route.endpoints[1].args.is_empty());
+    }
+}
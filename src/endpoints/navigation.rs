@@ -0,0 +1,20 @@
+pub use autosaves::{
+    CreateNavigationAutosave, ListNavigationAutosaves, RetrieveNavigationAutosave,
+};
+pub use create::CreateNavigation;
+pub use delete::{DeleteNavigation, DeletedNavigation};
+pub use list::ListNavigations;
+pub use retrieve::RetrieveNavigation;
+pub use revisions::{
+    DeleteNavigationRevision, DeletedNavigationRevision, ListNavigationRevisions,
+    RetrieveNavigationRevision,
+};
+pub use update::UpdateNavigation;
+
+pub mod autosaves;
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod retrieve;
+pub mod revisions;
+pub mod update;
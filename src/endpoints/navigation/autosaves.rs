@@ -0,0 +1,7 @@
+pub use create::CreateNavigationAutosave;
+pub use list::ListNavigationAutosaves;
+pub use retrieve::RetrieveNavigationAutosave;
+
+pub mod create;
+pub mod list;
+pub mod retrieve;
@@ -0,0 +1,126 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::endpoint::prelude::*;
+
+/// Create an autosave of a navigation menu post.
+///
+/// When the current user is the author of a draft, WordPress updates the
+/// draft itself instead of creating a separate autosave.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateNavigationAutosave {
+    /// The ID for the parent of the autosave.
+    #[serde(skip)]
+    parent: u32,
+
+    /// The date the post was published, in the site's timezone.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+
+    /// The date the post was published, as GMT.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_gmt: Option<String>,
+
+    /// An alphanumeric identifier for the post unique to its type.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// A named status for the post.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<PostStatus>,
+
+    /// A password to protect access to the post.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+
+    /// The title for the post.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+
+    /// The serialized blocks making up the navigation menu.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+
+    /// The theme file to use to display the post.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+}
+
+impl CreateNavigationAutosave {
+    pub fn builder() -> CreateNavigationAutosaveBuilder {
+        CreateNavigationAutosaveBuilder::default()
+    }
+}
+
+impl Endpoint for CreateNavigationAutosave {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/navigation/{}/autosaves", self.parent).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = CreateNavigationAutosave::builder()
+            .parent(1)
+            .content("<!-- wp:page-list /-->")
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "content": "<!-- wp:page-list /-->" })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = CreateNavigationAutosave::builder()
+            .parent(1)
+            .title("Header")
+            .build()
+            .unwrap();
+        let body = json!({ "id": 4, "parent": 1 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,69 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List the autosaves of a navigation menu post.
+#[derive(Builder)]
+pub struct ListNavigationAutosaves {
+    /// The ID for the parent of the autosaves.
+    parent: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl ListNavigationAutosaves {
+    pub fn builder() -> ListNavigationAutosavesBuilder {
+        ListNavigationAutosavesBuilder::default()
+    }
+}
+
+impl Endpoint for ListNavigationAutosaves {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/navigation/{}/autosaves", self.parent).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListNavigationAutosaves::builder()
+            .parent(1)
+            .context(Context::Edit)
+            .build()
+            .unwrap();
+        let body = json!([{ "id": 4, "parent": 1 }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("context=edit")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,71 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve an autosave of a navigation menu post.
+#[derive(Builder)]
+pub struct RetrieveNavigationAutosave {
+    /// The ID for the parent of the autosave.
+    parent: u32,
+
+    /// The ID for the autosave.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveNavigationAutosave {
+    pub fn builder() -> RetrieveNavigationAutosaveBuilder {
+        RetrieveNavigationAutosaveBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveNavigationAutosave {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/navigation/{}/autosaves/{}", self.parent, self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveNavigationAutosave::builder()
+            .parent(1)
+            .id(4)
+            .build()
+            .unwrap();
+        let body = json!({ "id": 4, "parent": 1 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
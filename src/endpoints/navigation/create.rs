@@ -0,0 +1,120 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::endpoint::prelude::*;
+
+/// Create a navigation menu post.
+#[derive(Default, Builder, Serialize)]
+#[builder(setter(strip_option), default)]
+pub struct CreateNavigation {
+    /// The date the post was published, in the site's timezone.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+
+    /// The date the post was published, as GMT.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_gmt: Option<String>,
+
+    /// An alphanumeric identifier for the post unique to its type.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// A named status for the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<PostStatus>,
+
+    /// A password to protect access to the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+
+    /// The title for the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+
+    /// The serialized blocks making up the navigation menu.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+
+    /// The theme file to use to display the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+}
+
+impl CreateNavigation {
+    pub fn builder() -> CreateNavigationBuilder {
+        CreateNavigationBuilder::default()
+    }
+}
+
+impl Endpoint for CreateNavigation {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/navigation".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = CreateNavigation::builder()
+            .title("Header")
+            .status("publish")
+            .content("<!-- wp:page-list /-->")
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "title": "Header",
+                "status": "publish",
+                "content": "<!-- wp:page-list /-->",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = CreateNavigation::builder().title("Header").build().unwrap();
+        let body = json!({ "id": 1, "title": { "rendered": "Header" } });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
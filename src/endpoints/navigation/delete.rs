@@ -0,0 +1,110 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::posts::DeletedPost};
+
+/// Delete a navigation menu post.
+///
+/// By default, the navigation menu post is moved to the trash. Set `force` to
+/// permanently delete the navigation menu post instead.
+#[derive(Builder)]
+pub struct DeleteNavigation {
+    /// Unique identifier for the navigation menu post.
+    id: u32,
+
+    /// Whether to bypass the trash and force deletion.
+    #[builder(setter(strip_option), default)]
+    force: Option<bool>,
+}
+
+impl DeleteNavigation {
+    pub fn builder() -> DeleteNavigationBuilder {
+        DeleteNavigationBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteNavigation {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/navigation/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("force", self.force);
+        params
+    }
+}
+
+/// The result of deleting a navigation menu post.
+pub type DeletedNavigation<T> = DeletedPost<T>;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn trash() {
+        let endpoint = DeleteNavigation::builder().id(123).build().unwrap();
+        let body = json!({
+            "id": endpoint.id,
+            "status": "trash",
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedNavigation<Json> = endpoint.query(&client).await.unwrap();
+
+        if let DeletedNavigation::Trashed(post) = response {
+            assert_eq!(post, body);
+        } else {
+            panic!("unexpected response: {:?}", response);
+        }
+    }
+
+    #[tokio::test]
+    async fn force() {
+        let endpoint = DeleteNavigation::builder()
+            .id(123)
+            .force(true)
+            .build()
+            .unwrap();
+        let previous = json!({
+            "id": endpoint.id,
+            "status": "publish",
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedNavigation<Json> = endpoint.query(&client).await.unwrap();
+
+        if let DeletedNavigation::Deleted { previous: post } = response {
+            assert_eq!(post, previous);
+        } else {
+            panic!("unexpected response: {:?}", response);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List navigation menu posts.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListNavigations {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Limit response to posts published after a given ISO8601 compliant date.
+    #[builder(setter(into))]
+    after: Option<Timestamp>,
+
+    /// Limit response to posts modified after a given ISO8601 compliant date.
+    #[builder(setter(into))]
+    modified_after: Option<Timestamp>,
+
+    /// Limit response to posts published before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    before: Option<Timestamp>,
+
+    /// Limit response to posts modified before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    modified_before: Option<Timestamp>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    order: Option<Order>,
+
+    /// Sort collection by post attribute.
+    #[builder(setter(into))]
+    orderby: Option<OrderBy>,
+
+    /// Limit result set to posts with one or more specific slugs.
+    #[builder(setter(into, strip_option = false))]
+    slug: Vec<String>,
+
+    /// Limit result set to posts assigned one or more statuses.
+    #[builder(setter(into, strip_option = false))]
+    status: Vec<PostStatus>,
+}
+
+impl ListNavigations {
+    pub fn builder() -> ListNavigationsBuilder {
+        ListNavigationsBuilder::default()
+    }
+}
+
+impl Endpoint for ListNavigations {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/navigation".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("after", self.after.as_ref())
+            .push_opt("modified_after", self.modified_after.as_ref())
+            .push_opt("before", self.before.as_ref())
+            .push_opt("modified_before", self.modified_before.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_list("slug", &self.slug)
+            .push_list("status", &self.status);
+        params
+    }
+}
+
+impl Pageable for ListNavigations {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListNavigations::builder().build().unwrap();
+        let body = json!([{ "id": 1 }, { "id": 2 }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+
+    #[tokio::test]
+    async fn filters() {
+        let endpoint = ListNavigations::builder()
+            .status(vec!["publish".into(), "draft".into()])
+            .slug(vec!["header".into()])
+            .build()
+            .unwrap();
+        let body = json!([]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("slug=header&status=publish%2Cdraft")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
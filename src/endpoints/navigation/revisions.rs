@@ -0,0 +1,7 @@
+pub use delete::{DeleteNavigationRevision, DeletedNavigationRevision};
+pub use list::ListNavigationRevisions;
+pub use retrieve::RetrieveNavigationRevision;
+
+pub mod delete;
+pub mod list;
+pub mod retrieve;
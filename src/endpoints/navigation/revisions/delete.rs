@@ -0,0 +1,78 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::common::Deleted};
+
+/// Delete a revision of a navigation menu post.
+///
+/// Revisions do not support trashing, so the deletion is always forced.
+#[derive(Builder)]
+pub struct DeleteNavigationRevision {
+    /// The ID for the parent of the revision.
+    parent: u32,
+
+    /// Unique identifier for the revision.
+    id: u32,
+}
+
+impl DeleteNavigationRevision {
+    pub fn builder() -> DeleteNavigationRevisionBuilder {
+        DeleteNavigationRevisionBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteNavigationRevision {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/navigation/{}/revisions/{}", self.parent, self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("force", true);
+        params
+    }
+}
+
+/// The result of deleting a navigation menu post revision.
+pub type DeletedNavigationRevision<T> = Deleted<T>;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = DeleteNavigationRevision::builder()
+            .parent(1)
+            .id(2)
+            .build()
+            .unwrap();
+        let previous = json!({ "id": 2, "parent": 1 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedNavigationRevision<Json> = endpoint.query(&client).await.unwrap();
+
+        assert!(response.deleted);
+        assert_eq!(response.previous, previous);
+    }
+}
@@ -0,0 +1,119 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List the revisions of a navigation menu post.
+#[derive(Builder)]
+#[builder(setter(strip_option))]
+pub struct ListNavigationRevisions {
+    /// The ID for the parent of the revisions.
+    parent: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into), default)]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    #[builder(default)]
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    #[builder(default)]
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into), default)]
+    search: Option<String>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false), default)]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false), default)]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    #[builder(default)]
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    #[builder(setter(into), default)]
+    order: Option<String>,
+
+    /// Sort collection by object attribute.
+    #[builder(setter(into), default)]
+    orderby: Option<String>,
+}
+
+impl ListNavigationRevisions {
+    pub fn builder() -> ListNavigationRevisionsBuilder {
+        ListNavigationRevisionsBuilder::default()
+    }
+}
+
+impl Endpoint for ListNavigationRevisions {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/navigation/{}/revisions", self.parent).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref());
+        params
+    }
+}
+
+impl Pageable for ListNavigationRevisions {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn parent_required() {
+        let err = ListNavigationRevisions::builder().build();
+        assert!(err.is_err());
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListNavigationRevisions::builder()
+            .parent(1)
+            .per_page(5)
+            .build()
+            .unwrap();
+        let body = json!([{ "id": 3, "parent": 1 }, { "id": 2, "parent": 1 }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("per_page=5")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
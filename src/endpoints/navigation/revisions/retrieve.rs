@@ -0,0 +1,71 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a revision of a navigation menu post.
+#[derive(Builder)]
+pub struct RetrieveNavigationRevision {
+    /// The ID for the parent of the revision.
+    parent: u32,
+
+    /// Unique identifier for the revision.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveNavigationRevision {
+    pub fn builder() -> RetrieveNavigationRevisionBuilder {
+        RetrieveNavigationRevisionBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveNavigationRevision {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/navigation/{}/revisions/{}", self.parent, self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveNavigationRevision::builder()
+            .parent(1)
+            .id(2)
+            .build()
+            .unwrap();
+        let body = json!({ "id": 2, "parent": 1 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,129 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::endpoint::prelude::*;
+
+/// Update a navigation menu post.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct UpdateNavigation {
+    /// Unique identifier for the post.
+    #[serde(skip)]
+    id: u32,
+
+    /// The date the post was published, in the site's timezone.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+
+    /// The date the post was published, as GMT.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_gmt: Option<String>,
+
+    /// An alphanumeric identifier for the post unique to its type.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// A named status for the post.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<PostStatus>,
+
+    /// A password to protect access to the post.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+
+    /// The title for the post.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+
+    /// The serialized blocks making up the navigation menu.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+
+    /// The theme file to use to display the post.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+}
+
+impl UpdateNavigation {
+    pub fn builder() -> UpdateNavigationBuilder {
+        UpdateNavigationBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateNavigation {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/navigation/{}", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn id_required() {
+        let err = UpdateNavigation::builder().title("Header").build();
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn body() {
+        let endpoint = UpdateNavigation::builder()
+            .id(1)
+            .content("<!-- wp:navigation-link /-->")
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "content": "<!-- wp:navigation-link /-->" })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = UpdateNavigation::builder()
+            .id(1)
+            .title("Footer")
+            .build()
+            .unwrap();
+        let body = json!({ "id": 1, "title": { "rendered": "Footer" } });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,5 @@
+pub use embed::RetrieveOEmbed;
+pub use proxy::ProxyOEmbed;
+
+pub mod embed;
+pub mod proxy;
@@ -0,0 +1,85 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve the oEmbed data of a post or page of the site.
+///
+/// Only URLs of the site itself can be embedded. Use [`ProxyOEmbed`] for
+/// other URLs.
+///
+/// [`ProxyOEmbed`]: super::ProxyOEmbed
+#[derive(Builder)]
+pub struct RetrieveOEmbed {
+    /// The URL of the resource to embed.
+    #[builder(setter(into))]
+    url: String,
+
+    /// The maximum width of the embed frame, in pixels.
+    #[builder(setter(strip_option), default)]
+    maxwidth: Option<u32>,
+}
+
+impl RetrieveOEmbed {
+    pub fn builder() -> RetrieveOEmbedBuilder {
+        RetrieveOEmbedBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveOEmbed {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/oembed/1.0/embed".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push("url", self.url.as_str())
+            .push_opt("maxwidth", self.maxwidth);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::OEmbed,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn typed() {
+        let endpoint = RetrieveOEmbed::builder()
+            .url("http://example.com/hello-world/")
+            .maxwidth(400)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/oembed/1.0/embed")
+            .query("url=http%3A%2F%2Fexample.com%2Fhello-world%2F&maxwidth=400")
+            .json(json!({
+                "version": "1.0",
+                "type": "rich",
+                "title": "Hello world!",
+                "width": 400,
+                "height": 225,
+                "html": "<blockquote class=\"wp-embedded-content\"></blockquote>",
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let embed: OEmbed = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(embed.title.as_deref(), Some("Hello world!"));
+        assert_eq!(embed.width, Some(400));
+    }
+}
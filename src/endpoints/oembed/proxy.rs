@@ -0,0 +1,96 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve the oEmbed data of any URL through the site.
+///
+/// The site fetches the data from the provider of the URL, so apps can embed
+/// URLs without knowing the provider. This needs the `edit_posts`
+/// capability.
+#[derive(Builder)]
+pub struct ProxyOEmbed {
+    /// The URL of the resource to embed.
+    #[builder(setter(into))]
+    url: String,
+
+    /// The maximum width of the embed frame, in pixels.
+    #[builder(setter(strip_option), default)]
+    maxwidth: Option<u32>,
+
+    /// The maximum height of the embed frame, in pixels.
+    #[builder(setter(strip_option), default)]
+    maxheight: Option<u32>,
+
+    /// Whether to discover the provider of URLs from unknown providers.
+    /// Defaults to true.
+    #[builder(setter(strip_option), default)]
+    discover: Option<bool>,
+}
+
+impl ProxyOEmbed {
+    pub fn builder() -> ProxyOEmbedBuilder {
+        ProxyOEmbedBuilder::default()
+    }
+}
+
+impl Endpoint for ProxyOEmbed {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/oembed/1.0/proxy".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push("url", self.url.as_str())
+            .push_opt("maxwidth", self.maxwidth)
+            .push_opt("maxheight", self.maxheight)
+            .push_opt("discover", self.discover);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::OEmbed,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn video() {
+        let endpoint = ProxyOEmbed::builder()
+            .url("https://www.youtube.com/watch?v=dQw4w9WgXcQ")
+            .discover(false)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/oembed/1.0/proxy")
+            .query("url=https%3A%2F%2Fwww.youtube.com%2Fwatch%3Fv%3DdQw4w9WgXcQ&discover=false")
+            .json(json!({
+                "version": "1.0",
+                "type": "video",
+                "provider_name": "YouTube",
+                "width": 200,
+                "height": 113,
+                "thumbnail_url": "https://i.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg",
+                "html": "<iframe></iframe>",
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let embed: OEmbed = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(embed.embed_type, "video");
+        assert_eq!(embed.provider_name.as_deref(), Some("YouTube"));
+    }
+}
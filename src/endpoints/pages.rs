@@ -0,0 +1,13 @@
+pub use create::CreatePage;
+pub use delete::{DeletePage, DeletedPage};
+pub use list::ListPages;
+pub use path::RetrievePageByPath;
+pub use retrieve::RetrievePage;
+pub use update::UpdatePage;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod path;
+pub mod retrieve;
+pub mod update;
@@ -0,0 +1,161 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, types::Meta};
+
+/// Create a page.
+#[derive(Default, Builder, Serialize)]
+#[builder(setter(strip_option), default)]
+pub struct CreatePage {
+    /// The date the page was published, in the site's timezone.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+
+    /// The date the page was published, as GMT.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_gmt: Option<String>,
+
+    /// An alphanumeric identifier for the page unique to its type.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// A named status for the page.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<PostStatus>,
+
+    /// A password to protect access to the content and excerpt.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+
+    /// The title for the page.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+
+    /// The content for the page.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+
+    /// The ID for the author of the page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<u32>,
+
+    /// The excerpt for the page.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    excerpt: Option<String>,
+
+    /// The ID of the featured media for the page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    featured_media: Option<u32>,
+
+    /// Whether or not comments are open on the page.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment_status: Option<String>,
+
+    /// Whether or not the page can be pinged.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ping_status: Option<String>,
+
+    /// The ID for the parent of the page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<u32>,
+
+    /// The order of the page in relation to other pages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    menu_order: Option<i32>,
+
+    /// Meta fields.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<Meta>,
+
+    /// The theme file to use to display the page.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+}
+
+impl CreatePage {
+    pub fn builder() -> CreatePageBuilder {
+        CreatePageBuilder::default()
+    }
+}
+
+impl Endpoint for CreatePage {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/pages".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = CreatePage::builder()
+            .title("Hello")
+            .status("draft")
+            .parent(2)
+            .menu_order(3)
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "title": "Hello",
+                "status": "draft",
+                "parent": 2,
+                "menu_order": 3,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = CreatePage::builder().title("Hello").build().unwrap();
+        let body = json!({
+            "id": 1,
+            "title": { "rendered": "Hello" },
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,106 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::posts::DeletedPost};
+
+/// Delete a page.
+///
+/// By default, the page is moved to the trash. Set `force` to permanently
+/// delete the page instead.
+#[derive(Builder)]
+pub struct DeletePage {
+    /// Unique identifier for the page.
+    id: u32,
+
+    /// Whether to bypass the trash and force deletion.
+    #[builder(setter(strip_option), default)]
+    force: Option<bool>,
+}
+
+impl DeletePage {
+    pub fn builder() -> DeletePageBuilder {
+        DeletePageBuilder::default()
+    }
+}
+
+impl Endpoint for DeletePage {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/pages/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("force", self.force);
+        params
+    }
+}
+
+/// The result of deleting a page.
+pub type DeletedPage<T> = DeletedPost<T>;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn trash() {
+        let endpoint = DeletePage::builder().id(123).build().unwrap();
+        let body = json!({
+            "id": endpoint.id,
+            "status": "trash",
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedPage<Json> = endpoint.query(&client).await.unwrap();
+
+        if let DeletedPage::Trashed(post) = response {
+            assert_eq!(post, body);
+        } else {
+            panic!("unexpected response: {:?}", response);
+        }
+    }
+
+    #[tokio::test]
+    async fn force() {
+        let endpoint = DeletePage::builder().id(123).force(true).build().unwrap();
+        let previous = json!({
+            "id": endpoint.id,
+            "status": "publish",
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedPage<Json> = endpoint.query(&client).await.unwrap();
+
+        if let DeletedPage::Deleted { previous: post } = response {
+            assert_eq!(post, previous);
+        } else {
+            panic!("unexpected response: {:?}", response);
+        }
+    }
+}
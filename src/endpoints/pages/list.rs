@@ -0,0 +1,180 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List pages.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListPages {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Limit response to pages published after a given ISO8601 compliant date.
+    #[builder(setter(into))]
+    after: Option<Timestamp>,
+
+    /// Limit response to pages modified after a given ISO8601 compliant date.
+    #[builder(setter(into))]
+    modified_after: Option<Timestamp>,
+
+    /// Limit result set to pages assigned to specific authors.
+    #[builder(setter(into, strip_option = false))]
+    author: Vec<u32>,
+
+    /// Ensure result set excludes pages assigned to specific authors.
+    #[builder(setter(into, strip_option = false))]
+    author_exclude: Vec<u32>,
+
+    /// Limit response to pages published before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    before: Option<Timestamp>,
+
+    /// Limit response to pages modified before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    modified_before: Option<Timestamp>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Limit result set to pages with a specific menu_order value.
+    menu_order: Option<i32>,
+
+    /// Offset the result set by a specific number of items.
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    order: Option<Order>,
+
+    /// Sort collection by page attribute.
+    #[builder(setter(into))]
+    orderby: Option<OrderBy>,
+
+    /// Limit result set to items with particular parent IDs.
+    #[builder(setter(into, strip_option = false))]
+    parent: Vec<u32>,
+
+    /// Limit result set to all items except those of a particular parent ID.
+    #[builder(setter(into, strip_option = false))]
+    parent_exclude: Vec<u32>,
+
+    /// Limit result set to pages with one or more specific slugs.
+    #[builder(setter(into, strip_option = false))]
+    slug: Vec<String>,
+
+    /// Limit result set to pages assigned one or more statuses.
+    #[builder(setter(into, strip_option = false))]
+    status: Vec<PostStatus>,
+}
+
+impl ListPages {
+    pub fn builder() -> ListPagesBuilder {
+        ListPagesBuilder::default()
+    }
+}
+
+impl Endpoint for ListPages {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/pages".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("after", self.after.as_ref())
+            .push_opt("modified_after", self.modified_after.as_ref())
+            .push_list("author", &self.author)
+            .push_list("author_exclude", &self.author_exclude)
+            .push_opt("before", self.before.as_ref())
+            .push_opt("modified_before", self.modified_before.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("menu_order", self.menu_order)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_list("parent", &self.parent)
+            .push_list("parent_exclude", &self.parent_exclude)
+            .push_list("slug", &self.slug)
+            .push_list("status", &self.status);
+        params
+    }
+}
+
+impl Pageable for ListPages {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListPages::builder().build().unwrap();
+        let body = json!([{ "id": 1 }, { "id": 2 }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+
+    #[tokio::test]
+    async fn hierarchy() {
+        let endpoint = ListPages::builder()
+            .parent(vec![1, 2])
+            .parent_exclude(vec![3])
+            .menu_order(0)
+            .build()
+            .unwrap();
+        let body = json!([]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("menu_order=0&parent=1%2C2&parent_exclude=3")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use url::Url;
+
+use crate::{
+    client::Client,
+    endpoints::{
+        common::{self, Context},
+        pages::ListPages,
+    },
+    error::ApiError,
+    query::Query,
+};
+
+/// Retrieve a page by its path, such as `about/team`.
+///
+/// Pages are looked up by the slug of the last segment and then matched
+/// against the path of their `link`, so this requires pretty permalinks when
+/// the path has more than one segment. A single segment only matches top-level
+/// pages.
+///
+/// Fails with [`ApiError::NotFound`] if no page has the path and with
+/// [`ApiError::MultipleMatches`] if more than one does.
+#[derive(Builder)]
+pub struct RetrievePageByPath {
+    /// The path of the page, relative to the site.
+    #[builder(setter(into))]
+    path: String,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrievePageByPath {
+    pub fn builder() -> RetrievePageByPathBuilder {
+        RetrievePageByPathBuilder::default()
+    }
+}
+
+fn matches_path(page: &Value, path: &str, nested: bool) -> bool {
+    if !nested {
+        return page["parent"].as_u64().unwrap_or(0) == 0;
+    }
+    page["link"]
+        .as_str()
+        .and_then(|link| Url::parse(link).ok())
+        .is_some_and(|link| {
+            link.path()
+                .trim_end_matches('/')
+                .ends_with(&format!("/{}", path))
+        })
+}
+
+#[async_trait]
+impl<T, C> Query<T, C> for RetrievePageByPath
+where
+    T: DeserializeOwned + 'static,
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let path = self.path.trim_matches('/');
+        let (nested, slug) = match path.rsplit_once('/') {
+            Some((_, slug)) => (true, slug),
+            None => (false, path),
+        };
+
+        let mut endpoint = ListPages::builder();
+        endpoint.slug(vec![slug.to_string()]);
+        if let Some(context) = self.context {
+            endpoint.context(context);
+        }
+        let endpoint = endpoint.build().expect("all fields have defaults");
+
+        let mut items: Vec<Value> = endpoint.query(client).await?;
+        items.retain(|page| matches_path(page, path, nested));
+        common::single(path, items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::test::{MockClient, MockResponse};
+
+    fn client(slug: &str) -> MockClient {
+        let response = MockResponse::builder()
+            .route("/wp/v2/pages")
+            .query(format!("slug={}", slug))
+            .json(json!([
+                { "id": 1, "parent": 0, "link": "http://example.com/team/" },
+                { "id": 2, "parent": 3, "link": "http://example.com/about/team/" },
+                { "id": 4, "parent": 5, "link": "http://example.com/careers/team/" },
+            ]))
+            .build()
+            .unwrap();
+        MockClient::with_response(response)
+    }
+
+    fn endpoint(path: &str) -> RetrievePageByPath {
+        RetrievePageByPath::builder().path(path).build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn top_level() {
+        let client = client("team");
+
+        let page: Value = endpoint("/team/").query(&client).await.unwrap();
+
+        assert_eq!(page["id"], 1);
+    }
+
+    #[tokio::test]
+    async fn nested() {
+        let client = client("team");
+
+        let page: Value = endpoint("about/team").query(&client).await.unwrap();
+
+        assert_eq!(page["id"], 2);
+    }
+
+    #[tokio::test]
+    async fn not_found() {
+        let client = client("team");
+
+        let err = Query::<Value, _>::query(&endpoint("company/team"), &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::NotFound { slug } if slug == "company/team"));
+    }
+}
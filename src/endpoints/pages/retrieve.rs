@@ -0,0 +1,70 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+#[derive(Builder)]
+pub struct RetrievePage {
+    id: u32,
+}
+
+impl RetrievePage {
+    pub fn builder() -> RetrievePageBuilder {
+        RetrievePageBuilder::default()
+    }
+}
+
+impl Endpoint for RetrievePage {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/pages/{}", self.id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrievePage::builder().id(123).build().unwrap();
+        let body = json!({
+            "id": endpoint.id,
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+
+    #[tokio::test]
+    async fn typed() {
+        let endpoint = RetrievePage::builder().id(123).build().unwrap();
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(json!({ "id": 123, "parent": 2, "menu_order": 1 }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let page: crate::types::Page = endpoint.query(&client).await.unwrap();
+
+        assert_eq!((page.id, page.parent, page.menu_order), (123, 2, 1));
+    }
+}
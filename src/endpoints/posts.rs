@@ -1,3 +1,11 @@
+pub use create::CreatePost;
+pub use delete::{DeletePost, DeletedPost};
+pub use list::{ListPosts, StickyFirst};
 pub use retrieve::RetrievePost;
+pub use slug::RetrievePostBySlug;
 
+pub mod create;
+pub mod delete;
+pub mod list;
 pub mod retrieve;
+pub mod slug;
@@ -0,0 +1,193 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, types::Meta};
+
+/// Create a post.
+#[derive(Default, Builder, Serialize)]
+#[builder(setter(strip_option), default)]
+pub struct CreatePost {
+    /// The date the post was published, in the site's timezone.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+
+    /// The date the post was published, as GMT.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_gmt: Option<String>,
+
+    /// An alphanumeric identifier for the post unique to its type.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// A named status for the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<PostStatus>,
+
+    /// A password to protect access to the content and excerpt.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+
+    /// The title for the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+
+    /// The content for the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+
+    /// The ID for the author of the post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<u32>,
+
+    /// The excerpt for the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    excerpt: Option<String>,
+
+    /// The ID of the featured media for the post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    featured_media: Option<u32>,
+
+    /// Whether or not comments are open on the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment_status: Option<String>,
+
+    /// Whether or not the post can be pinged.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ping_status: Option<String>,
+
+    /// The format for the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+
+    /// Meta fields.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<Meta>,
+
+    /// Whether or not the post should be treated as sticky.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sticky: Option<bool>,
+
+    /// The theme file to use to display the post.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+
+    /// The terms assigned to the post in the category taxonomy.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    categories: Option<Vec<u32>>,
+
+    /// The terms assigned to the post in the post_tag taxonomy.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<u32>>,
+}
+
+impl CreatePost {
+    pub fn builder() -> CreatePostBuilder {
+        CreatePostBuilder::default()
+    }
+}
+
+impl Endpoint for CreatePost {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/posts".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::MetaKey,
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = CreatePost::builder()
+            .title("Hello")
+            .status("draft")
+            .categories(vec![1, 2])
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "title": "Hello",
+                "status": "draft",
+                "categories": [1, 2],
+            })
+        );
+    }
+
+    #[test]
+    fn meta() {
+        const SUBTITLE: MetaKey<String> = MetaKey::new("subtitle");
+        let mut meta = Meta::new();
+        meta.set(&SUBTITLE, "World".into()).unwrap();
+        let endpoint = CreatePost::builder()
+            .title("Hello")
+            .meta(meta)
+            .build()
+            .unwrap();
+
+        let (_, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "title": "Hello",
+                "meta": { "subtitle": "World" },
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = CreatePost::builder().title("Hello").build().unwrap();
+        let body = json!({
+            "id": 1,
+            "title": { "rendered": "Hello" },
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
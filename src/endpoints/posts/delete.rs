@@ -0,0 +1,118 @@
+use derive_builder::Builder;
+use serde::Deserialize;
+
+use crate::endpoint::prelude::*;
+
+/// Delete a post.
+///
+/// By default, the post is moved to the trash. Set `force` to permanently
+/// delete the post instead.
+#[derive(Builder)]
+pub struct DeletePost {
+    /// Unique identifier for the post.
+    id: u32,
+
+    /// Whether to bypass the trash and force deletion.
+    #[builder(setter(strip_option), default)]
+    force: Option<bool>,
+}
+
+impl DeletePost {
+    pub fn builder() -> DeletePostBuilder {
+        DeletePostBuilder::default()
+    }
+}
+
+impl Endpoint for DeletePost {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/posts/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("force", self.force);
+        params
+    }
+}
+
+/// The result of deleting a post.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DeletedPost<T> {
+    /// The post was permanently deleted.
+    Deleted {
+        /// The post as it was before deletion.
+        previous: T,
+    },
+
+    /// The post was moved to the trash.
+    Trashed(T),
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn trash() {
+        let endpoint = DeletePost::builder().id(123).build().unwrap();
+        let body = json!({
+            "id": endpoint.id,
+            "status": "trash",
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedPost<Json> = endpoint.query(&client).await.unwrap();
+
+        if let DeletedPost::Trashed(post) = response {
+            assert_eq!(post, body);
+        } else {
+            panic!("unexpected response: {:?}", response);
+        }
+    }
+
+    #[tokio::test]
+    async fn force() {
+        let endpoint = DeletePost::builder().id(123).force(true).build().unwrap();
+        let previous = json!({
+            "id": endpoint.id,
+            "status": "publish",
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedPost<Json> = endpoint.query(&client).await.unwrap();
+
+        if let DeletedPost::Deleted { previous: post } = response {
+            assert_eq!(post, previous);
+        } else {
+            panic!("unexpected response: {:?}", response);
+        }
+    }
+}
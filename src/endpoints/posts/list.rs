@@ -0,0 +1,314 @@
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::de::DeserializeOwned;
+
+use crate::{client::Client, endpoint::prelude::*, error::ApiError, query::Query};
+
+/// List posts.
+#[derive(Default, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListPosts {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Limit response to posts published after a given ISO8601 compliant date.
+    #[builder(setter(into))]
+    after: Option<Timestamp>,
+
+    /// Limit response to posts modified after a given ISO8601 compliant date.
+    #[builder(setter(into))]
+    modified_after: Option<Timestamp>,
+
+    /// Limit result set to posts assigned to specific authors.
+    #[builder(setter(into, strip_option = false))]
+    author: Vec<u32>,
+
+    /// Ensure result set excludes posts assigned to specific authors.
+    #[builder(setter(into, strip_option = false))]
+    author_exclude: Vec<u32>,
+
+    /// Limit response to posts published before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    before: Option<Timestamp>,
+
+    /// Limit response to posts modified before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    modified_before: Option<Timestamp>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    order: Option<Order>,
+
+    /// Sort collection by post attribute.
+    #[builder(setter(into))]
+    orderby: Option<OrderBy>,
+
+    /// Limit result set to posts with one or more specific slugs.
+    #[builder(setter(into, strip_option = false))]
+    slug: Vec<String>,
+
+    /// Limit result set to posts assigned one or more statuses.
+    #[builder(setter(into, strip_option = false))]
+    status: Vec<PostStatus>,
+
+    /// Limit result set to items that are sticky.
+    sticky: Option<bool>,
+
+    /// Limit result set to items with specific terms assigned in the
+    /// categories taxonomy.
+    #[builder(setter(into, strip_option = false))]
+    categories: Vec<u32>,
+
+    /// Limit result set to items except those with specific terms assigned in
+    /// the categories taxonomy.
+    #[builder(setter(into, strip_option = false))]
+    categories_exclude: Vec<u32>,
+
+    /// Limit result set to items with specific terms assigned in the tags
+    /// taxonomy.
+    #[builder(setter(into, strip_option = false))]
+    tags: Vec<u32>,
+
+    /// Limit result set to items except those with specific terms assigned in
+    /// the tags taxonomy.
+    #[builder(setter(into, strip_option = false))]
+    tags_exclude: Vec<u32>,
+}
+
+impl ListPosts {
+    pub fn builder() -> ListPostsBuilder {
+        ListPostsBuilder::default()
+    }
+
+    /// List sticky posts before the other posts, as classic themes do on the
+    /// blog page.
+    ///
+    /// See [`StickyFirst`].
+    pub fn sticky_first(self) -> StickyFirst {
+        StickyFirst { endpoint: self }
+    }
+}
+
+impl Endpoint for ListPosts {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/posts".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("after", self.after.as_ref())
+            .push_opt("modified_after", self.modified_after.as_ref())
+            .push_list("author", &self.author)
+            .push_list("author_exclude", &self.author_exclude)
+            .push_opt("before", self.before.as_ref())
+            .push_opt("modified_before", self.modified_before.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_list("slug", &self.slug)
+            .push_list("status", &self.status)
+            .push_opt("sticky", self.sticky)
+            .push_list("categories", &self.categories)
+            .push_list("categories_exclude", &self.categories_exclude)
+            .push_list("tags", &self.tags)
+            .push_list("tags_exclude", &self.tags_exclude);
+        params
+    }
+}
+
+impl Pageable for ListPosts {}
+
+/// A query listing sticky posts followed by the other posts.
+///
+/// On the first page, every sticky post matching the filters is listed before
+/// the page of non-sticky posts, so the first page may hold more than
+/// `per_page` posts. Later pages, and pages selected with `offset`, only list
+/// non-sticky posts. If `sticky` is set on the endpoint, it is queried as is.
+#[derive(Clone)]
+pub struct StickyFirst {
+    endpoint: ListPosts,
+}
+
+#[async_trait]
+impl<T, C> Query<Vec<T>, C> for StickyFirst
+where
+    T: DeserializeOwned + Send + 'static,
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        if self.endpoint.sticky.is_some() {
+            return self.endpoint.query(client).await;
+        }
+
+        let mut posts = Vec::new();
+        if self.endpoint.page.unwrap_or(1) == 1 && self.endpoint.offset.is_none() {
+            let mut sticky = self.endpoint.clone();
+            sticky.sticky = Some(true);
+            sticky.page = None;
+            sticky.per_page = Some(100);
+            let sticky: Vec<T> = sticky.query(client).await?;
+            posts.extend(sticky);
+        }
+
+        let mut rest = self.endpoint.clone();
+        rest.sticky = Some(false);
+        let rest: Vec<T> = rest.query(client).await?;
+        posts.extend(rest);
+        Ok(posts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListPosts::builder().build().unwrap();
+        let body = json!([{ "id": 1 }, { "id": 2 }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+
+    #[tokio::test]
+    async fn ordering() {
+        let endpoint = ListPosts::builder()
+            .order(Order::Asc)
+            .orderby(OrderBy::Title)
+            .status(vec![
+                PostStatus::Draft,
+                PostStatus::Unknown("archived".into()),
+            ])
+            .build()
+            .unwrap();
+        let body = json!([]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("order=asc&orderby=title&status=draft%2Carchived")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+
+    fn sticky_client() -> MockClient {
+        let sticky = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("per_page=100&sticky=true")
+            .json(json!([{ "id": 3 }]))
+            .build()
+            .unwrap();
+        let rest = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("per_page=2&sticky=false")
+            .json(json!([{ "id": 1 }, { "id": 2 }]))
+            .build()
+            .unwrap();
+        let page = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("page=2&per_page=2&sticky=false")
+            .json(json!([{ "id": 4 }]))
+            .build()
+            .unwrap();
+        MockClient::with_responses([sticky, rest, page])
+    }
+
+    #[tokio::test]
+    async fn sticky_first() {
+        let client = sticky_client();
+        let endpoint = ListPosts::builder().per_page(2).build().unwrap();
+
+        let response: Vec<Json> = endpoint.sticky_first().query(&client).await.unwrap();
+
+        assert_eq!(
+            response,
+            [json!({ "id": 3 }), json!({ "id": 1 }), json!({ "id": 2 })]
+        );
+    }
+
+    #[tokio::test]
+    async fn sticky_first_later_page() {
+        let client = sticky_client();
+        let endpoint = ListPosts::builder().page(2).per_page(2).build().unwrap();
+
+        let response: Vec<Json> = endpoint.sticky_first().query(&client).await.unwrap();
+
+        assert_eq!(response, [json!({ "id": 4 })]);
+    }
+
+    #[tokio::test]
+    async fn dates() {
+        let endpoint = ListPosts::builder()
+            .after("2022-01-01T00:00:00")
+            .modified_before(std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_647_270_566))
+            .build()
+            .unwrap();
+        let body = json!([]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("after=2022-01-01T00%3A00%3A00&modified_before=2022-03-14T15%3A09%3A26Z")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -5,6 +5,10 @@ use crate::endpoint::prelude::*;
 #[derive(Builder)]
 pub struct RetrievePost {
     id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
 }
 
 impl RetrievePost {
@@ -21,6 +25,12 @@ impl Endpoint for RetrievePost {
     fn route(&self) -> Cow<'static, str> {
         format!("/wp/v2/posts/{}", self.id).into()
     }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
 }
 
 #[cfg(test)]
@@ -30,6 +40,7 @@ mod tests {
     use super::*;
     use crate::{
         test::{MockClient, MockResponse},
+        types::Post,
         Query,
     };
 
@@ -51,4 +62,61 @@ mod tests {
 
         assert_eq!(response, body);
     }
+
+    #[tokio::test]
+    async fn typed() {
+        let endpoint = RetrievePost::builder().id(123).build().unwrap();
+        let body = json!({
+            "id": endpoint.id,
+            "slug": "hello-world",
+            "type": "post",
+            "meta": { "subtitle": "World" },
+            "tags": [4],
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Post = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.id, 123);
+        assert_eq!(response.post_type, "post");
+        assert_eq!(response.meta.value("subtitle"), Some(&json!("World")));
+        assert_eq!(response.tags, vec![4]);
+    }
+
+    #[tokio::test]
+    async fn edit_context() {
+        let endpoint = RetrievePost::builder()
+            .id(123)
+            .context(Context::Edit)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route(endpoint.route())
+            .query("context=edit")
+            .json(json!({
+                "id": 123,
+                "slug": "",
+                "generated_slug": "hello-world",
+                "permalink_template": "http://example.com/%postname%/",
+                "title": { "raw": "Hello world", "rendered": "Hello world" },
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Post = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.generated_slug.as_deref(), Some("hello-world"));
+        assert_eq!(
+            response.permalink_template.as_deref(),
+            Some("http://example.com/%postname%/")
+        );
+        assert_eq!(response.title.raw(), Some("Hello world"));
+    }
 }
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    client::Client,
+    endpoints::{
+        common::{self, Context},
+        posts::ListPosts,
+    },
+    error::ApiError,
+    query::Query,
+};
+
+/// Retrieve a post by its slug.
+///
+/// Fails with [`ApiError::NotFound`] if no post has the slug and with
+/// [`ApiError::MultipleMatches`] if more than one does.
+#[derive(Builder)]
+pub struct RetrievePostBySlug {
+    /// An alphanumeric identifier for the post.
+    #[builder(setter(into))]
+    slug: String,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrievePostBySlug {
+    pub fn builder() -> RetrievePostBySlugBuilder {
+        RetrievePostBySlugBuilder::default()
+    }
+}
+
+#[async_trait]
+impl<T, C> Query<T, C> for RetrievePostBySlug
+where
+    T: DeserializeOwned + 'static,
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let mut endpoint = ListPosts::builder();
+        endpoint.slug(vec![self.slug.clone()]);
+        if let Some(context) = self.context {
+            endpoint.context(context);
+        }
+        let endpoint = endpoint.build().expect("all fields have defaults");
+
+        let items: Vec<Value> = endpoint.query(client).await?;
+        common::single(&self.slug, items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::Post,
+    };
+
+    fn client(body: Value) -> MockClient {
+        let response = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("slug=hello-world")
+            .json(body)
+            .build()
+            .unwrap();
+        MockClient::with_response(response)
+    }
+
+    fn endpoint() -> RetrievePostBySlug {
+        RetrievePostBySlug::builder()
+            .slug("hello-world")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn found() {
+        let client = client(json!([{ "id": 1, "slug": "hello-world" }]));
+
+        let post: Post = endpoint().query(&client).await.unwrap();
+
+        assert_eq!(post.id, 1);
+    }
+
+    #[tokio::test]
+    async fn not_found() {
+        let client = client(json!([]));
+
+        let err = Query::<Post, _>::query(&endpoint(), &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::NotFound { slug } if slug == "hello-world"));
+    }
+
+    #[tokio::test]
+    async fn multiple() {
+        let client = client(json!([{ "id": 1 }, { "id": 2 }]));
+
+        let err = Query::<Post, _>::query(&endpoint(), &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::MultipleMatches { count: 2, .. }));
+    }
+}
@@ -0,0 +1,185 @@
+use derive_builder::Builder;
+use serde_json::Value;
+
+use crate::endpoint::prelude::*;
+
+/// An arbitrary API route.
+///
+/// This can be used to query routes which are not modeled by this crate, such
+/// as routes registered by plugins.
+#[derive(Builder)]
+pub struct RawEndpoint {
+    /// HTTP method used for the request.
+    #[builder(default = "Method::GET")]
+    method: Method,
+
+    /// Route of the endpoint, e.g. `/my-plugin/v1/items`.
+    #[builder(setter(into))]
+    route: String,
+
+    /// Query parameters.
+    #[builder(setter(custom), default)]
+    params: Vec<(String, String)>,
+
+    /// JSON body of the request.
+    #[builder(setter(strip_option), default)]
+    body: Option<Value>,
+}
+
+impl RawEndpoint {
+    pub fn builder() -> RawEndpointBuilder {
+        RawEndpointBuilder::default()
+    }
+}
+
+impl RawEndpointBuilder {
+    /// Add a query parameter.
+    pub fn param<'a, V>(&mut self, key: impl Into<String>, value: V) -> &mut Self
+    where
+        V: ParamValue<'a>,
+    {
+        self.params
+            .get_or_insert_with(Vec::new)
+            .push((key.into(), value.as_value().into_owned()));
+        self
+    }
+
+    /// Add multiple query parameters.
+    pub fn params<'a, I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: ParamValue<'a>,
+    {
+        self.params.get_or_insert_with(Vec::new).extend(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.as_value().into_owned())),
+        );
+        self
+    }
+}
+
+impl Endpoint for RawEndpoint {
+    fn method(&self) -> Method {
+        self.method.clone()
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        self.route.clone().into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        for (key, value) in &self.params {
+            params.push(key.as_str(), value.as_str());
+        }
+        params
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        self.body
+            .as_ref()
+            .map(|body| Ok((ContentType::Json, serde_json::to_vec(body)?)))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        ApiError, Query,
+    };
+
+    #[test]
+    fn route_required() {
+        let err = RawEndpoint::builder().build();
+        assert!(err.is_err());
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RawEndpoint::builder()
+            .route("/my-plugin/v1/items")
+            .param("per_page", 5)
+            .params([("status", "active"), ("orderby", "title")])
+            .build()
+            .unwrap();
+        let body = json!([{ "id": 1 }]);
+        let response = MockResponse::builder()
+            .method(Method::GET)
+            .route("/my-plugin/v1/items")
+            .query("per_page=5&status=active&orderby=title")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+
+    #[test]
+    fn body() {
+        let endpoint = RawEndpoint::builder()
+            .method(Method::POST)
+            .route("/my-plugin/v1/items")
+            .body(json!({ "name": "Item" }))
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "name": "Item" })
+        );
+    }
+
+    #[test]
+    fn no_body() {
+        let endpoint = RawEndpoint::builder()
+            .route("/my-plugin/v1/items")
+            .build()
+            .unwrap();
+
+        assert!(endpoint.body().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn error_response() {
+        let endpoint = RawEndpoint::builder()
+            .method(Method::DELETE)
+            .route("/my-plugin/v1/items/1")
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(Method::DELETE)
+            .route("/my-plugin/v1/items/1")
+            .status(StatusCode::FORBIDDEN)
+            .json(json!({
+                "code": "rest_forbidden",
+                "message": "Sorry, you are not allowed to do that.",
+                "data": { "status": 403 },
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let result: Result<Json, _> = endpoint.query(&client).await;
+
+        let err = result.expect_err("expected ApiError::WordPress");
+        if let ApiError::WordPress { code, .. } = err {
+            assert_eq!(code, "rest_forbidden");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::prelude::*;
+
+/// The type of object a search result refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SearchType {
+    /// A post of any searchable post type.
+    Post,
+
+    /// A term of any searchable taxonomy.
+    Term,
+
+    /// A post format.
+    PostFormat,
+}
+
+impl SearchType {
+    fn as_str(self) -> &'static str {
+        match self {
+            SearchType::Post => "post",
+            SearchType::Term => "term",
+            SearchType::PostFormat => "post-format",
+        }
+    }
+}
+
+impl ParamValue<'static> for SearchType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Search the site's content.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct Search {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Limit results to items of an object type.
+    search_type: Option<SearchType>,
+
+    /// Limit results to items of one or more object subtypes.
+    ///
+    /// Subtypes are post type, taxonomy or post format names depending on
+    /// the search type.
+    #[builder(setter(into, strip_option = false))]
+    subtype: Vec<String>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+}
+
+impl Search {
+    pub fn builder() -> SearchBuilder {
+        SearchBuilder::default()
+    }
+}
+
+impl Endpoint for Search {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/search".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("type", self.search_type)
+            .push_list("subtype", &self.subtype)
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include);
+        params
+    }
+}
+
+impl Pageable for Search {}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::{SearchResult, SearchResultId},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = Search::builder().search("hello").build().unwrap();
+        let body = json!([{
+            "id": 1,
+            "title": "Hello world!",
+            "url": "https://example.com/hello-world/",
+            "type": "post",
+            "subtype": "post",
+        }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("search=hello")
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Vec<SearchResult> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].id, Some(SearchResultId::Id(1)));
+        assert_eq!(response[0].result_type, Some(SearchType::Post));
+        assert_eq!(response[0].subtype, "post");
+    }
+
+    #[tokio::test]
+    async fn filters() {
+        let endpoint = Search::builder()
+            .search_type(SearchType::PostFormat)
+            .subtype(vec!["aside".into(), "link".into()])
+            .build()
+            .unwrap();
+        let body = json!([{
+            "id": "aside",
+            "title": "Aside",
+            "url": "https://example.com/type/aside/",
+            "type": "post-format",
+            "subtype": "aside",
+        }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("type=post-format&subtype=aside%2Clink")
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Vec<SearchResult> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response[0].id, Some(SearchResultId::Slug("aside".into())));
+        assert_eq!(response[0].result_type, Some(SearchType::PostFormat));
+    }
+}
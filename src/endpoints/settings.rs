@@ -0,0 +1,5 @@
+pub use retrieve::RetrieveSettings;
+pub use update::UpdateSettings;
+
+pub mod retrieve;
+pub mod update;
@@ -0,0 +1,62 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve the site settings.
+#[derive(Default, Builder)]
+pub struct RetrieveSettings {}
+
+impl RetrieveSettings {
+    pub fn builder() -> RetrieveSettingsBuilder {
+        RetrieveSettingsBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveSettings {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/settings".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::SiteSettings,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveSettings::builder().build().unwrap();
+        let body = json!({
+            "title": "My Site",
+            "description": "Just another WordPress site",
+            "timezone": "UTC",
+            "date_format": "F j, Y",
+            "posts_per_page": 10,
+            "use_smilies": true,
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: SiteSettings = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.title.as_deref(), Some("My Site"));
+        assert_eq!(response.posts_per_page, Some(10));
+        assert_eq!(response.use_smilies, Some(true));
+    }
+}
@@ -0,0 +1,89 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, types::SiteSettings};
+
+/// Update the site settings.
+///
+/// Only the settings which are set are updated.
+#[derive(Builder)]
+pub struct UpdateSettings {
+    /// The settings to update.
+    settings: SiteSettings,
+}
+
+impl UpdateSettings {
+    pub fn builder() -> UpdateSettingsBuilder {
+        UpdateSettingsBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateSettings {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/settings".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((
+            ContentType::Json,
+            serde_json::to_vec(&self.settings)?,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = UpdateSettings::builder()
+            .settings(SiteSettings {
+                title: Some("My Site".into()),
+                timezone: Some("Asia/Tokyo".into()),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "title": "My Site", "timezone": "Asia/Tokyo" })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = UpdateSettings::builder()
+            .settings(SiteSettings {
+                posts_per_page: Some(20),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(json!({ "title": "My Site", "posts_per_page": 20 }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: SiteSettings = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.posts_per_page, Some(20));
+    }
+}
@@ -0,0 +1,5 @@
+pub use list::ListSidebars;
+pub use retrieve::RetrieveSidebar;
+
+pub mod list;
+pub mod retrieve;
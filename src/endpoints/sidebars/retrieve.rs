@@ -0,0 +1,65 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a registered sidebar.
+#[derive(Builder)]
+pub struct RetrieveSidebar {
+    /// The id of a registered sidebar.
+    #[builder(setter(into))]
+    id: String,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveSidebar {
+    pub fn builder() -> RetrieveSidebarBuilder {
+        RetrieveSidebarBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveSidebar {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/sidebars/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveSidebar::builder().id("sidebar-1").build().unwrap();
+        let body = json!({ "id": "sidebar-1", "status": "active", "widgets": ["block-2"] });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
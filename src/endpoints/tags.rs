@@ -0,0 +1,11 @@
+pub use create::CreateTag;
+pub use delete::{DeleteTag, DeletedTag};
+pub use list::ListTags;
+pub use retrieve::RetrieveTag;
+pub use update::UpdateTag;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod retrieve;
+pub mod update;
@@ -0,0 +1,71 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::common::Deleted};
+
+/// Delete a tag.
+///
+/// Terms do not support trashing, so the deletion is always forced.
+#[derive(Builder)]
+pub struct DeleteTag {
+    /// Unique identifier for the term.
+    id: u32,
+}
+
+impl DeleteTag {
+    pub fn builder() -> DeleteTagBuilder {
+        DeleteTagBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteTag {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/tags/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("force", true);
+        params
+    }
+}
+
+/// The result of deleting a tag.
+pub type DeletedTag<T> = Deleted<T>;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = DeleteTag::builder().id(2).build().unwrap();
+        let previous = json!({ "id": 2 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedTag<Json> = endpoint.query(&client).await.unwrap();
+
+        assert!(response.deleted);
+        assert_eq!(response.previous, previous);
+    }
+}
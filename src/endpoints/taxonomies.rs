@@ -0,0 +1,5 @@
+pub use list::ListTaxonomies;
+pub use retrieve::RetrieveTaxonomy;
+
+pub mod list;
+pub mod retrieve;
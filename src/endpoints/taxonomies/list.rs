@@ -0,0 +1,96 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List taxonomies.
+///
+/// The response is an object keyed by the taxonomy slug.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListTaxonomies {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Limit results to taxonomies associated with a specific post type.
+    #[builder(setter(into))]
+    post_type: Option<String>,
+}
+
+impl ListTaxonomies {
+    pub fn builder() -> ListTaxonomiesBuilder {
+        ListTaxonomiesBuilder::default()
+    }
+}
+
+impl Endpoint for ListTaxonomies {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/taxonomies".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("type", self.post_type.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::Taxonomy,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListTaxonomies::builder().post_type("post").build().unwrap();
+        let body = json!({
+            "category": {
+                "name": "Categories",
+                "slug": "category",
+                "description": "",
+                "types": ["post"],
+                "hierarchical": true,
+                "rest_base": "categories",
+                "rest_namespace": "wp/v2",
+            },
+            "post_tag": {
+                "name": "Tags",
+                "slug": "post_tag",
+                "description": "",
+                "types": ["post"],
+                "hierarchical": false,
+                "rest_base": "tags",
+                "rest_namespace": "wp/v2",
+            },
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("type=post")
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: BTreeMap<String, Taxonomy> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.len(), 2);
+        assert_eq!(response["category"].rest_base, "categories");
+        assert!(!response["post_tag"].hierarchical);
+    }
+}
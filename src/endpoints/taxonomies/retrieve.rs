@@ -0,0 +1,82 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a taxonomy.
+#[derive(Builder)]
+pub struct RetrieveTaxonomy {
+    /// An alphanumeric identifier for the taxonomy.
+    #[builder(setter(into))]
+    taxonomy: String,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveTaxonomy {
+    pub fn builder() -> RetrieveTaxonomyBuilder {
+        RetrieveTaxonomyBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveTaxonomy {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/taxonomies/{}", self.taxonomy).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::Taxonomy,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveTaxonomy::builder()
+            .taxonomy("genre")
+            .context(Context::Edit)
+            .build()
+            .unwrap();
+        let body = json!({
+            "name": "Genres",
+            "slug": "genre",
+            "description": "",
+            "types": ["book"],
+            "hierarchical": true,
+            "rest_base": "genres",
+            "rest_namespace": "wp/v2",
+            "show_cloud": false,
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("context=edit")
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Taxonomy = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.slug, "genre");
+        assert_eq!(response.show_cloud, Some(false));
+    }
+}
@@ -0,0 +1,13 @@
+pub use create::CreateTerm;
+pub use delete::{DeleteTerm, DeletedTerm};
+pub use list::ListTerms;
+pub use retrieve::RetrieveTerm;
+pub use tree::TermTree;
+pub use update::UpdateTerm;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod retrieve;
+pub mod tree;
+pub mod update;
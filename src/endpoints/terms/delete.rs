@@ -0,0 +1,83 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::common::Deleted};
+
+/// Delete a term of a taxonomy.
+///
+/// Terms do not support trashing, so the deletion is always forced.
+#[derive(Builder)]
+pub struct DeleteTerm {
+    /// The `rest_base` of the taxonomy.
+    #[builder(setter(into))]
+    taxonomy: String,
+
+    /// The REST namespace of the taxonomy.
+    #[builder(setter(into), default = "\"wp/v2\".into()")]
+    namespace: String,
+
+    /// Unique identifier for the term.
+    id: u32,
+}
+
+impl DeleteTerm {
+    pub fn builder() -> DeleteTermBuilder {
+        DeleteTermBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteTerm {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/{}/{}/{}", self.namespace, self.taxonomy, self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("force", true);
+        params
+    }
+}
+
+/// The result of deleting a term.
+pub type DeletedTerm<T> = Deleted<T>;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = DeleteTerm::builder()
+            .taxonomy("genre")
+            .id(2)
+            .build()
+            .unwrap();
+        let previous = json!({ "id": 2 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedTerm<Json> = endpoint.query(&client).await.unwrap();
+
+        assert!(response.deleted);
+        assert_eq!(response.previous, previous);
+    }
+}
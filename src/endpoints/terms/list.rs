@@ -0,0 +1,163 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List terms of a taxonomy.
+#[derive(Builder)]
+#[builder(setter(strip_option))]
+pub struct ListTerms {
+    /// The `rest_base` of the taxonomy.
+    #[builder(setter(into))]
+    taxonomy: String,
+
+    /// The REST namespace of the taxonomy.
+    #[builder(setter(into), default = "\"wp/v2\".into()")]
+    namespace: String,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into), default)]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    #[builder(default)]
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    #[builder(default)]
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into), default)]
+    search: Option<String>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false), default)]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false), default)]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    #[builder(default)]
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    #[builder(setter(into), default)]
+    order: Option<String>,
+
+    /// Sort collection by term attribute.
+    #[builder(setter(into), default)]
+    orderby: Option<String>,
+
+    /// Limit result set to terms assigned to a specific parent.
+    #[builder(default)]
+    parent: Option<u32>,
+
+    /// Whether to hide terms not assigned to any posts.
+    #[builder(default)]
+    hide_empty: Option<bool>,
+
+    /// Limit result set to terms assigned to a specific post.
+    #[builder(default)]
+    post: Option<u32>,
+
+    /// Limit result set to terms with one or more specific slugs.
+    #[builder(setter(into, strip_option = false), default)]
+    slug: Vec<String>,
+}
+
+impl ListTerms {
+    pub fn builder() -> ListTermsBuilder {
+        ListTermsBuilder::default()
+    }
+}
+
+impl Endpoint for ListTerms {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/{}/{}", self.namespace, self.taxonomy).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_opt("parent", self.parent)
+            .push_opt("hide_empty", self.hide_empty)
+            .push_opt("post", self.post)
+            .push_list("slug", &self.slug);
+        params
+    }
+}
+
+impl Pageable for ListTerms {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListTerms::builder().taxonomy("genre").build().unwrap();
+        let body = json!([{ "id": 1 }, { "id": 2 }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+
+    #[test]
+    fn taxonomy_required() {
+        let err = ListTerms::builder().build();
+        assert!(err.is_err());
+    }
+
+    #[tokio::test]
+    async fn filters() {
+        let endpoint = ListTerms::builder()
+            .taxonomy("genre")
+            .namespace("acme/v1")
+            .parent(2)
+            .hide_empty(true)
+            .build()
+            .unwrap();
+        let body = json!([]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("parent=2&hide_empty=true")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
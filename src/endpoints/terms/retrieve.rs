@@ -0,0 +1,68 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a term of a taxonomy.
+#[derive(Builder)]
+pub struct RetrieveTerm {
+    /// The `rest_base` of the taxonomy.
+    #[builder(setter(into))]
+    taxonomy: String,
+
+    /// The REST namespace of the taxonomy.
+    #[builder(setter(into), default = "\"wp/v2\".into()")]
+    namespace: String,
+
+    /// Unique identifier for the term.
+    id: u32,
+}
+
+impl RetrieveTerm {
+    pub fn builder() -> RetrieveTermBuilder {
+        RetrieveTermBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveTerm {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/{}/{}/{}", self.namespace, self.taxonomy, self.id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveTerm::builder()
+            .taxonomy("genre")
+            .id(123)
+            .build()
+            .unwrap();
+        let body = json!({
+            "id": endpoint.id,
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+
+use crate::{
+    client::Client,
+    endpoints::terms::ListTerms,
+    error::ApiError,
+    paged::{paged, Paged},
+    query::Query,
+    types::{Term, TermNode},
+};
+
+/// A query fetching every term of a taxonomy as a tree.
+///
+/// See [`ListTerms::tree`].
+pub struct TermTree {
+    paged: Paged<ListTerms>,
+}
+
+impl ListTerms {
+    /// Fetch every matching term across all pages and arrange them by parent,
+    /// e.g. to render nested category menus.
+    ///
+    /// The `parent` filter should be left unset, as only the direct children
+    /// of that term would be fetched.
+    pub fn tree(self) -> TermTree {
+        TermTree { paged: paged(self) }
+    }
+}
+
+#[async_trait]
+impl<C> Query<Vec<TermNode>, C> for TermTree
+where
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<Vec<TermNode>, ApiError<C::Error>> {
+        let terms: Vec<Term> = self.paged.query(client).await?;
+        Ok(TermNode::tree(terms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::test::{MockClient, MockResponse};
+
+    #[tokio::test]
+    async fn categories() {
+        let first = MockResponse::builder()
+            .route("/wp/v2/categories")
+            .query("page=1&per_page=2")
+            .header("x-wp-totalpages", "2")
+            .json(json!([
+                { "id": 1, "name": "News", "parent": 0 },
+                { "id": 2, "name": "Local", "parent": 1 },
+            ]))
+            .build()
+            .unwrap();
+        let second = MockResponse::builder()
+            .route("/wp/v2/categories")
+            .query("page=2&per_page=2")
+            .header("x-wp-totalpages", "2")
+            .json(json!([{ "id": 3, "name": "Sports", "parent": 0 }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_responses([first, second]);
+        let endpoint = ListTerms::builder().taxonomy("categories").build().unwrap();
+
+        let tree = TermTree {
+            paged: paged(endpoint).per_page(2),
+        }
+        .query(&client)
+        .await
+        .unwrap();
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].term.name, "News");
+        assert_eq!(tree[0].children[0].term.name, "Local");
+        assert_eq!(tree[1].term.name, "Sports");
+    }
+}
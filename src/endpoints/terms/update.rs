@@ -0,0 +1,120 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, types::Meta};
+
+/// Update a term of a taxonomy.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct UpdateTerm {
+    /// The `rest_base` of the taxonomy.
+    #[builder(setter(into))]
+    #[serde(skip)]
+    taxonomy: String,
+
+    /// The REST namespace of the taxonomy.
+    #[builder(setter(into), default = "\"wp/v2\".into()")]
+    #[serde(skip)]
+    namespace: String,
+
+    /// Unique identifier for the term.
+    #[serde(skip)]
+    id: u32,
+
+    /// HTML title for the term.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    /// HTML description of the term.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// An alphanumeric identifier for the term unique to its type.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// The parent term ID.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<u32>,
+
+    /// Meta fields.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<Meta>,
+}
+
+impl UpdateTerm {
+    pub fn builder() -> UpdateTermBuilder {
+        UpdateTermBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateTerm {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/{}/{}/{}", self.namespace, self.taxonomy, self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = UpdateTerm::builder()
+            .taxonomy("genre")
+            .id(1)
+            .description("The Rust programming language")
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "description": "The Rust programming language" })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = UpdateTerm::builder()
+            .taxonomy("genre")
+            .id(1)
+            .name("Jazz")
+            .build()
+            .unwrap();
+        let body = json!({ "id": 1, "name": "Jazz" });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,5 @@
+pub use list::ListTypes;
+pub use retrieve::RetrieveType;
+
+pub mod list;
+pub mod retrieve;
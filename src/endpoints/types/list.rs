@@ -0,0 +1,91 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List post types.
+///
+/// The response is an object keyed by the post type slug.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListTypes {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+}
+
+impl ListTypes {
+    pub fn builder() -> ListTypesBuilder {
+        ListTypesBuilder::default()
+    }
+}
+
+impl Endpoint for ListTypes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/types".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::PostType,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListTypes::builder().build().unwrap();
+        let body = json!({
+            "post": {
+                "description": "",
+                "hierarchical": false,
+                "has_archive": false,
+                "name": "Posts",
+                "slug": "post",
+                "taxonomies": ["category", "post_tag"],
+                "rest_base": "posts",
+                "rest_namespace": "wp/v2",
+            },
+            "page": {
+                "description": "",
+                "hierarchical": true,
+                "has_archive": false,
+                "name": "Pages",
+                "slug": "page",
+                "taxonomies": [],
+                "rest_base": "pages",
+                "rest_namespace": "wp/v2",
+            },
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: BTreeMap<String, PostType> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.len(), 2);
+        assert_eq!(response["post"].rest_base, "posts");
+        assert!(response["page"].hierarchical);
+    }
+}
@@ -0,0 +1,84 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a post type.
+#[derive(Builder)]
+pub struct RetrieveType {
+    /// An alphanumeric identifier for the post type.
+    #[builder(setter(into))]
+    post_type: String,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveType {
+    pub fn builder() -> RetrieveTypeBuilder {
+        RetrieveTypeBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveType {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/types/{}", self.post_type).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::PostType,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RetrieveType::builder()
+            .post_type("book")
+            .context(Context::Edit)
+            .build()
+            .unwrap();
+        let body = json!({
+            "description": "",
+            "hierarchical": false,
+            "has_archive": true,
+            "name": "Books",
+            "slug": "book",
+            "taxonomies": ["genre"],
+            "rest_base": "books",
+            "rest_namespace": "wp/v2",
+            "supports": { "title": true, "editor": true },
+            "viewable": true,
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("context=edit")
+            .json(body)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: PostType = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.slug, "book");
+        assert_eq!(response.viewable, Some(true));
+    }
+}
@@ -0,0 +1,7 @@
+pub use create::CreateUser;
+pub use delete::{DeleteUser, DeletedUser, Reassign};
+pub use update::UpdateUser;
+
+pub mod create;
+pub mod delete;
+pub mod update;
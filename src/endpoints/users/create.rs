@@ -0,0 +1,155 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, types::Meta};
+
+/// Create a user.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateUser {
+    /// Login name for the user.
+    #[builder(setter(into))]
+    username: String,
+
+    /// Display name for the user.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    /// First name for the user.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first_name: Option<String>,
+
+    /// Last name for the user.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_name: Option<String>,
+
+    /// The email address for the user.
+    #[builder(setter(into))]
+    email: String,
+
+    /// URL of the user.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+
+    /// Description of the user.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// Locale for the user.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+
+    /// The nickname for the user.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+
+    /// An alphanumeric identifier for the user.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// Roles assigned to the user.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    roles: Option<Vec<String>>,
+
+    /// Password for the user.
+    #[builder(setter(into))]
+    password: String,
+
+    /// Meta fields.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<Meta>,
+}
+
+impl CreateUser {
+    pub fn builder() -> CreateUserBuilder {
+        CreateUserBuilder::default()
+    }
+}
+
+impl Endpoint for CreateUser {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/users".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn required_fields() {
+        let err = CreateUser::builder().username("bob").build();
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn body() {
+        let endpoint = CreateUser::builder()
+            .username("bob")
+            .email("bob@example.com")
+            .password("loblaw")
+            .roles(vec!["editor".to_string()])
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "username": "bob",
+                "email": "bob@example.com",
+                "password": "loblaw",
+                "roles": ["editor"],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = CreateUser::builder()
+            .username("bob")
+            .email("bob@example.com")
+            .password("loblaw")
+            .build()
+            .unwrap();
+        let body = json!({ "id": 1, "username": "bob" });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
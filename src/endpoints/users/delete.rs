@@ -0,0 +1,158 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::common::Deleted};
+
+/// The user to reassign a deleted user's posts and links to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reassign {
+    /// Reassign the posts and links to another user.
+    User(u32),
+
+    /// Delete the posts and links along with the user.
+    Delete,
+}
+
+impl ParamValue<'static> for Reassign {
+    fn as_value(&self) -> Cow<'static, str> {
+        match self {
+            Reassign::User(id) => id.to_string().into(),
+            Reassign::Delete => "false".into(),
+        }
+    }
+}
+
+/// Delete a user.
+///
+/// Users do not support trashing, so the deletion is always forced.
+#[derive(Builder)]
+pub struct DeleteUser {
+    /// Unique identifier for the user.
+    id: u32,
+
+    /// Reassign the deleted user's posts and links to this user ID.
+    reassign: Reassign,
+}
+
+impl DeleteUser {
+    pub fn builder() -> DeleteUserBuilder {
+        DeleteUserBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteUser {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/users/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("force", true).push("reassign", self.reassign);
+        params
+    }
+}
+
+/// The result of deleting a user.
+pub type DeletedUser<T> = Deleted<T>;
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        ApiError, Query,
+    };
+
+    #[test]
+    fn reassign_required() {
+        let err = DeleteUser::builder().id(1).build();
+        assert!(err.is_err());
+    }
+
+    #[tokio::test]
+    async fn reassign() {
+        let endpoint = DeleteUser::builder()
+            .id(2)
+            .reassign(Reassign::User(1))
+            .build()
+            .unwrap();
+        let previous = json!({ "id": 2 });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true&reassign=1")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedUser<Json> = endpoint.query(&client).await.unwrap();
+
+        assert!(response.deleted);
+        assert_eq!(response.previous, previous);
+    }
+
+    #[tokio::test]
+    async fn delete_content() {
+        let endpoint = DeleteUser::builder()
+            .id(2)
+            .reassign(Reassign::Delete)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true&reassign=false")
+            .json(json!({
+                "deleted": true,
+                "previous": { "id": 2 },
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedUser<Json> = endpoint.query(&client).await.unwrap();
+
+        assert!(response.deleted);
+    }
+
+    #[tokio::test]
+    async fn invalid_reassign() {
+        let endpoint = DeleteUser::builder()
+            .id(2)
+            .reassign(Reassign::User(999))
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .status(StatusCode::BAD_REQUEST)
+            .json(json!({
+                "code": "rest_user_invalid_reassign",
+                "message": "Invalid user ID for reassignment.",
+                "data": { "status": 400 },
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let result: Result<DeletedUser<Json>, _> = endpoint.query(&client).await;
+
+        let err = result.expect_err("expected ApiError::WordPress");
+        if let ApiError::WordPress { code, .. } = err {
+            assert_eq!(code, "rest_user_invalid_reassign");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub use encode::{EncodeWidgetType, EncodedWidget};
+pub use list::ListWidgetTypes;
+pub use render::{RenderWidgetType, RenderedWidget};
+pub use retrieve::RetrieveWidgetType;
+
+pub mod encode;
+pub mod list;
+pub mod render;
+pub mod retrieve;
@@ -0,0 +1,118 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::prelude::*, endpoints::widgets::WidgetInstance};
+
+/// Convert the submitted form data of a widget type into encoded instance
+/// settings.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct EncodeWidgetType {
+    /// The widget type id.
+    #[builder(setter(into))]
+    #[serde(skip)]
+    id: String,
+
+    /// The number of the widget instance.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number: Option<u32>,
+
+    /// Current instance settings of the widget.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance: Option<WidgetInstance>,
+
+    /// Serialized widget form data to encode into instance settings.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    form_data: Option<String>,
+}
+
+impl EncodeWidgetType {
+    pub fn builder() -> EncodeWidgetTypeBuilder {
+        EncodeWidgetTypeBuilder::default()
+    }
+}
+
+impl Endpoint for EncodeWidgetType {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/widget-types/{}/encode", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+/// Encoded widget instance settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncodedWidget {
+    /// The widget admin form.
+    pub form: String,
+
+    /// The encoded instance settings.
+    pub instance: WidgetInstance,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = EncodeWidgetType::builder()
+            .id("search")
+            .number(2)
+            .form_data("widget-search[2][title]=Search")
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "number": 2, "form_data": "widget-search[2][title]=Search" })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = EncodeWidgetType::builder()
+            .id("search")
+            .form_data("widget-search[2][title]=Search")
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(json!({
+                "form": "<p></p>",
+                "instance": {
+                    "encoded": "YToxOntzOjU6InRpdGxlIjtzOjY6IlNlYXJjaCI7fQ==",
+                    "hash": "abc",
+                    "raw": { "title": "Search" },
+                },
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: EncodedWidget = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.form, "<p></p>");
+        assert_eq!(response.instance.raw, Some(json!({ "title": "Search" })));
+    }
+}
@@ -0,0 +1,62 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List widget types.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListWidgetTypes {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+}
+
+impl ListWidgetTypes {
+    pub fn builder() -> ListWidgetTypesBuilder {
+        ListWidgetTypesBuilder::default()
+    }
+}
+
+impl Endpoint for ListWidgetTypes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/widget-types".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListWidgetTypes::builder().build().unwrap();
+        let body = json!([{ "id": "search", "name": "Search", "is_multi": true }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
@@ -0,0 +1,81 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::prelude::*, endpoints::widgets::WidgetInstance};
+
+/// Render a preview of a widget type.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct RenderWidgetType {
+    /// The widget type id.
+    #[builder(setter(into))]
+    #[serde(skip)]
+    id: String,
+
+    /// Current instance settings of the widget.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance: Option<WidgetInstance>,
+}
+
+impl RenderWidgetType {
+    pub fn builder() -> RenderWidgetTypeBuilder {
+        RenderWidgetTypeBuilder::default()
+    }
+}
+
+impl Endpoint for RenderWidgetType {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/widget-types/{}/render", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+/// A rendered widget preview.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderedWidget {
+    /// The rendered widget.
+    pub preview: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = RenderWidgetType::builder()
+            .id("search")
+            .instance(WidgetInstance {
+                raw: Some(json!({ "title": "Search" })),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(json!({ "preview": "<form></form>" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: RenderedWidget = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response.preview, "<form></form>");
+    }
+}
@@ -0,0 +1,28 @@
+pub use create::CreateWidget;
+pub use delete::{DeleteWidget, DeletedWidget};
+pub use list::ListWidgets;
+pub use retrieve::RetrieveWidget;
+use serde::{Deserialize, Serialize};
+pub use update::UpdateWidget;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod retrieve;
+pub mod update;
+
+/// Instance settings of a widget.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WidgetInstance {
+    /// Base64 encoded representation of the instance settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoded: Option<String>,
+
+    /// Cryptographic hash of the instance settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+
+    /// Unencoded instance settings, if supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<serde_json::Value>,
+}
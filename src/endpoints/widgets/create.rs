@@ -0,0 +1,117 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, endpoints::widgets::WidgetInstance};
+
+/// Create a widget.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateWidget {
+    /// Unique identifier for the widget.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+
+    /// The type of the widget.
+    #[builder(setter(into))]
+    id_base: String,
+
+    /// The sidebar the widget belongs to.
+    #[builder(setter(into))]
+    sidebar: String,
+
+    /// Instance settings of the widget, if supported.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance: Option<WidgetInstance>,
+
+    /// URL-encoded form data from the widget admin form.
+    ///
+    /// Used to update a widget that does not support instance.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    form_data: Option<String>,
+}
+
+impl CreateWidget {
+    pub fn builder() -> CreateWidgetBuilder {
+        CreateWidgetBuilder::default()
+    }
+}
+
+impl Endpoint for CreateWidget {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/widgets".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = CreateWidget::builder()
+            .id_base("block")
+            .sidebar("sidebar-1")
+            .instance(WidgetInstance {
+                raw: Some(
+                    json!({ "content": "<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->" }),
+                ),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "id_base": "block",
+                "sidebar": "sidebar-1",
+                "instance": {
+                    "raw": { "content": "<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->" },
+                },
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = CreateWidget::builder()
+            .id_base("search")
+            .sidebar("sidebar-1")
+            .form_data("widget-search[2][title]=Search")
+            .build()
+            .unwrap();
+        let body = json!({ "id": "search-2", "sidebar": "sidebar-1" });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
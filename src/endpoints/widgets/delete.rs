@@ -0,0 +1,115 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, endpoints::posts::DeletedPost};
+
+/// Delete a widget.
+///
+/// By default, the widget is moved to the inactive widgets sidebar. Set
+/// `force` to permanently delete the widget instead.
+#[derive(Builder)]
+pub struct DeleteWidget {
+    /// Unique identifier for the widget.
+    #[builder(setter(into))]
+    id: String,
+
+    /// Whether to force removal of the widget, or move it to the inactive
+    /// sidebar.
+    #[builder(setter(strip_option), default)]
+    force: Option<bool>,
+}
+
+impl DeleteWidget {
+    pub fn builder() -> DeleteWidgetBuilder {
+        DeleteWidgetBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteWidget {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/widgets/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("force", self.force);
+        params
+    }
+}
+
+/// The result of deleting a widget.
+///
+/// A widget moved to the inactive widgets sidebar is returned as
+/// [`DeletedPost::Trashed`].
+pub type DeletedWidget<T> = DeletedPost<T>;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn unassign() {
+        let endpoint = DeleteWidget::builder().id("block-2").build().unwrap();
+        let body = json!({
+            "id": "block-2",
+            "sidebar": "wp_inactive_widgets",
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedWidget<Json> = endpoint.query(&client).await.unwrap();
+
+        if let DeletedWidget::Trashed(post) = response {
+            assert_eq!(post, body);
+        } else {
+            panic!("unexpected response: {:?}", response);
+        }
+    }
+
+    #[tokio::test]
+    async fn force() {
+        let endpoint = DeleteWidget::builder()
+            .id("block-2")
+            .force(true)
+            .build()
+            .unwrap();
+        let previous = json!({
+            "id": "block-2",
+            "sidebar": "sidebar-1",
+        });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("force=true")
+            .json(json!({
+                "deleted": true,
+                "previous": previous.clone(),
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: DeletedWidget<Json> = endpoint.query(&client).await.unwrap();
+
+        if let DeletedWidget::Deleted { previous: post } = response {
+            assert_eq!(post, previous);
+        } else {
+            panic!("unexpected response: {:?}", response);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List widgets.
+#[derive(Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListWidgets {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// The sidebar to return widgets for.
+    #[builder(setter(into))]
+    sidebar: Option<String>,
+}
+
+impl ListWidgets {
+    pub fn builder() -> ListWidgetsBuilder {
+        ListWidgetsBuilder::default()
+    }
+}
+
+impl Endpoint for ListWidgets {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wp/v2/widgets".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("sidebar", self.sidebar.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = ListWidgets::builder().sidebar("sidebar-1").build().unwrap();
+        let body = json!([{ "id": "block-2", "sidebar": "sidebar-1" }]);
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("sidebar=sidebar-1")
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
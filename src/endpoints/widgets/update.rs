@@ -0,0 +1,103 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, endpoints::widgets::WidgetInstance};
+
+/// Update a widget.
+///
+/// Setting the `sidebar` moves the widget to that sidebar.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct UpdateWidget {
+    /// Unique identifier for the widget.
+    #[builder(setter(into))]
+    #[serde(skip)]
+    id: String,
+
+    /// The sidebar the widget belongs to.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sidebar: Option<String>,
+
+    /// Instance settings of the widget, if supported.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance: Option<WidgetInstance>,
+
+    /// URL-encoded form data from the widget admin form.
+    ///
+    /// Used to update a widget that does not support instance.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    form_data: Option<String>,
+}
+
+impl UpdateWidget {
+    pub fn builder() -> UpdateWidgetBuilder {
+        UpdateWidgetBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateWidget {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wp/v2/widgets/{}", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn body() {
+        let endpoint = UpdateWidget::builder()
+            .id("block-2")
+            .sidebar("sidebar-2")
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "sidebar": "sidebar-2" })
+        );
+    }
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = UpdateWidget::builder()
+            .id("block-2")
+            .sidebar("sidebar-2")
+            .build()
+            .unwrap();
+        let body = json!({ "id": "block-2", "sidebar": "sidebar-2" });
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .json(body.clone())
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Json = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(response, body);
+    }
+}
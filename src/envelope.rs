@@ -0,0 +1,224 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use async_trait::async_trait;
+use http::{
+    header::{HeaderName, HeaderValue},
+    HeaderMap, Method, StatusCode,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+
+use crate::{
+    client::Client,
+    endpoint::{self, ContentType, Endpoint},
+    error::BodyError,
+    params::QueryParams,
+    query::Query,
+    request, ApiError,
+};
+
+/// A query asking WordPress to wrap its response in an envelope.
+///
+/// See [`envelope`].
+#[derive(Debug, Clone)]
+pub struct Envelope<E> {
+    endpoint: E,
+}
+
+/// Read the response of an endpoint from an envelope using the `_envelope`
+/// parameter.
+///
+/// WordPress then always responds with `200 OK` and includes the status and
+/// headers in the body. This is the only way to read response headers when a
+/// proxy strips them.
+pub fn envelope<E>(endpoint: E) -> Envelope<E>
+where
+    E: Endpoint,
+{
+    Envelope { endpoint }
+}
+
+/// A response read from an envelope.
+#[derive(Debug, Clone)]
+pub struct Enveloped<T> {
+    /// The status of the response.
+    pub status: StatusCode,
+
+    /// The headers of the response.
+    pub headers: HeaderMap,
+
+    /// The body of the response.
+    pub body: T,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RawEnvelope {
+    body: Value,
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, Value>,
+}
+
+impl RawEnvelope {
+    /// Check the status of the enveloped response and deserialize its body.
+    pub(crate) fn into_enveloped<T, E>(self) -> Result<Enveloped<T>, ApiError<E>>
+    where
+        T: DeserializeOwned + 'static,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let headers = header_map(self.headers);
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(ApiError::rate_limited(request::retry_after(&headers)));
+        }
+        if !status.is_success() {
+            return Err(ApiError::from_json(self.body));
+        }
+
+        Ok(Enveloped {
+            status,
+            headers,
+            body: serde_json::from_value(self.body).map_err(ApiError::data_type::<T>)?,
+        })
+    }
+}
+
+/// The wrapped endpoint with the `_envelope` parameter added.
+struct WithEnvelope<'a, E>(&'a E);
+
+impl<E> Endpoint for WithEnvelope<'_, E>
+where
+    E: Endpoint,
+{
+    fn method(&self) -> Method {
+        self.0.method()
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        self.0.route()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = self.0.parameters();
+        params.push("_envelope", true);
+        params
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        self.0.body()
+    }
+}
+
+fn header_map(headers: BTreeMap<String, Value>) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let name = match HeaderName::try_from(name) {
+            Ok(name) => name,
+            Err(_) => continue,
+        };
+        let values = match value {
+            Value::Array(values) => values,
+            value => vec![value],
+        };
+        for value in values {
+            let value = match value {
+                Value::String(value) => HeaderValue::try_from(value),
+                value => HeaderValue::try_from(value.to_string()),
+            };
+            if let Ok(value) = value {
+                map.append(name.clone(), value);
+            }
+        }
+    }
+    map
+}
+
+#[async_trait]
+impl<E, T, C> Query<Enveloped<T>, C> for Envelope<E>
+where
+    E: Endpoint + Sync,
+    T: DeserializeOwned + 'static,
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<Enveloped<T>, ApiError<C::Error>> {
+        let (_, json) = endpoint::request(&WithEnvelope(&self.endpoint), client)
+            .await?
+            .send(client)
+            .await?;
+        let envelope: RawEnvelope =
+            serde_json::from_value(json).map_err(ApiError::data_type::<Enveloped<T>>)?;
+        envelope.into_enveloped()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        endpoints::RawEndpoint,
+        test::{MockClient, MockResponse},
+    };
+
+    fn endpoint() -> RawEndpoint {
+        RawEndpoint::builder()
+            .route("/mock")
+            .param("per_page", 1_u32)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn success() {
+        let response = MockResponse::builder()
+            .query("per_page=1&_envelope=true")
+            .json(json!({
+                "body": [{ "id": 1 }],
+                "status": 200,
+                "headers": {
+                    "X-WP-Total": "2",
+                    "X-WP-TotalPages": 2,
+                    "Link": ["<http://example.com/wp-json/mock?page=2>; rel=\"next\""],
+                },
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response: Enveloped<Value> = envelope(endpoint()).query(&client).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.headers["x-wp-total"], "2");
+        assert_eq!(response.headers["x-wp-totalpages"], "2");
+        assert_eq!(
+            response.headers["link"],
+            "<http://example.com/wp-json/mock?page=2>; rel=\"next\""
+        );
+        assert_eq!(response.body, json!([{ "id": 1 }]));
+    }
+
+    #[tokio::test]
+    async fn error() {
+        let response = MockResponse::builder()
+            .json(json!({
+                "body": {
+                    "code": "rest_forbidden",
+                    "message": "Sorry, you are not allowed to do that.",
+                    "data": { "status": 401 },
+                },
+                "status": 401,
+                "headers": {},
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let err = Query::<Enveloped<Value>, _>::query(&envelope(endpoint()), &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::WordPress { code, .. } if code == "rest_forbidden"));
+    }
+}
@@ -1,4 +1,4 @@
-use std::error::Error;
+use std::{error::Error, time::Duration};
 
 use thiserror::Error;
 use url::Url;
@@ -32,14 +32,146 @@ where
         source: http::Error,
     },
 
+    /// Failed to create the request body.
+    #[error("failed to create request body: {}", source)]
+    Body {
+        /// The source of the error.
+        #[from]
+        source: BodyError,
+    },
+
+    /// Failed to compress or decompress a body.
+    #[error("failed to compress or decompress body: {}", source)]
+    Compression {
+        /// The source of the error.
+        source: Box<dyn Error + Send + Sync>,
+    },
+
+    /// Failed to authenticate the request.
+    #[error("failed to authenticate request: {}", source)]
+    Auth {
+        /// The source of the error.
+        #[from]
+        source: AuthError,
+    },
+
     /// Failed to discover API root route.
     #[error("failed to discover root route: {}", url)]
     RootRouteDiscovery { url: Url },
 
-    /// Failed to discover resource.
+    /// Failed to discover resource, as the page does not link to a WordPress
+    /// resource.
     #[error("failed to discover resource: {}", url)]
     ResourceDiscovery { url: Url },
 
+    /// A page requested during discovery responded with an error.
+    #[error("failed to fetch {} during discovery: {}", url, status)]
+    DiscoveryStatus {
+        /// The URL of the page.
+        url: Url,
+        /// The status code for the HTTP response.
+        status: http::StatusCode,
+    },
+
+    /// Failed to download remote media.
+    #[error("failed to download media from {}: {}", url, status)]
+    MediaDownload {
+        /// The URL of the media.
+        url: Url,
+        /// The status code for the HTTP response.
+        status: http::StatusCode,
+    },
+
+    /// Failed to write downloaded media.
+    #[error("failed to write media from {}: {}", url, source)]
+    MediaWrite {
+        /// The URL of the media.
+        url: Url,
+        /// The source of the error.
+        source: std::io::Error,
+    },
+
+    /// Remote media exceeded the size limit.
+    #[error("media from {} exceeds the size limit of {} bytes", url, limit)]
+    MediaTooLarge {
+        /// The URL of the media.
+        url: Url,
+        /// The size limit in bytes.
+        limit: usize,
+    },
+
+    /// Media from an untrusted source, such as an export, is not on a public
+    /// `http` or `https` host other than the site itself.
+    #[error("refusing to download media from {}", url)]
+    UntrustedMediaUrl {
+        /// The URL of the media.
+        url: Url,
+    },
+
+    /// No resource matched a slug or path.
+    #[error("no resource found matching `{}`", slug)]
+    NotFound {
+        /// The slug or path.
+        slug: String,
+    },
+
+    /// More than one resource matched a slug or path.
+    #[error("{} resources found matching `{}`", count, slug)]
+    MultipleMatches {
+        /// The slug or path.
+        slug: String,
+        /// The number of matching resources.
+        count: usize,
+    },
+
+    /// A link points outside of the site.
+    #[error("refusing to follow link to {} outside of the site", url)]
+    ForeignLink {
+        /// The URL of the link.
+        url: Url,
+    },
+
+    /// The response has no value at a JSON pointer.
+    #[error("no value at `{}` in the response", pointer)]
+    MissingPointer {
+        /// The JSON pointer.
+        pointer: String,
+    },
+
+    /// A batch holds more requests than WordPress allows.
+    #[error("batch of {} requests exceeds the limit of {}", count, limit)]
+    BatchTooLarge {
+        /// The number of requests in the batch.
+        count: usize,
+        /// The maximum number of requests in a batch.
+        limit: usize,
+    },
+
+    /// A request cannot be sent in a batch.
+    #[error(
+        "request {} cannot be batched: only writes with JSON bodies are supported",
+        index
+    )]
+    Unbatchable {
+        /// The index of the request in the batch.
+        index: usize,
+    },
+
+    /// A request in a batch was not performed because another request in the
+    /// batch failed validation.
+    #[error("request was not performed as the batch failed validation")]
+    BatchSkipped,
+
+    /// WordPress rejected the request because too many requests were sent.
+    #[error(
+        "rate limited by wordpress{}",
+        retry_after.map(|delay| format!(", retry after {:?}", delay)).unwrap_or_default()
+    )]
+    RateLimited {
+        /// How long to wait before retrying, if WordPress said so.
+        retry_after: Option<Duration>,
+    },
+
     /// WordPress returned an error response.
     #[error("gitlab server error: [{}] {}", code, message)]
     WordPress {
@@ -74,6 +206,64 @@ where
     },
 }
 
+/// Errors which may occur when creating the body of a request.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum BodyError {
+    /// Failed to serialize the body as JSON.
+    #[error("failed to serialize body as json: {}", source)]
+    Json {
+        /// The source of the error.
+        #[from]
+        source: serde_json::Error,
+    },
+
+    /// Failed to read a streamed body.
+    #[error("failed to read body: {}", source)]
+    Read {
+        /// The source of the error.
+        #[from]
+        source: std::io::Error,
+    },
+}
+
+/// Errors which may occur when authenticating a request.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum AuthError {
+    /// The credentials could not be used as a header value.
+    #[error("invalid credentials header: {}", source)]
+    InvalidHeader {
+        /// The source of the error.
+        #[from]
+        source: http::header::InvalidHeaderValue,
+    },
+
+    /// Logging in with a username and password failed.
+    #[error("failed to log in: invalid username or password")]
+    LoginFailed,
+
+    /// Failed to fetch a REST API nonce for a logged in session.
+    #[error("failed to fetch rest api nonce")]
+    Nonce,
+
+    /// A custom authentication scheme failed.
+    #[error("{}", source)]
+    Custom {
+        /// The source of the error.
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl AuthError {
+    /// Create an authentication error from a custom error.
+    pub fn custom(source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        AuthError::Custom {
+            source: source.into(),
+        }
+    }
+}
+
 impl<E> ApiError<E>
 where
     E: Error + Send + Sync + 'static,
@@ -102,6 +292,65 @@ where
         Self::ResourceDiscovery { url }
     }
 
+    pub(crate) fn discovery_status(url: Url, status: http::StatusCode) -> Self {
+        Self::DiscoveryStatus { url, status }
+    }
+
+    pub(crate) fn media_download(url: Url, status: http::StatusCode) -> Self {
+        Self::MediaDownload { url, status }
+    }
+
+    pub(crate) fn media_write(url: Url, source: std::io::Error) -> Self {
+        Self::MediaWrite { url, source }
+    }
+
+    pub(crate) fn media_too_large(url: Url, limit: usize) -> Self {
+        Self::MediaTooLarge { url, limit }
+    }
+
+    pub(crate) fn untrusted_media_url(url: Url) -> Self {
+        Self::UntrustedMediaUrl { url }
+    }
+
+    pub(crate) fn not_found(slug: impl Into<String>) -> Self {
+        Self::NotFound { slug: slug.into() }
+    }
+
+    pub(crate) fn multiple_matches(slug: impl Into<String>, count: usize) -> Self {
+        Self::MultipleMatches {
+            slug: slug.into(),
+            count,
+        }
+    }
+
+    pub(crate) fn foreign_link(url: Url) -> Self {
+        Self::ForeignLink { url }
+    }
+
+    pub(crate) fn batch_too_large(count: usize, limit: usize) -> Self {
+        Self::BatchTooLarge { count, limit }
+    }
+
+    pub(crate) fn unbatchable(index: usize) -> Self {
+        Self::Unbatchable { index }
+    }
+
+    pub(crate) fn missing_pointer(pointer: impl Into<String>) -> Self {
+        Self::MissingPointer {
+            pointer: pointer.into(),
+        }
+    }
+
+    pub(crate) fn compression(source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self::Compression {
+            source: source.into(),
+        }
+    }
+
+    pub(crate) fn rate_limited(retry_after: Option<Duration>) -> Self {
+        Self::RateLimited { retry_after }
+    }
+
     pub(crate) fn from_json(json: serde_json::Value) -> Self {
         let message = json.pointer("/message");
         let code = json.pointer("/code");
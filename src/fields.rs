@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+
+use http::Method;
+
+use crate::{
+    endpoint::{ContentType, Endpoint},
+    error::BodyError,
+    paged::Pageable,
+    params::QueryParams,
+};
+
+/// An endpoint returning only some fields of its response.
+///
+/// See [`fields`].
+#[derive(Debug, Clone)]
+pub struct Fields<E> {
+    endpoint: E,
+    fields: Vec<String>,
+}
+
+/// Limit the fields returned by an endpoint using the `_fields` parameter.
+///
+/// Nested fields may be selected using dots, e.g. `title.rendered`. Fields
+/// left out of the response take their default values when deserialized into
+/// the models in [`types`](crate::types).
+pub fn fields<E, I, S>(endpoint: E, fields: I) -> Fields<E>
+where
+    E: Endpoint,
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    Fields {
+        endpoint,
+        fields: fields.into_iter().map(Into::into).collect(),
+    }
+}
+
+impl<E> Endpoint for Fields<E>
+where
+    E: Endpoint,
+{
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        self.endpoint.route()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = self.endpoint.parameters();
+        params.push_list("_fields", self.fields.iter().map(String::as_str));
+        params
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        self.endpoint.body()
+    }
+}
+
+impl<E> Pageable for Fields<E> where E: Pageable {}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        endpoints::RetrievePost,
+        test::{MockClient, MockResponse},
+        types::Post,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn partial() {
+        let endpoint = fields(
+            RetrievePost::builder().id(1).build().unwrap(),
+            ["id", "title.rendered"],
+        );
+        let response = MockResponse::builder()
+            .route("/wp/v2/posts/1")
+            .query("_fields=id%2Ctitle.rendered")
+            .json(json!({ "id": 1, "title": { "rendered": "Hello" } }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let post: Post = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(post.id, 1);
+        assert_eq!(post.title.as_html(), "Hello");
+        assert_eq!(post.slug, "");
+    }
+}
@@ -0,0 +1,741 @@
+//! A fluent interface over the most common endpoints.
+//!
+//! The accessors on [`WordPress`](crate::WordPress), such as
+//! `wp.posts().list().per_page(50).send()` or `wp.posts().get(123)`, build
+//! the same endpoint types which can be queried directly, and deserialize the
+//! responses into the models in [`types`](crate::types). Other clients can
+//! use the accessors by creating them with e.g. [`Posts::new`].
+
+use futures_util::{io::AsyncWrite, stream, AsyncWriteExt, StreamExt, TryStreamExt};
+use http::{header, HeaderMap, Method, Request, StatusCode};
+use url::Url;
+
+use crate::{
+    batch::{Batch, MAX_REQUESTS},
+    client::Client,
+    endpoint::Endpoint,
+    endpoints::{
+        media::create::CreateMediaBuilder, pages::list::ListPagesBuilder,
+        posts::list::ListPostsBuilder, Context, CreateMedia, DeletePage, DeletePost, ListPages,
+        ListPosts, Order, OrderBy, PostStatus, RetrieveMedia, RetrievePage, RetrievePost,
+    },
+    error::ApiError,
+    ignore::ignore,
+    paged::{paged, Pageable},
+    params::Timestamp,
+    query::Query,
+    stream::streamed,
+    types::{Attachment, Page, Post},
+};
+
+/// The number of deletions sent at once when batching is unavailable.
+const DELETE_CONCURRENCY: usize = 6;
+
+/// The result of each deletion by [`Posts::delete_many`], by the ID of the
+/// deleted item, in order.
+pub type DeleteResults<E> = Vec<(u32, Result<(), ApiError<E>>)>;
+
+macro_rules! forward {
+    ($($(#[$attr:meta])* $name:ident: $ty:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            pub fn $name(mut self, value: $ty) -> Self {
+                self.builder.$name(value);
+                self
+            }
+        )*
+    };
+}
+
+/// A builder of a list endpoint which never fails to build.
+pub trait ListBuilder: Default {
+    /// The endpoint being built.
+    type Endpoint: Pageable + Send + Sync;
+
+    /// The model of the listed items.
+    type Item;
+
+    /// Build the endpoint.
+    fn finish(&self) -> Self::Endpoint;
+}
+
+impl ListBuilder for ListPostsBuilder {
+    type Endpoint = ListPosts;
+    type Item = Post;
+
+    fn finish(&self) -> ListPosts {
+        self.build().expect("all fields have defaults")
+    }
+}
+
+impl ListBuilder for ListPagesBuilder {
+    type Endpoint = ListPages;
+    type Item = Page;
+
+    fn finish(&self) -> ListPages {
+        self.build().expect("all fields have defaults")
+    }
+}
+
+/// A pending list request.
+pub struct List<'a, C, B> {
+    client: &'a C,
+    builder: B,
+}
+
+impl<'a, C, B> List<'a, C, B>
+where
+    C: Client + Sync,
+    B: ListBuilder,
+    B::Item: serde::de::DeserializeOwned + Send + 'static,
+{
+    fn new(client: &'a C) -> Self {
+        Self {
+            client,
+            builder: B::default(),
+        }
+    }
+
+    /// Set parameters which have no shortcut on the underlying builder.
+    pub fn configure(mut self, f: impl FnOnce(&mut B)) -> Self {
+        f(&mut self.builder);
+        self
+    }
+
+    /// The endpoint which is sent.
+    pub fn endpoint(&self) -> B::Endpoint {
+        self.builder.finish()
+    }
+
+    /// Fetch the requested page.
+    pub async fn send(self) -> Result<Vec<B::Item>, ApiError<C::Error>> {
+        streamed(self.endpoint()).query(self.client).await
+    }
+
+    /// Fetch every page, ignoring `page` and `per_page`.
+    pub async fn all(self) -> Result<Vec<B::Item>, ApiError<C::Error>> {
+        paged(self.endpoint()).query(self.client).await
+    }
+}
+
+macro_rules! forward_common {
+    () => {
+        forward! {
+            /// Scope under which the request is made.
+            context: Context,
+            /// Current page of the collection.
+            page: u32,
+            /// Maximum number of items to be returned in result set.
+            per_page: u32,
+            /// Limit results to those matching a string.
+            search: impl Into<String>,
+            /// Limit response to items published after a date.
+            after: impl Into<Timestamp>,
+            /// Limit response to items published before a date.
+            before: impl Into<Timestamp>,
+            /// Limit result set to items assigned to specific authors.
+            author: impl Into<Vec<u32>>,
+            /// Limit result set to specific IDs.
+            include: impl Into<Vec<u32>>,
+            /// Ensure result set excludes specific IDs.
+            exclude: impl Into<Vec<u32>>,
+            /// Order sort attribute ascending or descending.
+            order: Order,
+            /// Sort collection by attribute.
+            orderby: OrderBy,
+            /// Limit result set to items with one or more specific slugs.
+            slug: impl Into<Vec<String>>,
+            /// Limit result set to items assigned one or more statuses.
+            status: impl Into<Vec<PostStatus>>,
+        }
+    };
+}
+
+impl<C> List<'_, C, ListPostsBuilder> {
+    forward_common!();
+
+    forward! {
+        /// Limit result set to items that are sticky.
+        sticky: bool,
+        /// Limit result set to items in specific categories.
+        categories: impl Into<Vec<u32>>,
+        /// Limit result set to items with specific tags.
+        tags: impl Into<Vec<u32>>,
+    }
+}
+
+impl<C> List<'_, C, ListPagesBuilder> {
+    forward_common!();
+
+    forward! {
+        /// Limit result set to items with particular parent IDs.
+        parent: impl Into<Vec<u32>>,
+        /// Limit result set to pages with a specific menu_order value.
+        menu_order: i32,
+    }
+}
+
+/// Accessors for posts.
+pub struct Posts<'a, C> {
+    client: &'a C,
+}
+
+impl<'a, C> Posts<'a, C>
+where
+    C: Client + Sync,
+{
+    /// Create the accessors for a client.
+    pub fn new(client: &'a C) -> Self {
+        Self { client }
+    }
+
+    /// List posts.
+    pub fn list(&self) -> List<'a, C, ListPostsBuilder> {
+        List::new(self.client)
+    }
+
+    /// Retrieve a post.
+    pub async fn get(&self, id: u32) -> Result<Post, ApiError<C::Error>> {
+        let endpoint = RetrievePost::builder().id(id).build().expect("id is set");
+        endpoint.query(self.client).await
+    }
+
+    /// Delete several posts, moving them to the trash unless `force` is set.
+    ///
+    /// The deletions are sent in batches of 25 to the `/batch/v1` route, or
+    /// 6 at a time if the site does not support batching, and the result for
+    /// each post is reported. An error is only returned when a whole batch
+    /// fails.
+    pub async fn delete_many(
+        &self,
+        ids: impl IntoIterator<Item = u32>,
+        force: bool,
+    ) -> Result<DeleteResults<C::Error>, ApiError<C::Error>> {
+        delete_many(self.client, ids, |id| {
+            DeletePost::builder()
+                .id(id)
+                .force(force)
+                .build()
+                .expect("id is set")
+        })
+        .await
+    }
+}
+
+/// Accessors for pages.
+pub struct Pages<'a, C> {
+    client: &'a C,
+}
+
+impl<'a, C> Pages<'a, C>
+where
+    C: Client + Sync,
+{
+    /// Create the accessors for a client.
+    pub fn new(client: &'a C) -> Self {
+        Self { client }
+    }
+
+    /// List pages.
+    pub fn list(&self) -> List<'a, C, ListPagesBuilder> {
+        List::new(self.client)
+    }
+
+    /// Retrieve a page.
+    pub async fn get(&self, id: u32) -> Result<Page, ApiError<C::Error>> {
+        let endpoint = RetrievePage::builder().id(id).build().expect("id is set");
+        endpoint.query(self.client).await
+    }
+
+    /// Delete several pages, moving them to the trash unless `force` is set.
+    ///
+    /// See [`Posts::delete_many`].
+    pub async fn delete_many(
+        &self,
+        ids: impl IntoIterator<Item = u32>,
+        force: bool,
+    ) -> Result<DeleteResults<C::Error>, ApiError<C::Error>> {
+        delete_many(self.client, ids, |id| {
+            DeletePage::builder()
+                .id(id)
+                .force(force)
+                .build()
+                .expect("id is set")
+        })
+        .await
+    }
+}
+
+async fn delete_many<C, E>(
+    client: &C,
+    ids: impl IntoIterator<Item = u32>,
+    endpoint: impl Fn(u32) -> E,
+) -> Result<DeleteResults<C::Error>, ApiError<C::Error>>
+where
+    C: Client + Sync,
+    E: Endpoint + Send + Sync + 'static,
+{
+    let ids: Vec<u32> = ids.into_iter().collect();
+    let mut results = Vec::with_capacity(ids.len());
+    for chunk in ids.chunks(MAX_REQUESTS) {
+        let mut batch = Batch::new();
+        for &id in chunk {
+            batch.push(endpoint(id));
+        }
+        match batch.query(client).await {
+            Ok(batched) => {
+                let batched = batched.into_iter().map(|result| result.map(drop));
+                results.extend(chunk.iter().copied().zip(batched));
+            }
+            // Sites without the batch route, before WordPress 5.6.
+            Err(ApiError::WordPress { code, .. }) if code == "rest_no_route" => {
+                let rest = &ids[results.len()..];
+                let deleted: Vec<_> = stream::iter(rest)
+                    .map(|&id| {
+                        let endpoint = endpoint(id);
+                        async move { (id, ignore(endpoint).query(client).await) }
+                    })
+                    .buffered(DELETE_CONCURRENCY)
+                    .collect()
+                    .await;
+                results.extend(deleted);
+                break;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(results)
+}
+
+/// Accessors for media.
+pub struct Media<'a, C> {
+    client: &'a C,
+}
+
+impl<'a, C> Media<'a, C>
+where
+    C: Client + Sync,
+{
+    /// Create the accessors for a client.
+    pub fn new(client: &'a C) -> Self {
+        Self { client }
+    }
+
+    /// Upload a file as an attachment.
+    pub fn upload(&self, filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Upload<'a, C> {
+        let mut builder = CreateMedia::builder();
+        builder.filename(filename).data(data);
+        Upload {
+            client: self.client,
+            builder,
+        }
+    }
+
+    /// Download the file of an attachment, given by its ID or URL, into a
+    /// writer.
+    ///
+    /// The file is written chunk by chunk as it is received, so it is not
+    /// held in memory, provided the client supports streaming responses.
+    /// Files on other hosts, such as a CDN, are downloaded without the
+    /// client's credentials, see [`Client::send_external_request`].
+    pub fn download<W>(&self, source: impl Into<MediaSource>, writer: W) -> Download<'a, C, W>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        Download {
+            client: self.client,
+            source: source.into(),
+            writer,
+            offset: 0,
+            progress: None,
+        }
+    }
+}
+
+/// The attachment to download.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MediaSource {
+    /// The ID of the attachment, whose source URL is retrieved first.
+    Id(u32),
+
+    /// The URL of the file.
+    Url(Url),
+}
+
+impl From<u32> for MediaSource {
+    fn from(id: u32) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<Url> for MediaSource {
+    fn from(url: Url) -> Self {
+        Self::Url(url)
+    }
+}
+
+/// A pending download.
+pub struct Download<'a, C, W> {
+    client: &'a C,
+    source: MediaSource,
+    writer: W,
+    offset: u64,
+    #[allow(clippy::type_complexity)]
+    progress: Option<Box<dyn FnMut(u64, Option<u64>) + Send + 'a>>,
+}
+
+impl<'a, C, W> Download<'a, C, W>
+where
+    C: Client + Sync,
+    W: AsyncWrite + Unpin + Send,
+{
+    /// Resume a partial download, whose first `offset` bytes are already in
+    /// the writer, e.g. a file opened for appending.
+    ///
+    /// The rest of the file is requested with a `Range` header. If the server
+    /// sends the whole file instead, the bytes already downloaded are skipped.
+    pub fn resume_from(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Report progress after each chunk with the number of bytes downloaded,
+    /// including any resumed from, and the size of the file when known.
+    pub fn progress(mut self, progress: impl FnMut(u64, Option<u64>) + Send + 'a) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Download the file, returning its size.
+    ///
+    /// Fails with [`ApiError::MediaDownload`] if the file responds with an
+    /// error.
+    pub async fn send(mut self) -> Result<u64, ApiError<C::Error>> {
+        let url = match &self.source {
+            MediaSource::Url(url) => url.clone(),
+            MediaSource::Id(id) => {
+                let endpoint = RetrieveMedia::builder().id(*id).build().expect("id is set");
+                let attachment: Attachment = endpoint.query(self.client).await?;
+                Url::parse(&attachment.source_url)?
+            }
+        };
+
+        let mut req = Request::builder().method(Method::GET).uri(url.as_str());
+        if self.offset > 0 {
+            req = req.header(header::RANGE, format!("bytes={}-", self.offset));
+        }
+        let req = req.body(Vec::new()).map_err(ApiError::request)?;
+        let resp = self.client.send_external_request(req).await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(ApiError::media_download(url, status));
+        }
+        // Servers which do not support ranges send the whole file.
+        let mut skip = match status {
+            StatusCode::PARTIAL_CONTENT => 0,
+            _ => std::mem::take(&mut self.offset),
+        };
+        let total = total_size(resp.headers(), self.offset);
+
+        let mut downloaded = self.offset;
+        let mut body = resp.into_body();
+        while let Some(mut chunk) = body.try_next().await? {
+            if skip > 0 {
+                let skipped = skip.min(chunk.len() as u64);
+                let _ = chunk.split_to(skipped as usize);
+                skip -= skipped;
+                downloaded += skipped;
+            }
+            self.writer
+                .write_all(&chunk)
+                .await
+                .map_err(|err| ApiError::media_write(url.clone(), err))?;
+            downloaded += chunk.len() as u64;
+            if let Some(progress) = &mut self.progress {
+                progress(downloaded, total);
+            }
+        }
+        self.writer
+            .flush()
+            .await
+            .map_err(|err| ApiError::media_write(url, err))?;
+        Ok(downloaded)
+    }
+}
+
+/// The size of a downloaded file, from the range or the length of the
+/// response.
+fn total_size(headers: &HeaderMap, offset: u64) -> Option<u64> {
+    let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+    if let Some(range) = header(header::CONTENT_RANGE) {
+        return range.rsplit_once('/')?.1.parse().ok();
+    }
+    header(header::CONTENT_LENGTH)?
+        .parse::<u64>()
+        .ok()
+        .map(|length| length + offset)
+}
+
+/// A pending upload.
+pub struct Upload<'a, C> {
+    client: &'a C,
+    builder: CreateMediaBuilder,
+}
+
+impl<C> Upload<'_, C>
+where
+    C: Client + Sync,
+{
+    forward! {
+        /// The content type of the uploaded file.
+        mime_type: impl Into<String>,
+        /// The title for the attachment.
+        title: impl Into<String>,
+        /// Alternative text to display when the attachment is not displayed.
+        alt_text: impl Into<String>,
+        /// The attachment caption.
+        caption: impl Into<String>,
+        /// The attachment description.
+        description: impl Into<String>,
+        /// The ID for the associated post of the attachment.
+        post: u32,
+    }
+
+    /// Upload the file.
+    pub async fn send(self) -> Result<Attachment, ApiError<C::Error>> {
+        let endpoint = self.builder.build().expect("file is set");
+        endpoint.query(self.client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::test::{MockClient, MockResponse};
+
+    #[tokio::test]
+    async fn list_posts() {
+        let response = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("per_page=50&status=draft&sticky=true")
+            .json(json!([{ "id": 1 }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let posts = Posts::new(&client)
+            .list()
+            .per_page(50)
+            .status(vec![PostStatus::Draft])
+            .sticky(true)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].id, 1);
+    }
+
+    #[tokio::test]
+    async fn delete_many() {
+        let response = MockResponse::builder()
+            .method(http::Method::POST)
+            .route("/batch/v1")
+            .json(json!({
+                "responses": [
+                    { "status": 200, "headers": {}, "body": { "id": 1 } },
+                    {
+                        "status": 410,
+                        "headers": {},
+                        "body": { "code": "rest_already_trashed", "message": "Trashed", "data": null },
+                    },
+                ],
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let results = Posts::new(&client)
+            .delete_many([1, 2], false)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0], (1, Ok(()))));
+        assert!(matches!(
+            &results[1],
+            (2, Err(ApiError::WordPress { code, .. })) if code == "rest_already_trashed"
+        ));
+    }
+
+    #[tokio::test]
+    async fn delete_many_without_batch() {
+        let deleted = |id| {
+            MockResponse::builder()
+                .method(http::Method::DELETE)
+                .route(format!("/wp/v2/pages/{}", id))
+                .query("force=true")
+                .json(json!({ "deleted": true }))
+                .build()
+                .unwrap()
+        };
+        let client = MockClient::with_responses([
+            MockResponse::builder()
+                .method(http::Method::POST)
+                .route("/batch/v1")
+                .status(StatusCode::NOT_FOUND)
+                .json(json!({ "code": "rest_no_route", "message": "No route", "data": null }))
+                .build()
+                .unwrap(),
+            deleted(1),
+            deleted(2),
+        ]);
+
+        let results = Pages::new(&client).delete_many([2, 1], true).await.unwrap();
+
+        let ids: Vec<_> = results
+            .iter()
+            .map(|(id, result)| (*id, result.is_ok()))
+            .collect();
+        assert_eq!(ids, vec![(2, true), (1, true)]);
+    }
+
+    #[tokio::test]
+    async fn get_page() {
+        let response = MockResponse::builder()
+            .route("/wp/v2/pages/2")
+            .json(json!({ "id": 2, "parent": 1 }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let page = Pages::new(&client).get(2).await.unwrap();
+
+        assert_eq!(page.parent, 1);
+    }
+
+    #[tokio::test]
+    async fn upload() {
+        let response = MockResponse::builder()
+            .method(http::Method::POST)
+            .route("/wp/v2/media")
+            .json(json!({ "id": 7, "mime_type": "image/png" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let attachment = Media::new(&client)
+            .upload("photo.png", b"png".to_vec())
+            .alt_text("A photo")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(attachment.mime_type, "image/png");
+    }
+
+    const FILE: &[u8] = b"0123456789abcdefghij";
+
+    #[tokio::test]
+    async fn download() {
+        let client = MockClient::with_responses([
+            MockResponse::builder()
+                .route("/wp/v2/media/7")
+                .json(json!({ "id": 7, "source_url": "test://test/uploads/file.txt" }))
+                .build()
+                .unwrap(),
+            MockResponse::builder()
+                .route("/uploads/file.txt")
+                .header("content-length", "20")
+                .body(FILE)
+                .build()
+                .unwrap(),
+        ]);
+        let mut file = Vec::new();
+        let mut progress = Vec::new();
+
+        let size = Media::new(&client)
+            .download(7, &mut file)
+            .progress(|downloaded, total| progress.push((downloaded, total)))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(size, 20);
+        assert_eq!(file, FILE);
+        assert_eq!(
+            progress,
+            vec![(7, Some(20)), (14, Some(20)), (20, Some(20))]
+        );
+    }
+
+    #[tokio::test]
+    async fn resume_download() {
+        let response = MockResponse::builder()
+            .route("/uploads/file.txt")
+            .expect_header("range", "bytes=10-")
+            .status(StatusCode::PARTIAL_CONTENT)
+            .header("content-range", "bytes 10-19/20")
+            .body(&FILE[10..])
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let mut file = FILE[..10].to_vec();
+        let url = Url::parse("test://test/uploads/file.txt").unwrap();
+
+        let size = Media::new(&client)
+            .download(url, &mut file)
+            .resume_from(10)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(size, 20);
+        assert_eq!(file, FILE);
+    }
+
+    #[tokio::test]
+    async fn resume_download_without_range() {
+        let response = MockResponse::builder()
+            .route("/uploads/file.txt")
+            .body(FILE)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let mut file = FILE[..10].to_vec();
+        let url = Url::parse("test://test/uploads/file.txt").unwrap();
+
+        let size = Media::new(&client)
+            .download(url, &mut file)
+            .resume_from(10)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(size, 20);
+        assert_eq!(file, FILE);
+    }
+
+    #[tokio::test]
+    async fn download_error() {
+        let response = MockResponse::builder()
+            .route("/uploads/file.txt")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let url = Url::parse("test://test/uploads/file.txt").unwrap();
+
+        let err = Media::new(&client)
+            .download(url, Vec::new())
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::MediaDownload { status, .. } if status == 404));
+    }
+}
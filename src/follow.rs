@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use http::Method;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::{
+    client::{same_origin, Client},
+    query::Query,
+    request::RequestBuilder,
+    types::Link,
+    ApiError,
+};
+
+/// A query requesting the target of a link.
+///
+/// See [`follow`].
+#[derive(Debug, Clone)]
+pub struct Follow {
+    href: String,
+}
+
+/// Request the target of a link from the `_links` of a resource, such as the
+/// author of a post.
+///
+/// Only links to the same site as the client are followed, so that
+/// credentials are never sent elsewhere; other links fail with
+/// [`ApiError::ForeignLink`]. Templated links must be expanded first.
+pub fn follow(link: &Link) -> Follow {
+    Follow {
+        href: link.href.clone(),
+    }
+}
+
+#[async_trait]
+impl<T, C> Query<T, C> for Follow
+where
+    T: DeserializeOwned + 'static,
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let url = Url::parse(&self.href)?;
+        let root = client.route_url("/").await?;
+        if !same_origin(&url, &root) {
+            return Err(ApiError::foreign_link(url));
+        }
+
+        let (_, body) = RequestBuilder::new()
+            .method(Method::GET)
+            .url(url)
+            .send_as(client)
+            .await?;
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        types::{Post, User},
+    };
+
+    #[tokio::test]
+    async fn author() {
+        let post: Post = serde_json::from_value(json!({
+            "id": 1,
+            "_links": {
+                "author": [{ "embeddable": true, "href": "test://test/wp/v2/users/2" }],
+            },
+        }))
+        .unwrap();
+        let response = MockResponse::builder()
+            .route("/wp/v2/users/2")
+            .json(json!({ "id": 2, "name": "Jane" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let author: User = follow(&post.links.author()[0])
+            .query(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(author.name, "Jane");
+    }
+
+    #[tokio::test]
+    async fn foreign() {
+        let link = Link {
+            href: "https://example.com/wp-json/wp/v2/users/2".into(),
+            ..Link::default()
+        };
+        let client = MockClient::with_response(MockResponse::builder().build().unwrap());
+
+        let err = Query::<Value, _>::query(&follow(&link), &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::ForeignLink { .. }));
+    }
+}
@@ -0,0 +1,161 @@
+use std::borrow::Cow;
+
+use http::Method;
+use serde_json::Value;
+use url::form_urlencoded::Serializer;
+
+use crate::{
+    endpoint::{ContentType, Endpoint},
+    error::BodyError,
+    paged::Pageable,
+    params::QueryParams,
+};
+
+/// An endpoint sending its body as `application/x-www-form-urlencoded`.
+///
+/// See [`form_encoded`].
+#[derive(Debug, Clone)]
+pub struct FormEncoded<E> {
+    endpoint: E,
+}
+
+/// Send the JSON body of an endpoint as a form instead.
+///
+/// Some hosts and security plugins reject JSON request bodies. Nested values
+/// are encoded using the bracket syntax understood by PHP, e.g.
+/// `meta[key]=value` and `tags[0]=1`. Bodies which are not JSON are sent
+/// unchanged.
+pub fn form_encoded<E>(endpoint: E) -> FormEncoded<E>
+where
+    E: Endpoint,
+{
+    FormEncoded { endpoint }
+}
+
+impl<E> Endpoint for FormEncoded<E>
+where
+    E: Endpoint,
+{
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        self.endpoint.route()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.endpoint.parameters()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        match self.endpoint.body()? {
+            Some((ContentType::Json, body)) => {
+                let value: Value = serde_json::from_slice(&body)?;
+                Ok(Some((ContentType::FormUrlEncoded, encode(&value))))
+            }
+            body => Ok(body),
+        }
+    }
+}
+
+impl<E> Pageable for FormEncoded<E> where E: Pageable {}
+
+/// Encode a JSON value as a form.
+pub(crate) fn encode(value: &Value) -> Vec<u8> {
+    let mut serializer = Serializer::new(String::new());
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                append(&mut serializer, key.clone(), value);
+            }
+        }
+        Value::Null => {}
+        value => append(&mut serializer, "0".into(), value),
+    }
+    serializer.finish().into_bytes()
+}
+
+fn append(serializer: &mut Serializer<'_, String>, key: String, value: &Value) {
+    match value {
+        Value::Null => {}
+        Value::Bool(value) => {
+            serializer.append_pair(&key, if *value { "true" } else { "false" });
+        }
+        Value::Number(value) => {
+            serializer.append_pair(&key, &value.to_string());
+        }
+        Value::String(value) => {
+            serializer.append_pair(&key, value);
+        }
+        Value::Array(values) if values.is_empty() => {
+            // PHP has no way to represent an empty array in a form, so send
+            // an empty value which WordPress treats as an empty list.
+            serializer.append_pair(&key, "");
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                append(serializer, format!("{}[{}]", key, index), value);
+            }
+        }
+        Value::Object(object) => {
+            for (name, value) in object {
+                append(serializer, format!("{}[{}]", key, name), value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        endpoints::RawEndpoint,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[test]
+    fn nested() {
+        let body = encode(&json!({
+            "title": "Hello world",
+            "sticky": true,
+            "tags": [1, 2],
+            "categories": [],
+            "meta": { "key": "a&b" },
+            "excerpt": null,
+        }));
+
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "categories=&meta%5Bkey%5D=a%26b&sticky=true&tags%5B0%5D=1&tags%5B1%5D=2&title=Hello+world"
+        );
+    }
+
+    #[tokio::test]
+    async fn endpoint() {
+        let endpoint = RawEndpoint::builder()
+            .method(Method::POST)
+            .route("/mock")
+            .body(json!({ "title": "Hello" }))
+            .build()
+            .unwrap();
+        let endpoint = form_encoded(endpoint);
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .expect_header("content-type", "application/x-www-form-urlencoded")
+            .json(json!({ "id": 1 }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let (_, body) = endpoint.body().unwrap().unwrap();
+        let response: Value = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(body, b"title=Hello");
+        assert_eq!(response, json!({ "id": 1 }));
+    }
+}
@@ -0,0 +1,14 @@
+//! Endpoints and models for bbPress forums.
+//!
+//! bbPress does not register REST routes of its own. The endpoints use the
+//! `buddyboss/v1` namespace of BuddyBoss Platform, which serves the topics and
+//! replies of bbPress forums, and are queried with the same clients as the
+//! core endpoints.
+
+pub use replies::{CreateReply, ListReplies, RetrieveReply};
+pub use topics::{CreateTopic, ListTopics, RetrieveTopic};
+pub use types::{Reply, Topic};
+
+pub mod replies;
+pub mod topics;
+pub mod types;
@@ -0,0 +1,7 @@
+pub use create::CreateReply;
+pub use list::ListReplies;
+pub use retrieve::RetrieveReply;
+
+pub mod create;
+pub mod list;
+pub mod retrieve;
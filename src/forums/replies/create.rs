@@ -0,0 +1,84 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::endpoint::prelude::*;
+
+/// Reply to a forum topic.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateReply {
+    /// The ID of the topic to reply to.
+    topic_id: u32,
+
+    /// The content of the reply.
+    #[builder(setter(into))]
+    content: String,
+
+    /// The ID of the reply to reply to, for threaded replies.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to: Option<u32>,
+
+    /// The ID of the forum of the topic.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    forum_id: Option<u32>,
+}
+
+impl CreateReply {
+    pub fn builder() -> CreateReplyBuilder {
+        CreateReplyBuilder::default()
+    }
+}
+
+impl Endpoint for CreateReply {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/buddyboss/v1/reply".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        forums::Reply,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn threaded() {
+        let endpoint = CreateReply::builder()
+            .topic_id(20)
+            .reply_to(30)
+            .content("Agreed.")
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route("/buddyboss/v1/reply")
+            .json(json!({ "id": 31, "topic_id": 20, "reply_to": 30 }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let reply: Reply = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(reply.reply_to, 30);
+        assert_eq!(
+            endpoint.body().unwrap().unwrap().1,
+            br#"{"topic_id":20,"content":"Agreed.","reply_to":30}"#
+        );
+    }
+}
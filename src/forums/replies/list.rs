@@ -0,0 +1,109 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List replies to forum topics.
+#[derive(Default, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListReplies {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Limit result set to items by an author.
+    author: Option<u32>,
+
+    /// Limit result set to replies to a topic.
+    topic: Option<u32>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Order sort attribute ascending or descending.
+    order: Option<Order>,
+
+    /// Sort collection by object attribute.
+    #[builder(setter(into))]
+    orderby: Option<OrderBy>,
+}
+
+impl ListReplies {
+    pub fn builder() -> ListRepliesBuilder {
+        ListRepliesBuilder::default()
+    }
+}
+
+impl Endpoint for ListReplies {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/buddyboss/v1/reply".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("author", self.author)
+            .push_opt("parent", self.topic)
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref());
+        params
+    }
+}
+
+impl Pageable for ListReplies {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        forums::Reply,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn topic() {
+        let endpoint = ListReplies::builder()
+            .topic(20)
+            .order(Order::Asc)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/buddyboss/v1/reply")
+            .query("parent=20&order=asc")
+            .json(json!([{ "id": 30, "topic_id": 20 }, { "id": 31, "topic_id": 20 }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let replies: Vec<Reply> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(replies.len(), 2);
+    }
+}
@@ -0,0 +1,63 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a reply.
+#[derive(Builder)]
+pub struct RetrieveReply {
+    /// Unique identifier for the reply.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveReply {
+    pub fn builder() -> RetrieveReplyBuilder {
+        RetrieveReplyBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveReply {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/buddyboss/v1/reply/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        forums::Reply,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn typed() {
+        let endpoint = RetrieveReply::builder().id(30).build().unwrap();
+        let response = MockResponse::builder()
+            .route("/buddyboss/v1/reply/30")
+            .json(json!({ "id": 30, "topic_id": 20 }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let reply: Reply = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(reply.id, 30);
+    }
+}
@@ -0,0 +1,7 @@
+pub use create::CreateTopic;
+pub use list::ListTopics;
+pub use retrieve::RetrieveTopic;
+
+pub mod create;
+pub mod list;
+pub mod retrieve;
@@ -0,0 +1,83 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::endpoint::prelude::*;
+
+/// Create a topic in a forum.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateTopic {
+    /// The ID of the forum of the topic.
+    #[serde(rename = "parent")]
+    forum: u32,
+
+    /// The title of the topic.
+    #[builder(setter(into))]
+    title: String,
+
+    /// The content of the topic.
+    #[builder(setter(into))]
+    content: String,
+
+    /// A named status for the topic, such as `publish` or `closed`.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+
+    /// Tags of the topic, separated by commas.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topic_tags: Option<String>,
+}
+
+impl CreateTopic {
+    pub fn builder() -> CreateTopicBuilder {
+        CreateTopicBuilder::default()
+    }
+}
+
+impl Endpoint for CreateTopic {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/buddyboss/v1/topics".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+
+    #[test]
+    fn body() {
+        let endpoint = CreateTopic::builder()
+            .forum(10)
+            .title("Welcome")
+            .content("Introduce yourself!")
+            .build()
+            .unwrap();
+
+        let (_, body) = endpoint.body().unwrap().unwrap();
+        let body: Json = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            body,
+            json!({ "parent": 10, "title": "Welcome", "content": "Introduce yourself!" })
+        );
+    }
+
+    #[test]
+    fn forum_required() {
+        let err = CreateTopic::builder().title("Welcome").content("").build();
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,105 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// List forum topics.
+#[derive(Default, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListTopics {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Limit result set to items by an author.
+    author: Option<u32>,
+
+    /// Limit result set to topics of a forum.
+    forum: Option<u32>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Order sort attribute ascending or descending.
+    order: Option<Order>,
+
+    /// Sort collection by object attribute.
+    #[builder(setter(into))]
+    orderby: Option<OrderBy>,
+}
+
+impl ListTopics {
+    pub fn builder() -> ListTopicsBuilder {
+        ListTopicsBuilder::default()
+    }
+}
+
+impl Endpoint for ListTopics {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/buddyboss/v1/topics".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("author", self.author)
+            .push_opt("parent", self.forum)
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref());
+        params
+    }
+}
+
+impl Pageable for ListTopics {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        forums::Topic,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn forum() {
+        let endpoint = ListTopics::builder().forum(10).per_page(5).build().unwrap();
+        let response = MockResponse::builder()
+            .route("/buddyboss/v1/topics")
+            .query("per_page=5&parent=10")
+            .json(json!([{ "id": 20, "forum_id": 10, "title": { "rendered": "Welcome" } }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let topics: Vec<Topic> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(topics[0].title.rendered, "Welcome");
+    }
+}
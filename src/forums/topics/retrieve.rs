@@ -0,0 +1,63 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a topic.
+#[derive(Builder)]
+pub struct RetrieveTopic {
+    /// Unique identifier for the topic.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveTopic {
+    pub fn builder() -> RetrieveTopicBuilder {
+        RetrieveTopicBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveTopic {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/buddyboss/v1/topics/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        forums::Topic,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn typed() {
+        let endpoint = RetrieveTopic::builder().id(20).build().unwrap();
+        let response = MockResponse::builder()
+            .route("/buddyboss/v1/topics/20")
+            .json(json!({ "id": 20, "forum_id": 10, "status": "publish" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let topic: Topic = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(topic.id, 20);
+    }
+}
@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Links, Rendered};
+
+/// A forum topic.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Topic {
+    /// Unique identifier for the topic.
+    #[serde(default)]
+    pub id: u32,
+
+    /// The date the topic was created, in the site's timezone.
+    #[serde(default)]
+    pub date: String,
+
+    /// The date the topic was created, as GMT.
+    #[serde(default)]
+    pub date_gmt: String,
+
+    /// The date the topic was last modified, in the site's timezone.
+    #[serde(default)]
+    pub modified: String,
+
+    /// The date the topic was last modified, as GMT.
+    #[serde(default)]
+    pub modified_gmt: String,
+
+    /// An alphanumeric identifier for the topic.
+    #[serde(default)]
+    pub slug: String,
+
+    /// The status of the topic, such as `publish` or `closed`.
+    #[serde(default)]
+    pub status: String,
+
+    /// URL to the topic.
+    #[serde(default)]
+    pub link: String,
+
+    /// The ID of the author of the topic.
+    #[serde(default)]
+    pub author: u32,
+
+    /// The title of the topic.
+    #[serde(default)]
+    pub title: Rendered,
+
+    /// The content of the topic.
+    #[serde(default)]
+    pub content: Rendered,
+
+    /// The ID of the forum of the topic.
+    #[serde(default)]
+    pub forum_id: u32,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
+
+/// A reply to a forum topic.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reply {
+    /// Unique identifier for the reply.
+    #[serde(default)]
+    pub id: u32,
+
+    /// The date the reply was created, in the site's timezone.
+    #[serde(default)]
+    pub date: String,
+
+    /// The date the reply was created, as GMT.
+    #[serde(default)]
+    pub date_gmt: String,
+
+    /// The date the reply was last modified, in the site's timezone.
+    #[serde(default)]
+    pub modified: String,
+
+    /// The date the reply was last modified, as GMT.
+    #[serde(default)]
+    pub modified_gmt: String,
+
+    /// The status of the reply, such as `publish` or `spam`.
+    #[serde(default)]
+    pub status: String,
+
+    /// URL to the reply.
+    #[serde(default)]
+    pub link: String,
+
+    /// The ID of the author of the reply.
+    #[serde(default)]
+    pub author: u32,
+
+    /// The title of the reply.
+    #[serde(default)]
+    pub title: Rendered,
+
+    /// The content of the reply.
+    #[serde(default)]
+    pub content: Rendered,
+
+    /// The ID of the topic of the reply.
+    #[serde(default)]
+    pub topic_id: u32,
+
+    /// The ID of the forum of the reply.
+    #[serde(default)]
+    pub forum_id: u32,
+
+    /// The ID of the reply this replies to, or 0 for a reply to the topic.
+    #[serde(default)]
+    pub reply_to: u32,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn threaded_reply() {
+        let reply: Reply = serde_json::from_value(json!({
+            "id": 31,
+            "status": "publish",
+            "author": 2,
+            "content": { "rendered": "<p>Agreed.</p>" },
+            "topic_id": 20,
+            "forum_id": 10,
+            "reply_to": 30,
+        }))
+        .unwrap();
+
+        assert_eq!(reply.content.rendered, "<p>Agreed.</p>");
+        assert_eq!((reply.topic_id, reply.reply_to), (20, 30));
+    }
+}
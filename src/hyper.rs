@@ -0,0 +1,298 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::{future, stream, StreamExt, TryStreamExt};
+use http::{Request, Response};
+use hyper::{
+    body::{self, HttpBody},
+    client::{connect::Connect, HttpConnector},
+    Body, Client as HttpClient,
+};
+use thiserror::Error;
+use url::Url;
+
+#[cfg(all(feature = "unix", unix))]
+pub use self::unix::{UnixConnector, UnixStream};
+use crate::{
+    auth::{self, Auth, CookieAuth},
+    client::{self, BodyStream, Client, RequestBodyStream},
+    error::ApiError,
+    request,
+    root::{RootRoute, RootRouteCell},
+};
+
+#[cfg(all(feature = "unix", unix))]
+mod unix;
+
+/// Asynchronous WordPress client using `hyper` directly.
+///
+/// Unlike [`WordPress`](crate::WordPress), no timeouts are applied and
+/// redirects are never followed. The connector can be replaced to control how
+/// connections are made, e.g. to add TLS support.
+pub struct HyperWordPress<C = HttpConnector> {
+    client: HttpClient<C, Body>,
+    site_url: Url,
+    root_route: RootRouteCell,
+    auth: Option<Auth>,
+}
+
+impl HyperWordPress {
+    /// Create a new WordPress client for plain HTTP connections.
+    pub fn new(site_url: impl AsRef<str>) -> Result<Self, HyperError> {
+        Self::with_connector(site_url, HttpConnector::new())
+    }
+}
+
+impl<C> HyperWordPress<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    /// Create a new WordPress client using a custom connector.
+    pub fn with_connector(site_url: impl AsRef<str>, connector: C) -> Result<Self, HyperError> {
+        Ok(Self {
+            client: HttpClient::builder().build(connector),
+            site_url: Url::parse(site_url.as_ref())?,
+            root_route: RootRouteCell::default(),
+            auth: None,
+        })
+    }
+
+    /// Authenticate every request sent by the client.
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Log in with a username and password and authenticate every request
+    /// sent by the client using cookies.
+    ///
+    /// See [`CookieAuth`].
+    pub async fn login(self, username: &str, password: &str) -> Result<Self, ApiError<HyperError>> {
+        let auth = CookieAuth::login(&self, &self.site_url, username, password).await?;
+        Ok(self.with_auth(Auth::Cookie(auth)))
+    }
+
+    /// The root route for the WordPress instance.
+    ///
+    /// The value will change depending on the permalink structure configured
+    /// for the site.
+    pub async fn root_route(&self) -> Result<RootRoute, ApiError<HyperError>> {
+        self.root_route
+            .get_or_try_init(|| self.discover_root_route(&self.site_url))
+            .await
+    }
+
+    /// Use a previously discovered root route instead of discovering it on the
+    /// first request.
+    pub fn with_root_route(self, root_route: RootRoute) -> Self {
+        self.root_route.set(Some(root_route));
+        self
+    }
+
+    /// Forget the root route, so that it is discovered again on the next
+    /// request, e.g. after the permalink structure of the site changed.
+    pub fn invalidate_root_route(&self) {
+        self.root_route.set(None);
+    }
+
+    async fn execute(&self, request: Request<Vec<u8>>) -> Result<Response<Bytes>, HyperError> {
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+        let resp = self.client.request(request.map(Body::from)).await?;
+        let (parts, body) = resp.into_parts();
+        let resp = Response::from_parts(parts, body::to_bytes(body).await?);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(status = %resp.status(), latency = ?start.elapsed(), "received response");
+        Ok(resp)
+    }
+
+    async fn execute_streamed(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<HyperError>>, HyperError> {
+        let resp = self.client.request(request.map(Body::from)).await?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(status = %resp.status(), "received response headers");
+        Ok(resp.map(|body| {
+            stream::try_unfold(body, |mut body| async move {
+                let chunk = body.data().await.transpose().map_err(HyperError::from)?;
+                Ok(chunk.map(|chunk| (chunk, body)))
+            })
+            .map_err(ApiError::client)
+            .boxed()
+        }))
+    }
+
+    async fn execute_streaming(
+        &self,
+        request: Request<RequestBodyStream>,
+    ) -> Result<Response<Bytes>, HyperError> {
+        let (parts, body) = request.into_parts();
+        let (body, read) = request::channel_body(body);
+        let request = Request::from_parts(parts, body);
+        let (resp, read) = future::join(self.client.request(request), read).await;
+        read.map_err(|source| HyperError::Body { source })?;
+        let (parts, body) = resp?.into_parts();
+        let resp = Response::from_parts(parts, body::to_bytes(body).await?);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(status = %resp.status(), "received response");
+        Ok(resp)
+    }
+}
+
+#[async_trait]
+impl<C> Client for HyperWordPress<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    type Error = HyperError;
+
+    async fn route_url(&self, route: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.root_route().await?.join(route))
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(method = %request.method(), url = %request.uri()),
+        )
+    )]
+    async fn send_request(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        auth::send(self.auth.as_ref(), request, |request| self.execute(request)).await
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(method = %request.method(), url = %request.uri()),
+        )
+    )]
+    async fn send_request_streamed(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        auth::send(self.auth.as_ref(), request, |request| {
+            self.execute_streamed(request)
+        })
+        .await
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(method = %request.method(), url = %request.uri()),
+        )
+    )]
+    async fn send_streaming_request(
+        &self,
+        request: Request<RequestBodyStream>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        auth::send_streaming(self.auth.as_ref(), request, |request| {
+            self.execute_streaming(request)
+        })
+        .await
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(method = %request.method(), url = %request.uri()),
+        )
+    )]
+    async fn send_external_request(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        if client::is_site_request(self, &request).await? {
+            return self.send_request_streamed(request).await;
+        }
+        Ok(self.execute_streamed(request).await?)
+    }
+}
+
+/// Errors that may occur when using the hyper client.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum HyperError {
+    #[error("failed to parse url: {}", source)]
+    UrlParse {
+        #[from]
+        source: url::ParseError,
+    },
+
+    #[error("communication with wordpress: {}", source)]
+    Communication {
+        #[from]
+        source: hyper::Error,
+    },
+
+    #[error("failed to read request body: {}", source)]
+    Body { source: std::io::Error },
+}
+
+impl From<HyperError> for ApiError<HyperError> {
+    fn from(err: HyperError) -> Self {
+        ApiError::client(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::{endpoints::RawEndpoint, Query};
+
+    #[tokio::test]
+    async fn query() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("HEAD"))
+            .respond_with(
+                ResponseTemplate::new(200).insert_header(
+                    "link",
+                    format!(
+                        "<{}/wp-json/>; rel=\"https://api.w.org/\"",
+                        mock_server.uri()
+                    )
+                    .as_str(),
+                ),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/wp-json/wp/v2/users/me"))
+            .and(header("authorization", "Bearer abc123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 1 })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let wordpress = HyperWordPress::new(mock_server.uri())
+            .unwrap()
+            .with_auth(Auth::bearer("abc123"));
+        let endpoint = RawEndpoint::builder()
+            .route("/wp/v2/users/me")
+            .build()
+            .unwrap();
+
+        let user: Value = endpoint.query(&wordpress).await.unwrap();
+
+        assert_eq!(user, json!({ "id": 1 }));
+    }
+}
@@ -0,0 +1,151 @@
+use std::{
+    future::Future,
+    io,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use http::Uri;
+use hyper::{
+    client::connect::{Connected, Connection},
+    service::Service,
+};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use super::{HyperError, HyperWordPress};
+
+/// A connector which connects to a Unix domain socket, whatever the URL.
+///
+/// This is useful for local development setups where the web server listens
+/// on a socket rather than a port. The host of the site URL is still sent in
+/// the `Host` header.
+#[derive(Debug, Clone)]
+pub struct UnixConnector {
+    path: Arc<Path>,
+}
+
+impl UnixConnector {
+    /// Connect to the socket at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into().into(),
+        }
+    }
+}
+
+impl Service<Uri> for UnixConnector {
+    type Response = UnixStream;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = io::Result<UnixStream>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        let path = self.path.clone();
+        Box::pin(async move { Ok(UnixStream(tokio::net::UnixStream::connect(path).await?)) })
+    }
+}
+
+/// A connection made by [`UnixConnector`].
+#[derive(Debug)]
+pub struct UnixStream(tokio::net::UnixStream);
+
+impl Connection for UnixStream {
+    fn connected(&self) -> Connected {
+        Connected::new()
+    }
+}
+
+impl AsyncRead for UnixStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for UnixStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
+
+impl HyperWordPress<UnixConnector> {
+    /// Create a new WordPress client which connects to a Unix domain socket.
+    ///
+    /// The site URL should be the URL WordPress is configured with, such as
+    /// `http://localhost:8888`, since it determines the `Host` header and the
+    /// routes discovered.
+    pub fn unix(socket: impl Into<PathBuf>, site_url: impl AsRef<str>) -> Result<Self, HyperError> {
+        Self::with_connector(site_url, UnixConnector::new(socket))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Request;
+    use pretty_assertions::assert_eq;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::UnixListener,
+    };
+
+    use super::*;
+    use crate::Client;
+
+    #[tokio::test]
+    async fn unix() {
+        let path = std::env::temp_dir().join(format!("wordprs-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            let request = String::from_utf8(request).unwrap();
+            let line = request.lines().next().unwrap().to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                line.len(),
+                line
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+            request
+        });
+
+        let wordpress = HyperWordPress::unix(&path, "http://localhost:8888").unwrap();
+        let req = Request::builder()
+            .uri("http://localhost:8888/wp-json/")
+            .body(Vec::new())
+            .unwrap();
+
+        let resp = wordpress.send_request(req).await.unwrap();
+        let request = server.await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(resp.body().as_ref(), b"GET /wp-json/ HTTP/1.1");
+        assert!(request.contains("host: localhost:8888\r\n"));
+    }
+}
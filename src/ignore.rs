@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use http::StatusCode;
+
+use crate::{
+    client::Client,
+    endpoint::{self, Endpoint},
+    query::Query,
+    request, ApiError,
+};
+
+/// A query which ignores the body of a successful response.
+///
+/// See [`ignore`].
+#[derive(Debug, Clone)]
+pub struct Ignore<E> {
+    endpoint: E,
+}
+
+/// Perform the query of an endpoint, discarding the response on success.
+///
+/// Error responses are still reported as with any other query. This is useful
+/// when deleting resources or triggering actions, where the returned object is
+/// not needed.
+pub fn ignore<E>(endpoint: E) -> Ignore<E>
+where
+    E: Endpoint,
+{
+    Ignore { endpoint }
+}
+
+#[async_trait]
+impl<E, C> Query<(), C> for Ignore<E>
+where
+    E: Endpoint + Sync,
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<(), ApiError<C::Error>> {
+        let resp = endpoint::request(&self.endpoint, client)
+            .await?
+            .send_raw(client)
+            .await?;
+
+        let status = resp.status();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(ApiError::rate_limited(request::retry_after(resp.headers())));
+        }
+        if !status.is_success() {
+            return Err(match serde_json::from_slice(resp.body()) {
+                Ok(json) => ApiError::from_json(json),
+                Err(_) => ApiError::server_error(status, resp.body()),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        endpoints::RawEndpoint,
+        test::{MockClient, MockResponse},
+    };
+
+    fn endpoint() -> RawEndpoint {
+        RawEndpoint::builder()
+            .method(Method::DELETE)
+            .route("/mock")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn success() {
+        let response = MockResponse::builder()
+            .method(Method::DELETE)
+            .body("not json")
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        ignore(endpoint()).query(&client).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn error() {
+        let response = MockResponse::builder()
+            .method(Method::DELETE)
+            .status(StatusCode::FORBIDDEN)
+            .json(json!({
+                "code": "rest_cannot_delete",
+                "message": "Sorry, you are not allowed to delete this post.",
+                "data": { "status": 403 },
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let err = ignore(endpoint()).query(&client).await.unwrap_err();
+
+        assert!(matches!(err, ApiError::WordPress { code, .. } if code == "rest_cannot_delete"));
+    }
+}
@@ -1,20 +1,115 @@
+#[cfg(feature = "edd")]
+pub use crate::auth::EddAuth;
+#[cfg(feature = "woocommerce")]
+pub use crate::auth::WooCommerceAuth;
 pub use crate::{
-    client::Client, document::Document, endpoint::Endpoint, error::ApiError, query::Query,
+    auth::{Auth, Authenticate, Authenticated, BearerAuth, CookieAuth, CookieJar, RefreshToken},
+    batch::{Batch, BatchResults},
+    cache::ConditionalCache,
+    client::{BodyStream, Client},
+    compression::{Codec, Compression},
+    document::Document,
+    endpoint::{ContentType, Endpoint},
+    envelope::{envelope, Envelope, Enveloped},
+    error::{ApiError, AuthError, BodyError},
+    fields::{fields, Fields},
+    follow::{follow, Follow},
+    form::{form_encoded, FormEncoded},
+    ignore::{ignore, Ignore},
+    metrics::MetricsObserver,
+    middleware::{Middleware, Next},
+    multisite::Subsite,
+    paged::{paged, Pageable, Paged},
+    params::{ParamValue, QueryParams, Timestamp},
+    pointer::{pointer, Pointer},
+    query::{query_all, query_all_buffered, Query, QueryAs},
+    raw::{raw, Raw},
+    secret::SecretString,
+    stream::{streamed, Streamed},
 };
 
+#[cfg(feature = "acf")]
+pub mod acf;
+mod auth;
+mod batch;
+pub mod blocks;
+mod cache;
 mod client;
+mod compression;
+#[cfg(feature = "contact-form-7")]
+pub mod contact_form_7;
 mod document;
-mod endpoint;
+#[cfg(feature = "edd")]
+pub mod edd;
+pub mod endpoint;
 pub mod endpoints;
+mod envelope;
 mod error;
+mod fields;
+pub mod fluent;
+mod follow;
+mod form;
+#[cfg(feature = "forums")]
+pub mod forums;
+mod ignore;
+mod metrics;
+mod middleware;
+pub mod migrate;
+mod multipart;
+mod multisite;
+mod paged;
+mod params;
+mod pointer;
 mod query;
+mod random;
+mod raw;
 mod request;
 pub mod root;
+mod secret;
+pub mod shortcodes;
+mod stream;
+pub mod sync;
+pub mod types;
+#[cfg(feature = "woocommerce")]
+pub mod woocommerce;
+pub mod wxr;
+#[cfg(feature = "yoast")]
+pub mod yoast;
 
 #[cfg(feature = "client")]
-pub use crate::wordpress::WordPress;
+pub use crate::wordpress::{WordPress, WordPressBuilder, WordPressError};
 #[cfg(feature = "client")]
 mod wordpress;
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+pub use reqwest::Certificate;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+#[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+pub use crate::{
+    dedup::Deduplicate,
+    rate_limit::RateLimitRetry,
+    watch::{Watch, Watchable},
+};
+#[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+mod dedup;
+#[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+mod rate_limit;
+#[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+mod watch;
+
+#[cfg(feature = "hyper")]
+pub use crate::hyper::{HyperError, HyperWordPress};
+#[cfg(all(feature = "unix", unix))]
+pub use crate::hyper::{UnixConnector, UnixStream};
+#[cfg(feature = "hyper")]
+mod hyper;
+
+#[cfg(feature = "tower")]
+pub use crate::tower::{TowerError, TowerWordPress};
+#[cfg(feature = "tower")]
+mod tower;
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,53 @@
+use std::{error::Error, sync::Arc, time::Duration};
+
+use http::{Method, StatusCode, Uri};
+
+/// Callbacks for observing the requests sent by a client.
+///
+/// Each callback is invoked once per HTTP request, so a query which is retried
+/// is observed more than once. All callbacks do nothing by default.
+pub trait MetricsObserver: Send + Sync {
+    /// A request is about to be sent.
+    fn on_request_start(&self, method: &Method, uri: &Uri) {
+        let _ = (method, uri);
+    }
+
+    /// A response was received.
+    fn on_response(&self, method: &Method, uri: &Uri, status: StatusCode, latency: Duration) {
+        let _ = (method, uri, status, latency);
+    }
+
+    /// The request failed before a response was received.
+    fn on_error(
+        &self,
+        method: &Method,
+        uri: &Uri,
+        error: &(dyn Error + 'static),
+        latency: Duration,
+    ) {
+        let _ = (method, uri, error, latency);
+    }
+}
+
+impl<M> MetricsObserver for Arc<M>
+where
+    M: MetricsObserver + ?Sized,
+{
+    fn on_request_start(&self, method: &Method, uri: &Uri) {
+        (**self).on_request_start(method, uri)
+    }
+
+    fn on_response(&self, method: &Method, uri: &Uri, status: StatusCode, latency: Duration) {
+        (**self).on_response(method, uri, status, latency)
+    }
+
+    fn on_error(
+        &self,
+        method: &Method,
+        uri: &Uri,
+        error: &(dyn Error + 'static),
+        latency: Duration,
+    ) {
+        (**self).on_error(method, uri, error, latency)
+    }
+}
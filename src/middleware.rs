@@ -0,0 +1,179 @@
+use std::{error::Error, future::Future, pin::Pin, sync::Arc};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{Request, Response};
+
+use crate::error::ApiError;
+
+pub(crate) type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+type Endpoint<'a, E> = dyn Fn(Request<Vec<u8>>) -> BoxFuture<'a, Result<Response<Bytes>, ApiError<E>>>
+    + Send
+    + Sync
+    + 'a;
+
+/// A layer which wraps every request sent by a client.
+///
+/// Middleware may inspect or modify the outgoing request and the incoming
+/// response, short circuit the request entirely, or send it more than once.
+#[async_trait]
+pub trait Middleware<E>: Send + Sync
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// Handle a request, using `next` to pass it on to the rest of the
+    /// pipeline.
+    async fn handle(
+        &self,
+        request: Request<Vec<u8>>,
+        next: Next<'_, E>,
+    ) -> Result<Response<Bytes>, ApiError<E>>;
+}
+
+#[async_trait]
+impl<E, M> Middleware<E> for Arc<M>
+where
+    E: Error + Send + Sync + 'static,
+    M: Middleware<E> + ?Sized,
+{
+    async fn handle(
+        &self,
+        request: Request<Vec<u8>>,
+        next: Next<'_, E>,
+    ) -> Result<Response<Bytes>, ApiError<E>> {
+        (**self).handle(request, next).await
+    }
+}
+
+/// The remainder of the middleware pipeline.
+pub struct Next<'a, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    middleware: &'a [Arc<dyn Middleware<E>>],
+    endpoint: &'a Endpoint<'a, E>,
+}
+
+impl<'a, E> Next<'a, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    #[cfg(any(test, feature = "client"))]
+    pub(crate) fn new(
+        middleware: &'a [Arc<dyn Middleware<E>>],
+        endpoint: &'a Endpoint<'a, E>,
+    ) -> Self {
+        Self {
+            middleware,
+            endpoint,
+        }
+    }
+
+    /// Pass the request on to the next middleware, or send it if there are no
+    /// more.
+    pub async fn run(self, request: Request<Vec<u8>>) -> Result<Response<Bytes>, ApiError<E>> {
+        match self.middleware.split_first() {
+            Some((middleware, rest)) => {
+                let next = Next {
+                    middleware: rest,
+                    endpoint: self.endpoint,
+                };
+                middleware.handle(request, next).await
+            }
+            None => (self.endpoint)(request).await,
+        }
+    }
+}
+
+impl<E> Clone for Next<'_, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for Next<'_, E> where E: Error + Send + Sync + 'static {}
+
+#[cfg(test)]
+mod tests {
+    use http::{HeaderValue, StatusCode};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test::MockClientError;
+
+    struct Tag(&'static str);
+
+    #[async_trait]
+    impl Middleware<MockClientError> for Tag {
+        async fn handle(
+            &self,
+            mut request: Request<Vec<u8>>,
+            next: Next<'_, MockClientError>,
+        ) -> Result<Response<Bytes>, ApiError<MockClientError>> {
+            request
+                .headers_mut()
+                .append("x-tag", HeaderValue::from_static(self.0));
+            let mut resp = next.run(request).await?;
+            resp.headers_mut()
+                .append("x-tag", HeaderValue::from_static(self.0));
+            Ok(resp)
+        }
+    }
+
+    struct Teapot;
+
+    #[async_trait]
+    impl Middleware<MockClientError> for Teapot {
+        async fn handle(
+            &self,
+            _request: Request<Vec<u8>>,
+            _next: Next<'_, MockClientError>,
+        ) -> Result<Response<Bytes>, ApiError<MockClientError>> {
+            Ok(Response::builder()
+                .status(StatusCode::IM_A_TEAPOT)
+                .body(Bytes::new())
+                .unwrap())
+        }
+    }
+
+    fn tags<T>(message: &http::HeaderMap<T>) -> Vec<&T> {
+        message.get_all("x-tag").into_iter().collect()
+    }
+
+    #[tokio::test]
+    async fn order() {
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> =
+            vec![Arc::new(Tag("a")), Arc::new(Tag("b"))];
+        let endpoint = |request: Request<Vec<u8>>| -> BoxFuture<'_, _> {
+            Box::pin(async move {
+                assert_eq!(tags(request.headers()), ["a", "b"]);
+                Ok(Response::new(Bytes::new()))
+            })
+        };
+
+        let resp = Next::new(&middleware, &endpoint)
+            .run(Request::new(Vec::new()))
+            .await
+            .unwrap();
+
+        assert_eq!(tags(resp.headers()), ["b", "a"]);
+    }
+
+    #[tokio::test]
+    async fn short_circuit() {
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> = vec![Arc::new(Teapot)];
+        let endpoint = |_request: Request<Vec<u8>>| -> BoxFuture<'_, _> {
+            Box::pin(async { panic!("request should not be sent") })
+        };
+
+        let resp = Next::new(&middleware, &endpoint)
+            .run(Request::new(Vec::new()))
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::IM_A_TEAPOT);
+    }
+}
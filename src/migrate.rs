@@ -0,0 +1,607 @@
+//! Copying content from one site to another.
+//!
+//! A [`Migration`] reads the categories, tags, pages and posts of a source
+//! site and creates them on a target site through the [`Batch`]
+//! endpoint, mapping the IDs they refer to, such as the parent of a page or the
+//! terms of a post, to the items created on the target. The featured media of
+//! the copied items is uploaded to the target again unless disabled.
+//!
+//! The same [`IdMap`] as for [importing exports](crate::wxr) is used, so a
+//! migration which failed part of the way can be run again with it without
+//! copying the same items twice.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use derive_builder::Builder;
+use serde_json::Value;
+use url::Url;
+
+use crate::{
+    batch::{Batch, MAX_REQUESTS},
+    client::Client,
+    endpoint::Endpoint,
+    endpoints::{
+        media::sideload::{download, filename},
+        Context, CreateMedia, CreatePage, CreatePost, CreateTerm, ListPages, ListPosts, ListTerms,
+        PostStatus, RetrieveMedia,
+    },
+    error::ApiError,
+    paged::paged,
+    query::Query,
+    types::{Attachment, Page, Post, Term},
+    wxr::{generations, mapped, raw, IdMap},
+};
+
+/// Copies the content of a site to another.
+///
+/// The content is read in the `edit` context, so the source client must be
+/// authenticated as a user who can edit it.
+#[derive(Debug, Clone, Builder)]
+pub struct Migration {
+    /// Only copy posts and pages with these statuses. Defaults to published
+    /// items only.
+    #[builder(setter(into), default)]
+    status: Vec<PostStatus>,
+
+    /// Whether to upload the featured media of the copied items to the
+    /// target. Defaults to true.
+    ///
+    /// The files are downloaded through the source client, so the target's
+    /// credentials are never sent to the source.
+    ///
+    /// Otherwise featured media is only set when its ID is already in the
+    /// [`IdMap`].
+    #[builder(default = "true")]
+    upload_media: bool,
+
+    /// Maximum size in bytes of each featured media file. Defaults to 100 MiB.
+    ///
+    /// The migration fails with [`ApiError::MediaTooLarge`] as soon as a
+    /// download exceeds it.
+    #[builder(default = "100 * 1024 * 1024")]
+    max_media_size: usize,
+
+    /// The IDs of the users on the target site, by their IDs on the source.
+    #[builder(setter(custom), default)]
+    authors: BTreeMap<u32, u32>,
+}
+
+impl MigrationBuilder {
+    /// Attribute the content of an author of the source site to a user of
+    /// the target site.
+    ///
+    /// Content by authors which are not mapped is attributed to the user of
+    /// the target client.
+    pub fn author(&mut self, source: u32, target: u32) -> &mut Self {
+        self.authors
+            .get_or_insert_with(BTreeMap::new)
+            .insert(source, target);
+        self
+    }
+}
+
+impl Migration {
+    pub fn builder() -> MigrationBuilder {
+        MigrationBuilder::default()
+    }
+
+    /// Copy the content of the source site to the target site, adding the
+    /// created items to the map.
+    ///
+    /// Requests which fail within a batch do not stop the rest of it, but the
+    /// migration stops after that batch with the first of their errors.
+    pub async fn run<C>(
+        &self,
+        source: &C,
+        target: &C,
+        ids: &mut IdMap,
+    ) -> Result<(), ApiError<C::Error>>
+    where
+        C: Client + Sync,
+    {
+        for taxonomy in ["categories", "tags"] {
+            let endpoint = ListTerms::builder()
+                .taxonomy(taxonomy)
+                .context(Context::Edit)
+                .build()
+                .expect("taxonomy is set");
+            let terms: Vec<Term> = paged(endpoint).query(source).await?;
+            for generation in generations(&terms, |term| term.id, |term| term.parent) {
+                let creates = generation
+                    .into_iter()
+                    .filter(|term| !ids.terms.contains_key(&term.id))
+                    .map(|term| {
+                        let mut builder = CreateTerm::builder();
+                        builder
+                            .taxonomy(taxonomy)
+                            .name(term.name.as_str())
+                            .slug(term.slug.as_str())
+                            .description(term.description.as_str());
+                        if let Some(&parent) = ids.terms.get(&term.parent) {
+                            builder.parent(parent);
+                        }
+                        (term.id, builder.build().expect("required fields are set"))
+                    })
+                    .collect();
+                create(target, creates, &mut ids.terms).await?;
+            }
+        }
+
+        let pages: Vec<Page> = paged(
+            ListPages::builder()
+                .context(Context::Edit)
+                .status(self.status.clone())
+                .build()
+                .expect("all fields have defaults"),
+        )
+        .query(source)
+        .await?;
+        let posts: Vec<Post> = paged(
+            ListPosts::builder()
+                .context(Context::Edit)
+                .status(self.status.clone())
+                .build()
+                .expect("all fields have defaults"),
+        )
+        .query(source)
+        .await?;
+
+        if self.upload_media {
+            let media: BTreeSet<u32> = pages
+                .iter()
+                .map(|page| page.featured_media)
+                .chain(posts.iter().map(|post| post.featured_media))
+                .filter(|&id| id != 0 && !ids.posts.contains_key(&id))
+                .collect();
+            for id in media {
+                let endpoint = RetrieveMedia::builder()
+                    .id(id)
+                    .context(Context::Edit)
+                    .build()
+                    .expect("id is set");
+                let attachment: Attachment = endpoint.query(source).await?;
+                let url = Url::parse(&attachment.source_url)?;
+                let (_, data) = download(source, &url, Some(self.max_media_size)).await?;
+                let mut builder = CreateMedia::builder();
+                builder.filename(filename(&url)).data(data);
+                if !attachment.mime_type.is_empty() {
+                    builder.mime_type(attachment.mime_type.as_str());
+                }
+                if !attachment.alt_text.is_empty() {
+                    builder.alt_text(attachment.alt_text.as_str());
+                }
+                let caption = raw(&attachment.caption);
+                if !caption.is_empty() {
+                    builder.caption(caption);
+                }
+                let endpoint = builder.build().expect("file is set");
+                let uploaded: Attachment = endpoint.query(target).await?;
+                ids.posts.insert(id, uploaded.id);
+            }
+        }
+
+        for generation in generations(&pages, |page| page.id, |page| page.parent) {
+            let creates = generation
+                .into_iter()
+                .filter(|page| !ids.posts.contains_key(&page.id))
+                .map(|page| {
+                    let mut builder = CreatePage::builder();
+                    builder
+                        .slug(page.slug.as_str())
+                        .status(page.status.as_str())
+                        .title(raw(&page.title))
+                        .content(raw(&page.content))
+                        .excerpt(raw(&page.excerpt))
+                        .comment_status(page.comment_status.as_str())
+                        .ping_status(page.ping_status.as_str())
+                        .menu_order(page.menu_order)
+                        .template(page.template.as_str());
+                    if !page.date_gmt.is_empty() {
+                        builder.date_gmt(page.date_gmt.as_str());
+                    }
+                    if let Some(password) = page.password.as_deref().filter(|p| !p.is_empty()) {
+                        builder.password(password);
+                    }
+                    if let Some(&author) = self.authors.get(&page.author) {
+                        builder.author(author);
+                    }
+                    if let Some(&parent) = ids.posts.get(&page.parent) {
+                        builder.parent(parent);
+                    }
+                    if let Some(&media) = ids.posts.get(&page.featured_media) {
+                        builder.featured_media(media);
+                    }
+                    (page.id, builder.build().expect("all fields have defaults"))
+                })
+                .collect();
+            create(target, creates, &mut ids.posts).await?;
+        }
+
+        let creates = posts
+            .iter()
+            .filter(|post| !ids.posts.contains_key(&post.id))
+            .map(|post| {
+                let mut builder = CreatePost::builder();
+                builder
+                    .slug(post.slug.as_str())
+                    .status(post.status.as_str())
+                    .title(raw(&post.title))
+                    .content(raw(&post.content))
+                    .excerpt(raw(&post.excerpt))
+                    .comment_status(post.comment_status.as_str())
+                    .ping_status(post.ping_status.as_str())
+                    .sticky(post.sticky)
+                    .template(post.template.as_str())
+                    .categories(mapped(&ids.terms, &post.categories))
+                    .tags(mapped(&ids.terms, &post.tags));
+                if !post.format.is_empty() {
+                    builder.format(post.format.as_str());
+                }
+                if !post.date_gmt.is_empty() {
+                    builder.date_gmt(post.date_gmt.as_str());
+                }
+                if let Some(&author) = self.authors.get(&post.author) {
+                    builder.author(author);
+                }
+                if let Some(&media) = ids.posts.get(&post.featured_media) {
+                    builder.featured_media(media);
+                }
+                (post.id, builder.build().expect("all fields have defaults"))
+            })
+            .collect();
+        create(target, creates, &mut ids.posts).await
+    }
+}
+
+/// Send create requests in batches, mapping the ID of each item to the ID of
+/// the created item.
+///
+/// Requests which fail do not stop the rest of their batch, but no further
+/// batches are sent after it.
+async fn create<C, E>(
+    client: &C,
+    creates: Vec<(u32, E)>,
+    ids: &mut BTreeMap<u32, u32>,
+) -> Result<(), ApiError<C::Error>>
+where
+    C: Client + Sync,
+    E: Endpoint + Send + Sync + 'static,
+{
+    let mut creates = creates.into_iter().peekable();
+    while creates.peek().is_some() {
+        let mut batch = Batch::new();
+        let mut sources = Vec::with_capacity(MAX_REQUESTS);
+        for (id, endpoint) in creates.by_ref().take(MAX_REQUESTS) {
+            batch.push(endpoint);
+            sources.push(id);
+        }
+        let results = batch.query(client).await?;
+        let mut error = None;
+        for (source, result) in sources.into_iter().zip(results) {
+            let created = result.and_then(|created| {
+                created
+                    .body
+                    .get("id")
+                    .and_then(Value::as_u64)
+                    .and_then(|id| u32::try_from(id).ok())
+                    .ok_or_else(|| ApiError::missing_pointer("/id"))
+            });
+            match created {
+                Ok(id) => {
+                    ids.insert(source, id);
+                }
+                Err(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+        }
+        if let Some(err) = error {
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::{Method, Request, Response};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        client::BodyStream,
+        test::{MockClient, MockClientError, MockResponse},
+    };
+
+    /// Answers batches by creating every item, except those with a failing
+    /// slug, recording the requests.
+    struct Site {
+        client: MockClient,
+        fail: Option<&'static str>,
+        next_id: Mutex<u32>,
+        created: Mutex<Vec<(String, Value)>>,
+    }
+
+    impl Site {
+        fn new(responses: impl IntoIterator<Item = MockResponse>) -> Self {
+            Self {
+                client: MockClient::with_responses(responses),
+                fail: None,
+                next_id: Mutex::new(101),
+                created: Mutex::default(),
+            }
+        }
+
+        fn created(&self, path: &str) -> Vec<Value> {
+            let created = self.created.lock().unwrap();
+            created
+                .iter()
+                .filter(|(p, _)| p == path)
+                .map(|(_, body)| body.clone())
+                .collect()
+        }
+
+        fn batch(&self, body: &[u8]) -> Value {
+            let body: Value = serde_json::from_slice(body).unwrap();
+            let mut responses = Vec::new();
+            for request in body["requests"].as_array().unwrap() {
+                let body = request["body"].clone();
+                if self.fail.is_some() && body["slug"] == self.fail.unwrap() {
+                    responses.push(json!({
+                        "status": 400,
+                        "headers": {},
+                        "body": { "code": "rest_invalid_param", "message": "Invalid", "data": null },
+                    }));
+                    continue;
+                }
+                let mut next_id = self.next_id.lock().unwrap();
+                responses.push(json!({ "status": 201, "headers": {}, "body": { "id": *next_id } }));
+                *next_id += 1;
+                let path = request["path"].as_str().unwrap().to_owned();
+                self.created.lock().unwrap().push((path, body));
+            }
+            json!({ "responses": responses })
+        }
+    }
+
+    #[async_trait]
+    impl Client for Site {
+        type Error = MockClientError;
+
+        async fn route_url(&self, route: &str) -> Result<Url, ApiError<Self::Error>> {
+            self.client.route_url(route).await
+        }
+
+        async fn send_request(
+            &self,
+            request: Request<Vec<u8>>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            if request.uri().path() == "/batch/v1" {
+                let body = serde_json::to_vec(&self.batch(request.body())).unwrap();
+                return Ok(Response::new(body.into()));
+            }
+            self.client.send_request(request).await
+        }
+
+        async fn send_external_request(
+            &self,
+            request: Request<Vec<u8>>,
+        ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+            self.client.send_external_request(request).await
+        }
+    }
+
+    fn list(route: &str, items: Value) -> MockResponse {
+        MockResponse::builder()
+            .route(route)
+            .query("context=edit&page=1&per_page=100")
+            .header("x-wp-totalpages", "1")
+            .json(items)
+            .build()
+            .unwrap()
+    }
+
+    fn source() -> Site {
+        Site::new([
+            list(
+                "/wp/v2/categories",
+                json!([
+                    { "id": 4, "name": "Local", "slug": "local", "parent": 3 },
+                    { "id": 3, "name": "News", "slug": "news", "parent": 0 },
+                    { "id": 5, "name": "Events", "slug": "events", "parent": 0 },
+                ]),
+            ),
+            list(
+                "/wp/v2/tags",
+                json!([{ "id": 7, "name": "Rust", "slug": "rust" }]),
+            ),
+            list(
+                "/wp/v2/pages",
+                json!([
+                    {
+                        "id": 12,
+                        "slug": "team",
+                        "status": "publish",
+                        "title": { "raw": "Team", "rendered": "Team" },
+                        "parent": 11,
+                        "featured_media": 20,
+                        "author": 1,
+                    },
+                    {
+                        "id": 11,
+                        "slug": "about",
+                        "status": "publish",
+                        "title": { "raw": "About", "rendered": "About" },
+                    },
+                ]),
+            ),
+            list(
+                "/wp/v2/posts",
+                json!([{
+                    "id": 10,
+                    "date": "2024-01-02T10:00:00",
+                    "date_gmt": "2024-01-02T09:00:00",
+                    "slug": "hello",
+                    "status": "publish",
+                    "title": { "raw": "Hello", "rendered": "Hello" },
+                    "content": { "raw": "<p>Hi</p>", "rendered": "<p>Hi</p>\n" },
+                    "author": 1,
+                    "featured_media": 20,
+                    "categories": [4, 9],
+                    "tags": [7],
+                }]),
+            ),
+            MockResponse::builder()
+                .route("/wp/v2/media/20")
+                .query("context=edit")
+                .json(json!({
+                    "id": 20,
+                    "source_url": "https://old.example/wp-content/uploads/photo.png",
+                    "alt_text": "Waves",
+                    "caption": { "raw": "", "rendered": "" },
+                    "mime_type": "image/png",
+                }))
+                .build()
+                .unwrap(),
+            MockResponse::builder()
+                .route("/wp-content/uploads/photo.png")
+                .header("content-type", "image/png")
+                .body("png")
+                .build()
+                .unwrap(),
+        ])
+    }
+
+    fn target() -> Site {
+        Site::new([MockResponse::builder()
+            .method(Method::POST)
+            .route("/wp/v2/media")
+            .json(json!({ "id": 51 }))
+            .build()
+            .unwrap()])
+    }
+
+    #[tokio::test]
+    async fn migrate() {
+        let (source, target) = (source(), target());
+        let mut ids = IdMap::default();
+
+        Migration::builder()
+            .author(1, 2)
+            .build()
+            .unwrap()
+            .run(&source, &target, &mut ids)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ids.terms,
+            BTreeMap::from([(3, 101), (4, 103), (5, 102), (7, 104)])
+        );
+        assert_eq!(
+            ids.posts,
+            BTreeMap::from([(10, 107), (11, 105), (12, 106), (20, 51)])
+        );
+        assert_eq!(
+            target.created("/wp/v2/categories")[2],
+            json!({ "name": "Local", "slug": "local", "description": "", "parent": 101 })
+        );
+        let pages = target.created("/wp/v2/pages");
+        assert_eq!(pages[1]["parent"], 105);
+        assert_eq!(pages[1]["featured_media"], 51);
+        assert_eq!(pages[1]["author"], 2);
+        assert_eq!(pages[0].get("author"), None);
+        assert_eq!(
+            target.created("/wp/v2/posts"),
+            vec![json!({
+                "date_gmt": "2024-01-02T09:00:00",
+                "slug": "hello",
+                "status": "publish",
+                "title": "Hello",
+                "content": "<p>Hi</p>",
+                "excerpt": "",
+                "author": 2,
+                "featured_media": 51,
+                "comment_status": "",
+                "ping_status": "",
+                "sticky": false,
+                "template": "",
+                "categories": [103],
+                "tags": [104],
+            })]
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_request() {
+        let source = source();
+        let target = Site {
+            fail: Some("news"),
+            ..target()
+        };
+        let mut ids = IdMap::default();
+        // The tag was created by an earlier run.
+        ids.terms.insert(7, 77);
+
+        let err = Migration::builder()
+            .upload_media(false)
+            .build()
+            .unwrap()
+            .run(&source, &target, &mut ids)
+            .await
+            .unwrap_err();
+
+        // The rest of the batch is created, but the migration stops there.
+        assert!(matches!(err, ApiError::WordPress { .. }));
+        assert_eq!(ids.terms, BTreeMap::from([(5, 101), (7, 77)]));
+        assert_eq!(target.created("/wp/v2/categories").len(), 1);
+        assert!(target.created("/wp/v2/pages").is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_batch() {
+        let categories: Vec<Value> = (1..=MAX_REQUESTS as u32 + 5)
+            .map(|id| json!({ "id": id, "name": "Term", "slug": format!("term-{}", id) }))
+            .collect();
+        let source = Site::new([list("/wp/v2/categories", Value::from(categories))]);
+        let target = Site {
+            fail: Some("term-1"),
+            ..target()
+        };
+        let mut ids = IdMap::default();
+
+        let err = Migration::builder()
+            .build()
+            .unwrap()
+            .run(&source, &target, &mut ids)
+            .await
+            .unwrap_err();
+
+        // The second batch of the generation is never sent.
+        assert!(matches!(err, ApiError::WordPress { .. }));
+        assert_eq!(ids.terms.len(), MAX_REQUESTS - 1);
+        assert_eq!(target.created("/wp/v2/categories").len(), MAX_REQUESTS - 1);
+    }
+
+    #[tokio::test]
+    async fn media_too_large() {
+        let (source, target) = (source(), target());
+        let mut ids = IdMap::default();
+
+        let err = Migration::builder()
+            .max_media_size(2)
+            .build()
+            .unwrap()
+            .run(&source, &target, &mut ids)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::MediaTooLarge { limit: 2, .. }));
+        assert!(target.created("/wp/v2/pages").is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{endpoint::ContentType, random::random_hex};
+
+/// A `multipart/form-data` request body.
+pub struct Form {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl Form {
+    /// An empty form with a random boundary.
+    pub fn new() -> Self {
+        Self::with_boundary(random_hex())
+    }
+
+    /// An empty form with a fixed boundary, which must not occur in the
+    /// fields.
+    pub fn with_boundary(boundary: impl Into<String>) -> Self {
+        Self {
+            boundary: boundary.into(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Add a text field.
+    pub fn text(&mut self, name: &str, value: &str) -> &mut Self {
+        self.part_header(name, None, None);
+        self.body.extend_from_slice(value.as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// Add a file field.
+    pub fn file(
+        &mut self,
+        name: &str,
+        filename: &str,
+        content_type: &str,
+        data: &[u8],
+    ) -> &mut Self {
+        self.part_header(name, Some(filename), Some(content_type));
+        self.body.extend_from_slice(data);
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// The content type for the form.
+    pub fn content_type(&self) -> ContentType {
+        ContentType::Multipart {
+            boundary: self.boundary.clone(),
+        }
+    }
+
+    /// Finish the form and return the encoded body.
+    pub fn finish(mut self) -> Vec<u8> {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.body
+    }
+
+    fn part_header(&mut self, name: &str, filename: Option<&str>, content_type: Option<&str>) {
+        let mut header = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"",
+            self.boundary,
+            escape(name)
+        );
+        if let Some(filename) = filename {
+            header.push_str(&format!("; filename=\"{}\"", escape(filename)));
+        }
+        header.push_str("\r\n");
+        if let Some(content_type) = content_type {
+            header.push_str(&format!("Content-Type: {}\r\n", content_type));
+        }
+        header.push_str("\r\n");
+        self.body.extend_from_slice(header.as_bytes());
+    }
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn encode() {
+        let mut form = Form::with_boundary("boundary");
+        form.text("title", "Hello")
+            .file("file", "a\"b.txt", "text/plain", b"data");
+
+        assert_eq!(
+            form.content_type(),
+            "multipart/form-data; boundary=boundary"
+        );
+        assert_eq!(
+            String::from_utf8(form.finish()).unwrap(),
+            "--boundary\r\n\
+             Content-Disposition: form-data; name=\"title\"\r\n\
+             \r\n\
+             Hello\r\n\
+             --boundary\r\n\
+             Content-Disposition: form-data; name=\"file\"; filename=\"a\\\"b.txt\"\r\n\
+             Content-Type: text/plain\r\n\
+             \r\n\
+             data\r\n\
+             --boundary--\r\n"
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{Request, Response};
+use once_cell::sync::OnceCell;
+use url::Url;
+
+use crate::{
+    client::{BodyStream, Client, RequestBodyStream},
+    error::ApiError,
+    root::RootRoute,
+};
+
+/// A client for a subsite of a multisite network.
+///
+/// Requests are sent through the client of the main site, so the subsite
+/// shares its HTTP client and credentials. The root route of the subsite is
+/// derived from the root route of the main site without another discovery
+/// request, which assumes the whole network uses the same permalink
+/// structure. Use [`Subsite::with_root_route`] when it does not.
+///
+/// WordPress does not list the sites of a network over the REST API, but
+/// each subsite describes itself through
+/// [`RetrieveIndex`](crate::endpoints::RetrieveIndex).
+pub struct Subsite<'a, C> {
+    client: &'a C,
+    site_url: Url,
+    root_route: OnceCell<RootRoute>,
+}
+
+impl<'a, C> Subsite<'a, C> {
+    /// Send requests for the site at the given URL through a client.
+    pub fn new(client: &'a C, site_url: Url) -> Self {
+        Self {
+            client,
+            site_url: with_trailing_slash(site_url),
+            root_route: OnceCell::new(),
+        }
+    }
+
+    /// Use a known root route for the subsite.
+    pub fn with_root_route(self, root_route: RootRoute) -> Self {
+        Self {
+            root_route: OnceCell::with_value(root_route),
+            ..self
+        }
+    }
+
+    /// The URL of the subsite.
+    pub fn site_url(&self) -> &Url {
+        &self.site_url
+    }
+}
+
+impl<'a, C> Subsite<'a, C>
+where
+    C: Client + Sync,
+{
+    /// A subsite of a subdirectory network, such as `blog` for
+    /// `https://example.com/blog/`.
+    ///
+    /// The path is relative to the main site of the client.
+    pub async fn by_path(client: &'a C, path: &str) -> Result<Self, ApiError<C::Error>> {
+        let main = main_root_route(client).await?;
+        let path = path.trim_matches('/');
+        let site_url = main.site_url().join(&format!("{}/", path))?;
+        Ok(Self::new(client, site_url))
+    }
+
+    /// A subsite of a subdomain network, or one with a mapped domain, such as
+    /// `blog.example.com`.
+    ///
+    /// The scheme of the main site of the client is used.
+    pub async fn by_domain(client: &'a C, domain: &str) -> Result<Self, ApiError<C::Error>> {
+        let main = main_root_route(client).await?;
+        let site_url = Url::parse(&format!("{}://{}/", main.site_url().scheme(), domain))?;
+        Ok(Self::new(client, site_url))
+    }
+
+    /// The root route of the subsite.
+    pub async fn root_route(&self) -> Result<&RootRoute, ApiError<C::Error>> {
+        if let Some(root_route) = self.root_route.get() {
+            return Ok(root_route);
+        }
+        let main = main_root_route(self.client).await?;
+        let root_route = subsite_root_route(&main, &self.site_url)?;
+        Ok(self.root_route.get_or_init(|| root_route))
+    }
+}
+
+#[async_trait]
+impl<C> Client for Subsite<'_, C>
+where
+    C: Client + Sync,
+{
+    type Error = C::Error;
+
+    async fn route_url(&self, route: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.root_route().await?.join(route))
+    }
+
+    async fn send_request(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.client.send_request(request).await
+    }
+
+    async fn send_request_streamed(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        self.client.send_request_streamed(request).await
+    }
+
+    async fn send_streaming_request(
+        &self,
+        request: Request<RequestBodyStream>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.client.send_streaming_request(request).await
+    }
+
+    async fn send_external_request(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        self.client.send_external_request(request).await
+    }
+}
+
+async fn main_root_route<C>(client: &C) -> Result<RootRoute, ApiError<C::Error>>
+where
+    C: Client + Sync,
+{
+    Ok(client.route_url("/").await?.into())
+}
+
+fn with_trailing_slash(mut url: Url) -> Url {
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+    url
+}
+
+/// Apply the permalink structure of the main site to a subsite.
+fn subsite_root_route(main: &RootRoute, site_url: &Url) -> Result<RootRoute, url::ParseError> {
+    match main {
+        RootRoute::Default(_) => {
+            let mut url = site_url.clone();
+            url.set_query(Some("rest_route=/"));
+            Ok(RootRoute::Default(url))
+        }
+        RootRoute::PrettyPermalinks(url) => {
+            let main_site = main.site_url();
+            let prefix = url
+                .path()
+                .strip_prefix(main_site.path())
+                .unwrap_or("wp-json/");
+            Ok(RootRoute::PrettyPermalinks(site_url.join(prefix)?))
+        }
+        #[cfg(feature = "wpcom")]
+        RootRoute::WordPressCom(_) => Ok(RootRoute::wordpress_com(
+            site_url.host_str().unwrap_or_default(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        endpoints::RetrievePost,
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    fn root(main: &str, site: &str) -> String {
+        let main: RootRoute = main.parse().unwrap();
+        subsite_root_route(&main, &Url::parse(site).unwrap())
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn pretty_permalinks() {
+        assert_eq!(
+            root("http://example.com/wp-json/", "http://example.com/blog/"),
+            "http://example.com/blog/wp-json/"
+        );
+        assert_eq!(
+            root("http://example.com/wp/wp-json/", "http://blog.example.com/"),
+            "http://blog.example.com/wp-json/"
+        );
+    }
+
+    #[test]
+    fn default_permalinks() {
+        assert_eq!(
+            root(
+                "http://example.com/?rest_route=/",
+                "http://example.com/blog/"
+            ),
+            "http://example.com/blog/?rest_route=/"
+        );
+    }
+
+    #[tokio::test]
+    async fn by_path() {
+        let response = MockResponse::builder()
+            .route("/blog/wp/v2/posts/1")
+            .json(json!({ "id": 1 }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let subsite = Subsite::by_path(&client, "/blog").await.unwrap();
+
+        let endpoint = RetrievePost::builder().id(1).build().unwrap();
+        let post: Value = endpoint.query(&subsite).await.unwrap();
+
+        assert_eq!(subsite.site_url().as_str(), "test://test/blog/");
+        assert_eq!(post["id"], 1);
+    }
+
+    #[tokio::test]
+    async fn by_domain() {
+        let client = MockClient::new();
+
+        let subsite = Subsite::by_domain(&client, "blog.example.com")
+            .await
+            .unwrap();
+
+        assert_eq!(subsite.site_url().as_str(), "test://blog.example.com/");
+    }
+
+    #[tokio::test]
+    async fn known_root_route() {
+        let client = MockClient::new();
+        let root_route: RootRoute = "http://example.com/blog/?rest_route=/".parse().unwrap();
+
+        let subsite = Subsite::new(&client, Url::parse("http://example.com/blog").unwrap())
+            .with_root_route(root_route.clone());
+
+        assert_eq!(subsite.site_url().as_str(), "http://example.com/blog/");
+        assert_eq!(subsite.root_route().await.unwrap(), &root_route);
+    }
+}
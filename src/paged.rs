@@ -0,0 +1,345 @@
+use async_trait::async_trait;
+use futures_util::{stream, StreamExt, TryStreamExt};
+use http::{HeaderMap, Method};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::{
+    client::Client,
+    endpoint::{ContentType, Endpoint},
+    query::Query,
+    request::RequestBuilder,
+    ApiError,
+};
+
+const TOTAL_PAGES: &str = "x-wp-totalpages";
+
+/// A marker trait for endpoints returning a paginated collection.
+///
+/// These endpoints accept the `page` and `per_page` parameters and report the
+/// number of pages in the `X-WP-TotalPages` header.
+pub trait Pageable: Endpoint {}
+
+/// A query which fetches every page of a collection.
+///
+/// See [`paged`].
+#[derive(Debug, Clone)]
+pub struct Paged<E> {
+    endpoint: E,
+    per_page: u32,
+    limit: Option<usize>,
+    concurrency: usize,
+}
+
+/// Collect the results of a paginated endpoint across all of its pages.
+///
+/// Any `page` or `per_page` parameters set on the endpoint are replaced. Pages
+/// are requested one after the other until the last page reported by
+/// WordPress, unless [`Paged::concurrency`] is set. Combining this with the
+/// `offset` parameter is not supported, as WordPress ignores `page` when an
+/// offset is given.
+pub fn paged<E>(endpoint: E) -> Paged<E>
+where
+    E: Pageable,
+{
+    Paged {
+        endpoint,
+        per_page: 100,
+        limit: None,
+        concurrency: 1,
+    }
+}
+
+impl<E> Paged<E> {
+    /// The number of results requested per page. Defaults to 100, the maximum
+    /// allowed by WordPress.
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = per_page;
+        self
+    }
+
+    /// Stop once this many results have been collected.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Fetch up to this many pages at once.
+    ///
+    /// The first page is fetched on its own to find the number of pages, and
+    /// the rest are then fetched concurrently. Results are still returned in
+    /// order. Defaults to 1, which fetches the pages one after the other.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+}
+
+impl<E> Paged<E>
+where
+    E: Pageable,
+{
+    /// The requests for the pages of the collection.
+    async fn pages<C>(&self, client: &C) -> Result<Pages, ApiError<C::Error>>
+    where
+        C: Client + Sync,
+    {
+        let mut url = client.route_url(&self.endpoint.route()).await?;
+        self.endpoint.parameters().add_to_url(&mut url);
+        let pairs: Vec<_> = url
+            .query_pairs()
+            .filter(|(key, _)| key != "page" && key != "per_page")
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        url.set_query(None);
+        if !pairs.is_empty() {
+            url.query_pairs_mut().extend_pairs(pairs);
+        }
+        Ok(Pages {
+            method: self.endpoint.method(),
+            url,
+            per_page: self.per_page,
+            body: self.endpoint.body()?,
+        })
+    }
+}
+
+/// Builds the request for each page of a collection.
+struct Pages {
+    method: Method,
+    url: Url,
+    per_page: u32,
+    body: Option<(ContentType, Vec<u8>)>,
+}
+
+impl Pages {
+    async fn fetch<T, C>(
+        &self,
+        client: &C,
+        page: u32,
+    ) -> Result<(HeaderMap, Vec<T>), ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+        C: Client + Sync,
+    {
+        let mut url = self.url.clone();
+        url.query_pairs_mut()
+            .append_pair("page", &page.to_string())
+            .append_pair("per_page", &self.per_page.to_string());
+        let mut builder = RequestBuilder::new();
+        builder.method(self.method.clone()).url(url);
+        if let Some((content_type, body)) = &self.body {
+            let content_type = content_type.header_value().map_err(ApiError::request)?;
+            builder.content_type(content_type).body(body.clone());
+        }
+        builder.send_streamed(client).await
+    }
+}
+
+fn total_pages(headers: &HeaderMap) -> Option<u32> {
+    headers
+        .get(TOTAL_PAGES)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+#[async_trait]
+impl<E, T, C> Query<Vec<T>, C> for Paged<E>
+where
+    E: Pageable + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: Client + Sync,
+{
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(method = %self.endpoint.method(), route = %self.endpoint.route()),
+        )
+    )]
+    async fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        let pages = self.pages(client).await?;
+        let full = |results: &mut Vec<T>| match self.limit {
+            Some(limit) if results.len() >= limit => {
+                results.truncate(limit);
+                true
+            }
+            _ => false,
+        };
+
+        let (headers, mut results) = pages.fetch(client, 1).await?;
+        let mut total = total_pages(&headers).unwrap_or(1);
+        if full(&mut results) || results.is_empty() {
+            return Ok(results);
+        }
+
+        if self.concurrency > 1 {
+            if let Some(limit) = self.limit {
+                let needed = (limit as u64).div_ceil(u64::from(self.per_page.max(1)));
+                total = total.min(needed.try_into().unwrap_or(u32::MAX));
+            }
+            let rest: Vec<(_, Vec<T>)> = stream::iter(2..=total)
+                .map(|page| pages.fetch(client, page))
+                .buffered(self.concurrency)
+                .try_collect()
+                .await?;
+            results.extend(rest.into_iter().flat_map(|(_, items)| items));
+            full(&mut results);
+            return Ok(results);
+        }
+
+        let mut page = 1;
+        while page < total {
+            page += 1;
+            let (headers, items) = pages.fetch(client, page).await?;
+            let empty = items.is_empty();
+            results.extend(items);
+            if full(&mut results) || empty {
+                break;
+            }
+            total = total_pages(&headers).unwrap_or(page);
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        endpoint::prelude::*,
+        test::{MockClient, MockResponse},
+    };
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn route(&self) -> Cow<'static, str> {
+            "/mock".into()
+        }
+
+        fn parameters(&self) -> QueryParams<'_> {
+            let mut params = QueryParams::default();
+            params.push("page", 5_u32).push("search", "a b");
+            params
+        }
+    }
+
+    impl Pageable for Dummy {}
+
+    fn page(page: u32, items: Value) -> MockResponse {
+        MockResponse::builder()
+            .query(format!("search=a+b&page={}&per_page=2", page))
+            .header("x-wp-totalpages", "2")
+            .json(items)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn all_pages() {
+        let client = MockClient::with_responses([
+            page(1, json!([{ "id": 1 }, { "id": 2 }])),
+            page(2, json!([{ "id": 3 }])),
+        ]);
+
+        let results: Vec<Value> = paged(Dummy).per_page(2).query(&client).await.unwrap();
+
+        assert_eq!(
+            results,
+            vec![json!({ "id": 1 }), json!({ "id": 2 }), json!({ "id": 3 })]
+        );
+    }
+
+    #[tokio::test]
+    async fn limit() {
+        let client = MockClient::with_responses([page(1, json!([{ "id": 1 }, { "id": 2 }]))]);
+
+        let results: Vec<Value> = paged(Dummy)
+            .per_page(2)
+            .limit(1)
+            .query(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(results, vec![json!({ "id": 1 })]);
+    }
+
+    fn page_of(page: u32, total: u32, items: Value) -> MockResponse {
+        MockResponse::builder()
+            .query(format!("search=a+b&page={}&per_page=2", page))
+            .header("x-wp-totalpages", &total.to_string())
+            .json(items)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn concurrency() {
+        let client = MockClient::with_responses([
+            page_of(1, 3, json!([{ "id": 1 }, { "id": 2 }])),
+            page_of(2, 3, json!([{ "id": 3 }, { "id": 4 }])),
+            page_of(3, 3, json!([{ "id": 5 }])),
+        ]);
+
+        let results: Vec<Value> = paged(Dummy)
+            .per_page(2)
+            .concurrency(4)
+            .query(&client)
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = results.iter().map(|result| result["id"].clone()).collect();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+    }
+
+    /// Only the pages needed for the limit are fetched.
+    #[tokio::test]
+    async fn concurrency_limit() {
+        let client = MockClient::with_responses([
+            page_of(1, 3, json!([{ "id": 1 }, { "id": 2 }])),
+            page_of(2, 3, json!([{ "id": 3 }, { "id": 4 }])),
+        ]);
+
+        let results: Vec<Value> = paged(Dummy)
+            .per_page(2)
+            .limit(3)
+            .concurrency(4)
+            .query(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[2], json!({ "id": 3 }));
+    }
+
+    #[tokio::test]
+    async fn error() {
+        let client = MockClient::with_responses([
+            page(1, json!([{ "id": 1 }, { "id": 2 }])),
+            MockResponse::builder()
+                .query("search=a+b&page=2&per_page=2")
+                .status(StatusCode::BAD_REQUEST)
+                .json(json!({
+                    "code": "rest_post_invalid_page_number",
+                    "message": "The page number requested is larger than the number of pages available.",
+                    "data": { "status": 400 }
+                }))
+                .build()
+                .unwrap(),
+        ]);
+
+        let result: Result<Vec<Value>, _> = paged(Dummy).per_page(2).query(&client).await;
+
+        assert!(matches!(result, Err(ApiError::WordPress { .. })));
+    }
+}
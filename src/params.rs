@@ -0,0 +1,343 @@
+use std::{
+    borrow::Cow,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use url::Url;
+
+/// A trait representing a parameter value.
+pub trait ParamValue<'a> {
+    /// The parameter value as a string.
+    fn as_value(&self) -> Cow<'a, str>;
+}
+
+impl ParamValue<'static> for bool {
+    fn as_value(&self) -> Cow<'static, str> {
+        if *self {
+            "true".into()
+        } else {
+            "false".into()
+        }
+    }
+}
+
+impl<'a> ParamValue<'a> for &'a str {
+    fn as_value(&self) -> Cow<'a, str> {
+        (*self).into()
+    }
+}
+
+impl ParamValue<'static> for String {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.clone().into()
+    }
+}
+
+impl<'a, T> ParamValue<'a> for &T
+where
+    T: ParamValue<'a>,
+{
+    fn as_value(&self) -> Cow<'a, str> {
+        (*self).as_value()
+    }
+}
+
+impl<'a> ParamValue<'a> for Cow<'a, str> {
+    fn as_value(&self) -> Cow<'a, str> {
+        self.clone()
+    }
+}
+
+macro_rules! impl_param_value_display {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ParamValue<'static> for $ty {
+                fn as_value(&self) -> Cow<'static, str> {
+                    self.to_string().into()
+                }
+            }
+        )*
+    };
+}
+
+impl_param_value_display!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A date and time used to filter results.
+///
+/// This can be created from an ISO 8601 string, a [`SystemTime`] and, with
+/// the `chrono` feature, a `chrono::DateTime`. Strings without a timezone are
+/// interpreted by WordPress in the site's timezone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timestamp(String);
+
+impl From<&str> for Timestamp {
+    fn from(value: &str) -> Self {
+        Timestamp(value.into())
+    }
+}
+
+impl From<String> for Timestamp {
+    fn from(value: String) -> Self {
+        Timestamp(value)
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(value: SystemTime) -> Self {
+        Timestamp(value.as_value().into_owned())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> From<chrono::DateTime<Tz>> for Timestamp
+where
+    Tz: chrono::TimeZone,
+    Tz::Offset: std::fmt::Display,
+{
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        Timestamp(value.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+}
+
+/// Dates without a timezone are interpreted in the site's timezone.
+#[cfg(feature = "chrono")]
+impl From<crate::types::WpDateTime> for Timestamp {
+    fn from(value: crate::types::WpDateTime) -> Self {
+        Timestamp(value.to_string())
+    }
+}
+
+impl ParamValue<'static> for Timestamp {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.0.clone().into()
+    }
+}
+
+/// Dates are given in UTC using the ISO 8601 format WordPress expects, e.g.
+/// `2022-03-14T15:09:26Z`.
+impl ParamValue<'static> for SystemTime {
+    fn as_value(&self) -> Cow<'static, str> {
+        let secs = match self.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs() as i64,
+            Err(err) => -(err.duration().as_secs_f64().ceil() as i64),
+        };
+        format!("{}Z", format_seconds(secs)).into()
+    }
+}
+
+/// Format seconds since the epoch as an ISO 8601 date without a timezone,
+/// e.g. `2022-03-14T15:09:26`.
+pub(crate) fn format_seconds(secs: i64) -> String {
+    let (days, secs) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // Convert days since the epoch to a civil date, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// Parse an ISO 8601 date without a timezone, as WordPress returns them, into
+/// seconds since the epoch, reversing [`format_seconds`].
+pub(crate) fn parse_seconds(date: &str) -> Option<i64> {
+    let (date, time) = date.split_once('T')?;
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    let mut time = time.splitn(3, ':').map(str::parse::<i64>);
+    let (hours, minutes, secs) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+
+    // Convert a civil date to days since the epoch, see
+    // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    Some(days * 86_400 + hours * 3600 + minutes * 60 + secs)
+}
+
+/// A structure for query parameters.
+#[derive(Debug, Default, Clone)]
+pub struct QueryParams<'a> {
+    params: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> QueryParams<'a> {
+    /// Push a single parameter.
+    pub fn push<'b, K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        self.params.push((key.into(), value.as_value()));
+        self
+    }
+
+    /// Push a single parameter if it is set.
+    pub fn push_opt<'b, K, V>(&mut self, key: K, value: Option<V>) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        if let Some(value) = value {
+            self.params.push((key.into(), value.as_value()));
+        }
+        self
+    }
+
+    /// Push a list of values as a single comma separated parameter.
+    ///
+    /// Nothing is pushed if the list is empty.
+    pub fn push_list<'b, K, I, V>(&mut self, key: K, values: I) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = V>,
+        V: ParamValue<'b>,
+    {
+        let values: Vec<_> = values.into_iter().map(|value| value.as_value()).collect();
+        if !values.is_empty() {
+            self.params.push((key.into(), values.join(",").into()));
+        }
+        self
+    }
+
+    /// Whether any parameters have been set.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Encode the parameters as a query string.
+    pub(crate) fn to_query_string(&self) -> Option<String> {
+        if self.params.is_empty() {
+            return None;
+        }
+        let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+        serializer.extend_pairs(self.params.iter());
+        Some(serializer.finish())
+    }
+
+    /// Add the parameters to a URL.
+    pub fn add_to_url(&self, url: &mut Url) {
+        if self.params.is_empty() {
+            return;
+        }
+        let mut pairs = url.query_pairs_mut();
+        pairs.extend_pairs(self.params.iter());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn add_to_url() {
+        let mut url = Url::parse("http://example.com/wp-json/wp/v2/posts/1").unwrap();
+        let mut params = QueryParams::default();
+        params
+            .push("force", true)
+            .push("id", 1_u32)
+            .push_opt("missing", None::<&str>);
+        params.add_to_url(&mut url);
+        assert_eq!(
+            url.as_str(),
+            "http://example.com/wp-json/wp/v2/posts/1?force=true&id=1"
+        );
+    }
+
+    #[test]
+    fn push_list() {
+        let mut url = Url::parse("http://example.com/wp-json/wp/v2/pages").unwrap();
+        let mut params = QueryParams::default();
+        params
+            .push_list("parent", [1_u32, 2, 3])
+            .push_list("slug", ["a", "b"])
+            .push_list("status", Vec::<&str>::new());
+        params.add_to_url(&mut url);
+        assert_eq!(
+            url.as_str(),
+            "http://example.com/wp-json/wp/v2/pages?parent=1%2C2%2C3&slug=a%2Cb"
+        );
+    }
+
+    #[test]
+    fn date() {
+        let date = UNIX_EPOCH + std::time::Duration::from_secs(1_647_270_566);
+        assert_eq!(date.as_value(), "2022-03-14T15:09:26Z");
+        assert_eq!(UNIX_EPOCH.as_value(), "1970-01-01T00:00:00Z");
+        let leap = UNIX_EPOCH + std::time::Duration::from_secs(951_782_400);
+        assert_eq!(leap.as_value(), "2000-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn parse_date() {
+        for date in [
+            "2022-03-14T15:09:26",
+            "1970-01-01T00:00:00",
+            "2000-02-29T00:00:00",
+        ] {
+            assert_eq!(format_seconds(parse_seconds(date).unwrap()), date);
+        }
+        assert_eq!(parse_seconds("2022-03-14T15:09:26"), Some(1_647_270_566));
+        assert_eq!(parse_seconds("2022-03-14"), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        use chrono::{FixedOffset, TimeZone, Utc};
+
+        let utc = Utc.with_ymd_and_hms(2022, 3, 14, 15, 9, 26).unwrap();
+        let offset = FixedOffset::east_opt(9 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2022, 3, 14, 15, 9, 26)
+            .unwrap();
+
+        assert_eq!(Timestamp::from(utc).as_value(), "2022-03-14T15:09:26Z");
+        assert_eq!(
+            Timestamp::from(offset).as_value(),
+            "2022-03-14T15:09:26+09:00"
+        );
+    }
+
+    #[test]
+    fn add_to_url_default_root_route() {
+        let mut url = Url::parse("http://example.com/?rest_route=%2Fwp%2Fv2%2Fposts%2F1").unwrap();
+        let mut params = QueryParams::default();
+        params.push("force", true);
+        params.add_to_url(&mut url);
+        assert_eq!(
+            url.as_str(),
+            "http://example.com/?rest_route=%2Fwp%2Fv2%2Fposts%2F1&force=true"
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let mut url = Url::parse("http://example.com/wp-json/").unwrap();
+        QueryParams::default().add_to_url(&mut url);
+        assert_eq!(url.as_str(), "http://example.com/wp-json/");
+    }
+}
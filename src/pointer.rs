@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    client::Client,
+    endpoint::{self, Endpoint},
+    query::Query,
+    ApiError,
+};
+
+/// A query reading part of a response.
+///
+/// See [`pointer`](fn@pointer).
+#[derive(Debug, Clone)]
+pub struct Pointer<E> {
+    endpoint: E,
+    pointer: String,
+}
+
+/// Read the value at a JSON pointer in the response of an endpoint, such as
+/// `/styles/color/background`.
+///
+/// Only the value at the pointer is deserialized, which avoids modelling
+/// deeply nested responses in full. Fails with
+/// [`ApiError::MissingPointer`] if the response has no such value.
+pub fn pointer<E, P>(endpoint: E, pointer: P) -> Pointer<E>
+where
+    E: Endpoint,
+    P: Into<String>,
+{
+    Pointer {
+        endpoint,
+        pointer: pointer.into(),
+    }
+}
+
+#[async_trait]
+impl<E, T, C> Query<T, C> for Pointer<E>
+where
+    E: Endpoint + Sync,
+    T: DeserializeOwned + 'static,
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let (_, mut json) = endpoint::request(&self.endpoint, client)
+            .await?
+            .send(client)
+            .await?;
+        let value = json
+            .pointer_mut(&self.pointer)
+            .map(Value::take)
+            .ok_or_else(|| ApiError::missing_pointer(&*self.pointer))?;
+        serde_json::from_value(value).map_err(ApiError::data_type::<T>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        endpoints::RawEndpoint,
+        test::{MockClient, MockResponse},
+    };
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Color {
+        background: String,
+        text: String,
+    }
+
+    fn client() -> MockClient {
+        let response = MockResponse::builder()
+            .json(json!({
+                "id": 1,
+                "styles": {
+                    "color": { "background": "#fff", "text": "#000" },
+                },
+            }))
+            .build()
+            .unwrap();
+        MockClient::with_response(response)
+    }
+
+    fn endpoint() -> RawEndpoint {
+        RawEndpoint::builder().route("/mock").build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn nested() {
+        let client = client();
+
+        let color: Color = pointer(endpoint(), "/styles/color")
+            .query(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            color,
+            Color {
+                background: "#fff".into(),
+                text: "#000".into(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn missing() {
+        let client = client();
+
+        let err = Query::<Value, _>::query(&pointer(endpoint(), "/styles/typography"), &client)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(err, ApiError::MissingPointer { pointer } if pointer == "/styles/typography")
+        );
+    }
+}
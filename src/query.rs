@@ -1,6 +1,11 @@
 use async_trait::async_trait;
+use futures_util::{StreamExt, TryStreamExt};
 
-use crate::{client::Client, error::ApiError};
+use crate::{
+    auth::{Auth, Authenticated},
+    client::Client,
+    error::ApiError,
+};
 
 /// A trait which represents an asynchronous query.
 #[async_trait]
@@ -11,3 +16,131 @@ where
     /// Perform the query using the passed client.
     async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>>;
 }
+
+/// The number of queries [`query_all`] performs at once.
+const CONCURRENCY: usize = 6;
+
+/// Perform several queries concurrently, returning their results in order.
+///
+/// At most 6 queries are in flight at once; use [`query_all_buffered`] to
+/// choose another limit. Queries of different types can be mixed by passing
+/// them as `&dyn Query<T, C>`. The first error is returned and the remaining
+/// queries are dropped.
+pub async fn query_all<'q, T, C, Q, I>(client: &C, queries: I) -> Result<Vec<T>, ApiError<C::Error>>
+where
+    C: Client + Sync,
+    Q: Query<T, C> + Sync + ?Sized + 'q,
+    I: IntoIterator<Item = &'q Q>,
+{
+    query_all_buffered(client, queries, CONCURRENCY).await
+}
+
+/// Perform several queries with at most `limit` of them in flight at once.
+///
+/// See [`query_all`].
+pub async fn query_all_buffered<'q, T, C, Q, I>(
+    client: &C,
+    queries: I,
+    limit: usize,
+) -> Result<Vec<T>, ApiError<C::Error>>
+where
+    C: Client + Sync,
+    Q: Query<T, C> + Sync + ?Sized + 'q,
+    I: IntoIterator<Item = &'q Q>,
+{
+    futures_util::stream::iter(queries)
+        .map(|query| query.query(client))
+        .buffered(limit.max(1))
+        .try_collect()
+        .await
+}
+
+/// A trait for performing a query using credentials other than the client's
+/// defaults.
+#[async_trait]
+pub trait QueryAs<T, C>
+where
+    C: Client,
+{
+    /// Perform the query using the passed client and credentials.
+    async fn query_as(&self, client: &C, auth: &Auth) -> Result<T, ApiError<C::Error>>;
+}
+
+#[async_trait]
+impl<Q, T, C> QueryAs<T, C> for Q
+where
+    Q: for<'a> Query<T, Authenticated<'a, C>> + Sync,
+    C: Client + Sync,
+{
+    async fn query_as(&self, client: &C, auth: &Auth) -> Result<T, ApiError<C::Error>> {
+        self.query(&Authenticated::new(client, auth)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        endpoints::{ListPosts, RetrievePost},
+        test::{MockClient, MockResponse},
+    };
+
+    fn client() -> MockClient {
+        let post = MockResponse::builder()
+            .route("/wp/v2/posts/1")
+            .json(json!({ "id": 1 }))
+            .build()
+            .unwrap();
+        let posts = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .json(json!([{ "id": 1 }, { "id": 2 }]))
+            .build()
+            .unwrap();
+        let missing = MockResponse::builder()
+            .route("/wp/v2/posts/2")
+            .status(http::StatusCode::NOT_FOUND)
+            .json(json!({
+                "code": "rest_post_invalid_id",
+                "message": "Invalid post ID.",
+                "data": { "status": 404 },
+            }))
+            .build()
+            .unwrap();
+        MockClient::with_responses([post, posts, missing])
+    }
+
+    #[tokio::test]
+    async fn all() {
+        let client = client();
+        let post = RetrievePost::builder().id(1).build().unwrap();
+        let posts = ListPosts::builder().build().unwrap();
+        let queries: [&(dyn Query<Value, MockClient> + Sync); 3] = [&posts, &post, &posts];
+
+        let results = query_all_buffered(&client, queries, 2).await.unwrap();
+
+        assert_eq!(
+            results,
+            [
+                json!([{ "id": 1 }, { "id": 2 }]),
+                json!({ "id": 1 }),
+                json!([{ "id": 1 }, { "id": 2 }]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn error() {
+        let client = client();
+        let post = RetrievePost::builder().id(1).build().unwrap();
+        let missing = RetrievePost::builder().id(2).build().unwrap();
+
+        let result: Result<Vec<Value>, _> = query_all(&client, [&post, &missing]).await;
+
+        assert!(
+            matches!(result, Err(ApiError::WordPress { code, .. }) if code == "rest_post_invalid_id")
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+/// Generate a random string of 32 hexadecimal characters.
+///
+/// This is not suitable for cryptographic secrets, only for values which need
+/// to be unpredictable and unique such as boundaries and nonces.
+pub fn random_hex() -> String {
+    let state = RandomState::new();
+    let mut first = state.build_hasher();
+    first.write_u8(0);
+    let mut second = state.build_hasher();
+    second.write_u8(1);
+    format!("{:016x}{:016x}", first.finish(), second.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique() {
+        assert_ne!(random_hex(), random_hex());
+        assert_eq!(random_hex().len(), 32);
+    }
+}
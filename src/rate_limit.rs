@@ -0,0 +1,133 @@
+use std::{error::Error, time::Duration};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{Request, Response, StatusCode};
+
+use crate::{
+    error::ApiError,
+    middleware::{Middleware, Next},
+    request,
+};
+
+/// Middleware which waits and retries requests rejected with
+/// `429 Too Many Requests`.
+///
+/// The delay given by the `Retry-After` header is used, falling back to one
+/// second. Once the retries are exhausted, or if the server asks for a longer
+/// delay than allowed, the query fails with [`ApiError::RateLimited`].
+#[derive(Debug, Clone)]
+pub struct RateLimitRetry {
+    max_retries: usize,
+    max_delay: Duration,
+}
+
+impl RateLimitRetry {
+    /// Retry a request up to `max_retries` times.
+    pub fn new(max_retries: usize) -> Self {
+        Self {
+            max_retries,
+            max_delay: Duration::from_secs(60),
+        }
+    }
+
+    /// The longest delay to wait for before retrying. Defaults to 60 seconds.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+}
+
+#[async_trait]
+impl<E> Middleware<E> for RateLimitRetry
+where
+    E: Error + Send + Sync + 'static,
+{
+    async fn handle(
+        &self,
+        request: Request<Vec<u8>>,
+        next: Next<'_, E>,
+    ) -> Result<Response<Bytes>, ApiError<E>> {
+        let mut retries = 0;
+        loop {
+            let resp = next.run(request::copy(&request)).await?;
+            if resp.status() != StatusCode::TOO_MANY_REQUESTS || retries >= self.max_retries {
+                return Ok(resp);
+            }
+            let delay = request::retry_after(resp.headers()).unwrap_or(Duration::from_secs(1));
+            if delay > self.max_delay {
+                return Ok(resp);
+            }
+            retries += 1;
+            #[cfg(feature = "tracing")]
+            tracing::debug!(retry = retries, delay = ?delay, "rate limited, retrying");
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{middleware::BoxFuture, test::MockClientError};
+
+    fn response(status: StatusCode, retry_after: &str) -> Response<Bytes> {
+        Response::builder()
+            .status(status)
+            .header("retry-after", retry_after)
+            .body(Bytes::new())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn retry() {
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> =
+            vec![Arc::new(RateLimitRetry::new(3))];
+        let calls = AtomicUsize::new(0);
+        let endpoint = |_request| -> BoxFuture<'_, _> {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                if call < 2 {
+                    Ok(response(StatusCode::TOO_MANY_REQUESTS, "0"))
+                } else {
+                    Ok(response(StatusCode::OK, "0"))
+                }
+            })
+        };
+
+        let resp = Next::new(&middleware, &endpoint)
+            .run(Request::new(Vec::new()))
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn too_long() {
+        let middleware: Vec<Arc<dyn Middleware<MockClientError>>> = vec![Arc::new(
+            RateLimitRetry::new(3).max_delay(Duration::from_secs(5)),
+        )];
+        let calls = AtomicUsize::new(0);
+        let endpoint = |_request| -> BoxFuture<'_, _> {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async { Ok(response(StatusCode::TOO_MANY_REQUESTS, "3600")) })
+        };
+
+        let resp = Next::new(&middleware, &endpoint)
+            .run(Request::new(Vec::new()))
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::Response;
+
+use crate::{
+    client::Client,
+    endpoint::{self, Endpoint},
+    query::Query,
+    ApiError,
+};
+
+/// A query returning the response of an endpoint as is.
+///
+/// See [`raw`].
+#[derive(Debug, Clone)]
+pub struct Raw<E> {
+    endpoint: E,
+}
+
+/// Return the response of an endpoint without deserializing it.
+///
+/// The status and headers are returned along with the body, which is left
+/// untouched. The status is not checked, so error responses are returned as
+/// well. This is useful for non-JSON resources and for inspecting responses
+/// which fail to deserialize.
+pub fn raw<E>(endpoint: E) -> Raw<E>
+where
+    E: Endpoint,
+{
+    Raw { endpoint }
+}
+
+#[async_trait]
+impl<E, C> Query<Response<Bytes>, C> for Raw<E>
+where
+    E: Endpoint + Sync,
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<Response<Bytes>, ApiError<C::Error>> {
+        endpoint::request(&self.endpoint, client)
+            .await?
+            .send_raw(client)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        endpoints::RawEndpoint,
+        test::{MockClient, MockResponse},
+    };
+
+    #[tokio::test]
+    async fn untouched() {
+        let endpoint = RawEndpoint::builder().route("/mock").build().unwrap();
+        let response = MockResponse::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header("content-type", "text/html")
+            .body("<p>There has been a critical error on this website.</p>")
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let response = raw(endpoint).query(&client).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(response.headers()["content-type"], "text/html");
+        assert_eq!(
+            response.body().as_ref(),
+            b"<p>There has been a critical error on this website.</p>"
+        );
+    }
+}
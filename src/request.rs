@@ -1,16 +1,26 @@
-use std::error::Error;
+use std::{
+    error::Error,
+    time::{Duration, SystemTime},
+};
 
 use async_trait::async_trait;
-use http::{Method, Request};
-use serde::de::DeserializeOwned;
+use bytes::Bytes;
+use futures_util::TryStreamExt;
+use http::{
+    header::{self, HeaderName, HeaderValue},
+    HeaderMap, Method, Request, Response, StatusCode,
+};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde_json::Value;
 use url::Url;
 
-use crate::{client::Client, query::Query, ApiError};
+use crate::{client::Client, query::Query, stream::JsonArray, ApiError};
 
 #[derive(Default)]
 pub struct RequestBuilder {
     method: Option<Method>,
     url: Option<Url>,
+    headers: HeaderMap,
     body: Option<Vec<u8>>,
 }
 
@@ -29,7 +39,15 @@ impl RequestBuilder {
         self
     }
 
-    #[allow(dead_code)]
+    pub fn header(&mut self, key: HeaderName, value: HeaderValue) -> &mut Self {
+        self.headers.append(key, value);
+        self
+    }
+
+    pub fn content_type(&mut self, content_type: HeaderValue) -> &mut Self {
+        self.header(header::CONTENT_TYPE, content_type)
+    }
+
     pub fn body(&mut self, body: impl Into<Vec<u8>>) -> &mut Self {
         self.body = Some(body.into());
         self
@@ -46,6 +64,9 @@ impl RequestBuilder {
         if let Some(url) = self.url.clone() {
             builder = builder.uri(url.as_str());
         }
+        if let Some(headers) = builder.headers_mut() {
+            headers.extend(self.headers.clone());
+        }
         let request = if let Some(body) = self.body.clone() {
             builder.body(body)
         } else {
@@ -56,6 +77,74 @@ impl RequestBuilder {
     }
 }
 
+impl RequestBuilder {
+    /// Send the request, returning the response as is.
+    pub async fn send_raw<C>(&self, client: &C) -> Result<Response<Bytes>, ApiError<C::Error>>
+    where
+        C: Client + Sync,
+    {
+        let req = self.build()?;
+        client.send_request(req).await
+    }
+
+    /// Send the request, returning the response headers along with the JSON
+    /// body of a successful response.
+    pub async fn send<C>(&self, client: &C) -> Result<(HeaderMap, Value), ApiError<C::Error>>
+    where
+        C: Client + Sync,
+    {
+        self.send_as(client).await
+    }
+
+    /// Send the request, returning the response headers along with the body
+    /// of a successful response deserialized directly into `T`.
+    ///
+    /// The body is only parsed as untyped JSON for error responses.
+    pub async fn send_as<T, C>(&self, client: &C) -> Result<(HeaderMap, T), ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+        C: Client + Sync,
+    {
+        json_response(self.send_raw(client).await?)
+    }
+
+    /// Send the request, returning the response headers along with the items
+    /// of a successful response, which are deserialized as they are received.
+    pub async fn send_streamed<T, C>(
+        &self,
+        client: &C,
+    ) -> Result<(HeaderMap, Vec<T>), ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+        C: Client + Sync,
+    {
+        let resp = client.send_request_streamed(self.build()?).await?;
+
+        let status = resp.status();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(ApiError::rate_limited(retry_after(resp.headers())));
+        }
+
+        let (parts, mut body) = resp.into_parts();
+        if !status.is_success() {
+            let mut data = Vec::new();
+            while let Some(chunk) = body.try_next().await? {
+                data.extend_from_slice(&chunk);
+            }
+            return match serde_json::from_slice(&data) {
+                Ok(json) => Err(ApiError::from_json(json)),
+                Err(_) => Err(ApiError::server_error(status, &data.into())),
+            };
+        }
+
+        let mut items = JsonArray::new();
+        while let Some(chunk) = body.try_next().await? {
+            items.push(&chunk)?;
+        }
+        Ok((parts.headers, items.finish(status)?))
+    }
+}
+
 #[async_trait]
 impl<T, C> Query<T, C> for RequestBuilder
 where
@@ -63,30 +152,133 @@ where
     C: Client + Sync,
 {
     async fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
-        let req = self.build()?;
-        let resp = client.send_request(req).await?;
+        let (_, body) = self.send_as(client).await?;
+        Ok(body)
+    }
+}
 
-        let status = resp.status();
+/// The headers and the body of a successful JSON response, deserialized into
+/// `T`.
+pub(crate) fn json_response<T, E>(resp: Response<Bytes>) -> Result<(HeaderMap, T), ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: Error + Send + Sync,
+{
+    let status = resp.status();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(ApiError::rate_limited(retry_after(resp.headers())));
+    }
 
-        // we are assuming all endpoints return JSON for both success and error
-        // responses
-        let json = if let Ok(json) = serde_json::from_slice(resp.body()) {
-            json
-        } else {
-            return Err(ApiError::server_error(status, resp.body()));
+    // we are assuming all endpoints return JSON for both success and error
+    // responses
+    if !status.is_success() {
+        return match serde_json::from_slice(resp.body()) {
+            Ok(json) => Err(ApiError::from_json(json)),
+            Err(_) => Err(ApiError::server_error(status, resp.body())),
         };
+    }
 
-        if !status.is_success() {
-            return Err(ApiError::from_json(json));
+    match from_slice(resp.body()) {
+        Ok(body) => {
+            let (parts, _) = resp.into_parts();
+            Ok((parts.headers, body))
         }
+        Err(err) => Err(data_error::<T, _>(err, status, resp.body())),
+    }
+}
 
-        serde_json::from_value(json).map_err(ApiError::data_type::<T>)
+/// Deserialize a JSON body, with `simd-json` when the feature is enabled.
+///
+/// Bodies `simd-json` rejects are deserialized again with `serde_json`, so
+/// errors are the same with or without the feature.
+pub(crate) fn from_slice<T>(body: &[u8]) -> serde_json::Result<T>
+where
+    T: DeserializeOwned,
+{
+    #[cfg(feature = "simd-json")]
+    {
+        // simd-json parses in place.
+        let mut body = body.to_vec();
+        if let Ok(value) = simd_json::serde::from_slice(&mut body) {
+            return Ok(value);
+        }
     }
+    serde_json::from_slice(body)
+}
+
+/// The error for a successful response whose body could not be deserialized
+/// into `T`, depending on whether it is JSON at all.
+pub(crate) fn data_error<T, E>(
+    source: serde_json::Error,
+    status: StatusCode,
+    body: &Bytes,
+) -> ApiError<E>
+where
+    E: Error + Send + Sync,
+{
+    if serde_json::from_slice::<IgnoredAny>(body).is_ok() {
+        ApiError::data_type::<T>(source)
+    } else {
+        ApiError::server_error(status, body)
+    }
+}
+
+/// A body which is sent as a stream is read, along with the future reading
+/// it, which must run alongside the request.
+#[cfg(any(feature = "client", feature = "hyper"))]
+pub(crate) fn channel_body(
+    mut stream: crate::client::RequestBodyStream,
+) -> (
+    ::hyper::Body,
+    impl std::future::Future<Output = std::io::Result<()>>,
+) {
+    let (mut sender, body) = ::hyper::Body::channel();
+    let read = async move {
+        loop {
+            match stream.try_next().await {
+                Ok(Some(chunk)) => {
+                    // The body is dropped once the request fails.
+                    if sender.send_data(chunk).await.is_err() {
+                        return Ok(());
+                    }
+                }
+                Ok(None) => return Ok(()),
+                Err(err) => {
+                    sender.abort();
+                    return Err(err);
+                }
+            }
+        }
+    };
+    (body, read)
+}
+
+/// Copy a request so that it can be sent again.
+#[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+pub(crate) fn copy(request: &Request<Vec<u8>>) -> Request<Vec<u8>> {
+    let mut copy = Request::new(request.body().clone());
+    *copy.method_mut() = request.method().clone();
+    *copy.uri_mut() = request.uri().clone();
+    *copy.version_mut() = request.version();
+    *copy.headers_mut() = request.headers().clone();
+    copy
+}
+
+/// Parse the `Retry-After` header, given either in seconds or as a date.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
 }
 
 #[cfg(test)]
 mod tests {
-    use http::StatusCode;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
@@ -95,6 +287,15 @@ mod tests {
 
     type Json = serde_json::Value;
 
+    #[test]
+    fn deserialize() {
+        let items: Vec<Json> = from_slice(br#"[1, "two", {"three": [3]}]"#).unwrap();
+        assert_eq!(items, vec![json!(1), json!("two"), json!({ "three": [3] })]);
+
+        let err = from_slice::<Vec<u32>>(b"[1, 2").unwrap_err();
+        assert!(err.is_eof());
+    }
+
     #[tokio::test]
     async fn non_json_response() {
         let response = MockResponse::builder()
@@ -198,6 +399,61 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn data_type_response() {
+        let response = MockResponse::builder()
+            .json(json!({ "id": "not a number" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let result: Result<Vec<u32>, _> = RequestBuilder::new()
+            .url(client.route_url("/mock").await.unwrap())
+            .query(&client)
+            .await;
+
+        let err = result.expect_err("expected ApiError::DataType");
+        if let ApiError::DataType { typename, .. } = err {
+            assert_eq!(typename, "alloc::vec::Vec<u32>");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_limited() {
+        let response = MockResponse::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header("retry-after", "120")
+            .body("slow down")
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let result: Result<Json, _> = RequestBuilder::new()
+            .url(client.route_url("/mock").await.unwrap())
+            .query(&client)
+            .await;
+
+        let err = result.expect_err("expected ApiError::RateLimited");
+        if let ApiError::RateLimited { retry_after } = err {
+            assert_eq!(retry_after, Some(Duration::from_secs(120)));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn retry_after_date() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+    }
+
     #[tokio::test]
     async fn unrecognized_error_response() {
         let body_obj = json!({ "bob": "loblaw" });
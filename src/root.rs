@@ -1,11 +1,22 @@
-use url::Url;
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use url::{ParseError, Url};
 
 /// Name of query parameter that is used to specify the endpoint route when
 /// pretty permalinks is not enabled on a WordPress instance.
 const REST_ROUTE_QUERY_PARAM: &str = "rest_route";
 
+/// Host of the WordPress.com REST API.
+#[cfg(feature = "wpcom")]
+const WPCOM_API_HOST: &str = "public-api.wordpress.com";
+
 /// API root route.
-#[derive(Debug)]
+///
+/// A discovered root route can be saved as a string, or using `serde`, and
+/// passed to a new client to skip discovery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum RootRoute {
     /// The default root for WordPress instances without "pretty permalinks"
     /// enabled.
@@ -16,17 +27,69 @@ pub enum RootRoute {
 
     /// Pretty permalinks root
     PrettyPermalinks(Url),
+
+    /// The WordPress.com REST API for a site hosted on WordPress.com or
+    /// connected with Jetpack.
+    ///
+    /// The site is added to each route after its namespace, so `/wp/v2/posts`
+    /// is served from
+    /// `https://public-api.wordpress.com/wp/v2/sites/{site}/posts`.
+    #[cfg(feature = "wpcom")]
+    WordPressCom(Url),
 }
 
 impl RootRoute {
+    /// The root route of a site on WordPress.com, given by its domain or ID.
+    #[cfg(feature = "wpcom")]
+    pub fn wordpress_com(site: impl AsRef<str>) -> Self {
+        let mut url = Url::parse(&format!("https://{}/", WPCOM_API_HOST)).unwrap();
+        url.path_segments_mut().unwrap().pop_if_empty().extend([
+            "wp",
+            "v2",
+            "sites",
+            site.as_ref(),
+            "",
+        ]);
+        RootRoute::WordPressCom(url)
+    }
+
     pub fn as_str(&self) -> &str {
         let url = match self {
             RootRoute::Default(url) => url,
             RootRoute::PrettyPermalinks(url) => url,
+            #[cfg(feature = "wpcom")]
+            RootRoute::WordPressCom(url) => url,
         };
         url.as_str()
     }
 
+    /// The URL of the site serving the root route.
+    ///
+    /// With pretty permalinks this assumes the API is served from a single
+    /// path segment, `wp-json` by default. On WordPress.com the site must have
+    /// been given by its domain.
+    pub fn site_url(&self) -> Url {
+        match self {
+            RootRoute::Default(url) => {
+                let mut url = url.clone();
+                url.set_query(None);
+                url
+            }
+            RootRoute::PrettyPermalinks(url) => {
+                let mut url = url.clone();
+                if let Ok(mut segments) = url.path_segments_mut() {
+                    segments.pop_if_empty().pop().push("");
+                }
+                url
+            }
+            #[cfg(feature = "wpcom")]
+            RootRoute::WordPressCom(url) => {
+                let site = wpcom_site(url).unwrap_or_default();
+                Url::parse(&format!("https://{}/", site)).unwrap_or_else(|_| url.clone())
+            }
+        }
+    }
+
     /// Join an endpoint route onto the root route
     pub fn join(&self, route: &str) -> Url {
         match self {
@@ -39,7 +102,7 @@ impl RootRoute {
                     for (key, value) in prev_pairs {
                         if key == REST_ROUTE_QUERY_PARAM {
                             query_pairs.append_pair(REST_ROUTE_QUERY_PARAM, route);
-                        } else if value == "" {
+                        } else if value.is_empty() {
                             query_pairs.append_key_only(&key);
                         } else {
                             query_pairs.append_pair(&key, &value);
@@ -56,12 +119,55 @@ impl RootRoute {
                     .extend(route.trim_start_matches('/').split('/'));
                 url
             }
+            #[cfg(feature = "wpcom")]
+            RootRoute::WordPressCom(url) => {
+                let segments: Vec<_> = route
+                    .trim_start_matches('/')
+                    .split('/')
+                    .filter(|segment| !segment.is_empty())
+                    .collect();
+                if segments.len() < 2 {
+                    return url.clone();
+                }
+                let site = wpcom_site(url).unwrap_or_default();
+                let mut joined = url.clone();
+                joined
+                    .path_segments_mut()
+                    .unwrap()
+                    .clear()
+                    .extend(&segments[..2])
+                    .extend(["sites", &site])
+                    .extend(&segments[2..]);
+                joined
+            }
         }
     }
 }
 
+/// The site of a WordPress.com root route, such as `example.com` for
+/// `https://public-api.wordpress.com/wp/v2/sites/example.com/`.
+#[cfg(feature = "wpcom")]
+fn wpcom_site(url: &Url) -> Option<String> {
+    if url.host_str() != Some(WPCOM_API_HOST) {
+        return None;
+    }
+    let mut segments = url.path_segments()?.skip(2);
+    match (segments.next(), segments.next()) {
+        (Some("sites"), Some(site)) if !site.is_empty() => Some(
+            percent_encoding::percent_decode_str(site)
+                .decode_utf8_lossy()
+                .into_owned(),
+        ),
+        _ => None,
+    }
+}
+
 impl From<Url> for RootRoute {
     fn from(url: Url) -> Self {
+        #[cfg(feature = "wpcom")]
+        if let Some(site) = wpcom_site(&url) {
+            return RootRoute::wordpress_com(site);
+        }
         if url
             .query_pairs()
             .any(|(param, _)| param == REST_ROUTE_QUERY_PARAM)
@@ -73,6 +179,84 @@ impl From<Url> for RootRoute {
     }
 }
 
+impl FromStr for RootRoute {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Url::parse(s).map(Into::into)
+    }
+}
+
+impl fmt::Display for RootRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for RootRoute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RootRoute {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// The root route of a client, discovered on first use, which can be
+/// forgotten through a shared reference.
+#[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+#[derive(Default)]
+pub(crate) struct RootRouteCell {
+    root_route: std::sync::RwLock<Option<RootRoute>>,
+    /// Held while discovering, so that concurrent requests discover it once.
+    discovery: tokio::sync::Mutex<()>,
+}
+
+#[cfg(any(feature = "client", feature = "hyper", feature = "tower"))]
+impl RootRouteCell {
+    pub(crate) fn get(&self) -> Option<RootRoute> {
+        self.root_route
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    /// The root route, discovering it first if it is not known.
+    pub(crate) async fn get_or_try_init<F, Fut, E>(&self, discover: F) -> Result<RootRoute, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<RootRoute, E>>,
+    {
+        if let Some(root_route) = self.get() {
+            return Ok(root_route);
+        }
+        let _discovery = self.discovery.lock().await;
+        if let Some(root_route) = self.get() {
+            return Ok(root_route);
+        }
+        let root_route = discover().await?;
+        self.set(Some(root_route.clone()));
+        Ok(root_route)
+    }
+
+    pub(crate) fn set(&self, root_route: Option<RootRoute>) {
+        *self
+            .root_route
+            .write()
+            .unwrap_or_else(|err| err.into_inner()) = root_route;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -104,4 +288,63 @@ mod tests {
             "http://example.com/?rest_route=%2Fwp%2Fv2%2Fposts%2F1"
         )
     }
+
+    #[test]
+    fn roundtrip() {
+        let root: RootRoute = "http://example.com/?rest_route=/".parse().unwrap();
+        let json = serde_json::to_string(&root).unwrap();
+        assert_eq!(json, "\"http://example.com/?rest_route=/\"");
+
+        let restored: RootRoute = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, root);
+        assert!(matches!(restored, RootRoute::Default(_)));
+    }
+
+    #[test]
+    fn site() {
+        let site = |root: &str| root.parse::<RootRoute>().unwrap().site_url().to_string();
+
+        assert_eq!(site("http://example.com/wp-json/"), "http://example.com/");
+        assert_eq!(
+            site("http://example.com/blog/wp-json/"),
+            "http://example.com/blog/"
+        );
+        assert_eq!(
+            site("http://example.com/blog/?rest_route=/"),
+            "http://example.com/blog/"
+        );
+    }
+
+    #[cfg(feature = "wpcom")]
+    #[test]
+    fn join_wordpress_com() {
+        let root = RootRoute::wordpress_com("example.com");
+        assert_eq!(
+            root.as_str(),
+            "https://public-api.wordpress.com/wp/v2/sites/example.com/"
+        );
+        assert_eq!(
+            root.join("/wp/v2/posts/1").as_str(),
+            "https://public-api.wordpress.com/wp/v2/sites/example.com/posts/1"
+        );
+        assert_eq!(
+            root.join("/wpcom/v2/block-editor/has-seen-nux").as_str(),
+            "https://public-api.wordpress.com/wpcom/v2/sites/example.com/block-editor/has-seen-nux"
+        );
+        assert_eq!(root.join("/").as_str(), root.as_str());
+    }
+
+    #[cfg(feature = "wpcom")]
+    #[test]
+    fn roundtrip_wordpress_com() {
+        let root: RootRoute = "https://public-api.wordpress.com/wp/v2/sites/12345/"
+            .parse()
+            .unwrap();
+
+        assert_eq!(root, RootRoute::wordpress_com("12345"));
+        assert_eq!(
+            RootRoute::wordpress_com("example.com").site_url().as_str(),
+            "https://example.com/"
+        );
+    }
 }
@@ -0,0 +1,449 @@
+//! Parsing, expanding and stripping shortcodes.
+//!
+//! Shortcodes are the bracketed tags of classic content, such as
+//! `[gallery ids="1,2"]` or `[caption]<img src="a.png"> A photo[/caption]`,
+//! which WordPress replaces when rendering. They are found in raw content,
+//! and in rendered content when the plugin handling them is missing.
+//!
+//! [`parse`] splits content into text and shortcodes of any name, while
+//! [`Shortcodes`] only handles the registered names, as WordPress does:
+//!
+//! ```
+//! use wordprs::shortcodes::Shortcodes;
+//!
+//! let mut shortcodes = Shortcodes::new();
+//! shortcodes.register("button", |code, _| {
+//!     format!(
+//!         "<a href=\"{}\">{}</a>",
+//!         code.attr("url").unwrap_or("#"),
+//!         code.content()
+//!     )
+//! });
+//!
+//! let html = shortcodes.expand(r#"[button url="/buy"]Buy[/button] [[button]]"#);
+//! assert_eq!(html, r#"<a href="/buy">Buy</a> [button]"#);
+//! ```
+//!
+//! Shortcodes written with double brackets, such as `[[gallery]]`, are
+//! escaped and kept as text without the outer brackets.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+/// A part of the content: either text or a shortcode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Text(String),
+    Shortcode(Shortcode),
+}
+
+/// A shortcode, with its attributes and enclosed content.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Shortcode {
+    pub name: String,
+
+    /// The named attributes, such as `ids="1,2"`, with lowercase names.
+    pub attrs: BTreeMap<String, String>,
+
+    /// The attributes without a name, in order, such as `"a"` and `b` in
+    /// `[code "a" b]`.
+    pub positional: Vec<String>,
+
+    /// The content of an enclosing shortcode, between its opening and
+    /// closing tags. Shortcodes within it are not parsed.
+    pub enclosed: Option<String>,
+}
+
+impl Shortcode {
+    /// The value of a named attribute.
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.get(name).map(String::as_str)
+    }
+
+    /// The enclosed content, or an empty string for self-closing shortcodes.
+    pub fn content(&self) -> &str {
+        self.enclosed.as_deref().unwrap_or_default()
+    }
+}
+
+/// Displaying a shortcode writes it as it would be in content.
+impl fmt::Display for Shortcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}", self.name)?;
+        for (name, value) in &self.attrs {
+            write!(f, " {}=\"{}\"", name, value)?;
+        }
+        for value in &self.positional {
+            if value.contains(char::is_whitespace) || value.is_empty() {
+                write!(f, " \"{}\"", value)?;
+            } else {
+                write!(f, " {}", value)?;
+            }
+        }
+        match &self.enclosed {
+            Some(content) => write!(f, "]{}[/{}]", content, self.name),
+            None => f.write_str("]"),
+        }
+    }
+}
+
+/// Split content into text and shortcodes of any name.
+///
+/// Text between brackets, such as `[citation needed]`, is parsed as a
+/// shortcode too, so [`Shortcodes`] is more reliable when the names are
+/// known.
+pub fn parse(content: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+    scan(
+        content,
+        |_| true,
+        |piece| match piece {
+            Piece::Text(t) => text.push_str(t),
+            Piece::Shortcode(found) if found.escaped => text.push_str(found.unescaped),
+            Piece::Shortcode(found) => {
+                text.push_str(found.before);
+                if !text.is_empty() {
+                    tokens.push(Token::Text(std::mem::take(&mut text)));
+                }
+                tokens.push(Token::Shortcode(found.shortcode));
+                text.push_str(found.after);
+            }
+        },
+    );
+    if !text.is_empty() {
+        tokens.push(Token::Text(text));
+    }
+    tokens
+}
+
+type Handler = Box<dyn Fn(&Shortcode, &Shortcodes) -> String + Send + Sync>;
+
+/// A registry of shortcode handlers.
+///
+/// Only registered shortcodes are expanded or stripped, other bracketed text
+/// is kept as it is.
+#[derive(Default)]
+pub struct Shortcodes {
+    handlers: HashMap<String, Handler>,
+}
+
+impl Shortcodes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the handler of a shortcode, replacing any previous one.
+    ///
+    /// The handler returns the markup replacing the shortcode. Shortcodes in
+    /// the enclosed content are not expanded unless the handler expands them
+    /// with the registry it is given.
+    pub fn register<F>(&mut self, name: impl Into<String>, handler: F) -> &mut Self
+    where
+        F: Fn(&Shortcode, &Shortcodes) -> String + Send + Sync + 'static,
+    {
+        self.handlers.insert(name.into(), Box::new(handler));
+        self
+    }
+
+    /// Whether a shortcode is registered.
+    pub fn is_registered(&self, name: &str) -> bool {
+        self.handlers.contains_key(name)
+    }
+
+    /// Replace the registered shortcodes in content with the markup of their
+    /// handlers.
+    pub fn expand(&self, content: &str) -> String {
+        self.replace(content, |shortcode| {
+            (self.handlers[&shortcode.name])(shortcode, self)
+        })
+    }
+
+    /// Remove the registered shortcodes from content, along with the content
+    /// they enclose.
+    pub fn strip(&self, content: &str) -> String {
+        self.replace(content, |_| String::new())
+    }
+
+    fn replace(&self, content: &str, mut replacement: impl FnMut(&Shortcode) -> String) -> String {
+        let mut replaced = String::with_capacity(content.len());
+        scan(
+            content,
+            |name| self.is_registered(name),
+            |piece| match piece {
+                Piece::Text(text) => replaced.push_str(text),
+                Piece::Shortcode(found) if found.escaped => replaced.push_str(found.unescaped),
+                Piece::Shortcode(found) => {
+                    replaced.push_str(found.before);
+                    replaced.push_str(&replacement(&found.shortcode));
+                    replaced.push_str(found.after);
+                }
+            },
+        );
+        replaced
+    }
+}
+
+impl fmt::Debug for Shortcodes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names: Vec<_> = self.handlers.keys().collect();
+        names.sort();
+        f.debug_struct("Shortcodes").field("names", &names).finish()
+    }
+}
+
+enum Piece<'a> {
+    Text(&'a str),
+    Shortcode(Found<'a>),
+}
+
+/// A shortcode found in content.
+struct Found<'a> {
+    shortcode: Shortcode,
+    /// Whether the shortcode is within double brackets.
+    escaped: bool,
+    /// The shortcode without its outer brackets, when escaped.
+    unescaped: &'a str,
+    /// A lone extra bracket before or after the shortcode, which is kept.
+    before: &'a str,
+    after: &'a str,
+}
+
+/// Find the shortcodes with accepted names in content, following the
+/// shortcode pattern of WordPress.
+fn scan<'a>(content: &'a str, accept: impl Fn(&str) -> bool, mut f: impl FnMut(Piece<'a>)) {
+    let mut rest = content;
+    while let Some(start) = rest.find('[') {
+        match find(&rest[start..], &accept) {
+            Some((found, len)) => {
+                if start > 0 {
+                    f(Piece::Text(&rest[..start]));
+                }
+                f(Piece::Shortcode(found));
+                rest = &rest[start + len..];
+            }
+            None => {
+                f(Piece::Text(&rest[..=start]));
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    if !rest.is_empty() {
+        f(Piece::Text(rest));
+    }
+}
+
+/// Read a shortcode at the start of the input, along with its length.
+fn find<'a>(input: &'a str, accept: impl Fn(&str) -> bool) -> Option<(Found<'a>, usize)> {
+    let mut rest = &input[1..];
+    let open_escape = rest.starts_with('[');
+    if open_escape {
+        rest = &rest[1..];
+    }
+
+    let name_len = rest
+        .find(|c: char| matches!(c, '<' | '>' | '&' | '/' | '[' | ']' | '=') || c <= ' ')
+        .unwrap_or(rest.len());
+    let name = &rest[..name_len];
+    if name.is_empty() || !accept(name) {
+        return None;
+    }
+    rest = &rest[name_len..];
+
+    // The attributes end at the first `]`, where `/]` closes the shortcode.
+    let attrs_len = rest.find(']')?;
+    let self_closing = rest[..attrs_len].ends_with('/');
+    let attrs = &rest[..attrs_len - usize::from(self_closing)];
+    rest = &rest[attrs_len + 1..];
+
+    let mut enclosed = None;
+    if !self_closing {
+        let closing = format!("[/{}]", name);
+        if let Some(end) = rest.find(&closing) {
+            enclosed = Some(rest[..end].to_owned());
+            rest = &rest[end + closing.len()..];
+        }
+    }
+
+    let close_escape = rest.starts_with(']');
+    if close_escape {
+        rest = &rest[1..];
+    }
+    let len = input.len() - rest.len();
+
+    let (named, positional) = parse_attrs(attrs);
+    let found = Found {
+        shortcode: Shortcode {
+            name: name.to_owned(),
+            attrs: named,
+            positional,
+            enclosed,
+        },
+        escaped: open_escape && close_escape,
+        unescaped: &input[1..len.saturating_sub(1)],
+        before: if open_escape { "[" } else { "" },
+        after: if close_escape { "]" } else { "" },
+    };
+    Some((found, len))
+}
+
+/// Parse the attributes of a shortcode as WordPress does.
+///
+/// Curly quotes and quote entities, which rendered content may hold, are
+/// taken as straight quotes.
+fn parse_attrs(text: &str) -> (BTreeMap<String, String>, Vec<String>) {
+    let mut text = text.to_owned();
+    for (from, to) in [
+        ("&quot;", "\""),
+        ("&#8220;", "\""),
+        ("&#8221;", "\""),
+        ("&#8243;", "\""),
+        ("\u{201c}", "\""),
+        ("\u{201d}", "\""),
+        ("\u{2033}", "\""),
+        ("&#039;", "'"),
+        ("&#8216;", "'"),
+        ("&#8217;", "'"),
+        ("\u{2018}", "'"),
+        ("\u{2019}", "'"),
+        ("&nbsp;", " "),
+        ("\u{a0}", " "),
+        ("\u{200b}", " "),
+    ] {
+        text = text.replace(from, to);
+    }
+
+    let mut named = BTreeMap::new();
+    let mut positional = Vec::new();
+    let mut rest = text.trim_start();
+    while !rest.is_empty() {
+        let key_len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(rest.len());
+        let after_key = rest[key_len..].trim_start();
+        if key_len > 0 && after_key.starts_with('=') {
+            let key = rest[..key_len].to_lowercase();
+            let (value, after) = value(after_key[1..].trim_start());
+            named.insert(key, value.to_owned());
+            rest = after;
+        } else {
+            let (value, after) = value(rest);
+            positional.push(value.to_owned());
+            rest = after;
+        }
+        rest = rest.trim_start();
+    }
+    (named, positional)
+}
+
+/// Read a quoted or unquoted value, returning the rest after it.
+fn value(input: &str) -> (&str, &str) {
+    for quote in ['"', '\''] {
+        if let Some(quoted) = input.strip_prefix(quote) {
+            return match quoted.find(quote) {
+                Some(end) => (&quoted[..end], &quoted[end + 1..]),
+                None => (quoted, ""),
+            };
+        }
+    }
+    let end = input
+        .find(|c: char| c.is_whitespace())
+        .unwrap_or(input.len());
+    input.split_at(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn shortcode(name: &str, attrs: &[(&str, &str)], enclosed: Option<&str>) -> Token {
+        Token::Shortcode(Shortcode {
+            name: name.into(),
+            attrs: attrs
+                .iter()
+                .map(|(key, value)| ((*key).into(), (*value).into()))
+                .collect(),
+            positional: Vec::new(),
+            enclosed: enclosed.map(Into::into),
+        })
+    }
+
+    #[test]
+    fn tokens() {
+        let tokens = parse(
+            r#"<p>[gallery ids="1,2" Size='large' columns=3 /]</p>[caption]<img> A [b]photo[/b][/caption] [[escaped]]"#,
+        );
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Text("<p>".into()),
+                shortcode(
+                    "gallery",
+                    &[("columns", "3"), ("ids", "1,2"), ("size", "large")],
+                    None
+                ),
+                Token::Text("</p>".into()),
+                shortcode("caption", &[], Some("<img> A [b]photo[/b]")),
+                Token::Text(" [escaped]".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn positional() {
+        let tokens = parse(r#"[code "a b" c d=e]"#);
+
+        let Token::Shortcode(code) = &tokens[0] else {
+            panic!("expected a shortcode");
+        };
+        assert_eq!(code.positional, vec!["a b", "c"]);
+        assert_eq!(code.attr("d"), Some("e"));
+        assert_eq!(code.to_string(), r#"[code d="e" "a b" c]"#);
+    }
+
+    #[test]
+    fn rendered_quotes() {
+        let tokens = parse("[embed width=&#8221;400&#8243;]https://example.com[/embed]");
+
+        assert_eq!(
+            tokens,
+            vec![shortcode(
+                "embed",
+                &[("width", "400")],
+                Some("https://example.com")
+            )]
+        );
+    }
+
+    #[test]
+    fn expand() {
+        let mut shortcodes = Shortcodes::new();
+        shortcodes
+            .register("b", |code, _| format!("<b>{}</b>", code.content()))
+            .register("box", |code, shortcodes| {
+                format!("<div>{}</div>", shortcodes.expand(code.content()))
+            });
+
+        let expanded = shortcodes.expand("[box][b]Hi[/b] [other][/box] [[b]x[/b]] [[b]");
+
+        assert_eq!(expanded, "<div><b>Hi</b> [other]</div> [b]x[/b] [<b></b>");
+    }
+
+    #[test]
+    fn strip() {
+        let mut shortcodes = Shortcodes::new();
+        shortcodes
+            .register("gallery", |_, _| String::new())
+            .register("note", |_, _| String::new());
+
+        let stripped =
+            shortcodes.strip("<p>Photos:</p>[gallery ids=\"1\"]\n[unknown] [note]a[/note]");
+
+        assert_eq!(stripped, "<p>Photos:</p>\n[unknown] ");
+    }
+}
@@ -0,0 +1,340 @@
+use std::{error::Error, marker::PhantomData};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    client::Client,
+    endpoint::{self, Endpoint},
+    query::Query,
+    request, ApiError,
+};
+
+/// A query which deserializes a collection item by item as it is received.
+///
+/// See [`streamed`].
+#[derive(Debug, Clone)]
+pub struct Streamed<E> {
+    endpoint: E,
+}
+
+/// Deserialize the items of a collection as the response is received.
+///
+/// Each item is deserialized once it is complete, so neither the full body
+/// nor its JSON are held in memory along with the results. The body is only
+/// received in chunks from clients which support it, see
+/// [`Client::send_request_streamed`].
+pub fn streamed<E>(endpoint: E) -> Streamed<E>
+where
+    E: Endpoint,
+{
+    Streamed { endpoint }
+}
+
+#[async_trait]
+impl<E, T, C> Query<Vec<T>, C> for Streamed<E>
+where
+    E: Endpoint + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: Client + Sync,
+{
+    async fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        let (_, items) = endpoint::request(&self.endpoint, client)
+            .await?
+            .send_streamed(client)
+            .await?;
+        Ok(items)
+    }
+}
+
+/// Incrementally deserializes the items of a JSON array.
+///
+/// Bytes are kept until the item they belong to is complete. A body which is
+/// not an array is kept whole and deserialized at the end, so that it fails
+/// the same way as other queries.
+pub(crate) struct JsonArray<T> {
+    buf: Vec<u8>,
+    /// Where scanning resumes in `buf`.
+    pos: usize,
+    /// Where the current item starts in `buf`.
+    start: usize,
+    state: State,
+    /// Nesting depth within the current item.
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    items: Vec<T>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Before the opening bracket.
+    Start,
+    /// After the opening bracket, before the first item.
+    Items,
+    /// After a comma, where another item must follow.
+    Next,
+    /// Within an item.
+    Item,
+    /// After the closing bracket.
+    End,
+    /// Not an array.
+    Whole,
+}
+
+impl<T> JsonArray<T>
+where
+    T: DeserializeOwned,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            buf: Vec::new(),
+            pos: 0,
+            start: 0,
+            state: State::Start,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            items: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Deserialize the items completed by a chunk of the body.
+    pub(crate) fn push<E>(&mut self, chunk: &[u8]) -> Result<(), ApiError<E>>
+    where
+        E: Error + Send + Sync,
+    {
+        self.buf.extend_from_slice(chunk);
+        if self.state == State::Whole {
+            return Ok(());
+        }
+
+        while self.pos < self.buf.len() {
+            let byte = self.buf[self.pos];
+            match self.state {
+                State::Start if byte.is_ascii_whitespace() => {}
+                State::Start if byte == b'[' => self.state = State::Items,
+                State::Start => {
+                    self.state = State::Whole;
+                    return Ok(());
+                }
+                State::Items | State::Next if byte.is_ascii_whitespace() => {}
+                State::Items if byte == b']' => self.state = State::End,
+                State::Items if byte == b',' => return Err(syntax::<T, E>("expected value")),
+                State::Next if byte == b',' => return Err(syntax::<T, E>("expected value")),
+                State::Next if byte == b']' => return Err(syntax::<T, E>("trailing comma")),
+                State::Items | State::Next => {
+                    self.state = State::Item;
+                    self.start = self.pos;
+                    continue;
+                }
+                State::Item => self.scan(byte)?,
+                State::End if byte.is_ascii_whitespace() => {}
+                State::End => return Err(syntax::<T, E>("trailing characters after the list")),
+                State::Whole => unreachable!(),
+            }
+            self.pos += 1;
+        }
+
+        // Only the current item is needed from here on.
+        let consumed = match self.state {
+            State::Item => self.start,
+            _ => self.pos,
+        };
+        self.buf.drain(..consumed);
+        self.pos -= consumed;
+        self.start -= consumed.min(self.start);
+        Ok(())
+    }
+
+    /// Scan a byte of an item, deserializing the item if it ends there.
+    fn scan<E>(&mut self, byte: u8) -> Result<(), ApiError<E>>
+    where
+        E: Error + Send + Sync,
+    {
+        if self.in_string {
+            match byte {
+                _ if self.escaped => self.escaped = false,
+                b'\\' => self.escaped = true,
+                b'"' => self.in_string = false,
+                _ => {}
+            }
+            return Ok(());
+        }
+        match byte {
+            b'"' => self.in_string = true,
+            b'{' | b'[' => self.depth += 1,
+            b'}' | b']' if self.depth > 0 => self.depth -= 1,
+            b',' | b']' if self.depth == 0 => {
+                let item = request::from_slice(&self.buf[self.start..self.pos])
+                    .map_err(ApiError::data_type::<T>)?;
+                self.items.push(item);
+                self.state = if byte == b']' {
+                    State::End
+                } else {
+                    State::Next
+                };
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Finish deserializing once the body has been received.
+    pub(crate) fn finish<E>(self, status: StatusCode) -> Result<Vec<T>, ApiError<E>>
+    where
+        E: Error + Send + Sync,
+    {
+        match self.state {
+            State::End => Ok(self.items),
+            State::Items | State::Next | State::Item => {
+                Err(syntax::<T, E>("unexpected end of the list"))
+            }
+            State::Start | State::Whole => {
+                let body = Bytes::from(self.buf);
+                request::from_slice(&body)
+                    .map_err(|err| request::data_error::<Vec<T>, _>(err, status, &body))
+            }
+        }
+    }
+}
+
+fn syntax<T, E>(msg: &str) -> ApiError<E>
+where
+    E: Error + Send + Sync,
+{
+    ApiError::data_type::<Vec<T>>(serde::de::Error::custom(msg))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        endpoints::RawEndpoint,
+        test::{MockClient, MockClientError, MockResponse},
+    };
+
+    fn parse(chunks: &[&str]) -> Result<Vec<Value>, ApiError<MockClientError>> {
+        let mut array = JsonArray::new();
+        for chunk in chunks {
+            array.push(chunk.as_bytes())?;
+        }
+        array.finish(StatusCode::OK)
+    }
+
+    #[test]
+    fn items_across_chunks() {
+        let items = parse(&[
+            " [{\"title\": \"a, [b]",
+            "\", \"tags\": [1, 2]}, 3,",
+            " \"c\\\"]\", null, ",
+            "{}]\n",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            items,
+            vec![
+                json!({ "title": "a, [b]", "tags": [1, 2] }),
+                json!(3),
+                json!("c\"]"),
+                json!(null),
+                json!({}),
+            ]
+        );
+    }
+
+    #[test]
+    fn one_byte_chunks() {
+        let body = r#"[{"id": 1, "content": "{\"x\": [\\]}"}, {"id": 2}]"#;
+        let chunks: Vec<_> = body
+            .char_indices()
+            .map(|(i, c)| &body[i..i + c.len_utf8()])
+            .collect();
+
+        let items = parse(&chunks).unwrap();
+
+        assert_eq!(
+            items,
+            vec![
+                json!({ "id": 1, "content": "{\"x\": [\\]}" }),
+                json!({ "id": 2 })
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_array() {
+        assert_eq!(parse(&["[", " ]"]).unwrap(), Vec::<Value>::new());
+    }
+
+    #[test]
+    fn keeps_only_the_current_item() {
+        let mut array = JsonArray::<Value>::new();
+
+        array
+            .push::<MockClientError>(br#"[{"id": 1}, {"id": 2}, {"id""#)
+            .unwrap();
+
+        assert_eq!(array.items.len(), 2);
+        assert_eq!(array.buf, br#"{"id""#);
+    }
+
+    #[test]
+    fn truncated() {
+        let err = parse(&[r#"[{"id": 1}, {"id""#]).unwrap_err();
+        assert!(matches!(err, ApiError::DataType { .. }));
+    }
+
+    #[test]
+    fn missing_items() {
+        for body in ["[1,,2]", "[1,2,]", "[,1]", "[1,"] {
+            let err = parse(&[body]).unwrap_err();
+            assert!(matches!(err, ApiError::DataType { .. }), "{}", body);
+            assert!(serde_json::from_str::<Vec<Value>>(body).is_err());
+        }
+    }
+
+    #[test]
+    fn not_an_array() {
+        let err = parse(&[r#"{"id": 1}"#]).unwrap_err();
+        assert!(matches!(err, ApiError::DataType { .. }));
+    }
+
+    #[test]
+    fn not_json() {
+        let err = parse(&["<html>"]).unwrap_err();
+        if let ApiError::WordPressInternal { data, .. } = err {
+            assert_eq!(data, b"<html>");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn query() {
+        let endpoint = RawEndpoint::builder().route("/mock").build().unwrap();
+        let response = MockResponse::builder()
+            .json(json!([{ "id": 1, "title": "Hello, world!" }, { "id": 2 }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let items: Vec<Value> = streamed(endpoint).query(&client).await.unwrap();
+
+        assert_eq!(
+            items,
+            vec![
+                json!({ "id": 1, "title": "Hello, world!" }),
+                json!({ "id": 2 })
+            ]
+        );
+    }
+}
@@ -0,0 +1,574 @@
+//! Incremental mirroring of the content of a site.
+//!
+//! A [`Syncer`] keeps a [`Store`] up to date with collections such as posts,
+//! pages, media and terms. The first sync fetches every item of a collection,
+//! and later syncs only fetch the items modified since the last one, using
+//! the `modified_after` parameter, along with the IDs of all items to find
+//! those which were deleted. Each sync returns the changes it made to the
+//! store as [`SyncEvent`]s, for regenerating pages or updating a search index.
+//!
+//! The store can be serialized, so that tools which are run again against the
+//! same site carry on where the last run stopped.
+//!
+//! Items are only mirrored while they are listed by the collection, so with
+//! the default parameters a published post which is moved to the trash or
+//! back to draft is reported as deleted.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    client::Client, endpoint::prelude::*, error::ApiError, paged::paged, params, query::Query,
+};
+
+/// A collection which can be mirrored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Collection {
+    /// `/wp/v2/posts`
+    Posts,
+
+    /// `/wp/v2/pages`
+    Pages,
+
+    /// `/wp/v2/media`
+    Media,
+
+    /// The terms of a taxonomy, by its `rest_base`, such as `categories`.
+    ///
+    /// Terms have no modification date, so every term is fetched on each
+    /// sync and compared with the store.
+    Terms(String),
+}
+
+impl Collection {
+    /// The route of the collection, which is also its key in the store.
+    pub fn route(&self) -> String {
+        match self {
+            Collection::Posts => "/wp/v2/posts".into(),
+            Collection::Pages => "/wp/v2/pages".into(),
+            Collection::Media => "/wp/v2/media".into(),
+            Collection::Terms(taxonomy) => format!("/wp/v2/{}", taxonomy),
+        }
+    }
+
+    /// Whether the collection can be filtered by `modified_after`.
+    fn is_modified_tracked(&self) -> bool {
+        !matches!(self, Collection::Terms(_))
+    }
+}
+
+/// A change made to the store by a sync.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncEvent {
+    /// An item which was not in the store.
+    Created { collection: Collection, item: Value },
+
+    /// An item which differs from the one in the store.
+    Updated { collection: Collection, item: Value },
+
+    /// An item which is no longer listed by the collection.
+    Deleted { collection: Collection, id: u64 },
+}
+
+impl SyncEvent {
+    /// The collection of the changed item.
+    pub fn collection(&self) -> &Collection {
+        match self {
+            SyncEvent::Created { collection, .. }
+            | SyncEvent::Updated { collection, .. }
+            | SyncEvent::Deleted { collection, .. } => collection,
+        }
+    }
+
+    /// The ID of the changed item.
+    pub fn id(&self) -> u64 {
+        match self {
+            SyncEvent::Created { item, .. } | SyncEvent::Updated { item, .. } => {
+                item_id(item).unwrap_or_default()
+            }
+            SyncEvent::Deleted { id, .. } => *id,
+        }
+    }
+}
+
+/// The local copy of the mirrored collections.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Store {
+    collections: BTreeMap<String, Mirror>,
+}
+
+/// The local copy of a collection.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct Mirror {
+    /// The latest modification date of the items, in the site's timezone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    items: BTreeMap<u64, Value>,
+}
+
+impl Store {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An item of a collection.
+    pub fn get(&self, collection: &Collection, id: u64) -> Option<&Value> {
+        self.collections.get(&collection.route())?.items.get(&id)
+    }
+
+    /// The items of a collection, by ID.
+    pub fn items(&self, collection: &Collection) -> impl Iterator<Item = &Value> {
+        self.collections
+            .get(&collection.route())
+            .into_iter()
+            .flat_map(|mirror| mirror.items.values())
+    }
+
+    /// The latest modification date of the items of a collection, from which
+    /// on the next sync fetches them.
+    pub fn cursor(&self, collection: &Collection) -> Option<&str> {
+        self.collections.get(&collection.route())?.cursor.as_deref()
+    }
+}
+
+/// Mirrors collections to a [`Store`].
+#[derive(Debug, Clone, Builder)]
+pub struct Syncer {
+    /// The collections to mirror.
+    #[builder(setter(custom))]
+    collections: Vec<Collection>,
+
+    /// Scope under which the items are fetched.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+
+    /// The number of items fetched per page. Defaults to 100.
+    #[builder(default = "100")]
+    per_page: u32,
+
+    /// The number of pages fetched at once, see
+    /// [`Paged::concurrency`](crate::Paged::concurrency). Defaults to 1.
+    #[builder(default = "1")]
+    concurrency: usize,
+}
+
+impl SyncerBuilder {
+    /// Mirror a collection.
+    pub fn collection(&mut self, collection: Collection) -> &mut Self {
+        self.collections
+            .get_or_insert_with(Vec::new)
+            .push(collection);
+        self
+    }
+
+    /// Mirror multiple collections.
+    pub fn collections<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Collection>,
+    {
+        self.collections.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+}
+
+impl Syncer {
+    pub fn builder() -> SyncerBuilder {
+        SyncerBuilder::default()
+    }
+
+    /// Bring the store up to date, returning the changes made to it.
+    ///
+    /// The store is only updated once every collection has been fetched, so
+    /// a sync which fails leaves it as it was, and the changes are reported
+    /// by the next sync instead.
+    pub async fn sync<C>(
+        &self,
+        client: &C,
+        store: &mut Store,
+    ) -> Result<Vec<SyncEvent>, ApiError<C::Error>>
+    where
+        C: Client + Sync,
+    {
+        let mut staged = store.clone();
+        let mut events = Vec::new();
+        for collection in &self.collections {
+            let mirror = staged.collections.entry(collection.route()).or_default();
+            self.sync_collection(client, collection, mirror, &mut events)
+                .await?;
+        }
+        *store = staged;
+        Ok(events)
+    }
+
+    async fn sync_collection<C>(
+        &self,
+        client: &C,
+        collection: &Collection,
+        mirror: &mut Mirror,
+        events: &mut Vec<SyncEvent>,
+    ) -> Result<(), ApiError<C::Error>>
+    where
+        C: Client + Sync,
+    {
+        let cursor = mirror
+            .cursor
+            .as_deref()
+            .filter(|_| collection.is_modified_tracked());
+        let mut params = Vec::new();
+        if let Some(context) = self.context {
+            params.push(("context", context.as_value().into_owned()));
+        }
+        // Items modified in the same second as the cursor are listed again,
+        // as `modified_after` excludes that second, and skipped below if they
+        // are already in the store.
+        if let Some(cursor) = cursor {
+            let after = params::parse_seconds(cursor).map_or_else(
+                || cursor.to_owned(),
+                |secs| params::format_seconds(secs - 1),
+            );
+            params.push(("modified_after", after));
+        }
+        let changed = self.fetch(client, collection, params).await?;
+
+        // Without a cursor every item was fetched, so the rest were deleted.
+        let listed: BTreeSet<u64> = if cursor.is_some() {
+            let ids = self
+                .fetch(client, collection, vec![("_fields", "id".into())])
+                .await?;
+            ids.iter().filter_map(item_id).collect()
+        } else {
+            changed.iter().filter_map(item_id).collect()
+        };
+
+        let mut latest = mirror.cursor.clone();
+        let mut created = Vec::new();
+        let mut updated = Vec::new();
+        for item in changed {
+            let id = item_id(&item).ok_or_else(|| ApiError::missing_pointer("/id"))?;
+            let date = modified(&item);
+            if let Some(modified) = date {
+                if latest.as_deref().is_none_or(|latest| modified > latest) {
+                    latest = Some(modified.to_owned());
+                }
+            }
+            match mirror.items.get(&id) {
+                Some(stored) if *stored == item => {}
+                Some(stored) if date.is_some() && modified(stored) == date => {}
+                Some(_) => updated.push((id, item)),
+                None => created.push((id, item)),
+            }
+        }
+        let deleted: Vec<u64> = mirror
+            .items
+            .keys()
+            .filter(|id| !listed.contains(id))
+            .copied()
+            .collect();
+
+        if collection.is_modified_tracked() {
+            mirror.cursor = latest;
+        }
+        for (id, item) in created {
+            mirror.items.insert(id, item.clone());
+            events.push(SyncEvent::Created {
+                collection: collection.clone(),
+                item,
+            });
+        }
+        for (id, item) in updated {
+            mirror.items.insert(id, item.clone());
+            events.push(SyncEvent::Updated {
+                collection: collection.clone(),
+                item,
+            });
+        }
+        for id in deleted {
+            mirror.items.remove(&id);
+            events.push(SyncEvent::Deleted {
+                collection: collection.clone(),
+                id,
+            });
+        }
+        Ok(())
+    }
+
+    async fn fetch<C>(
+        &self,
+        client: &C,
+        collection: &Collection,
+        params: Vec<(&'static str, String)>,
+    ) -> Result<Vec<Value>, ApiError<C::Error>>
+    where
+        C: Client + Sync,
+    {
+        let endpoint = ListCollection {
+            route: collection.route(),
+            params,
+        };
+        paged(endpoint)
+            .per_page(self.per_page)
+            .concurrency(self.concurrency)
+            .query(client)
+            .await
+    }
+}
+
+fn item_id(item: &Value) -> Option<u64> {
+    item.get("id")?.as_u64()
+}
+
+fn modified(item: &Value) -> Option<&str> {
+    item.get("modified")?.as_str()
+}
+
+/// Lists a collection with the parameters of a sync.
+struct ListCollection {
+    route: String,
+    params: Vec<(&'static str, String)>,
+}
+
+impl Endpoint for ListCollection {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        self.route.clone().into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        for (key, value) in &self.params {
+            params.push(*key, value.as_str());
+        }
+        params
+    }
+}
+
+impl Pageable for ListCollection {}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::test::{MockClient, MockResponse};
+
+    fn post(id: u64, modified: &str) -> Value {
+        json!({ "id": id, "modified": modified, "title": { "rendered": format!("Post {}", id) } })
+    }
+
+    fn syncer() -> Syncer {
+        Syncer::builder()
+            .collection(Collection::Posts)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn initial_sync() {
+        let response = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("page=1&per_page=100")
+            .header("x-wp-totalpages", "1")
+            .json(json!([
+                post(1, "2024-01-01T10:00:00"),
+                post(2, "2024-01-02T10:00:00")
+            ]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let mut store = Store::new();
+
+        let events = syncer().sync(&client, &mut store).await.unwrap();
+
+        assert_eq!(
+            events,
+            vec![
+                SyncEvent::Created {
+                    collection: Collection::Posts,
+                    item: post(1, "2024-01-01T10:00:00"),
+                },
+                SyncEvent::Created {
+                    collection: Collection::Posts,
+                    item: post(2, "2024-01-02T10:00:00"),
+                },
+            ]
+        );
+        assert_eq!(
+            store.cursor(&Collection::Posts),
+            Some("2024-01-02T10:00:00")
+        );
+        assert_eq!(store.items(&Collection::Posts).count(), 2);
+    }
+
+    #[tokio::test]
+    async fn incremental_sync() {
+        let mut store = Store::new();
+        let mirror = store.collections.entry("/wp/v2/posts".into()).or_default();
+        mirror.cursor = Some("2024-01-02T10:00:00".into());
+        for item in [
+            post(1, "2024-01-01T10:00:00"),
+            post(2, "2024-01-02T10:00:00"),
+            post(3, "2024-01-02T09:00:00"),
+        ] {
+            mirror.items.insert(item_id(&item).unwrap(), item);
+        }
+        let changed = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("modified_after=2024-01-02T09%3A59%3A59&page=1&per_page=100")
+            .header("x-wp-totalpages", "1")
+            .json(json!([
+                post(2, "2024-01-02T10:00:00"),
+                post(5, "2024-01-02T10:00:00"),
+                post(1, "2024-01-03T10:00:00"),
+                post(4, "2024-01-04T10:00:00")
+            ]))
+            .build()
+            .unwrap();
+        let ids = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("_fields=id&page=1&per_page=100")
+            .header("x-wp-totalpages", "1")
+            .json(json!([{ "id": 1 }, { "id": 2 }, { "id": 4 }, { "id": 5 }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_responses([changed, ids]);
+
+        let events = syncer().sync(&client, &mut store).await.unwrap();
+
+        // Post 5 was modified in the same second as the cursor, and post 2
+        // is listed again without changes.
+        assert_eq!(
+            events,
+            vec![
+                SyncEvent::Created {
+                    collection: Collection::Posts,
+                    item: post(5, "2024-01-02T10:00:00"),
+                },
+                SyncEvent::Created {
+                    collection: Collection::Posts,
+                    item: post(4, "2024-01-04T10:00:00"),
+                },
+                SyncEvent::Updated {
+                    collection: Collection::Posts,
+                    item: post(1, "2024-01-03T10:00:00"),
+                },
+                SyncEvent::Deleted {
+                    collection: Collection::Posts,
+                    id: 3,
+                },
+            ]
+        );
+        assert_eq!(
+            store.cursor(&Collection::Posts),
+            Some("2024-01-04T10:00:00")
+        );
+        assert_eq!(
+            store.get(&Collection::Posts, 1),
+            Some(&post(1, "2024-01-03T10:00:00"))
+        );
+        assert_eq!(store.get(&Collection::Posts, 3), None);
+    }
+
+    #[tokio::test]
+    async fn terms() {
+        let categories = Collection::Terms("categories".into());
+        let response = MockResponse::builder()
+            .route("/wp/v2/categories")
+            .query("page=1&per_page=100")
+            .header("x-wp-totalpages", "1")
+            .json(json!([{ "id": 1, "name": "Uncategorized" }, { "id": 5, "name": "News" }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let syncer = Syncer::builder()
+            .collection(categories.clone())
+            .build()
+            .unwrap();
+        let mut store = Store::new();
+
+        let first = syncer.sync(&client, &mut store).await.unwrap();
+        let second = syncer.sync(&client, &mut store).await.unwrap();
+
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[1].id(), 5);
+        assert_eq!(second, Vec::new());
+        assert_eq!(store.cursor(&categories), None);
+    }
+
+    #[tokio::test]
+    async fn failed_sync() {
+        let response = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("page=1&per_page=100")
+            .status(http::StatusCode::INTERNAL_SERVER_ERROR)
+            .body("<html>")
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+        let mut store = Store::new();
+
+        syncer().sync(&client, &mut store).await.unwrap_err();
+
+        assert_eq!(store.items(&Collection::Posts).count(), 0);
+        assert_eq!(store.cursor(&Collection::Posts), None);
+    }
+
+    #[tokio::test]
+    async fn failed_collection() {
+        let posts = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("page=1&per_page=100")
+            .header("x-wp-totalpages", "1")
+            .json(json!([post(1, "2024-01-01T10:00:00")]))
+            .build()
+            .unwrap();
+        let pages = MockResponse::builder()
+            .route("/wp/v2/pages")
+            .query("page=1&per_page=100")
+            .status(http::StatusCode::INTERNAL_SERVER_ERROR)
+            .body("<html>")
+            .build()
+            .unwrap();
+        let client = MockClient::with_responses([posts, pages]);
+        let syncer = Syncer::builder()
+            .collections([Collection::Posts, Collection::Pages])
+            .build()
+            .unwrap();
+        let mut store = Store::new();
+
+        syncer.sync(&client, &mut store).await.unwrap_err();
+
+        // The posts which were fetched are reported by the next sync.
+        assert_eq!(store, Store::new());
+    }
+
+    #[test]
+    fn serialize_store() {
+        let mut store = Store::new();
+        let mirror = store.collections.entry("/wp/v2/pages".into()).or_default();
+        mirror.cursor = Some("2024-01-01T10:00:00".into());
+        mirror.items.insert(3, json!({ "id": 3 }));
+
+        let json = serde_json::to_value(&store).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "collections": {
+                    "/wp/v2/pages": {
+                        "cursor": "2024-01-01T10:00:00",
+                        "items": { "3": { "id": 3 } },
+                    },
+                },
+            })
+        );
+        assert_eq!(serde_json::from_value::<Store>(json).unwrap(), store);
+    }
+}
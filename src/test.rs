@@ -3,14 +3,16 @@ use std::collections::HashMap;
 use async_trait::async_trait;
 use bytes::Bytes;
 use derive_builder::Builder;
-use http::{Method, Request, Response, StatusCode};
+use futures_util::{stream, StreamExt};
+use http::{header::HeaderName, HeaderMap, HeaderValue, Method, Request, Response, StatusCode};
 use thiserror::Error;
 use url::Url;
 
-use crate::{ApiError, Client};
+use crate::{client::BodyStream, ApiError, Client};
 
-const MOCK_ROOT_ROUTE: &'static str = "test://test";
+const MOCK_ROOT_ROUTE: &str = "test://test";
 const MOCK_ROUTE: &str = "/mock";
+const MOCK_CHUNK_SIZE: usize = 7;
 
 /// Mock a response.
 #[derive(Debug, Builder)]
@@ -23,6 +25,18 @@ pub struct MockResponse {
     #[builder(default = "MOCK_ROUTE.to_string()", setter(into))]
     pub route: String,
 
+    /// Expected query string of the request
+    #[builder(default, setter(into, strip_option))]
+    pub query: Option<String>,
+
+    /// Headers expected to be present on the request
+    #[builder(default, setter(custom))]
+    pub request_headers: HeaderMap,
+
+    /// Response headers
+    #[builder(default, setter(custom))]
+    pub headers: HeaderMap,
+
     /// Response body
     #[builder(default, setter(into))]
     pub body: Vec<u8>,
@@ -43,6 +57,24 @@ impl MockResponseBuilder {
         let body = serde_json::to_vec(&value).expect("failed to convert json to vec");
         self.body(body)
     }
+
+    pub fn header(&mut self, key: &'static str, value: &str) -> &mut MockResponseBuilder {
+        self.headers.get_or_insert_with(HeaderMap::new).append(
+            HeaderName::from_static(key),
+            HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    pub fn expect_header(&mut self, key: &'static str, value: &str) -> &mut MockResponseBuilder {
+        self.request_headers
+            .get_or_insert_with(HeaderMap::new)
+            .append(
+                HeaderName::from_static(key),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        self
+    }
 }
 
 #[derive(Debug, Error)]
@@ -50,7 +82,7 @@ impl MockResponseBuilder {
 pub struct MockClientError;
 
 pub struct MockClient {
-    response_map: HashMap<(Method, String), MockResponse>,
+    response_map: HashMap<(Method, String), Vec<MockResponse>>,
 }
 
 impl MockClient {
@@ -65,9 +97,19 @@ impl MockClient {
         client
     }
 
+    /// Mock several responses, e.g. for the same route with different query
+    /// strings.
+    pub fn with_responses(responses: impl IntoIterator<Item = MockResponse>) -> Self {
+        let mut client = Self::new();
+        for response in responses {
+            client.insert(response);
+        }
+        client
+    }
+
     pub fn insert(&mut self, response: MockResponse) {
         let request = (response.method.clone(), response.route.to_string());
-        self.response_map.insert(request, response);
+        self.response_map.entry(request).or_default().push(response);
     }
 }
 
@@ -76,7 +118,7 @@ impl Client for MockClient {
     type Error = MockClientError;
 
     async fn route_url(&self, route: &str) -> Result<Url, ApiError<Self::Error>> {
-        let url = format!("{}/{}", MOCK_ROOT_ROUTE, route.trim_start_matches("/"));
+        let url = format!("{}/{}", MOCK_ROOT_ROUTE, route.trim_start_matches('/'));
         Ok(Url::parse(&url).expect("failed to parse url"))
     }
 
@@ -86,16 +128,55 @@ impl Client for MockClient {
     ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
         let key = (request.method().clone(), request.uri().path().into());
 
-        let mock = self
+        let mocks = self
             .response_map
             .get(&key)
             .expect("no matching request found");
-
-        let resp = Response::builder()
+        let mock = mocks
+            .iter()
+            .find(|mock| {
+                mock.query
+                    .as_deref()
+                    .is_none_or(|query| request.uri().query() == Some(query))
+            })
+            .unwrap_or(&mocks[0]);
+
+        if let Some(query) = &mock.query {
+            assert_eq!(request.uri().query(), Some(query.as_str()));
+        }
+        for (key, value) in &mock.request_headers {
+            assert_eq!(request.headers().get(key), Some(value));
+        }
+
+        let mut resp = Response::builder()
             .status(mock.status)
             .body(mock.body.clone().into())
             .expect("failed to build response");
+        resp.headers_mut().extend(mock.headers.clone());
 
         Ok(resp)
     }
+
+    /// Bodies are returned in small chunks, so that items span several.
+    async fn send_request_streamed(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        let resp = self.send_request(request).await?;
+        Ok(resp.map(|body| {
+            let chunks: Vec<_> = body
+                .chunks(MOCK_CHUNK_SIZE)
+                .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+                .collect();
+            stream::iter(chunks).boxed()
+        }))
+    }
+
+    /// Any host is served from the mocked responses.
+    async fn send_external_request(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        self.send_request_streamed(request).await
+    }
 }
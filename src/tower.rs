@@ -0,0 +1,204 @@
+use std::{error::Error, future::poll_fn};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::{stream, StreamExt};
+use http::{Request, Response};
+use thiserror::Error;
+use tower_service::Service;
+use url::Url;
+
+use crate::{
+    auth::{self, Auth},
+    client::{self, BodyStream, Client},
+    error::ApiError,
+    root::{RootRoute, RootRouteCell},
+};
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+/// Asynchronous WordPress client sending requests through a `tower` service.
+///
+/// This allows timeouts, load shedding, retries and other middleware from the
+/// `tower` ecosystem to be composed around the transport. The service is
+/// cloned for every request.
+pub struct TowerWordPress<S> {
+    service: S,
+    site_url: Url,
+    root_route: RootRouteCell,
+    auth: Option<Auth>,
+}
+
+impl<S> TowerWordPress<S>
+where
+    S: Service<Request<Vec<u8>>, Response = Response<Bytes>> + Clone + Send + Sync + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+{
+    /// Create a new WordPress client using a service.
+    pub fn new(site_url: impl AsRef<str>, service: S) -> Result<Self, TowerError> {
+        Ok(Self {
+            service,
+            site_url: Url::parse(site_url.as_ref())?,
+            root_route: RootRouteCell::default(),
+            auth: None,
+        })
+    }
+
+    /// Authenticate every request sent by the client.
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// The root route for the WordPress instance.
+    ///
+    /// The value will change depending on the permalink structure configured
+    /// for the site.
+    pub async fn root_route(&self) -> Result<RootRoute, ApiError<TowerError>> {
+        self.root_route
+            .get_or_try_init(|| self.discover_root_route(&self.site_url))
+            .await
+    }
+
+    /// Use a previously discovered root route instead of discovering it on the
+    /// first request.
+    pub fn with_root_route(self, root_route: RootRoute) -> Self {
+        self.root_route.set(Some(root_route));
+        self
+    }
+
+    /// Forget the root route, so that it is discovered again on the next
+    /// request, e.g. after the permalink structure of the site changed.
+    pub fn invalidate_root_route(&self) {
+        self.root_route.set(None);
+    }
+
+    async fn execute(&self, request: Request<Vec<u8>>) -> Result<Response<Bytes>, TowerError> {
+        let mut service = self.service.clone();
+        poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .map_err(TowerError::service)?;
+        service.call(request).await.map_err(TowerError::service)
+    }
+}
+
+#[async_trait]
+impl<S> Client for TowerWordPress<S>
+where
+    S: Service<Request<Vec<u8>>, Response = Response<Bytes>> + Clone + Send + Sync + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+{
+    type Error = TowerError;
+
+    async fn route_url(&self, route: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.root_route().await?.join(route))
+    }
+
+    async fn send_request(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        auth::send(self.auth.as_ref(), request, |request| self.execute(request)).await
+    }
+
+    async fn send_external_request(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        if client::is_site_request(self, &request).await? {
+            return self.send_request_streamed(request).await;
+        }
+        let resp = self.execute(request).await?;
+        Ok(resp.map(|body| stream::once(async { Ok(body) }).boxed()))
+    }
+}
+
+/// Errors that may occur when using the tower client.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum TowerError {
+    #[error("failed to parse url: {}", source)]
+    UrlParse {
+        #[from]
+        source: url::ParseError,
+    },
+
+    #[error("service error: {}", source)]
+    Service { source: BoxError },
+}
+
+impl TowerError {
+    fn service(source: impl Into<BoxError>) -> Self {
+        TowerError::Service {
+            source: source.into(),
+        }
+    }
+}
+
+impl From<TowerError> for ApiError<TowerError> {
+    fn from(err: TowerError) -> Self {
+        ApiError::client(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        future::{ready, Ready},
+        task::{Context, Poll},
+    };
+
+    use http::Method;
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{endpoints::RawEndpoint, Query};
+
+    #[derive(Clone)]
+    struct Site;
+
+    impl Service<Request<Vec<u8>>> for Site {
+        type Response = Response<Bytes>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<Vec<u8>>) -> Self::Future {
+            let resp = if request.method() == Method::HEAD {
+                Response::builder()
+                    .header(
+                        "link",
+                        "<http://example.com/wp-json/>; rel=\"https://api.w.org/\"",
+                    )
+                    .body(Bytes::new())
+            } else {
+                assert_eq!(request.uri(), "http://example.com/wp-json/wp/v2/users/me");
+                assert_eq!(request.headers()["authorization"], "Bearer abc123");
+                Response::builder().body(Bytes::from_static(b"{\"id\":1}"))
+            };
+            ready(Ok(resp.unwrap()))
+        }
+    }
+
+    #[tokio::test]
+    async fn query() {
+        let wordpress = TowerWordPress::new("http://example.com", Site)
+            .unwrap()
+            .with_auth(Auth::bearer("abc123"));
+        let endpoint = RawEndpoint::builder()
+            .route("/wp/v2/users/me")
+            .build()
+            .unwrap();
+
+        let user: Value = endpoint.query(&wordpress).await.unwrap();
+
+        assert_eq!(user, json!({ "id": 1 }));
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use super::de::php_map;
+
+/// Advanced Custom Fields of an object.
+///
+/// ACF adds the fields of posts, pages, terms and users to their responses
+/// when "Show in REST API" is enabled for a field group. The values are as
+/// ACF formats them, which depends on the type and return format of each
+/// field, so they are kept as JSON and read with [`AcfFields::get`].
+///
+/// ACF sends `false` for fields which have no value, and `false` or an empty
+/// array for objects without any fields.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AcfFields(#[serde(deserialize_with = "acf_map")] BTreeMap<String, Value>);
+
+impl AcfFields {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the value of a field.
+    ///
+    /// Returns `Ok(None)` if the field is not present or has no value, which
+    /// ACF sends as `null` or as `false` for fields other than true / false
+    /// fields.
+    pub fn get<T>(&self, name: &str) -> Result<Option<T>, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        match self.0.get(name) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => match T::deserialize(value) {
+                Ok(value) => Ok(Some(value)),
+                Err(_) if *value == Value::Bool(false) => Ok(None),
+                Err(err) => Err(err),
+            },
+        }
+    }
+
+    /// Get the value of a text field, or of any other field holding a string.
+    pub fn str(&self, name: &str) -> Option<&str> {
+        self.0.get(name).and_then(Value::as_str)
+    }
+
+    /// Get the value of a true / false field.
+    pub fn bool(&self, name: &str) -> Option<bool> {
+        self.0.get(name).and_then(Value::as_bool)
+    }
+
+    /// Get the value of a number or range field.
+    ///
+    /// ACF may send numbers as strings, which are parsed.
+    pub fn number(&self, name: &str) -> Option<f64> {
+        match self.0.get(name)? {
+            Value::Number(number) => number.as_f64(),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Set the value of a field.
+    pub fn set<T>(
+        &mut self,
+        name: impl Into<String>,
+        value: T,
+    ) -> Result<&mut Self, serde_json::Error>
+    where
+        T: Serialize,
+    {
+        self.0.insert(name.into(), serde_json::to_value(value)?);
+        Ok(self)
+    }
+
+    /// Get the raw value of a field.
+    pub fn value(&self, name: &str) -> Option<&Value> {
+        self.0.get(name)
+    }
+
+    /// Insert a raw field value.
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<Value>) -> &mut Self {
+        self.0.insert(name.into(), value.into());
+        self
+    }
+
+    /// Remove a field, returning its raw value.
+    pub fn remove(&mut self, name: &str) -> Option<Value> {
+        self.0.remove(name)
+    }
+
+    /// Whether the field is present.
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.0.iter()
+    }
+}
+
+impl From<BTreeMap<String, Value>> for AcfFields {
+    fn from(map: BTreeMap<String, Value>) -> Self {
+        Self(map)
+    }
+}
+
+impl From<AcfFields> for BTreeMap<String, Value> {
+    fn from(fields: AcfFields) -> Self {
+        fields.0
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for AcfFields
+where
+    K: Into<String>,
+    V: Into<Value>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+/// Deserialize the fields of an object, which are `false` or `null` when the
+/// object has none.
+fn acf_map<'de, D>(deserializer: D) -> Result<BTreeMap<String, Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null | Value::Bool(false) => Ok(BTreeMap::new()),
+        value => php_map(value).map_err(serde::de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    fn fields() -> AcfFields {
+        serde_json::from_value(json!({
+            "subtitle": "Hello",
+            "featured": true,
+            "rating": "4.5",
+            "related": [1, 2],
+            "hero_image": false,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn typed() {
+        let fields = fields();
+
+        assert_eq!(fields.get::<Vec<u32>>("related").unwrap(), Some(vec![1, 2]));
+        assert_eq!(fields.str("subtitle"), Some("Hello"));
+        assert_eq!(fields.bool("featured"), Some(true));
+        assert_eq!(fields.number("rating"), Some(4.5));
+    }
+
+    #[test]
+    fn empty_field() {
+        let fields = fields();
+
+        assert_eq!(fields.get::<String>("hero_image").unwrap(), None);
+        assert_eq!(fields.get::<bool>("hero_image").unwrap(), Some(false));
+        assert_eq!(fields.get::<String>("missing").unwrap(), None);
+        assert!(fields.get::<u32>("subtitle").is_err());
+    }
+
+    #[test]
+    fn no_fields() {
+        for json in [json!(false), json!([]), json!(null)] {
+            let fields: AcfFields = serde_json::from_value(json).unwrap();
+            assert!(fields.is_empty());
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut fields = AcfFields::new();
+        fields
+            .set("related", [3, 4])
+            .unwrap()
+            .insert("subtitle", "Hi");
+
+        let json = serde_json::to_value(&fields).unwrap();
+        assert_eq!(json, json!({ "related": [3, 4], "subtitle": "Hi" }));
+    }
+}
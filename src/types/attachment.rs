@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Links, Meta, Rendered};
+
+/// A media item.
+///
+/// Fields which are not returned in the requested context, or which are left
+/// out using [`fields`](crate::fields), are left at their default values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+    /// Unique identifier for the attachment.
+    #[serde(default)]
+    pub id: u32,
+
+    /// The date the attachment was published, in the site's timezone.
+    #[serde(default)]
+    pub date: String,
+
+    /// The date the attachment was published, as GMT.
+    #[serde(default)]
+    pub date_gmt: String,
+
+    /// The globally unique identifier for the attachment.
+    #[serde(default)]
+    pub guid: Rendered,
+
+    /// The date the attachment was last modified, in the site's timezone.
+    #[serde(default)]
+    pub modified: String,
+
+    /// The date the attachment was last modified, as GMT.
+    #[serde(default)]
+    pub modified_gmt: String,
+
+    /// An alphanumeric identifier for the attachment unique to its type.
+    #[serde(default)]
+    pub slug: String,
+
+    /// A named status for the attachment.
+    #[serde(default)]
+    pub status: String,
+
+    /// Type of post.
+    #[serde(rename = "type", default)]
+    pub post_type: String,
+
+    /// URL to the attachment.
+    #[serde(default)]
+    pub link: String,
+
+    /// The title for the attachment.
+    ///
+    /// Includes the `raw` title in the `edit` context.
+    #[serde(default)]
+    pub title: Rendered,
+
+    /// The ID for the author of the attachment.
+    #[serde(default)]
+    pub author: u32,
+
+    /// Whether or not comments are open on the attachment.
+    #[serde(default)]
+    pub comment_status: String,
+
+    /// Whether or not the attachment can be pinged.
+    #[serde(default)]
+    pub ping_status: String,
+
+    /// The theme file to use to display the attachment.
+    #[serde(default)]
+    pub template: String,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta: Meta,
+
+    /// The attachment description.
+    ///
+    /// Includes the `raw` description in the `edit` context.
+    #[serde(default)]
+    pub description: Rendered,
+
+    /// The attachment caption.
+    ///
+    /// Includes the `raw` caption in the `edit` context.
+    #[serde(default)]
+    pub caption: Rendered,
+
+    /// Alternative text to display when the attachment is not displayed.
+    #[serde(default)]
+    pub alt_text: String,
+
+    /// The attachment type, e.g. `image` or `file`.
+    #[serde(default)]
+    pub media_type: String,
+
+    /// The attachment MIME type.
+    #[serde(default)]
+    pub mime_type: String,
+
+    /// Details about the media file, specific to its type.
+    #[serde(default)]
+    pub media_details: Value,
+
+    /// The ID for the associated post of the attachment, if any.
+    #[serde(default)]
+    pub post: Option<u32>,
+
+    /// URL to the original attachment file.
+    #[serde(default)]
+    pub source_url: String,
+
+    /// List of the missing image sizes of the attachment.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing_image_sizes: Option<Vec<String>>,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn unattached() {
+        let attachment: Attachment = serde_json::from_value(json!({
+            "id": 7,
+            "media_type": "image",
+            "mime_type": "image/png",
+            "media_details": { "width": 10, "height": 10 },
+            "post": null,
+            "source_url": "http://example.com/wp-content/uploads/pixel.png",
+        }))
+        .unwrap();
+
+        assert_eq!(attachment.post, None);
+        assert_eq!(attachment.media_details["width"], 10);
+        assert_eq!(attachment.missing_image_sizes, None);
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Links, Meta, Rendered};
+
+/// A comment.
+///
+/// Fields which are not returned in the requested context, or which are left
+/// out using [`fields`](crate::fields), are left at their default values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Comment {
+    /// Unique identifier for the comment.
+    #[serde(default)]
+    pub id: u32,
+
+    /// The ID of the user object, if the author was a user.
+    #[serde(default)]
+    pub author: u32,
+
+    /// Email address for the comment author.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+
+    /// IP address for the comment author.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_ip: Option<String>,
+
+    /// Display name for the comment author.
+    #[serde(default)]
+    pub author_name: String,
+
+    /// URL for the comment author.
+    #[serde(default)]
+    pub author_url: String,
+
+    /// User agent for the comment author.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_user_agent: Option<String>,
+
+    /// The content for the comment.
+    ///
+    /// Includes the `raw` content in the `edit` context.
+    #[serde(default)]
+    pub content: Rendered,
+
+    /// The date the comment was published, in the site's timezone.
+    #[serde(default)]
+    pub date: String,
+
+    /// The date the comment was published, as GMT.
+    #[serde(default)]
+    pub date_gmt: String,
+
+    /// URL to the comment.
+    #[serde(default)]
+    pub link: String,
+
+    /// The ID for the parent of the comment.
+    #[serde(default)]
+    pub parent: u32,
+
+    /// The ID of the associated post object.
+    #[serde(default)]
+    pub post: u32,
+
+    /// State of the comment.
+    #[serde(default)]
+    pub status: String,
+
+    /// Type of the comment.
+    #[serde(rename = "type", default)]
+    pub comment_type: String,
+
+    /// Avatar URLs for the comment author, keyed by size in pixels.
+    #[serde(default)]
+    pub author_avatar_urls: BTreeMap<String, String>,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta: Meta,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
@@ -0,0 +1,119 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime, ParseError, TimeZone, Utc};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+const FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+/// A date and time as returned by WordPress, such as `2022-03-14T15:09:26`.
+///
+/// WordPress leaves out the timezone designator: fields such as `date` are in
+/// the site's timezone, while their `_gmt` counterparts are in UTC. Use
+/// [`and_utc`](Self::and_utc) for the latter and
+/// [`and_offset`](Self::and_offset) with the site's offset for the former.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WpDateTime(NaiveDateTime);
+
+impl WpDateTime {
+    pub fn new(datetime: NaiveDateTime) -> Self {
+        Self(datetime)
+    }
+
+    /// The date and time without a timezone.
+    pub fn naive(&self) -> NaiveDateTime {
+        self.0
+    }
+
+    /// Interpret the date and time as UTC, as for `_gmt` fields.
+    pub fn and_utc(&self) -> DateTime<Utc> {
+        self.0.and_utc()
+    }
+
+    /// Interpret the date and time in a timezone with a fixed offset, as for
+    /// fields in the site's timezone.
+    ///
+    /// Returns `None` if the offset is out of range.
+    pub fn and_offset(&self, offset_seconds: i32) -> Option<DateTime<FixedOffset>> {
+        FixedOffset::east_opt(offset_seconds)?
+            .from_local_datetime(&self.0)
+            .single()
+    }
+}
+
+impl From<NaiveDateTime> for WpDateTime {
+    fn from(datetime: NaiveDateTime) -> Self {
+        Self(datetime)
+    }
+}
+
+impl From<WpDateTime> for NaiveDateTime {
+    fn from(datetime: WpDateTime) -> Self {
+        datetime.0
+    }
+}
+
+impl FromStr for WpDateTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Dates sent by older versions and some plugins use a space.
+        NaiveDateTime::parse_from_str(s, FORMAT)
+            .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S"))
+            .map(Self)
+    }
+}
+
+impl fmt::Display for WpDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.format(FORMAT).fmt(f)
+    }
+}
+
+impl Serialize for WpDateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WpDateTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let date: WpDateTime = serde_json::from_value(json!("2022-03-14T15:09:26")).unwrap();
+
+        assert_eq!(date.to_string(), "2022-03-14T15:09:26");
+        assert_eq!(
+            serde_json::to_value(date).unwrap(),
+            json!("2022-03-14T15:09:26")
+        );
+        assert_eq!(date.and_utc().timestamp(), 1_647_270_566);
+        assert_eq!(
+            date.and_offset(9 * 3600).unwrap().to_rfc3339(),
+            "2022-03-14T15:09:26+09:00"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!("2022-03-14T15:09:26Z".parse::<WpDateTime>().is_err());
+        assert!(serde_json::from_value::<WpDateTime>(json!("today")).is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use std::collections::BTreeMap;
+
+use serde::{de, de::DeserializeOwned, Deserialize, Deserializer};
+use serde_json::Value;
+
+/// Deserialize a map which PHP encodes as an empty array when it is empty.
+pub(crate) fn php_map<'de, D, V>(deserializer: D) -> Result<BTreeMap<String, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: DeserializeOwned,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| {
+                serde_json::from_value(value)
+                    .map(|value| (key, value))
+                    .map_err(de::Error::custom)
+            })
+            .collect(),
+        Value::Array(array) if array.is_empty() => Ok(BTreeMap::new()),
+        other => Err(de::Error::custom(format!("expected a map: {}", other))),
+    }
+}
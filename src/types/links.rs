@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::de::php_map;
+
+/// The links of a resource, as returned in its `_links` field.
+///
+/// Links are grouped by their relation, such as `author`, `replies` or
+/// `wp:featuredmedia`. Use [`follow`](crate::follow) to request the target of
+/// a link.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Links(#[serde(deserialize_with = "php_map")] BTreeMap<String, Vec<Link>>);
+
+impl Links {
+    /// All links with a relation.
+    pub fn get(&self, rel: &str) -> &[Link] {
+        self.0.get(rel).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// The first link with a relation.
+    pub fn first(&self, rel: &str) -> Option<&Link> {
+        self.get(rel).first()
+    }
+
+    /// The link to the resource itself.
+    pub fn self_link(&self) -> Option<&Link> {
+        self.first("self")
+    }
+
+    /// The links to the authors of the resource.
+    pub fn author(&self) -> &[Link] {
+        self.get("author")
+    }
+
+    /// The links to the replies of the resource.
+    pub fn replies(&self) -> &[Link] {
+        self.get("replies")
+    }
+
+    /// The link to the featured media of the resource.
+    pub fn featured_media(&self) -> Option<&Link> {
+        self.first("wp:featuredmedia")
+    }
+
+    /// The links to the terms of the resource, one per taxonomy.
+    pub fn terms(&self) -> &[Link] {
+        self.get("wp:term")
+    }
+
+    /// Iterate over the relations and their links.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[Link])> {
+        self.0
+            .iter()
+            .map(|(rel, links)| (rel.as_str(), links.as_slice()))
+    }
+
+    /// Whether there are no links.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A link to another resource.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Link {
+    /// The URL of the target.
+    pub href: String,
+
+    /// Whether the target is included when embedding with `_embed`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub embeddable: bool,
+
+    /// Whether the URL is a template to be expanded before use.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub templated: bool,
+
+    /// Other attributes of the link, such as `taxonomy` for `wp:term` links.
+    #[serde(flatten)]
+    pub attributes: BTreeMap<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let links: Links = serde_json::from_value(json!({
+            "self": [{ "href": "http://example.com/wp-json/wp/v2/posts/1" }],
+            "author": [{
+                "embeddable": true,
+                "href": "http://example.com/wp-json/wp/v2/users/1",
+            }],
+            "wp:term": [
+                {
+                    "taxonomy": "category",
+                    "embeddable": true,
+                    "href": "http://example.com/wp-json/wp/v2/categories?post=1",
+                },
+                {
+                    "taxonomy": "post_tag",
+                    "embeddable": true,
+                    "href": "http://example.com/wp-json/wp/v2/tags?post=1",
+                },
+            ],
+            "curies": [{ "name": "wp", "href": "https://api.w.org/{rel}", "templated": true }],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            links.self_link().unwrap().href,
+            "http://example.com/wp-json/wp/v2/posts/1"
+        );
+        assert!(links.author()[0].embeddable);
+        assert_eq!(links.terms()[1].attributes["taxonomy"], "post_tag");
+        assert!(links.first("curies").unwrap().templated);
+        assert!(links.replies().is_empty());
+        assert!(serde_json::from_value::<Links>(json!([]))
+            .unwrap()
+            .is_empty());
+    }
+}
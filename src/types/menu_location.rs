@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// A navigation menu location.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MenuLocation {
+    /// The name of the menu location.
+    #[serde(default)]
+    pub name: String,
+
+    /// The description of the menu location.
+    #[serde(default)]
+    pub description: String,
+
+    /// The ID of the assigned menu.
+    ///
+    /// This is `0` when no menu is assigned to the location.
+    #[serde(default)]
+    pub menu: u32,
+}
+
+impl MenuLocation {
+    /// The ID of the assigned menu, if any.
+    pub fn menu(&self) -> Option<u32> {
+        Some(self.menu).filter(|&id| id != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn unassigned() {
+        let location: MenuLocation = serde_json::from_value(json!({
+            "name": "footer",
+            "description": "Footer Menu",
+            "menu": 0,
+        }))
+        .unwrap();
+        assert_eq!(location.menu(), None);
+    }
+}
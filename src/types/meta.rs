@@ -0,0 +1,181 @@
+use std::{collections::BTreeMap, fmt, marker::PhantomData};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use super::de::php_map;
+
+/// A registered meta key with a known value type.
+///
+/// WordPress only exposes meta keys which have been registered with
+/// `show_in_rest`, and silently ignores unregistered keys on writes. Keys
+/// registered with `single => false` hold a list of values.
+pub struct MetaKey<T> {
+    key: &'static str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> MetaKey<T> {
+    pub const fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The name of the meta key.
+    pub fn key(&self) -> &'static str {
+        self.key
+    }
+}
+
+impl<T> Clone for MetaKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MetaKey<T> {}
+
+impl<T> fmt::Debug for MetaKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MetaKey").field(&self.key).finish()
+    }
+}
+
+/// Meta fields of an object.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Meta(#[serde(deserialize_with = "php_map")] BTreeMap<String, Value>);
+
+impl Meta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the value of a registered meta key.
+    ///
+    /// Returns `Ok(None)` if the key is not present.
+    pub fn get<T>(&self, key: &MetaKey<T>) -> Result<Option<T>, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.0
+            .get(key.key)
+            .map(|value| T::deserialize(value))
+            .transpose()
+    }
+
+    /// Set the value of a registered meta key.
+    pub fn set<T>(&mut self, key: &MetaKey<T>, value: T) -> Result<&mut Self, serde_json::Error>
+    where
+        T: Serialize,
+    {
+        self.0.insert(key.key.into(), serde_json::to_value(value)?);
+        Ok(self)
+    }
+
+    /// Get the raw value of a meta key.
+    pub fn value(&self, key: &str) -> Option<&Value> {
+        self.0.get(key)
+    }
+
+    /// Insert a raw meta value.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<Value>) -> &mut Self {
+        self.0.insert(key.into(), value.into());
+        self
+    }
+
+    /// Remove a meta key, returning its raw value.
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.0.remove(key)
+    }
+
+    /// Whether the meta key is present.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.0.iter()
+    }
+}
+
+impl From<BTreeMap<String, Value>> for Meta {
+    fn from(map: BTreeMap<String, Value>) -> Self {
+        Self(map)
+    }
+}
+
+impl From<Meta> for BTreeMap<String, Value> {
+    fn from(meta: Meta) -> Self {
+        meta.0
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Meta
+where
+    K: Into<String>,
+    V: Into<Value>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    const SUBTITLE: MetaKey<String> = MetaKey::new("subtitle");
+    const RELATED: MetaKey<Vec<u32>> = MetaKey::new("related");
+
+    #[test]
+    fn round_trip() {
+        let mut meta = Meta::new();
+        meta.set(&SUBTITLE, "Hello".into())
+            .unwrap()
+            .set(&RELATED, vec![1, 2])
+            .unwrap();
+
+        let json = serde_json::to_value(&meta).unwrap();
+        assert_eq!(json, json!({ "related": [1, 2], "subtitle": "Hello" }));
+
+        let meta: Meta = serde_json::from_value(json).unwrap();
+        assert_eq!(meta.get(&SUBTITLE).unwrap().as_deref(), Some("Hello"));
+        assert_eq!(meta.get(&RELATED).unwrap(), Some(vec![1, 2]));
+    }
+
+    #[test]
+    fn missing_key() {
+        let meta = Meta::new();
+        assert_eq!(meta.get(&SUBTITLE).unwrap(), None);
+    }
+
+    #[test]
+    fn wrong_type() {
+        let meta: Meta = [("subtitle", 1)].into_iter().collect();
+        assert!(meta.get(&SUBTITLE).is_err());
+    }
+
+    #[test]
+    fn empty_array() {
+        let meta: Meta = serde_json::from_value(json!([])).unwrap();
+        assert!(meta.is_empty());
+    }
+}
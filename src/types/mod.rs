@@ -0,0 +1,44 @@
+pub use acf::AcfFields;
+pub use attachment::Attachment;
+pub use comment::Comment;
+#[cfg(feature = "chrono")]
+pub use datetime::WpDateTime;
+pub use links::{Link, Links};
+pub use menu_location::MenuLocation;
+pub use meta::{Meta, MetaKey};
+pub use oembed::OEmbed;
+pub use page::Page;
+pub use post::Post;
+pub use post_type::PostType;
+pub use rendered::Rendered;
+pub use route::{Namespace, Route, RouteArgument, RouteEndpoint};
+pub use search_result::{SearchResult, SearchResultId};
+pub use settings::SiteSettings;
+pub use site_index::SiteIndex;
+pub use taxonomy::Taxonomy;
+pub use term::{Term, TermNode};
+pub use user::User;
+pub use yoast::{YoastHead, YoastImage};
+
+pub mod acf;
+pub mod attachment;
+pub mod comment;
+#[cfg(feature = "chrono")]
+pub mod datetime;
+pub(crate) mod de;
+pub mod links;
+pub mod menu_location;
+pub mod meta;
+pub mod oembed;
+pub mod page;
+pub mod post;
+pub mod post_type;
+pub mod rendered;
+pub mod route;
+pub mod search_result;
+pub mod settings;
+pub mod site_index;
+pub mod taxonomy;
+pub mod term;
+pub mod user;
+pub mod yoast;
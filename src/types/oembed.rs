@@ -0,0 +1,163 @@
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// An oEmbed response, describing how to embed a URL.
+///
+/// Responses proxied from other providers do not always follow the oEmbed
+/// specification, so dimensions sent as strings are parsed and fields not
+/// sent are left at their default values.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OEmbed {
+    /// The type of the embed: `rich`, `video`, `photo` or `link`.
+    #[serde(rename = "type", default)]
+    pub embed_type: String,
+
+    /// The oEmbed version, which is `1.0`.
+    #[serde(default, deserialize_with = "version")]
+    pub version: String,
+
+    /// The title of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// The name of the author of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+
+    /// A URL for the author of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_url: Option<String>,
+
+    /// The name of the provider of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>,
+
+    /// The URL of the provider of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_url: Option<String>,
+
+    /// The HTML to embed, for `rich` and `video` embeds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+
+    /// The URL of the image, for `photo` embeds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// The width of the embed, in pixels.
+    #[serde(
+        default,
+        deserialize_with = "dimension",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub width: Option<u32>,
+
+    /// The height of the embed, in pixels.
+    #[serde(
+        default,
+        deserialize_with = "dimension",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub height: Option<u32>,
+
+    /// The URL of a thumbnail image of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+
+    /// The width of the thumbnail, in pixels.
+    #[serde(
+        default,
+        deserialize_with = "dimension",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub thumbnail_width: Option<u32>,
+
+    /// The height of the thumbnail, in pixels.
+    #[serde(
+        default,
+        deserialize_with = "dimension",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub thumbnail_height: Option<u32>,
+}
+
+/// Deserialize the version, which some providers send as a number.
+fn version<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::String(version) => Ok(version),
+        Value::Number(version) => Ok(format!("{:.1}", version.as_f64().unwrap_or_default())),
+        Value::Null => Ok(String::new()),
+        other => Err(de::Error::custom(format!("expected a version: {}", other))),
+    }
+}
+
+/// Deserialize a dimension, which may be a string, or `null` or an empty
+/// string when it is not known.
+fn dimension<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(None),
+        Value::String(s) if s.is_empty() => Ok(None),
+        Value::String(s) => s.parse().map(Some).map_err(de::Error::custom),
+        Value::Number(n) => match n.as_f64() {
+            Some(n) if n >= 0.0 && n <= f64::from(u32::MAX) => Ok(Some(n.round() as u32)),
+            _ => Err(de::Error::custom(format!("expected a dimension: {}", n))),
+        },
+        other => Err(de::Error::custom(format!(
+            "expected a dimension: {}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn rich() {
+        let embed: OEmbed = serde_json::from_value(json!({
+            "version": "1.0",
+            "provider_name": "Example",
+            "provider_url": "http://example.com",
+            "author_name": "admin",
+            "title": "Hello world!",
+            "type": "rich",
+            "width": 600,
+            "height": 338,
+            "html": "<blockquote class=\"wp-embedded-content\"></blockquote>",
+        }))
+        .unwrap();
+
+        assert_eq!(embed.embed_type, "rich");
+        assert_eq!(embed.width, Some(600));
+        assert_eq!(embed.thumbnail_url, None);
+    }
+
+    #[test]
+    fn lenient() {
+        let embed: OEmbed = serde_json::from_value(json!({
+            "version": 1.0,
+            "type": "video",
+            "width": "480",
+            "height": "",
+            "thumbnail_height": 360.0,
+            "thumbnail_width": null,
+        }))
+        .unwrap();
+
+        assert_eq!(embed.version, "1.0");
+        assert_eq!(embed.width, Some(480));
+        assert_eq!(embed.height, None);
+        assert_eq!(embed.thumbnail_width, None);
+        assert_eq!(embed.thumbnail_height, Some(360));
+    }
+}
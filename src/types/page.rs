@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AcfFields, Links, Meta, Rendered, YoastHead};
+
+/// A page.
+///
+/// Fields which are not returned in the requested context, or which are left
+/// out using [`fields`](crate::fields), are left at their default values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Page {
+    /// Unique identifier for the page.
+    #[serde(default)]
+    pub id: u32,
+
+    /// The date the page was published, in the site's timezone.
+    #[serde(default)]
+    pub date: String,
+
+    /// The date the page was published, as GMT.
+    #[serde(default)]
+    pub date_gmt: String,
+
+    /// The globally unique identifier for the page.
+    #[serde(default)]
+    pub guid: Rendered,
+
+    /// The date the page was last modified, in the site's timezone.
+    #[serde(default)]
+    pub modified: String,
+
+    /// The date the page was last modified, as GMT.
+    #[serde(default)]
+    pub modified_gmt: String,
+
+    /// An alphanumeric identifier for the page unique to its type.
+    #[serde(default)]
+    pub slug: String,
+
+    /// The slug automatically generated from the page title.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_slug: Option<String>,
+
+    /// Permalink template for the page.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permalink_template: Option<String>,
+
+    /// A password to protect access to the content and excerpt.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// A named status for the page.
+    #[serde(default)]
+    pub status: String,
+
+    /// Type of post.
+    #[serde(rename = "type", default)]
+    pub post_type: String,
+
+    /// URL to the page.
+    #[serde(default)]
+    pub link: String,
+
+    /// The title for the page.
+    ///
+    /// Includes the `raw` title in the `edit` context.
+    #[serde(default)]
+    pub title: Rendered,
+
+    /// The content for the page.
+    ///
+    /// Includes the `raw` content in the `edit` context.
+    #[serde(default)]
+    pub content: Rendered,
+
+    /// The excerpt for the page.
+    #[serde(default)]
+    pub excerpt: Rendered,
+
+    /// The ID for the author of the page.
+    #[serde(default)]
+    pub author: u32,
+
+    /// The ID of the featured media for the page.
+    #[serde(default)]
+    pub featured_media: u32,
+
+    /// The ID for the parent of the page.
+    #[serde(default)]
+    pub parent: u32,
+
+    /// The order of the page in relation to other pages.
+    #[serde(default)]
+    pub menu_order: i32,
+
+    /// Whether or not comments are open on the page.
+    #[serde(default)]
+    pub comment_status: String,
+
+    /// Whether or not the page can be pinged.
+    #[serde(default)]
+    pub ping_status: String,
+
+    /// The theme file to use to display the page.
+    #[serde(default)]
+    pub template: String,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta: Meta,
+
+    /// Advanced Custom Fields, if ACF exposes any.
+    #[serde(default, skip_serializing_if = "AcfFields::is_empty")]
+    pub acf: AcfFields,
+
+    /// The meta tags Yoast SEO generates for the page, as HTML.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yoast_head: Option<String>,
+
+    /// The meta tags Yoast SEO generates for the page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yoast_head_json: Option<YoastHead>,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AcfFields, Links, Meta, Rendered, YoastHead};
+
+/// A post.
+///
+/// Fields which are not returned in the requested context, or which are left
+/// out using [`fields`](crate::fields), are left at their default values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Post {
+    /// Unique identifier for the post.
+    #[serde(default)]
+    pub id: u32,
+
+    /// The date the post was published, in the site's timezone.
+    #[serde(default)]
+    pub date: String,
+
+    /// The date the post was published, as GMT.
+    #[serde(default)]
+    pub date_gmt: String,
+
+    /// The globally unique identifier for the post.
+    #[serde(default)]
+    pub guid: Rendered,
+
+    /// The date the post was last modified, in the site's timezone.
+    #[serde(default)]
+    pub modified: String,
+
+    /// The date the post was last modified, as GMT.
+    #[serde(default)]
+    pub modified_gmt: String,
+
+    /// An alphanumeric identifier for the post unique to its type.
+    #[serde(default)]
+    pub slug: String,
+
+    /// The slug automatically generated from the post title.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_slug: Option<String>,
+
+    /// Permalink template for the post.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permalink_template: Option<String>,
+
+    /// A named status for the post.
+    #[serde(default)]
+    pub status: String,
+
+    /// Type of post.
+    #[serde(rename = "type", default)]
+    pub post_type: String,
+
+    /// URL to the post.
+    #[serde(default)]
+    pub link: String,
+
+    /// The title for the post.
+    ///
+    /// Includes the `raw` title in the `edit` context.
+    #[serde(default)]
+    pub title: Rendered,
+
+    /// The content for the post.
+    ///
+    /// Includes the `raw` content in the `edit` context.
+    #[serde(default)]
+    pub content: Rendered,
+
+    /// The excerpt for the post.
+    #[serde(default)]
+    pub excerpt: Rendered,
+
+    /// The ID for the author of the post.
+    #[serde(default)]
+    pub author: u32,
+
+    /// The ID of the featured media for the post.
+    #[serde(default)]
+    pub featured_media: u32,
+
+    /// Whether or not comments are open on the post.
+    #[serde(default)]
+    pub comment_status: String,
+
+    /// Whether or not the post can be pinged.
+    #[serde(default)]
+    pub ping_status: String,
+
+    /// Whether or not the post should be treated as sticky.
+    #[serde(default)]
+    pub sticky: bool,
+
+    /// The theme file to use to display the post.
+    #[serde(default)]
+    pub template: String,
+
+    /// The format for the post.
+    #[serde(default)]
+    pub format: String,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta: Meta,
+
+    /// Advanced Custom Fields, if ACF exposes any.
+    #[serde(default, skip_serializing_if = "AcfFields::is_empty")]
+    pub acf: AcfFields,
+
+    /// The meta tags Yoast SEO generates for the post, as HTML.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yoast_head: Option<String>,
+
+    /// The meta tags Yoast SEO generates for the post.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yoast_head_json: Option<YoastHead>,
+
+    /// The terms assigned to the post in the category taxonomy.
+    #[serde(default)]
+    pub categories: Vec<u32>,
+
+    /// The terms assigned to the post in the post_tag taxonomy.
+    #[serde(default)]
+    pub tags: Vec<u32>,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
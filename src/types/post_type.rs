@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+fn default_rest_namespace() -> String {
+    "wp/v2".into()
+}
+
+/// A post type.
+///
+/// Fields which are not returned, e.g. when limited using
+/// [`fields`](crate::fields), are left at their default values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostType {
+    /// The title for the post type.
+    #[serde(default)]
+    pub name: String,
+
+    /// An alphanumeric identifier for the post type.
+    #[serde(default)]
+    pub slug: String,
+
+    /// A human-readable description of the post type.
+    #[serde(default)]
+    pub description: String,
+
+    /// Whether or not the post type should have children.
+    #[serde(default)]
+    pub hierarchical: bool,
+
+    /// If the value is a string, the value will be used as the archive slug.
+    /// If the value is `false` the post type has no archive.
+    #[serde(default)]
+    pub has_archive: serde_json::Value,
+
+    /// Taxonomies associated with the post type.
+    #[serde(default)]
+    pub taxonomies: Vec<String>,
+
+    /// REST base route for the post type.
+    #[serde(default)]
+    pub rest_base: String,
+
+    /// REST namespace route for the post type.
+    #[serde(default = "default_rest_namespace")]
+    pub rest_namespace: String,
+
+    /// The icon for the post type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+
+    /// All capabilities used by the post type.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<BTreeMap<String, String>>,
+
+    /// Human-readable labels for the post type for various contexts.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<BTreeMap<String, Option<String>>>,
+
+    /// All features supported by the post type.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supports: Option<BTreeMap<String, serde_json::Value>>,
+
+    /// Whether or not the post type can be viewed.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewable: Option<bool>,
+}
+
+impl PostType {
+    /// The route for the collection of posts of this type.
+    pub fn route(&self) -> String {
+        format!("/{}/{}", self.rest_namespace, self.rest_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let post_type: PostType = serde_json::from_value(json!({
+            "description": "",
+            "hierarchical": false,
+            "has_archive": "books",
+            "name": "Books",
+            "slug": "book",
+            "icon": "dashicons-book",
+            "taxonomies": ["genre"],
+            "rest_base": "books",
+            "rest_namespace": "wp/v2",
+        }))
+        .unwrap();
+
+        assert_eq!(post_type.has_archive, json!("books"));
+        assert_eq!(post_type.route(), "/wp/v2/books");
+        assert_eq!(post_type.supports, None);
+    }
+}
@@ -0,0 +1,284 @@
+use serde::{Deserialize, Serialize};
+
+/// A field holding rendered HTML, such as the title or content of a post.
+///
+/// The `raw` value is only available in the `edit` context, and `protected`
+/// is only returned for the content and excerpt of posts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rendered {
+    /// The HTML of the field, as displayed on the site.
+    #[serde(default)]
+    pub rendered: String,
+
+    /// The field as stored in the database.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+
+    /// Whether the field is protected by a password.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protected: Option<bool>,
+}
+
+impl Rendered {
+    /// The rendered HTML.
+    pub fn as_html(&self) -> &str {
+        &self.rendered
+    }
+
+    /// The raw value, if requested in the `edit` context.
+    pub fn raw(&self) -> Option<&str> {
+        self.raw.as_deref()
+    }
+
+    /// Whether the field is protected by a password.
+    pub fn is_protected(&self) -> bool {
+        self.protected.unwrap_or(false)
+    }
+
+    /// The rendered HTML as plain text.
+    ///
+    /// Tags and comments are removed, along with the content of scripts and
+    /// styles, entities are decoded and runs of whitespace are collapsed
+    /// into single spaces.
+    pub fn to_plaintext(&self) -> String {
+        let text = decode_entities(&strip_tags(&self.rendered));
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    /// The first words of the plain text, ending with `…` when some are cut,
+    /// such as for a summary of the excerpt or content of a post.
+    pub fn summary(&self, words: usize) -> String {
+        let text = self.to_plaintext();
+        let mut summary = text.split(' ').take(words).collect::<Vec<_>>().join(" ");
+        if summary.len() < text.len() {
+            summary.push('\u{2026}');
+        }
+        summary
+    }
+}
+
+/// Remove the tags of HTML, replacing block-level elements and line breaks
+/// with spaces so that the text of adjacent blocks stays apart.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let is_tag = rest[1..]
+            .starts_with(|c: char| c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?'));
+        if !is_tag {
+            text.push('<');
+            rest = &rest[1..];
+            continue;
+        }
+
+        let end = tag_end(rest);
+        let name: String = rest[1..end]
+            .chars()
+            .take_while(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_ascii_lowercase();
+        rest = &rest[end..];
+        if matches!(name.as_str(), "script" | "style") {
+            let closing = format!("</{}", name);
+            rest = match rest.to_ascii_lowercase().find(&closing) {
+                Some(close) => &rest[close + tag_end(&rest[close..])..],
+                None => "",
+            };
+        }
+        if is_block(&name) {
+            text.push(' ');
+        }
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Whether an element separates the text around it, unlike inline elements
+/// such as links and emphasis.
+fn is_block(name: &str) -> bool {
+    matches!(
+        name,
+        "address"
+            | "article"
+            | "aside"
+            | "blockquote"
+            | "br"
+            | "caption"
+            | "dd"
+            | "details"
+            | "div"
+            | "dl"
+            | "dt"
+            | "figcaption"
+            | "figure"
+            | "footer"
+            | "form"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "header"
+            | "hr"
+            | "li"
+            | "main"
+            | "nav"
+            | "ol"
+            | "p"
+            | "pre"
+            | "section"
+            | "summary"
+            | "table"
+            | "td"
+            | "th"
+            | "tr"
+            | "ul"
+    )
+}
+
+/// The length of the tag at the start of HTML, up to its `>` outside of
+/// quoted attribute values.
+fn tag_end(tag: &str) -> usize {
+    let mut quote = None;
+    for (i, c) in tag.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (None, '>') => return i + 1,
+            _ => {}
+        }
+    }
+    tag.len()
+}
+
+/// Decode the numeric entities and the named entities common in rendered
+/// content, keeping others as they are.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((entity(&rest[1..=end])?, end + 2)));
+        match entity {
+            Some((c, len)) => {
+                decoded.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn entity(name: &str) -> Option<char> {
+    let c = match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "hellip" => '\u{2026}',
+        "ndash" => '\u{2013}',
+        "mdash" => '\u{2014}',
+        "lsquo" => '\u{2018}',
+        "rsquo" => '\u{2019}',
+        "ldquo" => '\u{201c}',
+        "rdquo" => '\u{201d}',
+        "laquo" => '\u{ab}',
+        "raquo" => '\u{bb}',
+        "bull" => '\u{2022}',
+        "middot" => '\u{b7}',
+        "copy" => '\u{a9}',
+        "reg" => '\u{ae}',
+        "trade" => '\u{2122}',
+        "deg" => '\u{b0}',
+        "times" => '\u{d7}',
+        "euro" => '\u{20ac}',
+        "pound" => '\u{a3}',
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+    Some(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let content: Rendered = serde_json::from_value(json!({
+            "raw": "<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->",
+            "rendered": "<p>Hi</p>\n",
+            "protected": false,
+            "block_version": 1,
+        }))
+        .unwrap();
+
+        assert_eq!(content.as_html(), "<p>Hi</p>\n");
+        assert_eq!(
+            content.raw(),
+            Some("<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->")
+        );
+        assert!(!content.is_protected());
+    }
+
+    fn rendered(html: &str) -> Rendered {
+        Rendered {
+            rendered: html.into(),
+            ..Rendered::default()
+        }
+    }
+
+    #[test]
+    fn plaintext() {
+        let excerpt = rendered(
+            "<p>Fish &amp; chips&nbsp;&#8211; <a href=\"/x\" title=\"a > b\">the&nbsp;best</a>\
+             &hellip;</p>\n<style>p { color: red }</style><p>1 < 2 &unknown;</p>\
+             <!-- note --><script type=\"text/javascript\">alert('<p>')</SCRIPT><br/>End",
+        );
+
+        assert_eq!(
+            excerpt.to_plaintext(),
+            "Fish & chips \u{2013} the best\u{2026} 1 < 2 &unknown; End"
+        );
+        assert_eq!(
+            rendered("<p>wo<em>r</em>d</p><ul><li>one</li><li>two</li></ul>").to_plaintext(),
+            "word one two"
+        );
+    }
+
+    #[test]
+    fn summary() {
+        let excerpt = rendered("<p>One two\n three <em>four</em></p>\n");
+
+        assert_eq!(excerpt.summary(2), "One two\u{2026}");
+        assert_eq!(excerpt.summary(4), "One two three four");
+        assert_eq!(rendered("").summary(3), "");
+    }
+}
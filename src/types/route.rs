@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::de::php_map;
+
+/// The routes registered under an API namespace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Namespace {
+    /// The namespace name.
+    pub namespace: String,
+
+    /// Routes registered under the namespace, keyed by route pattern.
+    #[serde(default, deserialize_with = "php_map")]
+    pub routes: BTreeMap<String, Route>,
+}
+
+impl Namespace {
+    /// Look up a route by its pattern.
+    pub fn route(&self, pattern: &str) -> Option<&Route> {
+        self.routes.get(pattern)
+    }
+}
+
+/// A route registered with the API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Route {
+    /// The namespace the route belongs to.
+    pub namespace: String,
+
+    /// HTTP methods supported by the route.
+    #[serde(default)]
+    pub methods: Vec<String>,
+
+    /// Endpoints registered for the route.
+    #[serde(default)]
+    pub endpoints: Vec<RouteEndpoint>,
+}
+
+impl Route {
+    /// Whether the route supports an HTTP method.
+    pub fn supports(&self, method: &Method) -> bool {
+        self.methods.iter().any(|m| m == method.as_str())
+    }
+}
+
+/// An endpoint registered for a route.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteEndpoint {
+    /// HTTP methods handled by the endpoint.
+    pub methods: Vec<String>,
+
+    /// Arguments accepted by the endpoint, keyed by name.
+    #[serde(default, deserialize_with = "php_map")]
+    pub args: BTreeMap<String, RouteArgument>,
+}
+
+/// The schema of an endpoint argument.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteArgument {
+    /// A description of the argument.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The JSON schema type of the argument.
+    ///
+    /// This is either a single type name or a list of type names.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub arg_type: Option<Value>,
+
+    /// Whether the argument is required.
+    #[serde(default)]
+    pub required: bool,
+
+    /// The default value of the argument.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+
+    /// The remaining JSON schema keywords of the argument.
+    #[serde(flatten)]
+    pub schema: BTreeMap<String, Value>,
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::search::SearchType;
+
+/// The identifier of a search result.
+///
+/// Posts and terms are identified by a numeric ID, while post formats are
+/// identified by their slug.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SearchResultId {
+    /// A numeric object ID.
+    Id(u32),
+
+    /// A slug.
+    Slug(String),
+}
+
+/// An item returned by the search endpoint.
+///
+/// Fields which are not returned, e.g. when limited using
+/// [`fields`](crate::fields), are left empty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchResult {
+    /// Unique identifier for the object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<SearchResultId>,
+
+    /// The title for the object.
+    #[serde(default)]
+    pub title: String,
+
+    /// URL to the object.
+    #[serde(default)]
+    pub url: String,
+
+    /// Object type.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub result_type: Option<SearchType>,
+
+    /// Object subtype.
+    #[serde(default)]
+    pub subtype: String,
+}
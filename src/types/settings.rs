@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Site settings.
+///
+/// Fields which are not set are omitted when updating the settings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SiteSettings {
+    /// Site title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Site tagline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Site URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// This address is used for admin purposes, like new user notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// A city in the same timezone as you.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// A date format for all date strings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_format: Option<String>,
+
+    /// A time format for all time strings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_format: Option<String>,
+
+    /// A day number of the week that the week should start on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_of_week: Option<u8>,
+
+    /// WordPress locale code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    /// Convert emoticons like `:-)` and `:-P` to graphics on display.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_smilies: Option<bool>,
+
+    /// Default post category.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_category: Option<u32>,
+
+    /// Default post format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_post_format: Option<String>,
+
+    /// Blog pages show at most.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub posts_per_page: Option<u32>,
+
+    /// What to show on the front page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_on_front: Option<String>,
+
+    /// The ID of the page that should be displayed on the front page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_on_front: Option<u32>,
+
+    /// The ID of the page that should display the latest posts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_for_posts: Option<u32>,
+
+    /// Allow link notifications from other blogs (pingbacks and trackbacks)
+    /// on new articles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_ping_status: Option<String>,
+
+    /// Allow people to submit comments on new posts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_comment_status: Option<String>,
+
+    /// Site logo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub site_logo: Option<u32>,
+
+    /// Site icon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub site_icon: Option<u32>,
+
+    /// Settings registered by themes and plugins.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn omit_unset() {
+        let settings = SiteSettings {
+            title: Some("My Site".into()),
+            posts_per_page: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(settings).unwrap(),
+            json!({ "title": "My Site", "posts_per_page": 20 })
+        );
+    }
+
+    #[test]
+    fn extra() {
+        let settings: SiteSettings = serde_json::from_value(json!({
+            "title": "My Site",
+            "my_plugin_option": "value",
+        }))
+        .unwrap();
+        assert_eq!(settings.title.as_deref(), Some("My Site"));
+        assert_eq!(settings.extra["my_plugin_option"], json!("value"));
+    }
+}
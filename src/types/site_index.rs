@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use super::{de::php_map, Route};
+
+/// The API index of a site.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SiteIndex {
+    /// Site title.
+    pub name: String,
+
+    /// Site tagline.
+    #[serde(default)]
+    pub description: String,
+
+    /// Site URL.
+    pub url: String,
+
+    /// Home URL.
+    pub home: String,
+
+    /// Offset from UTC in hours.
+    #[serde(default, deserialize_with = "deserialize_gmt_offset")]
+    pub gmt_offset: f64,
+
+    /// A city in the same timezone as the site.
+    #[serde(default)]
+    pub timezone_string: String,
+
+    /// Namespaces registered with the API.
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+
+    /// Authentication methods supported by the API, keyed by name.
+    #[serde(default, deserialize_with = "php_map")]
+    pub authentication: BTreeMap<String, Value>,
+
+    /// Routes registered with the API, keyed by route pattern.
+    #[serde(default, deserialize_with = "php_map")]
+    pub routes: BTreeMap<String, Route>,
+}
+
+impl SiteIndex {
+    /// Whether a namespace is registered with the API.
+    pub fn supports_namespace(&self, namespace: &str) -> bool {
+        self.namespaces.iter().any(|ns| ns == namespace)
+    }
+}
+
+/// WordPress returns the offset as either a number or a numeric string.
+fn deserialize_gmt_offset<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Number(number) => number
+            .as_f64()
+            .ok_or_else(|| de::Error::custom("invalid gmt_offset")),
+        Value::String(string) if string.is_empty() => Ok(0.0),
+        Value::String(string) => string.parse().map_err(de::Error::custom),
+        Value::Null => Ok(0.0),
+        other => Err(de::Error::custom(format!("invalid gmt_offset: {}", other))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn empty_authentication() {
+        let index: SiteIndex = serde_json::from_value(json!({
+            "name": "My Site",
+            "url": "https://example.com",
+            "home": "https://example.com",
+            "gmt_offset": 5.5,
+            "authentication": [],
+        }))
+        .unwrap();
+
+        assert_eq!(index.gmt_offset, 5.5);
+        assert!(index.authentication.is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::terms::list::{ListTerms, ListTermsBuilder};
+
+fn default_rest_namespace() -> String {
+    "wp/v2".into()
+}
+
+/// A taxonomy.
+///
+/// Fields which are not returned, e.g. when limited using
+/// [`fields`](crate::fields), are left at their default values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Taxonomy {
+    /// The title for the taxonomy.
+    #[serde(default)]
+    pub name: String,
+
+    /// An alphanumeric identifier for the taxonomy.
+    #[serde(default)]
+    pub slug: String,
+
+    /// A human-readable description of the taxonomy.
+    #[serde(default)]
+    pub description: String,
+
+    /// Types associated with the taxonomy.
+    #[serde(default)]
+    pub types: Vec<String>,
+
+    /// Whether or not the taxonomy should have children.
+    #[serde(default)]
+    pub hierarchical: bool,
+
+    /// REST base route for the taxonomy.
+    #[serde(default)]
+    pub rest_base: String,
+
+    /// REST namespace route for the taxonomy.
+    #[serde(default = "default_rest_namespace")]
+    pub rest_namespace: String,
+
+    /// All capabilities used by the taxonomy.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<BTreeMap<String, String>>,
+
+    /// Human-readable labels for the taxonomy for various contexts.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<BTreeMap<String, Option<String>>>,
+
+    /// Whether or not the term cloud should be displayed.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_cloud: Option<bool>,
+
+    /// The visibility settings for the taxonomy.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<BTreeMap<String, bool>>,
+}
+
+impl Taxonomy {
+    /// Create a builder for listing the terms of this taxonomy.
+    pub fn list_terms(&self) -> ListTermsBuilder {
+        let mut builder = ListTerms::builder();
+        builder
+            .taxonomy(self.rest_base.clone())
+            .namespace(self.rest_namespace.clone());
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::Endpoint;
+
+    fn genre() -> Taxonomy {
+        serde_json::from_value(json!({
+            "name": "Genres",
+            "slug": "genre",
+            "description": "",
+            "types": ["book"],
+            "hierarchical": true,
+            "rest_base": "genres",
+            "rest_namespace": "acme/v1",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn deserialize() {
+        let taxonomy = genre();
+        assert_eq!(taxonomy.rest_base, "genres");
+        assert_eq!(taxonomy.types, vec!["book".to_string()]);
+        assert!(taxonomy.hierarchical);
+        assert_eq!(taxonomy.labels, None);
+    }
+
+    #[test]
+    fn list_terms() {
+        let endpoint = genre().list_terms().per_page(10).build().unwrap();
+        assert_eq!(endpoint.route(), "/acme/v1/genres");
+    }
+}
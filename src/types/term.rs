@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{AcfFields, Links, Meta};
+
+/// A term, such as a category or a tag.
+///
+/// Fields which are not returned, e.g. when limited using
+/// [`fields`](crate::fields), are left at their default values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Term {
+    /// Unique identifier for the term.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Number of published posts for the term.
+    #[serde(default)]
+    pub count: u32,
+
+    /// HTML description of the term.
+    #[serde(default)]
+    pub description: String,
+
+    /// URL of the term.
+    #[serde(default)]
+    pub link: String,
+
+    /// HTML title for the term.
+    #[serde(default)]
+    pub name: String,
+
+    /// An alphanumeric identifier for the term unique to its type.
+    #[serde(default)]
+    pub slug: String,
+
+    /// Type attribution for the term.
+    #[serde(default)]
+    pub taxonomy: String,
+
+    /// The parent term ID, or 0 for top-level terms and terms of
+    /// non-hierarchical taxonomies.
+    #[serde(default)]
+    pub parent: u32,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta: Meta,
+
+    /// Advanced Custom Fields, if ACF exposes any.
+    #[serde(default, skip_serializing_if = "AcfFields::is_empty")]
+    pub acf: AcfFields,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
+
+/// A term with its child terms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TermNode {
+    /// The term.
+    pub term: Term,
+
+    /// The terms whose parent is this term, in their original order.
+    pub children: Vec<TermNode>,
+}
+
+impl TermNode {
+    /// Arrange terms into trees using their `parent`.
+    ///
+    /// Terms whose parent is not among the given terms become roots, so a
+    /// partial list still yields every term once.
+    pub fn tree(terms: Vec<Term>) -> Vec<TermNode> {
+        let ids: Vec<u32> = terms.iter().map(|term| term.id).collect();
+        let mut roots = Vec::new();
+        let mut children: HashMap<u32, Vec<Term>> = HashMap::new();
+        for term in terms {
+            if term.parent != 0 && term.parent != term.id && ids.contains(&term.parent) {
+                children.entry(term.parent).or_default().push(term);
+            } else {
+                roots.push(term);
+            }
+        }
+
+        fn node(term: Term, children: &mut HashMap<u32, Vec<Term>>) -> TermNode {
+            let nested = children.remove(&term.id).unwrap_or_default();
+            TermNode {
+                children: nested
+                    .into_iter()
+                    .map(|child| node(child, children))
+                    .collect(),
+                term,
+            }
+        }
+
+        roots
+            .into_iter()
+            .map(|term| node(term, &mut children))
+            .collect()
+    }
+
+    /// Iterate over this term and its descendants, depth first.
+    pub fn iter(&self) -> impl Iterator<Item = &Term> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(&node.term)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    fn term(id: u32, parent: u32) -> Term {
+        serde_json::from_value(json!({ "id": id, "parent": parent })).unwrap()
+    }
+
+    #[test]
+    fn tree() {
+        let tree = TermNode::tree(vec![
+            term(3, 1),
+            term(1, 0),
+            term(4, 3),
+            term(2, 0),
+            term(5, 1),
+            term(6, 99),
+        ]);
+
+        let roots: Vec<u32> = tree.iter().map(|node| node.term.id).collect();
+        assert_eq!(roots, [1, 2, 6]);
+        let order: Vec<u32> = tree[0].iter().map(|term| term.id).collect();
+        assert_eq!(order, [1, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{AcfFields, Links, Meta};
+
+/// A user.
+///
+/// Fields which are not returned in the requested context, or which are left
+/// out using [`fields`](crate::fields), are left at their default values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    /// Unique identifier for the user.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Login name for the user.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Display name for the user.
+    #[serde(default)]
+    pub name: String,
+
+    /// First name for the user.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+
+    /// Last name for the user.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    /// The email address for the user.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// URL of the user.
+    #[serde(default)]
+    pub url: String,
+
+    /// Description of the user.
+    #[serde(default)]
+    pub description: String,
+
+    /// Author URL of the user.
+    #[serde(default)]
+    pub link: String,
+
+    /// Locale for the user.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+
+    /// The nickname for the user.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+
+    /// An alphanumeric identifier for the user.
+    #[serde(default)]
+    pub slug: String,
+
+    /// Registration date for the user.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registered_date: Option<String>,
+
+    /// Roles assigned to the user.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<String>>,
+
+    /// All capabilities assigned to the user.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<BTreeMap<String, bool>>,
+
+    /// Any extra capabilities assigned to the user.
+    ///
+    /// Only available in the `edit` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_capabilities: Option<BTreeMap<String, bool>>,
+
+    /// Avatar URLs for the user, keyed by size in pixels.
+    #[serde(default)]
+    pub avatar_urls: BTreeMap<String, String>,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta: Meta,
+
+    /// Advanced Custom Fields, if ACF exposes any.
+    #[serde(default, skip_serializing_if = "AcfFields::is_empty")]
+    pub acf: AcfFields,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn view_context() {
+        let user: User = serde_json::from_value(json!({
+            "id": 1,
+            "name": "admin",
+            "url": "",
+            "description": "",
+            "link": "http://example.com/author/admin/",
+            "slug": "admin",
+            "avatar_urls": { "24": "http://example.com/avatar-24.png" },
+            "meta": [],
+        }))
+        .unwrap();
+
+        assert_eq!(user.slug, "admin");
+        assert_eq!(user.email, None);
+        assert_eq!(user.roles, None);
+        assert_eq!(user.avatar_urls["24"], "http://example.com/avatar-24.png");
+    }
+
+    #[test]
+    fn edit_context() {
+        let user: User = serde_json::from_value(json!({
+            "id": 1,
+            "username": "admin",
+            "email": "admin@example.com",
+            "roles": ["administrator"],
+            "capabilities": { "manage_options": true },
+            "extra_capabilities": { "administrator": true },
+        }))
+        .unwrap();
+
+        assert_eq!(user.username.as_deref(), Some("admin"));
+        assert_eq!(user.roles, Some(vec!["administrator".to_string()]));
+        assert_eq!(user.capabilities.unwrap()["manage_options"], true);
+    }
+
+    #[test]
+    fn acf_fields() {
+        let user: User = serde_json::from_value(json!({
+            "id": 1,
+            "acf": { "twitter": "@admin" },
+        }))
+        .unwrap();
+
+        assert_eq!(user.acf.str("twitter"), Some("@admin"));
+
+        let user: User = serde_json::from_value(json!({ "id": 1, "acf": false })).unwrap();
+        assert!(user.acf.is_empty());
+        assert!(serde_json::to_value(user).unwrap().get("acf").is_none());
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The meta tags Yoast SEO generates for a page.
+///
+/// Yoast SEO adds these to posts and pages as `yoast_head_json`, alongside
+/// the same tags as HTML in `yoast_head`. Tags which Yoast does not output
+/// for a page are left at their default values.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct YoastHead {
+    /// The title of the page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// The meta description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Robots directives by name, such as `index` and `max-snippet`, with
+    /// their values, such as `noindex` and `max-snippet:-1`.
+    #[serde(default)]
+    pub robots: BTreeMap<String, String>,
+
+    /// The canonical URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canonical: Option<String>,
+
+    /// Open Graph locale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub og_locale: Option<String>,
+
+    /// Open Graph type, such as `article`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub og_type: Option<String>,
+
+    /// Open Graph title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub og_title: Option<String>,
+
+    /// Open Graph description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub og_description: Option<String>,
+
+    /// Open Graph URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub og_url: Option<String>,
+
+    /// Open Graph site name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub og_site_name: Option<String>,
+
+    /// Open Graph images.
+    #[serde(default)]
+    pub og_image: Vec<YoastImage>,
+
+    /// The date the article was published.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub article_published_time: Option<String>,
+
+    /// The date the article was last modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub article_modified_time: Option<String>,
+
+    /// The name of the author.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+
+    /// Twitter card type, such as `summary_large_image`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub twitter_card: Option<String>,
+
+    /// Twitter handle of the author.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub twitter_creator: Option<String>,
+
+    /// Twitter handle of the site.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub twitter_site: Option<String>,
+
+    /// Extra labels and values shown on Twitter cards, such as
+    /// `Est. reading time`.
+    #[serde(default)]
+    pub twitter_misc: BTreeMap<String, String>,
+
+    /// The schema.org graph of the page, as JSON-LD.
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub schema: Value,
+}
+
+/// An Open Graph image.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct YoastImage {
+    /// Image URL.
+    #[serde(default)]
+    pub url: String,
+
+    /// Width of the image, in pixels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+
+    /// Height of the image, in pixels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+
+    /// MIME type of the image.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let head: YoastHead = serde_json::from_value(json!({
+            "title": "Hello world! - Example",
+            "robots": {
+                "index": "index",
+                "follow": "follow",
+                "max-snippet": "max-snippet:-1",
+            },
+            "canonical": "http://example.com/hello-world/",
+            "og_type": "article",
+            "og_image": [{
+                "width": 1200,
+                "height": 675,
+                "url": "http://example.com/hello.jpg",
+                "type": "image/jpeg",
+            }],
+            "twitter_card": "summary_large_image",
+            "twitter_misc": { "Written by": "admin", "Est. reading time": "1 minute" },
+            "schema": { "@context": "https://schema.org", "@graph": [] },
+        }))
+        .unwrap();
+
+        assert_eq!(head.title.as_deref(), Some("Hello world! - Example"));
+        assert_eq!(head.robots["max-snippet"], "max-snippet:-1");
+        assert_eq!(head.og_image[0].mime_type.as_deref(), Some("image/jpeg"));
+        assert_eq!(head.twitter_misc["Written by"], "admin");
+        assert_eq!(head.schema["@context"], "https://schema.org");
+        assert_eq!(head.description, None);
+    }
+}
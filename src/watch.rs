@@ -0,0 +1,330 @@
+use std::{
+    collections::HashSet,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_util::{
+    stream::{self, BoxStream},
+    Stream, StreamExt,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    client::Client,
+    endpoints::{pages::list::ListPagesBuilder, posts::list::ListPostsBuilder, Order, OrderBy},
+    error::ApiError,
+    fluent::ListBuilder,
+    paged::paged,
+    params::{ParamValue, Timestamp},
+    query::Query,
+};
+
+type Changes<'a, T, E> = BoxStream<'a, Result<T, ApiError<E>>>;
+
+/// A list builder whose items can be watched for changes.
+pub trait Watchable: ListBuilder + Clone + Send {
+    /// Only list items modified after a date.
+    fn set_modified_after(&mut self, after: Timestamp);
+
+    /// Sort the items by their modification date.
+    fn set_order_by_modified(&mut self, order: Order);
+
+    /// The number of items listed per page.
+    fn set_per_page(&mut self, per_page: u32);
+}
+
+macro_rules! impl_watchable {
+    ($($ty:ty),*) => {
+        $(
+            impl Watchable for $ty {
+                fn set_modified_after(&mut self, after: Timestamp) {
+                    self.modified_after(after);
+                }
+
+                fn set_order_by_modified(&mut self, order: Order) {
+                    self.orderby(OrderBy::Modified).order(order);
+                }
+
+                fn set_per_page(&mut self, per_page: u32) {
+                    self.per_page(per_page);
+                }
+            }
+        )*
+    };
+}
+
+impl_watchable!(ListPostsBuilder, ListPagesBuilder);
+
+/// A stream of the items of a list which are created or modified, found by
+/// polling the list.
+///
+/// Each poll lists the items modified since the previous one, using
+/// `modified_after`, and yields the ones which were not seen yet with the
+/// same modification date. Items modified before the watch started are
+/// skipped unless [`since`](Self::since) is set.
+///
+/// An error is yielded when a poll fails, and polling carries on at the next
+/// interval.
+pub struct Watch<'a, C, B>
+where
+    C: Client,
+    B: ListBuilder,
+{
+    client: &'a C,
+    builder: B,
+    interval: Duration,
+    since: Option<Timestamp>,
+    stream: Option<Changes<'a, B::Item, C::Error>>,
+}
+
+impl<'a, C, B> Watch<'a, C, B>
+where
+    C: Client + Sync,
+    B: Watchable + 'a,
+    B::Item: DeserializeOwned + Send + 'static,
+{
+    /// Watch the items listed by a builder.
+    ///
+    /// Any `modified_after`, `orderby` and `order` set on the builder are
+    /// replaced.
+    pub fn new(client: &'a C, builder: &B) -> Self {
+        Self {
+            client,
+            builder: builder.clone(),
+            interval: Duration::from_secs(60),
+            since: None,
+            stream: None,
+        }
+    }
+
+    /// How long to wait between polls. Defaults to 60 seconds.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Yield the items modified after a date, in the site's timezone, on the
+    /// first poll.
+    pub fn since(mut self, since: impl Into<Timestamp>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
+    fn start(&mut self) -> Changes<'a, B::Item, C::Error> {
+        let poller = Poller {
+            client: self.client,
+            builder: self.builder.clone(),
+            after: self
+                .since
+                .as_ref()
+                .map(|since| since.as_value().into_owned()),
+            latest: None,
+            seen: HashSet::new(),
+            started: self.since.is_some(),
+        };
+        let interval = self.interval;
+        stream::unfold((poller, true), move |(mut poller, first)| async move {
+            if !first {
+                tokio::time::sleep(interval).await;
+            }
+            let items = match poller.poll().await {
+                Ok(items) => items.into_iter().map(Ok).collect(),
+                Err(err) => vec![Err(err)],
+            };
+            Some((stream::iter(items), (poller, false)))
+        })
+        .flatten()
+        .boxed()
+    }
+}
+
+impl<'a, C, B> Stream for Watch<'a, C, B>
+where
+    C: Client + Sync,
+    B: Watchable + Unpin + 'a,
+    B::Item: DeserializeOwned + Send + 'static,
+{
+    type Item = Result<B::Item, ApiError<C::Error>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.stream.is_none() {
+            self.stream = Some(self.start());
+        }
+        self.stream
+            .as_mut()
+            .expect("stream is started")
+            .poll_next_unpin(cx)
+    }
+}
+
+/// The state of a watch between polls.
+struct Poller<'a, C, B> {
+    client: &'a C,
+    builder: B,
+    /// The date after which items are listed.
+    after: Option<String>,
+    /// The latest modification date seen.
+    latest: Option<String>,
+    /// The IDs and modification dates of the items modified after `after`
+    /// which were yielded.
+    seen: HashSet<(u64, String)>,
+    /// Whether the first poll found where to start.
+    started: bool,
+}
+
+impl<C, B> Poller<'_, C, B>
+where
+    C: Client + Sync,
+    B: Watchable,
+    B::Item: DeserializeOwned + Send + 'static,
+{
+    async fn poll(&mut self) -> Result<Vec<B::Item>, ApiError<C::Error>> {
+        if !self.started {
+            self.start().await?;
+            return Ok(Vec::new());
+        }
+
+        let mut builder = self.builder.clone();
+        builder.set_order_by_modified(Order::Asc);
+        if let Some(after) = &self.after {
+            builder.set_modified_after(after.as_str().into());
+        }
+        let listed: Vec<Value> = paged(builder.finish()).query(self.client).await?;
+
+        let mut items = Vec::new();
+        for item in listed {
+            let Some(key) = key(&item) else {
+                continue;
+            };
+            if self.seen.contains(&key) {
+                continue;
+            }
+            let value = serde_json::from_value(item).map_err(ApiError::data_type::<B::Item>)?;
+            items.push(value);
+            self.seen.insert(key);
+        }
+
+        // Items modified in the same second as the latest one are listed by
+        // the next poll too, as `modified_after` excludes that second.
+        let latest = self
+            .seen
+            .iter()
+            .map(|(_, modified)| modified)
+            .max()
+            .cloned();
+        if latest > self.latest {
+            self.after = self.latest.take().or_else(|| self.after.take());
+            self.latest = latest;
+        }
+        if let Some(after) = &self.after {
+            self.seen.retain(|(_, modified)| modified > after);
+        }
+        Ok(items)
+    }
+
+    /// Start from the most recently modified item.
+    async fn start(&mut self) -> Result<(), ApiError<C::Error>> {
+        let mut builder = self.builder.clone();
+        builder.set_order_by_modified(Order::Desc);
+        builder.set_per_page(1);
+        let listed: Vec<Value> = builder.finish().query(self.client).await?;
+        if let Some(key) = listed.first().and_then(key) {
+            self.after = Some(key.1.clone());
+            self.latest = Some(key.1.clone());
+            self.seen.insert(key);
+        }
+        self.started = true;
+        Ok(())
+    }
+}
+
+/// The ID and modification date of an item.
+fn key(item: &Value) -> Option<(u64, String)> {
+    let id = item.get("id")?.as_u64()?;
+    let modified = item.get("modified")?.as_str()?;
+    Some((id, modified.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        endpoints::ListPosts,
+        test::{MockClient, MockResponse},
+        types::Post,
+    };
+
+    fn post(id: u64, modified: &str) -> Value {
+        json!({ "id": id, "modified": modified })
+    }
+
+    #[tokio::test]
+    async fn watch() {
+        let latest = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("per_page=1&order=desc&orderby=modified")
+            .json(json!([post(1, "2024-01-01T10:00:00")]))
+            .build()
+            .unwrap();
+        let changed = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("modified_after=2024-01-01T10%3A00%3A00&order=asc&orderby=modified&page=1&per_page=100")
+            .header("x-wp-totalpages", "1")
+            .json(json!([post(2, "2024-01-02T10:00:00"), post(3, "2024-01-02T10:00:00")]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_responses([latest, changed]);
+
+        let mut watch =
+            Watch::new(&client, &ListPosts::builder()).interval(Duration::from_millis(1));
+        let first: Post = watch.next().await.unwrap().unwrap();
+        let second: Post = watch.next().await.unwrap().unwrap();
+        let third = tokio::time::timeout(Duration::from_millis(50), watch.next()).await;
+
+        assert_eq!((first.id, second.id), (2, 3));
+        assert!(third.is_err());
+    }
+
+    #[tokio::test]
+    async fn since() {
+        let changed = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("modified_after=2024-01-01T00%3A00%3A00&order=asc&orderby=modified&page=1&per_page=100")
+            .header("x-wp-totalpages", "1")
+            .json(json!([post(1, "2024-01-01T10:00:00")]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(changed);
+
+        let mut watch = Watch::new(&client, ListPosts::builder().per_page(5))
+            .since("2024-01-01T00:00:00")
+            .interval(Duration::from_millis(1));
+        let first: Post = watch.next().await.unwrap().unwrap();
+
+        assert_eq!(first.id, 1);
+    }
+
+    #[tokio::test]
+    async fn error() {
+        let response = MockResponse::builder()
+            .route("/wp/v2/posts")
+            .query("per_page=1&order=desc&orderby=modified")
+            .status(http::StatusCode::INTERNAL_SERVER_ERROR)
+            .body("<html>")
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let mut watch =
+            Watch::new(&client, &ListPosts::builder()).interval(Duration::from_millis(1));
+
+        assert!(watch.next().await.unwrap().is_err());
+        assert!(watch.next().await.unwrap().is_err());
+    }
+}
@@ -0,0 +1,28 @@
+//! Endpoints and models for the WooCommerce REST API.
+//!
+//! The endpoints use the `wc/v3` namespace of WooCommerce 3.5 and later, and
+//! are queried with the same clients as the core endpoints. Amounts of money
+//! are exact [`Decimal`] numbers.
+
+pub use customers::{
+    CreateCustomer, CustomerOrderBy, DeleteCustomer, ListCustomers, RetrieveCustomer,
+    UpdateCustomer,
+};
+pub use orders::{
+    CreateOrderRefund, DeleteOrderRefund, ListOrderRefunds, ListOrders, OrderStatus,
+    RefundLineItem, RetrieveOrder, RetrieveOrderRefund, UpdateOrderStatus,
+};
+pub use products::{
+    CreateProduct, DeleteProduct, ListProductVariations, ListProducts, ProductOrderBy, ProductType,
+    RetrieveProduct, RetrieveProductVariation, StockStatus, UpdateProduct,
+};
+pub use types::{
+    Address, CouponLine, Customer, Decimal, Dimensions, FeeLine, LineItem, MetaData, Order,
+    ParseDecimalError, Product, ProductAttribute, ProductImage, ProductTerm, ProductVariation,
+    Refund, RefundSummary, ShippingLine, TaxLine, VariationAttribute,
+};
+
+pub mod customers;
+pub mod orders;
+pub mod products;
+pub mod types;
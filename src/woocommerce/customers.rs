@@ -0,0 +1,40 @@
+pub use create::CreateCustomer;
+pub use delete::DeleteCustomer;
+pub use list::ListCustomers;
+pub use retrieve::RetrieveCustomer;
+pub use update::UpdateCustomer;
+
+use crate::endpoints::common::impl_string_enum;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod retrieve;
+pub mod update;
+
+/// The attribute to sort a collection of customers by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CustomerOrderBy {
+    /// The customer ID.
+    Id,
+
+    /// The order of the IDs given with `include`.
+    Include,
+
+    /// The display name. This is the default.
+    Name,
+
+    /// The registration date.
+    RegisteredDate,
+
+    /// A value not known to this crate.
+    Unknown(String),
+}
+
+impl_string_enum!(CustomerOrderBy {
+    Id => "id",
+    Include => "include",
+    Name => "name",
+    RegisteredDate => "registered_date",
+});
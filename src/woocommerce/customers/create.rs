@@ -0,0 +1,106 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{
+    endpoint::prelude::*,
+    woocommerce::types::{Address, MetaData},
+};
+
+/// Create a customer.
+///
+/// A username and password are generated if not given, depending on the
+/// store's settings.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateCustomer {
+    /// The email address for the customer.
+    #[builder(setter(into))]
+    email: String,
+
+    /// Customer first name.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first_name: Option<String>,
+
+    /// Customer last name.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_name: Option<String>,
+
+    /// Customer login name.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+
+    /// Customer password.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+
+    /// Billing address.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    billing: Option<Address>,
+
+    /// Shipping address.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping: Option<Address>,
+
+    /// Meta fields.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta_data: Option<Vec<MetaData>>,
+}
+
+impl CreateCustomer {
+    pub fn builder() -> CreateCustomerBuilder {
+        CreateCustomerBuilder::default()
+    }
+}
+
+impl Endpoint for CreateCustomer {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wc/v3/customers".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+
+    #[test]
+    fn body() {
+        let endpoint = CreateCustomer::builder()
+            .email("john.doe@example.com")
+            .first_name("John")
+            .shipping(Address {
+                city: "San Francisco".into(),
+                country: "US".into(),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        let (_, body) = endpoint.body().unwrap().unwrap();
+        let body = serde_json::from_slice::<Json>(&body).unwrap();
+
+        assert_eq!(body["email"], "john.doe@example.com");
+        assert_eq!(body["first_name"], "John");
+        assert_eq!(body["shipping"]["city"], "San Francisco");
+        assert_eq!(body["shipping"].get("email"), None);
+        assert_eq!(body.get("billing"), None);
+        assert_eq!(body["shipping"]["country"], json!("US"));
+    }
+}
@@ -0,0 +1,74 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Delete a customer.
+///
+/// Customers cannot be trashed, so they are always deleted permanently. The
+/// response is the customer as it was before deletion.
+#[derive(Builder)]
+pub struct DeleteCustomer {
+    /// Unique identifier for the customer.
+    id: u32,
+
+    /// Reassign the deleted customer's posts and links to this user ID.
+    #[builder(setter(strip_option), default)]
+    reassign: Option<u32>,
+}
+
+impl DeleteCustomer {
+    pub fn builder() -> DeleteCustomerBuilder {
+        DeleteCustomerBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteCustomer {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/customers/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push("force", true)
+            .push_opt("reassign", self.reassign);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::Customer,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = DeleteCustomer::builder()
+            .id(25)
+            .reassign(1)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(Method::DELETE)
+            .route("/wc/v3/customers/25")
+            .query("force=true&reassign=1")
+            .json(json!({ "id": 25, "email": "john.doe@example.com" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let customer: Customer = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(customer.id, 25);
+    }
+}
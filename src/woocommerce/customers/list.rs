@@ -0,0 +1,117 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, woocommerce::customers::CustomerOrderBy};
+
+/// List customers.
+#[derive(Default, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListCustomers {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    order: Option<Order>,
+
+    /// Sort collection by customer attribute.
+    #[builder(setter(into))]
+    orderby: Option<CustomerOrderBy>,
+
+    /// Limit result set to customers with a specific email address.
+    #[builder(setter(into))]
+    email: Option<String>,
+
+    /// Limit result set to customers with a specific role, or `all`. Defaults
+    /// to `customer`.
+    #[builder(setter(into))]
+    role: Option<String>,
+}
+
+impl ListCustomers {
+    pub fn builder() -> ListCustomersBuilder {
+        ListCustomersBuilder::default()
+    }
+}
+
+impl Endpoint for ListCustomers {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wc/v3/customers".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_opt("email", self.email.as_ref())
+            .push_opt("role", self.role.as_ref());
+        params
+    }
+}
+
+impl Pageable for ListCustomers {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::Customer,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn email() {
+        let endpoint = ListCustomers::builder()
+            .email("john.doe@example.com")
+            .role("all")
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("email=john.doe%40example.com&role=all")
+            .json(json!([{ "id": 25, "email": "john.doe@example.com" }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let customers: Vec<Customer> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(customers[0].id, 25);
+    }
+}
@@ -0,0 +1,69 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a customer.
+#[derive(Builder)]
+pub struct RetrieveCustomer {
+    /// Unique identifier for the customer.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveCustomer {
+    pub fn builder() -> RetrieveCustomerBuilder {
+        RetrieveCustomerBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveCustomer {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/customers/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::Customer,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn typed() {
+        let endpoint = RetrieveCustomer::builder().id(25).build().unwrap();
+        let response = MockResponse::builder()
+            .route("/wc/v3/customers/25")
+            .json(json!({
+                "id": 25,
+                "billing": { "city": "San Francisco", "country": "US" },
+                "shipping": { "city": "San Francisco" },
+                "is_paying_customer": true,
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let customer: Customer = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(customer.billing.country, "US");
+        assert!(customer.is_paying_customer);
+    }
+}
@@ -0,0 +1,96 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{
+    endpoint::prelude::*,
+    woocommerce::types::{Address, MetaData},
+};
+
+/// Update a customer.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct UpdateCustomer {
+    /// Unique identifier for the customer.
+    #[serde(skip)]
+    id: u32,
+
+    /// The email address for the customer.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+
+    /// Customer first name.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first_name: Option<String>,
+
+    /// Customer last name.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_name: Option<String>,
+
+    /// Customer password.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+
+    /// Billing address.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    billing: Option<Address>,
+
+    /// Shipping address.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping: Option<Address>,
+
+    /// Meta fields.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta_data: Option<Vec<MetaData>>,
+}
+
+impl UpdateCustomer {
+    pub fn builder() -> UpdateCustomerBuilder {
+        UpdateCustomerBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateCustomer {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/customers/{}", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+
+    #[test]
+    fn body() {
+        let endpoint = UpdateCustomer::builder()
+            .id(25)
+            .meta_data(vec![MetaData::new("loyalty_tier", "gold")])
+            .build()
+            .unwrap();
+
+        let (_, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(endpoint.route(), "/wc/v3/customers/25");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "meta_data": [{ "key": "loyalty_tier", "value": "gold" }] })
+        );
+    }
+}
@@ -0,0 +1,67 @@
+pub use list::ListOrders;
+pub use refunds::{
+    CreateOrderRefund, DeleteOrderRefund, ListOrderRefunds, RefundLineItem, RetrieveOrderRefund,
+};
+pub use retrieve::RetrieveOrder;
+pub use update::UpdateOrderStatus;
+
+use crate::endpoints::common::impl_string_enum;
+
+pub mod list;
+pub mod refunds;
+pub mod retrieve;
+pub mod update;
+
+/// The status of an order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OrderStatus {
+    /// Awaiting payment.
+    Pending,
+
+    /// Paid and awaiting fulfillment.
+    Processing,
+
+    /// Awaiting action, such as a payment confirmation.
+    OnHold,
+
+    /// Fulfilled.
+    Completed,
+
+    /// Cancelled by an admin or the customer.
+    Cancelled,
+
+    /// Fully refunded.
+    Refunded,
+
+    /// The payment failed or was declined.
+    Failed,
+
+    /// In the trash.
+    Trash,
+
+    /// Created by the block checkout before it is submitted.
+    CheckoutDraft,
+
+    /// Orders of any status.
+    ///
+    /// Only valid as a filter when listing orders.
+    Any,
+
+    /// A status not known to this crate, such as one registered by an
+    /// extension.
+    Unknown(String),
+}
+
+impl_string_enum!(OrderStatus {
+    Pending => "pending",
+    Processing => "processing",
+    OnHold => "on-hold",
+    Completed => "completed",
+    Cancelled => "cancelled",
+    Refunded => "refunded",
+    Failed => "failed",
+    Trash => "trash",
+    CheckoutDraft => "checkout-draft",
+    Any => "any",
+});
@@ -0,0 +1,156 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, woocommerce::orders::OrderStatus};
+
+/// List orders.
+#[derive(Default, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListOrders {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Limit response to orders created after a given ISO8601 compliant date.
+    #[builder(setter(into))]
+    after: Option<Timestamp>,
+
+    /// Limit response to orders created before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    before: Option<Timestamp>,
+
+    /// Limit response to orders modified after a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    modified_after: Option<Timestamp>,
+
+    /// Limit response to orders modified before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    modified_before: Option<Timestamp>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    order: Option<Order>,
+
+    /// Sort collection by order attribute.
+    #[builder(setter(into))]
+    orderby: Option<OrderBy>,
+
+    /// Limit result set to orders with specific parent IDs.
+    #[builder(setter(into, strip_option = false))]
+    parent: Vec<u32>,
+
+    /// Limit result set to all orders except those of particular parent IDs.
+    #[builder(setter(into, strip_option = false))]
+    parent_exclude: Vec<u32>,
+
+    /// Limit result set to orders assigned one or more statuses.
+    #[builder(setter(into, strip_option = false))]
+    status: Vec<OrderStatus>,
+
+    /// Limit result set to orders assigned a specific customer.
+    customer: Option<u32>,
+
+    /// Limit result set to orders with a specific product.
+    product: Option<u32>,
+
+    /// Number of decimal points to use in amounts.
+    dp: Option<u32>,
+}
+
+impl ListOrders {
+    pub fn builder() -> ListOrdersBuilder {
+        ListOrdersBuilder::default()
+    }
+}
+
+impl Endpoint for ListOrders {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wc/v3/orders".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("after", self.after.as_ref())
+            .push_opt("before", self.before.as_ref())
+            .push_opt("modified_after", self.modified_after.as_ref())
+            .push_opt("modified_before", self.modified_before.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_list("parent", &self.parent)
+            .push_list("parent_exclude", &self.parent_exclude)
+            .push_list("status", &self.status)
+            .push_opt("customer", self.customer)
+            .push_opt("product", self.product)
+            .push_opt("dp", self.dp);
+        params
+    }
+}
+
+impl Pageable for ListOrders {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::Order as WcOrder,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn status() {
+        let endpoint = ListOrders::builder()
+            .status(vec![OrderStatus::Processing, OrderStatus::OnHold])
+            .customer(25)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("status=processing%2Con-hold&customer=25")
+            .json(json!([{ "id": 727, "status": "on-hold", "total": "29.35" }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let orders: Vec<WcOrder> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(orders[0].status, "on-hold");
+    }
+}
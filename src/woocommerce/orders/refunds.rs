@@ -0,0 +1,312 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, woocommerce::types::Decimal};
+
+/// List the refunds of an order.
+#[derive(Builder)]
+#[builder(setter(strip_option))]
+pub struct ListOrderRefunds {
+    /// Unique identifier for the order.
+    order_id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into), default)]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    #[builder(default)]
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    #[builder(default)]
+    per_page: Option<u32>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false), default)]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false), default)]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    #[builder(default)]
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    #[builder(default)]
+    order: Option<Order>,
+
+    /// Number of decimal points to use in amounts.
+    #[builder(default)]
+    dp: Option<u32>,
+}
+
+impl ListOrderRefunds {
+    pub fn builder() -> ListOrderRefundsBuilder {
+        ListOrderRefundsBuilder::default()
+    }
+}
+
+impl Endpoint for ListOrderRefunds {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/orders/{}/refunds", self.order_id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("dp", self.dp);
+        params
+    }
+}
+
+impl Pageable for ListOrderRefunds {}
+
+/// Retrieve a refund of an order.
+#[derive(Builder)]
+#[builder(setter(strip_option))]
+pub struct RetrieveOrderRefund {
+    /// Unique identifier for the order.
+    order_id: u32,
+
+    /// Unique identifier for the refund.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into), default)]
+    context: Option<Context>,
+
+    /// Number of decimal points to use in amounts.
+    #[builder(default)]
+    dp: Option<u32>,
+}
+
+impl RetrieveOrderRefund {
+    pub fn builder() -> RetrieveOrderRefundBuilder {
+        RetrieveOrderRefundBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveOrderRefund {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/orders/{}/refunds/{}", self.order_id, self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("dp", self.dp);
+        params
+    }
+}
+
+/// Refund an order.
+///
+/// By default, only a refund is recorded. Set `api_refund` to also refund
+/// the payment through the payment gateway, if it supports refunds.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateOrderRefund {
+    /// Unique identifier for the order.
+    #[serde(skip)]
+    order_id: u32,
+
+    /// Refund amount.
+    #[builder(setter(into))]
+    amount: Decimal,
+
+    /// Reason for the refund.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+
+    /// ID of the user creating the refund.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refunded_by: Option<u32>,
+
+    /// Line items being refunded.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<RefundLineItem>>,
+
+    /// Whether to refund the payment through the payment gateway.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_refund: Option<bool>,
+
+    /// Whether to restock the refunded line items.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_restock: Option<bool>,
+}
+
+/// A line item being refunded.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RefundLineItem {
+    /// The ID of the line item in the order.
+    pub id: u32,
+
+    /// The quantity refunded.
+    pub quantity: u32,
+
+    /// The amount refunded for the line item, not including tax.
+    pub refund_total: Decimal,
+}
+
+impl CreateOrderRefund {
+    pub fn builder() -> CreateOrderRefundBuilder {
+        CreateOrderRefundBuilder::default()
+    }
+}
+
+impl Endpoint for CreateOrderRefund {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/orders/{}/refunds", self.order_id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+/// Delete a refund of an order.
+///
+/// Refunds cannot be trashed, so they are always deleted permanently. The
+/// response is the refund as it was before deletion. Refunds made through
+/// the payment gateway are not reversed.
+#[derive(Builder)]
+pub struct DeleteOrderRefund {
+    /// Unique identifier for the order.
+    order_id: u32,
+
+    /// Unique identifier for the refund.
+    id: u32,
+}
+
+impl DeleteOrderRefund {
+    pub fn builder() -> DeleteOrderRefundBuilder {
+        DeleteOrderRefundBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteOrderRefund {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/orders/{}/refunds/{}", self.order_id, self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("force", true);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::Refund,
+        Query,
+    };
+
+    #[test]
+    fn create_body() {
+        let endpoint = CreateOrderRefund::builder()
+            .order_id(727)
+            .amount(Decimal::new(1000, 2))
+            .reason("Damaged")
+            .line_items(vec![RefundLineItem {
+                id: 315,
+                quantity: 1,
+                refund_total: Decimal::new(300, 2),
+            }])
+            .build()
+            .unwrap();
+
+        let (_, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "amount": "10.00",
+                "reason": "Damaged",
+                "line_items": [{ "id": 315, "quantity": 1, "refund_total": "3.00" }],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn create() {
+        let endpoint = CreateOrderRefund::builder()
+            .order_id(727)
+            .amount(Decimal::new(1000, 2))
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route("/wc/v3/orders/727/refunds")
+            .status(StatusCode::CREATED)
+            .json(json!({ "id": 728, "amount": "10.00", "refunded_payment": false }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let refund: Refund = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(refund.id, 728);
+        assert_eq!(refund.amount, Decimal::new(10, 0));
+    }
+
+    #[tokio::test]
+    async fn delete() {
+        let endpoint = DeleteOrderRefund::builder()
+            .order_id(727)
+            .id(728)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(Method::DELETE)
+            .route("/wc/v3/orders/727/refunds/728")
+            .query("force=true")
+            .json(json!({ "id": 728, "amount": "10.00" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let refund: Refund = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(refund.id, 728);
+    }
+}
@@ -0,0 +1,70 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve an order.
+#[derive(Builder)]
+#[builder(setter(strip_option))]
+pub struct RetrieveOrder {
+    /// Unique identifier for the order.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into), default)]
+    context: Option<Context>,
+
+    /// Number of decimal points to use in amounts.
+    #[builder(default)]
+    dp: Option<u32>,
+}
+
+impl RetrieveOrder {
+    pub fn builder() -> RetrieveOrderBuilder {
+        RetrieveOrderBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveOrder {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/orders/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("dp", self.dp);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::{Decimal, Order},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn typed() {
+        let endpoint = RetrieveOrder::builder().id(727).build().unwrap();
+        let response = MockResponse::builder()
+            .route("/wc/v3/orders/727")
+            .json(json!({ "id": 727, "total": "29.35", "currency": "USD" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let order: Order = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(order.total, Decimal::new(2935, 2));
+    }
+}
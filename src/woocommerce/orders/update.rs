@@ -0,0 +1,76 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{endpoint::prelude::*, woocommerce::orders::OrderStatus};
+
+/// Update the status of an order.
+///
+/// Changing the status runs the same actions as in the admin, such as
+/// sending emails to the customer and adjusting stock.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct UpdateOrderStatus {
+    /// Unique identifier for the order.
+    #[serde(skip)]
+    id: u32,
+
+    /// The new status.
+    #[builder(setter(into))]
+    status: OrderStatus,
+
+    /// Mark the order as paid, which also sets its status to `processing` and
+    /// reduces stock.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    set_paid: Option<bool>,
+
+    /// Unique transaction ID of the payment.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transaction_id: Option<String>,
+}
+
+impl UpdateOrderStatus {
+    pub fn builder() -> UpdateOrderStatusBuilder {
+        UpdateOrderStatusBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateOrderStatus {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/orders/{}", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+
+    #[test]
+    fn body() {
+        let endpoint = UpdateOrderStatus::builder()
+            .id(727)
+            .status(OrderStatus::Completed)
+            .build()
+            .unwrap();
+
+        let (_, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(endpoint.route(), "/wc/v3/orders/727");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({ "status": "completed" })
+        );
+    }
+}
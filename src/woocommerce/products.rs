@@ -0,0 +1,127 @@
+pub use create::CreateProduct;
+pub use delete::DeleteProduct;
+pub use list::ListProducts;
+pub use retrieve::RetrieveProduct;
+use serde::{ser::SerializeSeq, Serializer};
+use serde_json::json;
+pub use update::UpdateProduct;
+pub use variations::{ListProductVariations, RetrieveProductVariation};
+
+use crate::endpoints::common::impl_string_enum;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod retrieve;
+pub mod update;
+pub mod variations;
+
+/// The type of a product.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProductType {
+    /// A product without options.
+    Simple,
+
+    /// A collection of related products.
+    Grouped,
+
+    /// A product sold elsewhere.
+    External,
+
+    /// A product with variations, such as sizes or colors.
+    Variable,
+
+    /// A type not known to this crate, such as one added by an extension.
+    Unknown(String),
+}
+
+impl_string_enum!(ProductType {
+    Simple => "simple",
+    Grouped => "grouped",
+    External => "external",
+    Variable => "variable",
+});
+
+/// The stock status of a product.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StockStatus {
+    /// In stock.
+    InStock,
+
+    /// Out of stock.
+    OutOfStock,
+
+    /// Out of stock, but accepting backorders.
+    OnBackorder,
+
+    /// A status not known to this crate.
+    Unknown(String),
+}
+
+impl_string_enum!(StockStatus {
+    InStock => "instock",
+    OutOfStock => "outofstock",
+    OnBackorder => "onbackorder",
+});
+
+/// The attribute to sort a collection of products by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProductOrderBy {
+    /// The publication date. This is the default.
+    Date,
+
+    /// The product ID.
+    Id,
+
+    /// The order of the IDs given with `include`.
+    Include,
+
+    /// The name.
+    Title,
+
+    /// The slug.
+    Slug,
+
+    /// The price.
+    Price,
+
+    /// The number of sales.
+    Popularity,
+
+    /// The average rating.
+    Rating,
+
+    /// The menu order.
+    MenuOrder,
+
+    /// A value not known to this crate.
+    Unknown(String),
+}
+
+impl_string_enum!(ProductOrderBy {
+    Date => "date",
+    Id => "id",
+    Include => "include",
+    Title => "title",
+    Slug => "slug",
+    Price => "price",
+    Popularity => "popularity",
+    Rating => "rating",
+    MenuOrder => "menu_order",
+});
+
+/// Serialize category or tag IDs as the objects WooCommerce expects.
+fn term_ids<S>(ids: &Option<Vec<u32>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let ids = ids.as_deref().unwrap_or_default();
+    let mut seq = serializer.serialize_seq(Some(ids.len()))?;
+    for id in ids {
+        seq.serialize_element(&json!({ "id": id }))?;
+    }
+    seq.end()
+}
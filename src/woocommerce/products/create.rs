@@ -0,0 +1,213 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{
+    endpoint::prelude::*,
+    woocommerce::{
+        products::{term_ids, ProductType, StockStatus},
+        types::{
+            Decimal, Dimensions, MetaData, ProductAttribute, ProductImage, VariationAttribute,
+        },
+    },
+};
+
+/// Create a product.
+#[derive(Default, Builder, Serialize)]
+#[builder(setter(strip_option), default)]
+pub struct CreateProduct {
+    /// Product name.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    /// Product slug.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// Product type.
+    #[builder(setter(into))]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    product_type: Option<ProductType>,
+
+    /// Product status.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<PostStatus>,
+
+    /// Whether the product is featured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    featured: Option<bool>,
+
+    /// Where the product is visible: `visible`, `catalog`, `search` or
+    /// `hidden`.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    catalog_visibility: Option<String>,
+
+    /// HTML description of the product.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// HTML short description of the product.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<String>,
+
+    /// Stock keeping unit.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sku: Option<String>,
+
+    /// Regular price.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    regular_price: Option<Decimal>,
+
+    /// Sale price.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sale_price: Option<Decimal>,
+
+    /// Whether the product is virtual, i.e. not shipped.
+    #[serde(rename = "virtual", skip_serializing_if = "Option::is_none")]
+    is_virtual: Option<bool>,
+
+    /// Whether the product is downloadable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    downloadable: Option<bool>,
+
+    /// Tax status: `taxable`, `shipping` or `none`.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_status: Option<String>,
+
+    /// Tax class.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_class: Option<String>,
+
+    /// Whether stock is managed at the product level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    manage_stock: Option<bool>,
+
+    /// Stock quantity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stock_quantity: Option<i64>,
+
+    /// Stock status.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stock_status: Option<StockStatus>,
+
+    /// Whether backorders are allowed: `no`, `notify` or `yes`.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    backorders: Option<String>,
+
+    /// Product weight.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<String>,
+
+    /// Product dimensions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dimensions: Option<Dimensions>,
+
+    /// Shipping class slug.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_class: Option<String>,
+
+    /// Product parent ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<u32>,
+
+    /// IDs of the product categories.
+    #[builder(setter(into))]
+    #[serde(serialize_with = "term_ids", skip_serializing_if = "Option::is_none")]
+    categories: Option<Vec<u32>>,
+
+    /// IDs of the product tags.
+    #[builder(setter(into))]
+    #[serde(serialize_with = "term_ids", skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<u32>>,
+
+    /// Product images. The first image is the featured image.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    images: Option<Vec<ProductImage>>,
+
+    /// Product attributes.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<ProductAttribute>>,
+
+    /// Default attributes of a variable product.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_attributes: Option<Vec<VariationAttribute>>,
+
+    /// Meta fields.
+    #[builder(setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta_data: Option<Vec<MetaData>>,
+}
+
+impl CreateProduct {
+    pub fn builder() -> CreateProductBuilder {
+        CreateProductBuilder::default()
+    }
+}
+
+impl Endpoint for CreateProduct {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wc/v3/products".into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value as Json};
+
+    use super::*;
+
+    #[test]
+    fn body() {
+        let endpoint = CreateProduct::builder()
+            .name("Premium Quality")
+            .product_type(ProductType::Simple)
+            .regular_price(Decimal::new(2199, 2))
+            .categories(vec![9, 14])
+            .images(vec![ProductImage {
+                src: "http://example.com/shirt.jpg".into(),
+                ..Default::default()
+            }])
+            .build()
+            .unwrap();
+
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<Json>(&body).unwrap(),
+            json!({
+                "name": "Premium Quality",
+                "type": "simple",
+                "regular_price": "21.99",
+                "categories": [{ "id": 9 }, { "id": 14 }],
+                "images": [{ "src": "http://example.com/shirt.jpg" }],
+            })
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Delete a product.
+///
+/// By default, the product is moved to the trash. Set `force` to permanently
+/// delete the product instead. Either way, the response is the product as it
+/// was before deletion.
+#[derive(Builder)]
+pub struct DeleteProduct {
+    /// Unique identifier for the product.
+    id: u32,
+
+    /// Whether to bypass the trash and force deletion.
+    #[builder(setter(strip_option), default)]
+    force: Option<bool>,
+}
+
+impl DeleteProduct {
+    pub fn builder() -> DeleteProductBuilder {
+        DeleteProductBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteProduct {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/products/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("force", self.force);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::Product,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn force() {
+        let endpoint = DeleteProduct::builder()
+            .id(794)
+            .force(true)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(Method::DELETE)
+            .route("/wc/v3/products/794")
+            .query("force=true")
+            .json(json!({ "id": 794, "status": "publish" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let product: Product = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(product.id, 794);
+    }
+}
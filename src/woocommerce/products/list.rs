@@ -0,0 +1,213 @@
+use derive_builder::Builder;
+
+use crate::{
+    endpoint::prelude::*,
+    woocommerce::products::{ProductOrderBy, ProductType, StockStatus},
+};
+
+/// List products.
+#[derive(Default, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListProducts {
+    /// Scope under which the request is made.
+    #[builder(setter(into))]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into))]
+    search: Option<String>,
+
+    /// Limit response to products published after a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    after: Option<Timestamp>,
+
+    /// Limit response to products published before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    before: Option<Timestamp>,
+
+    /// Limit response to products modified after a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    modified_after: Option<Timestamp>,
+
+    /// Limit response to products modified before a given ISO8601 compliant
+    /// date.
+    #[builder(setter(into))]
+    modified_before: Option<Timestamp>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false))]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    order: Option<Order>,
+
+    /// Sort collection by product attribute.
+    #[builder(setter(into))]
+    orderby: Option<ProductOrderBy>,
+
+    /// Limit result set to products with specific parent IDs.
+    #[builder(setter(into, strip_option = false))]
+    parent: Vec<u32>,
+
+    /// Limit result set to all products except those of particular parent IDs.
+    #[builder(setter(into, strip_option = false))]
+    parent_exclude: Vec<u32>,
+
+    /// Limit result set to products with a specific slug.
+    #[builder(setter(into))]
+    slug: Option<String>,
+
+    /// Limit result set to products assigned a specific status.
+    #[builder(setter(into))]
+    status: Option<PostStatus>,
+
+    /// Limit result set to products assigned a specific type.
+    #[builder(setter(into))]
+    product_type: Option<ProductType>,
+
+    /// Limit result set to products with specific SKUs.
+    #[builder(setter(into, strip_option = false))]
+    sku: Vec<String>,
+
+    /// Limit result set to featured products.
+    featured: Option<bool>,
+
+    /// Limit result set to products assigned specific category IDs.
+    #[builder(setter(into, strip_option = false))]
+    category: Vec<u32>,
+
+    /// Limit result set to products assigned specific tag IDs.
+    #[builder(setter(into, strip_option = false))]
+    tag: Vec<u32>,
+
+    /// Limit result set to products assigned a specific shipping class ID.
+    shipping_class: Option<u32>,
+
+    /// Limit result set to products with a specific attribute, such as
+    /// `pa_color`.
+    #[builder(setter(into))]
+    attribute: Option<String>,
+
+    /// Limit result set to products with a specific attribute term ID.
+    /// Requires `attribute`.
+    attribute_term: Option<u32>,
+
+    /// Limit result set to products on sale.
+    on_sale: Option<bool>,
+
+    /// Limit result set to products based on a minimum price.
+    #[builder(setter(into))]
+    min_price: Option<String>,
+
+    /// Limit result set to products based on a maximum price.
+    #[builder(setter(into))]
+    max_price: Option<String>,
+
+    /// Limit result set to products with specified stock status.
+    #[builder(setter(into))]
+    stock_status: Option<StockStatus>,
+}
+
+impl ListProducts {
+    pub fn builder() -> ListProductsBuilder {
+        ListProductsBuilder::default()
+    }
+}
+
+impl Endpoint for ListProducts {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/wc/v3/products".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("after", self.after.as_ref())
+            .push_opt("before", self.before.as_ref())
+            .push_opt("modified_after", self.modified_after.as_ref())
+            .push_opt("modified_before", self.modified_before.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("orderby", self.orderby.as_ref())
+            .push_list("parent", &self.parent)
+            .push_list("parent_exclude", &self.parent_exclude)
+            .push_opt("slug", self.slug.as_ref())
+            .push_opt("status", self.status.as_ref())
+            .push_opt("type", self.product_type.as_ref())
+            .push_list("sku", &self.sku)
+            .push_opt("featured", self.featured)
+            .push_list("category", &self.category)
+            .push_list("tag", &self.tag)
+            .push_opt("shipping_class", self.shipping_class)
+            .push_opt("attribute", self.attribute.as_ref())
+            .push_opt("attribute_term", self.attribute_term)
+            .push_opt("on_sale", self.on_sale)
+            .push_opt("min_price", self.min_price.as_ref())
+            .push_opt("max_price", self.max_price.as_ref())
+            .push_opt("stock_status", self.stock_status.as_ref());
+        params
+    }
+}
+
+impl Pageable for ListProducts {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::Product,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn filters() {
+        let endpoint = ListProducts::builder()
+            .product_type(ProductType::Variable)
+            .category(vec![9, 10])
+            .stock_status(StockStatus::InStock)
+            .orderby(ProductOrderBy::Price)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route(endpoint.route())
+            .query("orderby=price&type=variable&category=9%2C10&stock_status=instock")
+            .json(json!([{ "id": 794, "type": "variable" }]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let products: Vec<Product> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(products[0].id, 794);
+    }
+}
@@ -0,0 +1,69 @@
+use derive_builder::Builder;
+
+use crate::endpoint::prelude::*;
+
+/// Retrieve a product.
+#[derive(Builder)]
+pub struct RetrieveProduct {
+    /// Unique identifier for the product.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveProduct {
+    pub fn builder() -> RetrieveProductBuilder {
+        RetrieveProductBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveProduct {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/products/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::{Decimal, Product},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn typed() {
+        let endpoint = RetrieveProduct::builder().id(794).build().unwrap();
+        let response = MockResponse::builder()
+            .method(endpoint.method())
+            .route("/wc/v3/products/794")
+            .json(json!({
+                "id": 794,
+                "name": "Premium Quality",
+                "regular_price": "21.99",
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let product: Product = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(product.name, "Premium Quality");
+        assert_eq!(product.regular_price, Some(Decimal::new(2199, 2)));
+    }
+}
@@ -0,0 +1,226 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{
+    endpoint::prelude::*,
+    woocommerce::{
+        products::{term_ids, ProductType, StockStatus},
+        types::{
+            Decimal, Dimensions, MetaData, ProductAttribute, ProductImage, VariationAttribute,
+        },
+    },
+};
+
+/// Update a product.
+#[derive(Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct UpdateProduct {
+    /// Unique identifier for the product.
+    #[serde(skip)]
+    id: u32,
+
+    /// Product name.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    /// Product slug.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+
+    /// Product type.
+    #[builder(setter(into), default)]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    product_type: Option<ProductType>,
+
+    /// Product status.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<PostStatus>,
+
+    /// Whether the product is featured.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    featured: Option<bool>,
+
+    /// Where the product is visible: `visible`, `catalog`, `search` or
+    /// `hidden`.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    catalog_visibility: Option<String>,
+
+    /// HTML description of the product.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// HTML short description of the product.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<String>,
+
+    /// Stock keeping unit.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sku: Option<String>,
+
+    /// Regular price.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    regular_price: Option<Decimal>,
+
+    /// Sale price.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sale_price: Option<Decimal>,
+
+    /// Whether the product is virtual, i.e. not shipped.
+    #[builder(default)]
+    #[serde(rename = "virtual", skip_serializing_if = "Option::is_none")]
+    is_virtual: Option<bool>,
+
+    /// Whether the product is downloadable.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    downloadable: Option<bool>,
+
+    /// Tax status: `taxable`, `shipping` or `none`.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_status: Option<String>,
+
+    /// Tax class.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_class: Option<String>,
+
+    /// Whether stock is managed at the product level.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    manage_stock: Option<bool>,
+
+    /// Stock quantity.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stock_quantity: Option<i64>,
+
+    /// Stock status.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stock_status: Option<StockStatus>,
+
+    /// Whether backorders are allowed: `no`, `notify` or `yes`.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    backorders: Option<String>,
+
+    /// Product weight.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<String>,
+
+    /// Product dimensions.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dimensions: Option<Dimensions>,
+
+    /// Shipping class slug.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_class: Option<String>,
+
+    /// Product parent ID.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<u32>,
+
+    /// IDs of the product categories.
+    #[builder(setter(into), default)]
+    #[serde(serialize_with = "term_ids", skip_serializing_if = "Option::is_none")]
+    categories: Option<Vec<u32>>,
+
+    /// IDs of the product tags.
+    #[builder(setter(into), default)]
+    #[serde(serialize_with = "term_ids", skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<u32>>,
+
+    /// Product images. The first image is the featured image.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    images: Option<Vec<ProductImage>>,
+
+    /// Product attributes.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<ProductAttribute>>,
+
+    /// Default attributes of a variable product.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_attributes: Option<Vec<VariationAttribute>>,
+
+    /// Meta fields.
+    #[builder(setter(into), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta_data: Option<Vec<MetaData>>,
+}
+
+impl UpdateProduct {
+    pub fn builder() -> UpdateProductBuilder {
+        UpdateProductBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateProduct {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/products/{}", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(ContentType, Vec<u8>)>, BodyError> {
+        Ok(Some((ContentType::Json, serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::Product,
+        Query,
+    };
+
+    #[tokio::test]
+    async fn basic() {
+        let endpoint = UpdateProduct::builder()
+            .id(794)
+            .sale_price(Decimal::new(1999, 2))
+            .stock_status(StockStatus::OnBackorder)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .method(Method::POST)
+            .route("/wc/v3/products/794")
+            .json(json!({ "id": 794, "sale_price": "19.99", "stock_status": "onbackorder" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let (_, body) = endpoint.body().unwrap().unwrap();
+        let product: Product = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            json!({ "sale_price": "19.99", "stock_status": "onbackorder" })
+        );
+        assert_eq!(product.sale_price, Some(Decimal::new(1999, 2)));
+    }
+}
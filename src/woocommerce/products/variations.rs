@@ -0,0 +1,181 @@
+use derive_builder::Builder;
+
+use crate::{endpoint::prelude::*, woocommerce::products::StockStatus};
+
+/// List the variations of a variable product.
+#[derive(Builder)]
+#[builder(setter(strip_option))]
+pub struct ListProductVariations {
+    /// Unique identifier for the parent product.
+    product: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into), default)]
+    context: Option<Context>,
+
+    /// Current page of the collection.
+    #[builder(default)]
+    page: Option<u32>,
+
+    /// Maximum number of items to be returned in result set.
+    #[builder(default)]
+    per_page: Option<u32>,
+
+    /// Limit results to those matching a string.
+    #[builder(setter(into), default)]
+    search: Option<String>,
+
+    /// Ensure result set excludes specific IDs.
+    #[builder(setter(into, strip_option = false), default)]
+    exclude: Vec<u32>,
+
+    /// Limit result set to specific IDs.
+    #[builder(setter(into, strip_option = false), default)]
+    include: Vec<u32>,
+
+    /// Offset the result set by a specific number of items.
+    #[builder(default)]
+    offset: Option<u32>,
+
+    /// Order sort attribute ascending or descending.
+    #[builder(default)]
+    order: Option<Order>,
+
+    /// Limit result set to variations with specific SKUs.
+    #[builder(setter(into, strip_option = false), default)]
+    sku: Vec<String>,
+
+    /// Limit result set to variations on sale.
+    #[builder(default)]
+    on_sale: Option<bool>,
+
+    /// Limit result set to variations with specified stock status.
+    #[builder(setter(into), default)]
+    stock_status: Option<StockStatus>,
+}
+
+impl ListProductVariations {
+    pub fn builder() -> ListProductVariationsBuilder {
+        ListProductVariationsBuilder::default()
+    }
+}
+
+impl Endpoint for ListProductVariations {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/products/{}/variations", self.product).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("context", self.context.as_ref())
+            .push_opt("page", self.page)
+            .push_opt("per_page", self.per_page)
+            .push_opt("search", self.search.as_ref())
+            .push_list("exclude", &self.exclude)
+            .push_list("include", &self.include)
+            .push_opt("offset", self.offset)
+            .push_opt("order", self.order.as_ref())
+            .push_list("sku", &self.sku)
+            .push_opt("on_sale", self.on_sale)
+            .push_opt("stock_status", self.stock_status.as_ref());
+        params
+    }
+}
+
+impl Pageable for ListProductVariations {}
+
+/// Retrieve a variation of a variable product.
+#[derive(Builder)]
+pub struct RetrieveProductVariation {
+    /// Unique identifier for the parent product.
+    product: u32,
+
+    /// Unique identifier for the variation.
+    id: u32,
+
+    /// Scope under which the request is made.
+    #[builder(setter(into, strip_option), default)]
+    context: Option<Context>,
+}
+
+impl RetrieveProductVariation {
+    pub fn builder() -> RetrieveProductVariationBuilder {
+        RetrieveProductVariationBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveProductVariation {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        format!("/wc/v3/products/{}/variations/{}", self.product, self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("context", self.context.as_ref());
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        woocommerce::{Decimal, ProductVariation},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn list() {
+        let endpoint = ListProductVariations::builder()
+            .product(794)
+            .per_page(50)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/wc/v3/products/794/variations")
+            .query("per_page=50")
+            .json(json!([
+                { "id": 799, "attributes": [{ "id": 6, "name": "Color", "option": "Black" }] },
+                { "id": 800, "attributes": [{ "id": 6, "name": "Color", "option": "Green" }] },
+            ]))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let variations: Vec<ProductVariation> = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(variations.len(), 2);
+        assert_eq!(variations[1].attributes[0].option, "Green");
+    }
+
+    #[tokio::test]
+    async fn retrieve() {
+        let endpoint = RetrieveProductVariation::builder()
+            .product(794)
+            .id(799)
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/wc/v3/products/794/variations/799")
+            .json(json!({ "id": 799, "price": "21.99" }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let variation: ProductVariation = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(variation.price, Some(Decimal::new(2199, 2)));
+    }
+}
@@ -0,0 +1,24 @@
+pub use address::Address;
+pub use customer::Customer;
+pub use decimal::{Decimal, ParseDecimalError};
+pub use meta::MetaData;
+pub use order::{
+    CouponLine, FeeLine, LineItem, Order, Refund, RefundSummary, ShippingLine, TaxLine,
+};
+pub use product::{
+    Dimensions, Product, ProductAttribute, ProductImage, ProductTerm, ProductVariation,
+    VariationAttribute,
+};
+
+pub mod address;
+pub mod customer;
+pub mod decimal;
+pub mod meta;
+pub mod order;
+pub mod product;
+
+/// Skip serializing IDs which are left unset, e.g. when adding an image or a
+/// meta field.
+fn is_zero(id: &u32) -> bool {
+    *id == 0
+}
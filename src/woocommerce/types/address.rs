@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// A billing or shipping address.
+///
+/// Shipping addresses have no email address.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Address {
+    /// First name.
+    #[serde(default)]
+    pub first_name: String,
+
+    /// Last name.
+    #[serde(default)]
+    pub last_name: String,
+
+    /// Company name.
+    #[serde(default)]
+    pub company: String,
+
+    /// Address line 1.
+    #[serde(default)]
+    pub address_1: String,
+
+    /// Address line 2.
+    #[serde(default)]
+    pub address_2: String,
+
+    /// City name.
+    #[serde(default)]
+    pub city: String,
+
+    /// ISO code or name of the state, province or district.
+    #[serde(default)]
+    pub state: String,
+
+    /// Postal code.
+    #[serde(default)]
+    pub postcode: String,
+
+    /// ISO 3166-1 alpha-2 country code.
+    #[serde(default)]
+    pub country: String,
+
+    /// Email address.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub email: String,
+
+    /// Phone number.
+    #[serde(default)]
+    pub phone: String,
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Address, MetaData};
+use crate::types::Links;
+
+/// A WooCommerce customer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Customer {
+    /// Unique identifier for the customer, which is their user ID.
+    #[serde(default)]
+    pub id: u32,
+
+    /// The date the customer was created, in the store's timezone.
+    #[serde(default)]
+    pub date_created: Option<String>,
+
+    /// The date the customer was created, as GMT.
+    #[serde(default)]
+    pub date_created_gmt: Option<String>,
+
+    /// The date the customer was last modified, in the store's timezone.
+    #[serde(default)]
+    pub date_modified: Option<String>,
+
+    /// The date the customer was last modified, as GMT.
+    #[serde(default)]
+    pub date_modified_gmt: Option<String>,
+
+    /// The email address for the customer.
+    #[serde(default)]
+    pub email: String,
+
+    /// Customer first name.
+    #[serde(default)]
+    pub first_name: String,
+
+    /// Customer last name.
+    #[serde(default)]
+    pub last_name: String,
+
+    /// Customer role.
+    #[serde(default)]
+    pub role: String,
+
+    /// Customer login name.
+    #[serde(default)]
+    pub username: String,
+
+    /// Billing address.
+    #[serde(default)]
+    pub billing: Address,
+
+    /// Shipping address.
+    #[serde(default)]
+    pub shipping: Address,
+
+    /// Whether the customer has paid for an order.
+    #[serde(default)]
+    pub is_paying_customer: bool,
+
+    /// Avatar URL.
+    #[serde(default)]
+    pub avatar_url: String,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta_data: Vec<MetaData>,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
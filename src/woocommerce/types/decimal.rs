@@ -0,0 +1,288 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use thiserror::Error;
+
+/// The largest number of decimal places a [`Decimal`] can hold.
+const MAX_SCALE: u32 = 18;
+
+/// An exact decimal number, as used by WooCommerce for amounts of money.
+///
+/// WooCommerce sends amounts as strings such as `"21.99"`, and some as JSON
+/// numbers, which are parsed without going through floating point. The
+/// number of decimal places is kept, so an amount is displayed and sent back
+/// as it was received, but amounts are compared by value.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Decimal {
+    mantissa: i64,
+    scale: u32,
+}
+
+/// The error returned when a string is not a decimal number.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid decimal number: {input:?}")]
+pub struct ParseDecimalError {
+    input: String,
+}
+
+impl Decimal {
+    /// Zero, without decimal places.
+    pub const ZERO: Decimal = Decimal {
+        mantissa: 0,
+        scale: 0,
+    };
+
+    /// The decimal `mantissa × 10^-scale`, such as 2199 with a scale of 2 for
+    /// 21.99.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale is greater than 18.
+    pub const fn new(mantissa: i64, scale: u32) -> Self {
+        assert!(scale <= MAX_SCALE, "decimal scale out of range");
+        Self { mantissa, scale }
+    }
+
+    /// The digits of the number, without the decimal point.
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    /// The number of decimal places.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Whether the number is zero.
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// The amount in minor units of a currency with the given number of
+    /// decimal places, such as cents for 2.
+    ///
+    /// Returns `None` if the amount has more decimal places than the currency
+    /// and they are not zero, or if it is out of range.
+    pub fn to_minor_units(&self, decimals: u32) -> Option<i64> {
+        if decimals >= self.scale {
+            let factor = 10i64.checked_pow(decimals - self.scale)?;
+            self.mantissa.checked_mul(factor)
+        } else {
+            let factor = 10i64.pow(self.scale - decimals);
+            (self.mantissa % factor == 0).then(|| self.mantissa / factor)
+        }
+    }
+
+    /// The amount as a floating point number, which may not be exact.
+    pub fn to_f64(&self) -> f64 {
+        self.mantissa as f64 / 10f64.powi(self.scale as i32)
+    }
+
+    /// Add two numbers, returning `None` on overflow.
+    pub fn checked_add(self, other: Decimal) -> Option<Decimal> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = self.rescaled(scale)?.checked_add(other.rescaled(scale)?)?;
+        Some(Decimal { mantissa, scale })
+    }
+
+    /// Subtract a number, returning `None` on overflow.
+    pub fn checked_sub(self, other: Decimal) -> Option<Decimal> {
+        self.checked_add(Decimal {
+            mantissa: other.mantissa.checked_neg()?,
+            scale: other.scale,
+        })
+    }
+
+    fn rescaled(&self, scale: u32) -> Option<i64> {
+        self.mantissa
+            .checked_mul(10i64.checked_pow(scale - self.scale)?)
+    }
+
+    fn widened(&self) -> i128 {
+        self.mantissa as i128 * 10i128.pow(MAX_SCALE - self.scale)
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.widened() == other.widened()
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.widened().cmp(&other.widened())
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Self {
+        Decimal::new(value, 0)
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseDecimalError { input: s.into() };
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let valid = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if integer.is_empty() && fraction.is_empty()
+            || !valid(integer)
+            || !valid(fraction)
+            || fraction.len() > MAX_SCALE as usize
+        {
+            return Err(err());
+        }
+
+        let mut mantissa: i64 = 0;
+        for b in integer.bytes().chain(fraction.bytes()) {
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(i64::from(b - b'0')))
+                .ok_or_else(err)?;
+        }
+        if negative {
+            mantissa = -mantissa;
+        }
+        Ok(Decimal {
+            mantissa,
+            scale: fraction.len() as u32,
+        })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.mantissa < 0 {
+            f.write_str("-")?;
+        }
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        if scale == 0 {
+            return f.write_str(&digits);
+        }
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        write!(f, "{}.{}", integer, fraction)
+    }
+}
+
+impl Serialize for Decimal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::String(s) => s.parse().map_err(de::Error::custom),
+            Value::Number(n) => n.to_string().parse().map_err(de::Error::custom),
+            other => Err(de::Error::custom(format!(
+                "expected a decimal number: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Deserialize an amount which is an empty string or `null` when it is not
+/// set, such as the sale price of a product.
+pub(crate) fn optional<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(None),
+        Value::String(s) if s.is_empty() => Ok(None),
+        value => Decimal::deserialize(value)
+            .map(Some)
+            .map_err(de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let price: Decimal = "21.99".parse().unwrap();
+        assert_eq!((price.mantissa(), price.scale()), (2199, 2));
+
+        let refund: Decimal = "-0.5".parse().unwrap();
+        assert_eq!((refund.mantissa(), refund.scale()), (-5, 1));
+
+        assert!("".parse::<Decimal>().is_err());
+        assert!("1.2.3".parse::<Decimal>().is_err());
+        assert!("1e3".parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Decimal::new(2199, 2).to_string(), "21.99");
+        assert_eq!(Decimal::new(5, 2).to_string(), "0.05");
+        assert_eq!(Decimal::new(-5, 1).to_string(), "-0.5");
+        assert_eq!(Decimal::new(1200, 0).to_string(), "1200");
+    }
+
+    #[test]
+    fn compare_by_value() {
+        assert_eq!(Decimal::new(150, 2), Decimal::new(15, 1));
+        assert!(Decimal::new(1999, 2) < Decimal::new(20, 0));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let total = Decimal::new(2199, 2)
+            .checked_add(Decimal::new(5, 1))
+            .unwrap();
+
+        assert_eq!(total.to_string(), "22.49");
+        assert_eq!(
+            total.checked_sub(Decimal::new(2249, 2)).unwrap(),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn minor_units() {
+        assert_eq!(Decimal::new(2199, 2).to_minor_units(2), Some(2199));
+        assert_eq!(Decimal::new(215, 1).to_minor_units(2), Some(2150));
+        assert_eq!(Decimal::new(2100, 2).to_minor_units(0), Some(21));
+        assert_eq!(Decimal::new(2199, 2).to_minor_units(0), None);
+    }
+
+    #[test]
+    fn serde() {
+        let amount: Decimal = serde_json::from_value(json!("10.00")).unwrap();
+        assert_eq!(serde_json::to_value(amount).unwrap(), json!("10.00"));
+
+        let price: Decimal = serde_json::from_value(json!(21.99)).unwrap();
+        assert_eq!(price, Decimal::new(2199, 2));
+    }
+}
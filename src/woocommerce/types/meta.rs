@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::is_zero;
+
+/// A meta field of a WooCommerce resource.
+///
+/// Unlike core meta fields, WooCommerce returns every meta field which is not
+/// protected, as a list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetaData {
+    /// Unique identifier for the meta field, or 0 when adding one.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub id: u32,
+
+    /// The meta key.
+    pub key: String,
+
+    /// The meta value.
+    #[serde(default)]
+    pub value: Value,
+}
+
+impl MetaData {
+    /// A new meta field, to be added to a resource.
+    pub fn new(key: impl Into<String>, value: impl Into<Value>) -> Self {
+        Self {
+            id: 0,
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use serde::{Deserialize, Serialize};
+
+use super::{decimal, Address, Decimal, MetaData};
+use crate::types::Links;
+
+/// A WooCommerce order.
+///
+/// Amounts are in the currency of the order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    /// Unique identifier for the order.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Parent order ID.
+    #[serde(default)]
+    pub parent_id: u32,
+
+    /// The order number shown to customers, which may differ from the ID.
+    #[serde(default)]
+    pub number: String,
+
+    /// Order key.
+    #[serde(default)]
+    pub order_key: String,
+
+    /// How the order was created, such as `checkout` or `admin`.
+    #[serde(default)]
+    pub created_via: String,
+
+    /// Order status, such as `processing` or `completed`.
+    #[serde(default)]
+    pub status: String,
+
+    /// ISO 4217 currency code of the order.
+    #[serde(default)]
+    pub currency: String,
+
+    /// The date the order was created, in the store's timezone.
+    #[serde(default)]
+    pub date_created: Option<String>,
+
+    /// The date the order was created, as GMT.
+    #[serde(default)]
+    pub date_created_gmt: Option<String>,
+
+    /// The date the order was last modified, in the store's timezone.
+    #[serde(default)]
+    pub date_modified: Option<String>,
+
+    /// The date the order was last modified, as GMT.
+    #[serde(default)]
+    pub date_modified_gmt: Option<String>,
+
+    /// The date the order was paid, in the store's timezone.
+    #[serde(default)]
+    pub date_paid: Option<String>,
+
+    /// The date the order was completed, in the store's timezone.
+    #[serde(default)]
+    pub date_completed: Option<String>,
+
+    /// Total discount amount.
+    #[serde(default)]
+    pub discount_total: Decimal,
+
+    /// Total discount tax amount.
+    #[serde(default)]
+    pub discount_tax: Decimal,
+
+    /// Total shipping amount.
+    #[serde(default)]
+    pub shipping_total: Decimal,
+
+    /// Total shipping tax amount.
+    #[serde(default)]
+    pub shipping_tax: Decimal,
+
+    /// Sum of line item taxes.
+    #[serde(default)]
+    pub cart_tax: Decimal,
+
+    /// Grand total.
+    #[serde(default)]
+    pub total: Decimal,
+
+    /// Sum of all taxes.
+    #[serde(default)]
+    pub total_tax: Decimal,
+
+    /// Whether prices included tax when the order was placed.
+    #[serde(default)]
+    pub prices_include_tax: bool,
+
+    /// The ID of the customer, or 0 for guests.
+    #[serde(default)]
+    pub customer_id: u32,
+
+    /// Note left by the customer during checkout.
+    #[serde(default)]
+    pub customer_note: String,
+
+    /// Billing address.
+    #[serde(default)]
+    pub billing: Address,
+
+    /// Shipping address.
+    #[serde(default)]
+    pub shipping: Address,
+
+    /// Payment method ID.
+    #[serde(default)]
+    pub payment_method: String,
+
+    /// Payment method title.
+    #[serde(default)]
+    pub payment_method_title: String,
+
+    /// Unique transaction ID.
+    #[serde(default)]
+    pub transaction_id: String,
+
+    /// Line items.
+    #[serde(default)]
+    pub line_items: Vec<LineItem>,
+
+    /// Tax lines.
+    #[serde(default)]
+    pub tax_lines: Vec<TaxLine>,
+
+    /// Shipping lines.
+    #[serde(default)]
+    pub shipping_lines: Vec<ShippingLine>,
+
+    /// Fee lines.
+    #[serde(default)]
+    pub fee_lines: Vec<FeeLine>,
+
+    /// Coupon lines.
+    #[serde(default)]
+    pub coupon_lines: Vec<CouponLine>,
+
+    /// Refunds of the order.
+    #[serde(default)]
+    pub refunds: Vec<RefundSummary>,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta_data: Vec<MetaData>,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
+
+/// A product ordered, or refunded.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LineItem {
+    /// Item ID.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Product name.
+    #[serde(default)]
+    pub name: String,
+
+    /// Product ID.
+    #[serde(default)]
+    pub product_id: u32,
+
+    /// Variation ID, or 0 if the product has no variations.
+    #[serde(default)]
+    pub variation_id: u32,
+
+    /// Quantity ordered, which is negative for refunded items.
+    #[serde(default)]
+    pub quantity: i64,
+
+    /// Tax class of the product.
+    #[serde(default)]
+    pub tax_class: String,
+
+    /// Line subtotal, before discounts.
+    #[serde(default)]
+    pub subtotal: Decimal,
+
+    /// Line subtotal tax, before discounts.
+    #[serde(default)]
+    pub subtotal_tax: Decimal,
+
+    /// Line total, after discounts.
+    #[serde(default)]
+    pub total: Decimal,
+
+    /// Line total tax, after discounts.
+    #[serde(default)]
+    pub total_tax: Decimal,
+
+    /// Product SKU.
+    #[serde(default)]
+    pub sku: String,
+
+    /// Product price.
+    #[serde(default, deserialize_with = "decimal::optional")]
+    pub price: Option<Decimal>,
+
+    /// Meta fields, such as the selected attributes of a variation.
+    #[serde(default)]
+    pub meta_data: Vec<MetaData>,
+}
+
+/// A tax applied to an order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaxLine {
+    /// Item ID.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Tax rate code.
+    #[serde(default)]
+    pub rate_code: String,
+
+    /// Tax rate ID.
+    #[serde(default)]
+    pub rate_id: u32,
+
+    /// Tax rate label.
+    #[serde(default)]
+    pub label: String,
+
+    /// Whether the tax rate is compound.
+    #[serde(default)]
+    pub compound: bool,
+
+    /// Tax total, not including shipping taxes.
+    #[serde(default)]
+    pub tax_total: Decimal,
+
+    /// Shipping tax total.
+    #[serde(default)]
+    pub shipping_tax_total: Decimal,
+}
+
+/// A shipping method of an order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShippingLine {
+    /// Item ID.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Shipping method name.
+    #[serde(default)]
+    pub method_title: String,
+
+    /// Shipping method ID.
+    #[serde(default)]
+    pub method_id: String,
+
+    /// Line total, after discounts.
+    #[serde(default)]
+    pub total: Decimal,
+
+    /// Line total tax, after discounts.
+    #[serde(default)]
+    pub total_tax: Decimal,
+}
+
+/// A fee added to an order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FeeLine {
+    /// Item ID.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Fee name.
+    #[serde(default)]
+    pub name: String,
+
+    /// Tax status of the fee.
+    #[serde(default)]
+    pub tax_status: String,
+
+    /// Line total, after discounts.
+    #[serde(default)]
+    pub total: Decimal,
+
+    /// Line total tax, after discounts.
+    #[serde(default)]
+    pub total_tax: Decimal,
+}
+
+/// A coupon applied to an order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CouponLine {
+    /// Item ID.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Coupon code.
+    #[serde(default)]
+    pub code: String,
+
+    /// Discount total.
+    #[serde(default)]
+    pub discount: Decimal,
+
+    /// Discount total tax.
+    #[serde(default)]
+    pub discount_tax: Decimal,
+}
+
+/// A refund as listed on its order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RefundSummary {
+    /// Refund ID.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Refund reason.
+    #[serde(default)]
+    pub reason: String,
+
+    /// Refund total, which is negative.
+    #[serde(default)]
+    pub total: Decimal,
+}
+
+/// A refund of an order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Refund {
+    /// Unique identifier for the refund.
+    #[serde(default)]
+    pub id: u32,
+
+    /// The date the refund was created, in the store's timezone.
+    #[serde(default)]
+    pub date_created: Option<String>,
+
+    /// The date the refund was created, as GMT.
+    #[serde(default)]
+    pub date_created_gmt: Option<String>,
+
+    /// Refund amount.
+    #[serde(default)]
+    pub amount: Decimal,
+
+    /// Reason for the refund.
+    #[serde(default)]
+    pub reason: String,
+
+    /// ID of the user who created the refund.
+    #[serde(default)]
+    pub refunded_by: u32,
+
+    /// Whether the payment was refunded through the payment gateway.
+    #[serde(default)]
+    pub refunded_payment: bool,
+
+    /// Refunded line items.
+    #[serde(default)]
+    pub line_items: Vec<LineItem>,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta_data: Vec<MetaData>,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let order: Order = serde_json::from_value(json!({
+            "id": 727,
+            "number": "727",
+            "status": "processing",
+            "currency": "USD",
+            "total": "29.35",
+            "shipping_total": "10.00",
+            "billing": { "first_name": "John", "email": "john.doe@example.com" },
+            "line_items": [{
+                "id": 315,
+                "product_id": 93,
+                "quantity": 2,
+                "subtotal": "6.00",
+                "total": "6.00",
+                "price": 3,
+            }],
+            "refunds": [{ "id": 726, "reason": "", "total": "-10.00" }],
+        }))
+        .unwrap();
+
+        assert_eq!(order.total, Decimal::new(2935, 2));
+        assert_eq!(order.shipping_total.to_minor_units(2), Some(1000));
+        assert_eq!(order.billing.email, "john.doe@example.com");
+        assert_eq!(order.line_items[0].price, Some(Decimal::from(3)));
+        assert_eq!(order.refunds[0].total, Decimal::new(-1000, 2));
+    }
+}
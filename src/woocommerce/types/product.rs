@@ -0,0 +1,457 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use super::{decimal, is_zero, Decimal, MetaData};
+use crate::types::Links;
+
+/// A WooCommerce product.
+///
+/// Prices are in the store's currency, and are `None` when the product has no
+/// price.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Product {
+    /// Unique identifier for the product.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Product name.
+    #[serde(default)]
+    pub name: String,
+
+    /// Product slug.
+    #[serde(default)]
+    pub slug: String,
+
+    /// Product URL.
+    #[serde(default)]
+    pub permalink: String,
+
+    /// The date the product was created, in the store's timezone.
+    #[serde(default)]
+    pub date_created: Option<String>,
+
+    /// The date the product was created, as GMT.
+    #[serde(default)]
+    pub date_created_gmt: Option<String>,
+
+    /// The date the product was last modified, in the store's timezone.
+    #[serde(default)]
+    pub date_modified: Option<String>,
+
+    /// The date the product was last modified, as GMT.
+    #[serde(default)]
+    pub date_modified_gmt: Option<String>,
+
+    /// Product type, such as `simple` or `variable`.
+    #[serde(rename = "type", default)]
+    pub product_type: String,
+
+    /// Product status, such as `publish` or `draft`.
+    #[serde(default)]
+    pub status: String,
+
+    /// Whether the product is featured.
+    #[serde(default)]
+    pub featured: bool,
+
+    /// Where the product is visible: `visible`, `catalog`, `search` or
+    /// `hidden`.
+    #[serde(default)]
+    pub catalog_visibility: String,
+
+    /// HTML description of the product.
+    #[serde(default)]
+    pub description: String,
+
+    /// HTML short description of the product.
+    #[serde(default)]
+    pub short_description: String,
+
+    /// Stock keeping unit.
+    #[serde(default)]
+    pub sku: String,
+
+    /// Current price.
+    #[serde(default, deserialize_with = "decimal::optional")]
+    pub price: Option<Decimal>,
+
+    /// Regular price.
+    #[serde(default, deserialize_with = "decimal::optional")]
+    pub regular_price: Option<Decimal>,
+
+    /// Sale price.
+    #[serde(default, deserialize_with = "decimal::optional")]
+    pub sale_price: Option<Decimal>,
+
+    /// Whether the product is on sale.
+    #[serde(default)]
+    pub on_sale: bool,
+
+    /// Whether the product can be bought.
+    #[serde(default)]
+    pub purchasable: bool,
+
+    /// Amount of sales.
+    #[serde(default)]
+    pub total_sales: u64,
+
+    /// Whether the product is virtual, i.e. not shipped.
+    #[serde(rename = "virtual", default)]
+    pub is_virtual: bool,
+
+    /// Whether the product is downloadable.
+    #[serde(default)]
+    pub downloadable: bool,
+
+    /// Tax status: `taxable`, `shipping` or `none`.
+    #[serde(default)]
+    pub tax_status: String,
+
+    /// Tax class.
+    #[serde(default)]
+    pub tax_class: String,
+
+    /// Whether stock is managed at the product level.
+    #[serde(default)]
+    pub manage_stock: bool,
+
+    /// Stock quantity, if stock is managed.
+    #[serde(default)]
+    pub stock_quantity: Option<i64>,
+
+    /// Stock status: `instock`, `outofstock` or `onbackorder`.
+    #[serde(default)]
+    pub stock_status: String,
+
+    /// Whether backorders are allowed: `no`, `notify` or `yes`.
+    #[serde(default)]
+    pub backorders: String,
+
+    /// Product weight.
+    #[serde(default)]
+    pub weight: String,
+
+    /// Product dimensions.
+    #[serde(default)]
+    pub dimensions: Dimensions,
+
+    /// Shipping class slug.
+    #[serde(default)]
+    pub shipping_class: String,
+
+    /// Product parent ID.
+    #[serde(default)]
+    pub parent_id: u32,
+
+    /// Product categories.
+    #[serde(default)]
+    pub categories: Vec<ProductTerm>,
+
+    /// Product tags.
+    #[serde(default)]
+    pub tags: Vec<ProductTerm>,
+
+    /// Product images. The first image is the featured image.
+    #[serde(default)]
+    pub images: Vec<ProductImage>,
+
+    /// Product attributes.
+    #[serde(default)]
+    pub attributes: Vec<ProductAttribute>,
+
+    /// Default attributes of a variable product.
+    #[serde(default)]
+    pub default_attributes: Vec<VariationAttribute>,
+
+    /// IDs of the variations of a variable product.
+    #[serde(default)]
+    pub variations: Vec<u32>,
+
+    /// IDs of the products in a grouped product.
+    #[serde(default)]
+    pub grouped_products: Vec<u32>,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta_data: Vec<MetaData>,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
+
+/// A variation of a variable product.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProductVariation {
+    /// Unique identifier for the variation.
+    #[serde(default)]
+    pub id: u32,
+
+    /// The date the variation was created, in the store's timezone.
+    #[serde(default)]
+    pub date_created: Option<String>,
+
+    /// The date the variation was last modified, in the store's timezone.
+    #[serde(default)]
+    pub date_modified: Option<String>,
+
+    /// HTML description of the variation.
+    #[serde(default)]
+    pub description: String,
+
+    /// Variation URL.
+    #[serde(default)]
+    pub permalink: String,
+
+    /// Stock keeping unit.
+    #[serde(default)]
+    pub sku: String,
+
+    /// Current price.
+    #[serde(default, deserialize_with = "decimal::optional")]
+    pub price: Option<Decimal>,
+
+    /// Regular price.
+    #[serde(default, deserialize_with = "decimal::optional")]
+    pub regular_price: Option<Decimal>,
+
+    /// Sale price.
+    #[serde(default, deserialize_with = "decimal::optional")]
+    pub sale_price: Option<Decimal>,
+
+    /// Whether the variation is on sale.
+    #[serde(default)]
+    pub on_sale: bool,
+
+    /// Variation status, such as `publish` or `private`.
+    #[serde(default)]
+    pub status: String,
+
+    /// Whether the variation can be bought.
+    #[serde(default)]
+    pub purchasable: bool,
+
+    /// Whether the variation is virtual, i.e. not shipped.
+    #[serde(rename = "virtual", default)]
+    pub is_virtual: bool,
+
+    /// Whether the variation is downloadable.
+    #[serde(default)]
+    pub downloadable: bool,
+
+    /// Whether stock is managed at the variation level, or `None` when it is
+    /// managed by the parent product.
+    #[serde(default, deserialize_with = "manage_stock")]
+    pub manage_stock: Option<bool>,
+
+    /// Stock quantity, if stock is managed.
+    #[serde(default)]
+    pub stock_quantity: Option<i64>,
+
+    /// Stock status: `instock`, `outofstock` or `onbackorder`.
+    #[serde(default)]
+    pub stock_status: String,
+
+    /// Variation weight.
+    #[serde(default)]
+    pub weight: String,
+
+    /// Variation dimensions.
+    #[serde(default)]
+    pub dimensions: Dimensions,
+
+    /// Variation image.
+    #[serde(default)]
+    pub image: Option<ProductImage>,
+
+    /// The attribute options selecting the variation.
+    #[serde(default)]
+    pub attributes: Vec<VariationAttribute>,
+
+    /// Meta fields.
+    #[serde(default)]
+    pub meta_data: Vec<MetaData>,
+
+    /// Links to related resources.
+    #[serde(rename = "_links", default, skip_serializing_if = "Links::is_empty")]
+    pub links: Links,
+}
+
+/// The dimensions of a product, in the store's unit of length.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dimensions {
+    /// Product length.
+    #[serde(default)]
+    pub length: String,
+
+    /// Product width.
+    #[serde(default)]
+    pub width: String,
+
+    /// Product height.
+    #[serde(default)]
+    pub height: String,
+}
+
+/// A category or tag assigned to a product.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductTerm {
+    /// Term ID.
+    pub id: u32,
+
+    /// Term name.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+
+    /// Term slug.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub slug: String,
+}
+
+/// An image of a product.
+///
+/// To add an image from a URL, leave `id` at 0 and set `src`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductImage {
+    /// Attachment ID of the image.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub id: u32,
+
+    /// Image URL.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub src: String,
+
+    /// Image name.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+
+    /// Image alternative text.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub alt: String,
+}
+
+/// An attribute of a product, such as its size or color.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductAttribute {
+    /// ID of a global attribute, or 0 for an attribute of this product only.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Attribute name.
+    #[serde(default)]
+    pub name: String,
+
+    /// Position of the attribute.
+    #[serde(default)]
+    pub position: u32,
+
+    /// Whether the attribute is shown on the product page.
+    #[serde(default)]
+    pub visible: bool,
+
+    /// Whether the attribute is used for variations.
+    #[serde(default)]
+    pub variation: bool,
+
+    /// The options of the attribute.
+    #[serde(default)]
+    pub options: Vec<String>,
+}
+
+/// The option of an attribute selecting a variation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariationAttribute {
+    /// ID of a global attribute, or 0 for an attribute of the product only.
+    #[serde(default)]
+    pub id: u32,
+
+    /// Attribute name.
+    #[serde(default)]
+    pub name: String,
+
+    /// The selected option.
+    #[serde(default)]
+    pub option: String,
+}
+
+/// Deserialize `manage_stock`, which is `"parent"` when the variation uses the
+/// stock of its product.
+fn manage_stock<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Bool(manage) => Some(manage),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let product: Product = serde_json::from_value(json!({
+            "id": 794,
+            "name": "Premium Quality",
+            "type": "variable",
+            "price": "21.99",
+            "sale_price": "",
+            "virtual": false,
+            "stock_quantity": null,
+            "dimensions": { "length": "", "width": "", "height": "" },
+            "categories": [{ "id": 9, "name": "Clothing", "slug": "clothing" }],
+            "attributes": [{
+                "id": 6,
+                "name": "Color",
+                "position": 0,
+                "visible": false,
+                "variation": true,
+                "options": ["Black", "Green"],
+            }],
+            "variations": [799, 800],
+            "meta_data": [{ "id": 1, "key": "_featured", "value": "yes" }],
+        }))
+        .unwrap();
+
+        assert_eq!(product.id, 794);
+        assert_eq!(product.product_type, "variable");
+        assert_eq!(product.price, Some(Decimal::new(2199, 2)));
+        assert_eq!(product.sale_price, None);
+        assert_eq!(product.stock_quantity, None);
+        assert_eq!(product.categories[0].slug, "clothing");
+        assert_eq!(product.attributes[0].options, ["Black", "Green"]);
+        assert_eq!(product.variations, [799, 800]);
+        assert_eq!(product.meta_data[0].key, "_featured");
+    }
+
+    #[test]
+    fn variation_parent_stock() {
+        let variation: ProductVariation = serde_json::from_value(json!({
+            "id": 799,
+            "manage_stock": "parent",
+            "attributes": [{ "id": 6, "name": "Color", "option": "Black" }],
+        }))
+        .unwrap();
+
+        assert_eq!(variation.manage_stock, None);
+        assert_eq!(variation.attributes[0].option, "Black");
+    }
+
+    #[test]
+    fn serialize_new_image() {
+        let image = ProductImage {
+            src: "http://example.com/shirt.jpg".into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(image).unwrap(),
+            json!({ "src": "http://example.com/shirt.jpg" })
+        );
+    }
+}
@@ -0,0 +1,523 @@
+//! Reading WordPress export (WXR) files and importing them into a site.
+//!
+//! [`parse`] reads the XML file written by _Tools → Export_ into the models of
+//! [`types`](crate::types), as they would be returned in the `edit` context,
+//! and [`import`] creates them on another site through the REST API.
+//!
+//! Exports hold the stored values of titles and content rather than their
+//! rendered HTML, so both the `raw` and `rendered` values of these fields are
+//! set to the stored value. Post types other than posts, pages and
+//! attachments, such as navigation menu items, are skipped.
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+use thiserror::Error;
+
+pub(crate) use self::import::{generations, mapped, raw};
+pub use self::import::{import, import_with, IdMap, ImportOptions, ImportOptionsBuilder};
+use self::xml::Element;
+use crate::types::{Attachment, Comment, Page, Post, Term, User};
+
+mod import;
+mod xml;
+
+/// The error returned when a WXR file cannot be read.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum WxrError {
+    /// The file is not well-formed XML.
+    #[error("invalid xml at byte {}: {}", position, message)]
+    Xml {
+        /// The offset in the file where reading failed.
+        position: usize,
+        /// What was wrong.
+        message: &'static str,
+    },
+
+    /// The file is XML but not an export.
+    #[error("not a wxr file: missing <{}>", element)]
+    NotWxr {
+        /// The missing element.
+        element: &'static str,
+    },
+
+    /// An exported item could not be converted to its model.
+    #[error("invalid exported {}: {}", kind, source)]
+    Item {
+        /// The kind of item, such as `post`.
+        kind: &'static str,
+        /// The source of the error.
+        source: serde_json::Error,
+    },
+}
+
+/// The content of an export.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Export {
+    /// The title of the exported site.
+    pub title: String,
+
+    /// The URL of the exported site.
+    pub link: String,
+
+    /// The URL of the WordPress installation of the exported site.
+    pub base_site_url: String,
+
+    /// The authors of the exported content.
+    pub authors: Vec<User>,
+
+    /// Terms of every taxonomy, including categories and tags.
+    pub terms: Vec<Term>,
+
+    /// The exported posts.
+    pub posts: Vec<Post>,
+
+    /// The exported pages.
+    pub pages: Vec<Page>,
+
+    /// The exported media attachments.
+    pub attachments: Vec<Attachment>,
+
+    /// Comments of the exported posts, pages and attachments.
+    pub comments: Vec<Comment>,
+}
+
+/// Read an export.
+pub fn parse(input: &str) -> Result<Export, WxrError> {
+    let rss = xml::parse(input)?;
+    if rss.name != "rss" {
+        return Err(WxrError::NotWxr { element: "rss" });
+    }
+    let channel = rss
+        .child("channel")
+        .ok_or(WxrError::NotWxr { element: "channel" })?;
+
+    let mut export = Export {
+        title: channel.child_text("title"),
+        link: channel.child_text("link"),
+        base_site_url: channel.child_text("wp:base_site_url"),
+        ..Export::default()
+    };
+
+    for author in channel.elements("wp:author") {
+        export.authors.push(model("author", author_json(author))?);
+    }
+    let authors: HashMap<_, _> = export
+        .authors
+        .iter()
+        .filter_map(|author| Some((author.username.clone()?, author.id)))
+        .collect();
+
+    let terms: Vec<_> = channel
+        .elements("wp:category")
+        .map(category_json)
+        .chain(channel.elements("wp:tag").map(tag_json))
+        .chain(channel.elements("wp:term").map(term_json))
+        .collect();
+    let ids: HashMap<_, _> = terms
+        .iter()
+        .map(|(term, _)| {
+            (
+                (term["taxonomy"].clone(), term["slug"].clone()),
+                term["id"].clone(),
+            )
+        })
+        .collect();
+    for (mut term, parent) in terms {
+        if let Some(id) = parent.and_then(|parent| ids.get(&(term["taxonomy"].clone(), parent))) {
+            term["parent"] = id.clone();
+        }
+        export.terms.push(model("term", term)?);
+    }
+    let term_id = |taxonomy: &str, slug: &str| -> Option<Value> {
+        ids.get(&(taxonomy.into(), slug.into())).cloned()
+    };
+
+    for item in channel.elements("item") {
+        let mut json = item_json(item, &authors);
+        let id = json["id"].clone();
+        match item.child_text("wp:post_type").as_str() {
+            "post" => {
+                let mut categories = Vec::new();
+                let mut tags = Vec::new();
+                for category in item.elements("category") {
+                    let (Some(domain), Some(slug)) =
+                        (category.attr("domain"), category.attr("nicename"))
+                    else {
+                        continue;
+                    };
+                    match domain {
+                        "category" => categories.extend(term_id(domain, slug)),
+                        "post_tag" => tags.extend(term_id(domain, slug)),
+                        "post_format" => {
+                            json["format"] = slug.trim_start_matches("post-format-").into()
+                        }
+                        _ => {}
+                    }
+                }
+                json["content"] = rendered(item.child_text("content:encoded"));
+                json["excerpt"] = rendered(item.child_text("excerpt:encoded"));
+                json["sticky"] = (item.child_text("wp:is_sticky") == "1").into();
+                json["featured_media"] = integer(&post_meta(item, "_thumbnail_id")).into();
+                json["categories"] = categories.into();
+                json["tags"] = tags.into();
+                export.posts.push(model("post", json)?);
+            }
+            "page" => {
+                json["content"] = rendered(item.child_text("content:encoded"));
+                json["excerpt"] = rendered(item.child_text("excerpt:encoded"));
+                json["featured_media"] = integer(&post_meta(item, "_thumbnail_id")).into();
+                json["parent"] = integer(&item.child_text("wp:post_parent")).into();
+                json["menu_order"] = item
+                    .child_text("wp:menu_order")
+                    .trim()
+                    .parse::<i32>()
+                    .unwrap_or_default()
+                    .into();
+                json["password"] = item.child_text("wp:post_password").into();
+                let template = post_meta(item, "_wp_page_template");
+                if template != "default" {
+                    json["template"] = template.into();
+                }
+                export.pages.push(model("page", json)?);
+            }
+            "attachment" => {
+                let parent = integer(&item.child_text("wp:post_parent"));
+                json["description"] = rendered(item.child_text("content:encoded"));
+                json["caption"] = rendered(item.child_text("excerpt:encoded"));
+                json["alt_text"] = post_meta(item, "_wp_attachment_image_alt").into();
+                json["source_url"] = item.child_text("wp:attachment_url").into();
+                json["post"] = if parent == 0 {
+                    Value::Null
+                } else {
+                    parent.into()
+                };
+                export.attachments.push(model("attachment", json)?);
+            }
+            _ => continue,
+        }
+        for comment in item.elements("wp:comment") {
+            export
+                .comments
+                .push(model("comment", comment_json(comment, &id))?);
+        }
+    }
+
+    Ok(export)
+}
+
+fn model<T>(kind: &'static str, json: Value) -> Result<T, WxrError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_json::from_value(json).map_err(|source| WxrError::Item { kind, source })
+}
+
+/// A field with the same raw and rendered value.
+fn rendered(value: String) -> Value {
+    json!({ "raw": value, "rendered": value })
+}
+
+fn integer(value: &str) -> u32 {
+    value.trim().parse().unwrap_or_default()
+}
+
+/// A date of an export, such as `2024-01-02 10:00:00`, in the format of the
+/// REST API, or an empty string for dates which are not set.
+fn date(value: &str) -> String {
+    let value = value.trim();
+    if value.is_empty() || value.starts_with("0000-00-00") {
+        return String::new();
+    }
+    value.replacen(' ', "T", 1)
+}
+
+/// The value of a meta field of an item, or an empty string.
+fn post_meta(item: &Element, key: &str) -> String {
+    item.elements("wp:postmeta")
+        .find(|meta| meta.child_text("wp:meta_key") == key)
+        .map(|meta| meta.child_text("wp:meta_value"))
+        .unwrap_or_default()
+}
+
+fn author_json(author: &Element) -> Value {
+    let login = author.child_text("wp:author_login");
+    json!({
+        "id": integer(&author.child_text("wp:author_id")),
+        "username": login,
+        "slug": login,
+        "name": author.child_text("wp:author_display_name"),
+        "email": author.child_text("wp:author_email"),
+        "first_name": author.child_text("wp:author_first_name"),
+        "last_name": author.child_text("wp:author_last_name"),
+    })
+}
+
+/// A category, with the slug of its parent.
+fn category_json(category: &Element) -> (Value, Option<Value>) {
+    let term = json!({
+        "id": integer(&category.child_text("wp:term_id")),
+        "taxonomy": "category",
+        "slug": category.child_text("wp:category_nicename"),
+        "name": category.child_text("wp:cat_name"),
+        "description": category.child_text("wp:category_description"),
+    });
+    (term, parent_slug(category, "wp:category_parent"))
+}
+
+fn tag_json(tag: &Element) -> (Value, Option<Value>) {
+    let term = json!({
+        "id": integer(&tag.child_text("wp:term_id")),
+        "taxonomy": "post_tag",
+        "slug": tag.child_text("wp:tag_slug"),
+        "name": tag.child_text("wp:tag_name"),
+        "description": tag.child_text("wp:tag_description"),
+    });
+    (term, None)
+}
+
+/// A term of another taxonomy, with the slug of its parent.
+fn term_json(term: &Element) -> (Value, Option<Value>) {
+    let json = json!({
+        "id": integer(&term.child_text("wp:term_id")),
+        "taxonomy": term.child_text("wp:term_taxonomy"),
+        "slug": term.child_text("wp:term_slug"),
+        "name": term.child_text("wp:term_name"),
+        "description": term.child_text("wp:term_description"),
+    });
+    (json, parent_slug(term, "wp:term_parent"))
+}
+
+fn parent_slug(term: &Element, name: &str) -> Option<Value> {
+    Some(term.child_text(name))
+        .filter(|slug| !slug.is_empty())
+        .map(Value::from)
+}
+
+/// The fields shared by posts, pages and attachments.
+fn item_json(item: &Element, authors: &HashMap<String, u32>) -> Value {
+    let creator = item.child_text("dc:creator");
+    json!({
+        "id": integer(&item.child_text("wp:post_id")),
+        "date": date(&item.child_text("wp:post_date")),
+        "date_gmt": date(&item.child_text("wp:post_date_gmt")),
+        "modified": date(&item.child_text("wp:post_modified")),
+        "modified_gmt": date(&item.child_text("wp:post_modified_gmt")),
+        "guid": { "rendered": item.child_text("guid") },
+        "slug": item.child_text("wp:post_name"),
+        "status": item.child_text("wp:status"),
+        "type": item.child_text("wp:post_type"),
+        "link": item.child_text("link"),
+        "title": rendered(item.child_text("title")),
+        "author": authors.get(&creator).copied().unwrap_or_default(),
+        "comment_status": item.child_text("wp:comment_status"),
+        "ping_status": item.child_text("wp:ping_status"),
+    })
+}
+
+fn comment_json(comment: &Element, post: &Value) -> Value {
+    let status = match comment.child_text("wp:comment_approved").as_str() {
+        "1" => "approved".to_owned(),
+        "0" => "hold".to_owned(),
+        status => status.to_owned(),
+    };
+    let comment_type = comment.child_text("wp:comment_type");
+    json!({
+        "id": integer(&comment.child_text("wp:comment_id")),
+        "post": post,
+        "parent": integer(&comment.child_text("wp:comment_parent")),
+        "author": integer(&comment.child_text("wp:comment_user_id")),
+        "author_name": comment.child_text("wp:comment_author"),
+        "author_email": comment.child_text("wp:comment_author_email"),
+        "author_url": comment.child_text("wp:comment_author_url"),
+        "author_ip": comment.child_text("wp:comment_author_IP"),
+        "date": date(&comment.child_text("wp:comment_date")),
+        "date_gmt": date(&comment.child_text("wp:comment_date_gmt")),
+        "content": rendered(comment.child_text("wp:comment_content")),
+        "status": status,
+        "type": if comment_type.is_empty() { "comment".to_owned() } else { comment_type },
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    pub(crate) const EXPORT: &str = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<!-- This is a WordPress eXtended RSS file generated by WordPress as an export of your site. -->
+<rss version="2.0"
+	xmlns:excerpt="http://wordpress.org/export/1.2/excerpt/"
+	xmlns:content="http://purl.org/rss/1.0/modules/content/"
+	xmlns:dc="http://purl.org/dc/elements/1.1/"
+	xmlns:wp="http://wordpress.org/export/1.2/"
+>
+<channel>
+	<title>Old Site</title>
+	<link>https://old.example</link>
+	<wp:wxr_version>1.2</wp:wxr_version>
+	<wp:base_site_url>https://old.example</wp:base_site_url>
+	<wp:author><wp:author_id>2</wp:author_id><wp:author_login><![CDATA[jo]]></wp:author_login><wp:author_email><![CDATA[jo@old.example]]></wp:author_email><wp:author_display_name><![CDATA[Jo]]></wp:author_display_name><wp:author_first_name><![CDATA[]]></wp:author_first_name><wp:author_last_name><![CDATA[]]></wp:author_last_name></wp:author>
+	<wp:category><wp:term_id>3</wp:term_id><wp:category_nicename><![CDATA[news]]></wp:category_nicename><wp:category_parent><![CDATA[]]></wp:category_parent><wp:cat_name><![CDATA[News]]></wp:cat_name></wp:category>
+	<wp:category><wp:term_id>4</wp:term_id><wp:category_nicename><![CDATA[local]]></wp:category_nicename><wp:category_parent><![CDATA[news]]></wp:category_parent><wp:cat_name><![CDATA[Local News]]></wp:cat_name></wp:category>
+	<wp:tag><wp:term_id>7</wp:term_id><wp:tag_slug><![CDATA[rust]]></wp:tag_slug><wp:tag_name><![CDATA[Rust]]></wp:tag_name></wp:tag>
+	<item>
+		<title><![CDATA[Hello & welcome]]></title>
+		<link>https://old.example/hello/</link>
+		<dc:creator><![CDATA[jo]]></dc:creator>
+		<guid isPermaLink="false">https://old.example/?p=10</guid>
+		<content:encoded><![CDATA[<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->]]></content:encoded>
+		<excerpt:encoded><![CDATA[]]></excerpt:encoded>
+		<wp:post_id>10</wp:post_id>
+		<wp:post_date><![CDATA[2024-01-02 10:00:00]]></wp:post_date>
+		<wp:post_date_gmt><![CDATA[2024-01-02 09:00:00]]></wp:post_date_gmt>
+		<wp:post_modified><![CDATA[2024-01-03 10:00:00]]></wp:post_modified>
+		<wp:post_modified_gmt><![CDATA[2024-01-03 09:00:00]]></wp:post_modified_gmt>
+		<wp:comment_status><![CDATA[open]]></wp:comment_status>
+		<wp:ping_status><![CDATA[closed]]></wp:ping_status>
+		<wp:post_name><![CDATA[hello]]></wp:post_name>
+		<wp:status><![CDATA[publish]]></wp:status>
+		<wp:post_parent>0</wp:post_parent>
+		<wp:menu_order>0</wp:menu_order>
+		<wp:post_type><![CDATA[post]]></wp:post_type>
+		<wp:post_password><![CDATA[]]></wp:post_password>
+		<wp:is_sticky>1</wp:is_sticky>
+		<category domain="category" nicename="local"><![CDATA[Local News]]></category>
+		<category domain="post_tag" nicename="rust"><![CDATA[Rust]]></category>
+		<category domain="post_format" nicename="post-format-aside"><![CDATA[Aside]]></category>
+		<wp:postmeta><wp:meta_key><![CDATA[_thumbnail_id]]></wp:meta_key><wp:meta_value><![CDATA[20]]></wp:meta_value></wp:postmeta>
+		<wp:comment>
+			<wp:comment_id>30</wp:comment_id>
+			<wp:comment_author><![CDATA[Sam]]></wp:comment_author>
+			<wp:comment_author_email><![CDATA[sam@example.com]]></wp:comment_author_email>
+			<wp:comment_author_url>https://sam.example</wp:comment_author_url>
+			<wp:comment_author_IP><![CDATA[127.0.0.1]]></wp:comment_author_IP>
+			<wp:comment_date><![CDATA[2024-01-04 10:00:00]]></wp:comment_date>
+			<wp:comment_date_gmt><![CDATA[2024-01-04 09:00:00]]></wp:comment_date_gmt>
+			<wp:comment_content><![CDATA[Nice post!]]></wp:comment_content>
+			<wp:comment_approved><![CDATA[1]]></wp:comment_approved>
+			<wp:comment_type><![CDATA[]]></wp:comment_type>
+			<wp:comment_parent>0</wp:comment_parent>
+			<wp:comment_user_id>0</wp:comment_user_id>
+		</wp:comment>
+	</item>
+	<item>
+		<title><![CDATA[About]]></title>
+		<dc:creator><![CDATA[jo]]></dc:creator>
+		<content:encoded><![CDATA[<p>About us</p>]]></content:encoded>
+		<excerpt:encoded><![CDATA[]]></excerpt:encoded>
+		<wp:post_id>11</wp:post_id>
+		<wp:post_date><![CDATA[2024-01-01 10:00:00]]></wp:post_date>
+		<wp:post_date_gmt><![CDATA[0000-00-00 00:00:00]]></wp:post_date_gmt>
+		<wp:post_name><![CDATA[about]]></wp:post_name>
+		<wp:status><![CDATA[draft]]></wp:status>
+		<wp:post_parent>0</wp:post_parent>
+		<wp:menu_order>2</wp:menu_order>
+		<wp:post_type><![CDATA[page]]></wp:post_type>
+		<wp:postmeta><wp:meta_key><![CDATA[_wp_page_template]]></wp:meta_key><wp:meta_value><![CDATA[wide.php]]></wp:meta_value></wp:postmeta>
+	</item>
+	<item>
+		<title><![CDATA[Photo]]></title>
+		<content:encoded><![CDATA[A photo]]></content:encoded>
+		<excerpt:encoded><![CDATA[At the beach]]></excerpt:encoded>
+		<wp:post_id>20</wp:post_id>
+		<wp:post_name><![CDATA[photo]]></wp:post_name>
+		<wp:status><![CDATA[inherit]]></wp:status>
+		<wp:post_parent>10</wp:post_parent>
+		<wp:post_type><![CDATA[attachment]]></wp:post_type>
+		<wp:attachment_url><![CDATA[https://old.example/wp-content/uploads/photo.png]]></wp:attachment_url>
+		<wp:postmeta><wp:meta_key><![CDATA[_wp_attachment_image_alt]]></wp:meta_key><wp:meta_value><![CDATA[Waves]]></wp:meta_value></wp:postmeta>
+	</item>
+	<item>
+		<title><![CDATA[Home]]></title>
+		<wp:post_id>40</wp:post_id>
+		<wp:post_type><![CDATA[nav_menu_item]]></wp:post_type>
+	</item>
+</channel>
+</rss>
+"#;
+
+    #[test]
+    fn export() {
+        let export = parse(EXPORT).unwrap();
+
+        assert_eq!(export.title, "Old Site");
+        assert_eq!(export.base_site_url, "https://old.example");
+        assert_eq!(export.authors[0].id, 2);
+        assert_eq!(export.authors[0].name, "Jo");
+        assert_eq!(export.authors[0].email.as_deref(), Some("jo@old.example"));
+
+        let terms: Vec<_> = export
+            .terms
+            .iter()
+            .map(|term| {
+                (
+                    term.id,
+                    term.taxonomy.as_str(),
+                    term.slug.as_str(),
+                    term.parent,
+                )
+            })
+            .collect();
+        assert_eq!(
+            terms,
+            vec![
+                (3, "category", "news", 0),
+                (4, "category", "local", 3),
+                (7, "post_tag", "rust", 0),
+            ]
+        );
+
+        let post = &export.posts[0];
+        assert_eq!(post.id, 10);
+        assert_eq!(post.title.raw(), Some("Hello & welcome"));
+        assert_eq!(
+            post.content.as_html(),
+            "<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->"
+        );
+        assert_eq!(post.date, "2024-01-02T10:00:00");
+        assert_eq!(post.modified_gmt, "2024-01-03T09:00:00");
+        assert_eq!(post.author, 2);
+        assert_eq!(post.status, "publish");
+        assert!(post.sticky);
+        assert_eq!(post.format, "aside");
+        assert_eq!(post.featured_media, 20);
+        assert_eq!(post.categories, vec![4]);
+        assert_eq!(post.tags, vec![7]);
+        assert_eq!(post.guid.as_html(), "https://old.example/?p=10");
+
+        let page = &export.pages[0];
+        assert_eq!(page.id, 11);
+        assert_eq!(page.date_gmt, "");
+        assert_eq!(page.menu_order, 2);
+        assert_eq!(page.template, "wide.php");
+
+        let attachment = &export.attachments[0];
+        assert_eq!(attachment.id, 20);
+        assert_eq!(attachment.post, Some(10));
+        assert_eq!(attachment.alt_text, "Waves");
+        assert_eq!(attachment.caption.as_html(), "At the beach");
+        assert_eq!(
+            attachment.source_url,
+            "https://old.example/wp-content/uploads/photo.png"
+        );
+
+        let comment = &export.comments[0];
+        assert_eq!((comment.id, comment.post), (30, 10));
+        assert_eq!(comment.author_name, "Sam");
+        assert_eq!(comment.status, "approved");
+        assert_eq!(comment.comment_type, "comment");
+        assert_eq!(comment.content.raw(), Some("Nice post!"));
+    }
+
+    #[test]
+    fn not_wxr() {
+        let err = parse("<html><body></body></html>").unwrap_err();
+        assert!(matches!(err, WxrError::NotWxr { element: "rss" }));
+
+        let err = parse("<rss></rss>").unwrap_err();
+        assert!(matches!(err, WxrError::NotWxr { element: "channel" }));
+    }
+}
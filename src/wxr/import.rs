@@ -0,0 +1,557 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    net::Ipv4Addr,
+};
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use url::{Host, Url};
+
+use super::Export;
+use crate::{
+    client::{same_origin, Client},
+    endpoints::{
+        comments::CommentStatus, CreateComment, CreatePage, CreatePost, CreateTerm, SideloadMedia,
+    },
+    error::ApiError,
+    query::Query,
+    types::{Attachment, Comment, Page, Post, Rendered, Term},
+};
+
+/// The IDs of the imported items on the target site, by their IDs in the
+/// export.
+///
+/// Items already in the map are skipped by [`import`], so an import which
+/// failed part of the way can be carried on with the same map.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdMap {
+    /// Terms of every taxonomy.
+    pub terms: BTreeMap<u32, u32>,
+
+    /// Posts, pages and attachments, which share their IDs.
+    pub posts: BTreeMap<u32, u32>,
+
+    /// Comments of every post type.
+    pub comments: BTreeMap<u32, u32>,
+}
+
+/// Options for [`import_with`].
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(default)]
+pub struct ImportOptions {
+    /// Whether to sideload the attachments from their URLs on the exported
+    /// site, which must still be reachable. Defaults to false.
+    ///
+    /// The URLs are taken from the export, so only `http` and `https` URLs
+    /// on other hosts than the site itself are downloaded, without the
+    /// client's credentials. Hosts given as private, loopback or link-local
+    /// addresses, or as `localhost`, fail with [`ApiError::UntrustedMediaUrl`].
+    media: bool,
+}
+
+impl ImportOptions {
+    pub fn builder() -> ImportOptionsBuilder {
+        ImportOptionsBuilder::default()
+    }
+}
+
+/// Create the content of an export on a site, without its media.
+///
+/// See [`import_with`].
+pub async fn import<C>(
+    client: &C,
+    export: &Export,
+    ids: &mut IdMap,
+) -> Result<(), ApiError<C::Error>>
+where
+    C: Client + Sync,
+{
+    import_with(client, export, ids, &ImportOptions::default()).await
+}
+
+/// Create the content of an export on a site.
+///
+/// Terms are created first, then attachments, pages, posts and comments, so
+/// that the references between them are mapped to the created items.
+/// Attachments are only imported if enabled in the options.
+///
+/// Authors are not created, as users cannot be created without a password,
+/// and the content is attributed to the user of the client. Links to the
+/// exported site within the content are kept as they are.
+pub async fn import_with<C>(
+    client: &C,
+    export: &Export,
+    ids: &mut IdMap,
+    options: &ImportOptions,
+) -> Result<(), ApiError<C::Error>>
+where
+    C: Client + Sync,
+{
+    for term in parents_first(&export.terms, |term| term.id, |term| term.parent) {
+        let Some(taxonomy) = rest_base(&term.taxonomy) else {
+            continue;
+        };
+        if ids.terms.contains_key(&term.id) {
+            continue;
+        }
+        let mut builder = CreateTerm::builder();
+        builder
+            .taxonomy(taxonomy)
+            .name(term.name.as_str())
+            .slug(term.slug.as_str())
+            .description(term.description.as_str());
+        if let Some(&parent) = ids.terms.get(&term.parent) {
+            builder.parent(parent);
+        }
+        let endpoint = builder.build().expect("required fields are set");
+        let created: Term = endpoint.query(client).await?;
+        ids.terms.insert(term.id, created.id);
+    }
+
+    let attachments = if options.media {
+        export.attachments.as_slice()
+    } else {
+        &[]
+    };
+    for attachment in attachments {
+        if ids.posts.contains_key(&attachment.id) {
+            continue;
+        }
+        let url = Url::parse(&attachment.source_url)?;
+        if !is_public(&url) || same_origin(&url, &client.route_url("/").await?) {
+            return Err(ApiError::untrusted_media_url(url));
+        }
+        let mut builder = SideloadMedia::builder();
+        builder.url(url);
+        if !attachment.alt_text.is_empty() {
+            builder.alt_text(attachment.alt_text.as_str());
+        }
+        if let Some(caption) = attachment
+            .caption
+            .raw()
+            .filter(|caption| !caption.is_empty())
+        {
+            builder.caption(caption);
+        }
+        let endpoint = builder.build().expect("required fields are set");
+        let created: Attachment = endpoint.query(client).await?;
+        ids.posts.insert(attachment.id, created.id);
+    }
+
+    for page in parents_first(&export.pages, |page| page.id, |page| page.parent) {
+        if ids.posts.contains_key(&page.id) {
+            continue;
+        }
+        let mut builder = CreatePage::builder();
+        builder
+            .slug(page.slug.as_str())
+            .status(page.status.as_str())
+            .title(raw(&page.title))
+            .content(raw(&page.content))
+            .excerpt(raw(&page.excerpt))
+            .comment_status(page.comment_status.as_str())
+            .ping_status(page.ping_status.as_str())
+            .menu_order(page.menu_order)
+            .template(page.template.as_str());
+        if !page.date.is_empty() {
+            builder.date(page.date.as_str());
+        }
+        if !page.date_gmt.is_empty() {
+            builder.date_gmt(page.date_gmt.as_str());
+        }
+        if let Some(password) = page
+            .password
+            .as_deref()
+            .filter(|password| !password.is_empty())
+        {
+            builder.password(password);
+        }
+        if let Some(&parent) = ids.posts.get(&page.parent) {
+            builder.parent(parent);
+        }
+        if let Some(&media) = ids.posts.get(&page.featured_media) {
+            builder.featured_media(media);
+        }
+        let endpoint = builder.build().expect("all fields have defaults");
+        let created: Page = endpoint.query(client).await?;
+        ids.posts.insert(page.id, created.id);
+    }
+
+    for post in &export.posts {
+        if ids.posts.contains_key(&post.id) {
+            continue;
+        }
+        let mut builder = CreatePost::builder();
+        builder
+            .slug(post.slug.as_str())
+            .status(post.status.as_str())
+            .title(raw(&post.title))
+            .content(raw(&post.content))
+            .excerpt(raw(&post.excerpt))
+            .comment_status(post.comment_status.as_str())
+            .ping_status(post.ping_status.as_str())
+            .sticky(post.sticky)
+            .categories(mapped(&ids.terms, &post.categories))
+            .tags(mapped(&ids.terms, &post.tags));
+        if !post.format.is_empty() {
+            builder.format(post.format.as_str());
+        }
+        if let Some(&media) = ids.posts.get(&post.featured_media) {
+            builder.featured_media(media);
+        }
+        if !post.date.is_empty() {
+            builder.date(post.date.as_str());
+        }
+        if !post.date_gmt.is_empty() {
+            builder.date_gmt(post.date_gmt.as_str());
+        }
+        let endpoint = builder.build().expect("all fields have defaults");
+        let created: Post = endpoint.query(client).await?;
+        ids.posts.insert(post.id, created.id);
+    }
+
+    for comment in parents_first(
+        &export.comments,
+        |comment| comment.id,
+        |comment| comment.parent,
+    ) {
+        if ids.comments.contains_key(&comment.id) {
+            continue;
+        }
+        let Some(&post) = ids.posts.get(&comment.post) else {
+            continue;
+        };
+        let mut builder = CreateComment::builder();
+        builder
+            .post(post)
+            .content(raw(&comment.content))
+            .author_name(comment.author_name.as_str())
+            .author_url(comment.author_url.as_str());
+        if let Some(email) = comment
+            .author_email
+            .as_deref()
+            .filter(|email| !email.is_empty())
+        {
+            builder.author_email(email);
+        }
+        if let Some(ip) = comment.author_ip.as_deref().filter(|ip| !ip.is_empty()) {
+            builder.author_ip(ip);
+        }
+        if let Ok(status) = serde_json::from_value::<CommentStatus>(comment.status.as_str().into())
+        {
+            builder.status(status);
+        }
+        if let Some(&parent) = ids.comments.get(&comment.parent) {
+            builder.parent(parent);
+        }
+        if !comment.date.is_empty() {
+            builder.date(comment.date.as_str());
+        }
+        if !comment.date_gmt.is_empty() {
+            builder.date_gmt(comment.date_gmt.as_str());
+        }
+        let endpoint = builder.build().expect("required fields are set");
+        let created: Comment = endpoint.query(client).await?;
+        ids.comments.insert(comment.id, created.id);
+    }
+
+    Ok(())
+}
+
+/// Whether a URL is an `http` or `https` URL whose host is not obviously
+/// private. Domain names are not resolved.
+fn is_public(url: &Url) -> bool {
+    fn public_v4(ip: Ipv4Addr) -> bool {
+        let shared = ip.octets()[0] == 100 && ip.octets()[1] & 0xc0 == 64;
+        !(ip.is_private()
+            || ip.is_loopback()
+            || ip.is_link_local()
+            || ip.is_unspecified()
+            || ip.is_broadcast()
+            || shared)
+    }
+
+    if !matches!(url.scheme(), "http" | "https") {
+        return false;
+    }
+    match url.host() {
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        Some(Host::Ipv4(ip)) => public_v4(ip),
+        Some(Host::Ipv6(ip)) => match ip.to_ipv4_mapped() {
+            Some(ip) => public_v4(ip),
+            None => {
+                let unique_local = ip.segments()[0] & 0xfe00 == 0xfc00;
+                let link_local = ip.segments()[0] & 0xffc0 == 0xfe80;
+                !(ip.is_loopback() || ip.is_unspecified() || unique_local || link_local)
+            }
+        },
+        None => false,
+    }
+}
+
+/// The `rest_base` of a taxonomy, assuming custom taxonomies use their name.
+/// Navigation menus and post formats are not imported as terms.
+pub(crate) fn rest_base(taxonomy: &str) -> Option<&str> {
+    match taxonomy {
+        "category" => Some("categories"),
+        "post_tag" => Some("tags"),
+        "nav_menu" | "post_format" => None,
+        taxonomy => Some(taxonomy),
+    }
+}
+
+/// Sort items so that parents come before their children.
+fn parents_first<T>(items: &[T], id: impl Fn(&T) -> u32, parent: impl Fn(&T) -> u32) -> Vec<&T> {
+    generations(items, id, parent)
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Group items by their depth, so that each item comes after its parent.
+/// Items whose parent is not in the list are at the top.
+pub(crate) fn generations<T>(
+    items: &[T],
+    id: impl Fn(&T) -> u32,
+    parent: impl Fn(&T) -> u32,
+) -> Vec<Vec<&T>> {
+    let mut generations = Vec::new();
+    let mut placed = HashSet::new();
+    let mut rest: Vec<&T> = items.iter().collect();
+    while !rest.is_empty() {
+        let ready = |item: &&T| {
+            let parent = parent(item);
+            parent == 0
+                || placed.contains(&parent)
+                || !items.iter().any(|other| id(other) == parent)
+        };
+        let (next, waiting): (Vec<_>, Vec<_>) = rest.into_iter().partition(ready);
+        if next.is_empty() {
+            // A cycle of parents, which is created as it is.
+            generations.push(waiting);
+            break;
+        }
+        placed.extend(next.iter().map(|item| id(item)));
+        generations.push(next);
+        rest = waiting;
+    }
+    generations
+}
+
+pub(crate) fn raw(field: &Rendered) -> &str {
+    field.raw().unwrap_or(field.as_html())
+}
+
+pub(crate) fn mapped(ids: &BTreeMap<u32, u32>, old: &[u32]) -> Vec<u32> {
+    old.iter().filter_map(|id| ids.get(id).copied()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::{Method, Request, Response};
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        client::BodyStream,
+        test::{MockClient, MockClientError, MockResponse},
+        wxr::{parse, tests::EXPORT},
+    };
+
+    /// Records the JSON bodies sent to each route.
+    struct Recorder {
+        client: MockClient,
+        bodies: Mutex<Vec<(String, Value)>>,
+    }
+
+    #[async_trait]
+    impl Client for Recorder {
+        type Error = MockClientError;
+
+        async fn route_url(&self, route: &str) -> Result<Url, ApiError<Self::Error>> {
+            self.client.route_url(route).await
+        }
+
+        async fn send_request(
+            &self,
+            request: Request<Vec<u8>>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            if let Ok(body) = serde_json::from_slice(request.body()) {
+                let route = request.uri().path().to_owned();
+                self.bodies.lock().unwrap().push((route, body));
+            }
+            self.client.send_request(request).await
+        }
+
+        async fn send_external_request(
+            &self,
+            request: Request<Vec<u8>>,
+        ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+            self.client.send_external_request(request).await
+        }
+    }
+
+    fn created(method: Method, route: &str, id: u32) -> MockResponse {
+        MockResponse::builder()
+            .method(method)
+            .route(route)
+            .json(json!({ "id": id }))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn import_export() {
+        let export = parse(EXPORT).unwrap();
+        let client = Recorder {
+            client: MockClient::with_responses([
+                created(Method::POST, "/wp/v2/categories", 14),
+                created(Method::POST, "/wp/v2/tags", 17),
+                MockResponse::builder()
+                    .route("/wp-content/uploads/photo.png")
+                    .header("content-type", "image/png")
+                    .body("png")
+                    .build()
+                    .unwrap(),
+                MockResponse::builder()
+                    .method(Method::POST)
+                    .route("/wp/v2/media")
+                    .query("alt_text=Waves&caption=At+the+beach")
+                    .json(json!({ "id": 21 }))
+                    .build()
+                    .unwrap(),
+                created(Method::POST, "/wp/v2/pages", 22),
+                created(Method::POST, "/wp/v2/posts", 23),
+                created(Method::POST, "/wp/v2/comments", 24),
+            ]),
+            bodies: Mutex::default(),
+        };
+        // The parent category was imported by an earlier run.
+        let mut ids = IdMap::default();
+        ids.terms.insert(3, 13);
+
+        let options = ImportOptions::builder().media(true).build().unwrap();
+        import_with(&client, &export, &mut ids, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(ids.terms, BTreeMap::from([(3, 13), (4, 14), (7, 17)]));
+        assert_eq!(ids.posts, BTreeMap::from([(10, 23), (11, 22), (20, 21)]));
+        assert_eq!(ids.comments, BTreeMap::from([(30, 24)]));
+
+        let bodies = client.bodies.into_inner().unwrap();
+        let body = |route: &str| {
+            bodies
+                .iter()
+                .find(|(r, _)| r == route)
+                .map(|(_, body)| body.clone())
+                .unwrap()
+        };
+        assert_eq!(
+            body("/wp/v2/categories"),
+            json!({ "name": "Local News", "slug": "local", "description": "", "parent": 13 })
+        );
+        assert_eq!(
+            body("/wp/v2/pages"),
+            json!({
+                "date": "2024-01-01T10:00:00",
+                "slug": "about",
+                "status": "draft",
+                "title": "About",
+                "content": "<p>About us</p>",
+                "excerpt": "",
+                "comment_status": "",
+                "ping_status": "",
+                "menu_order": 2,
+                "template": "wide.php",
+            })
+        );
+        assert_eq!(
+            body("/wp/v2/posts"),
+            json!({
+                "date": "2024-01-02T10:00:00",
+                "date_gmt": "2024-01-02T09:00:00",
+                "slug": "hello",
+                "status": "publish",
+                "title": "Hello & welcome",
+                "content": "<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->",
+                "excerpt": "",
+                "featured_media": 21,
+                "comment_status": "open",
+                "ping_status": "closed",
+                "format": "aside",
+                "sticky": true,
+                "categories": [14],
+                "tags": [17],
+            })
+        );
+        assert_eq!(body("/wp/v2/comments")["post"], 23);
+        assert_eq!(body("/wp/v2/comments")["status"], "approve");
+    }
+
+    #[tokio::test]
+    async fn without_media() {
+        let export = parse(EXPORT).unwrap();
+        // Sideloading the attachment would fail, as it is not mocked.
+        let client = MockClient::with_responses([
+            created(Method::POST, "/wp/v2/categories", 14),
+            created(Method::POST, "/wp/v2/tags", 17),
+            created(Method::POST, "/wp/v2/pages", 22),
+            created(Method::POST, "/wp/v2/posts", 23),
+            created(Method::POST, "/wp/v2/comments", 24),
+        ]);
+        let mut ids = IdMap::default();
+
+        import(&client, &export, &mut ids).await.unwrap();
+
+        assert_eq!(ids.posts, BTreeMap::from([(10, 23), (11, 22)]));
+    }
+
+    #[tokio::test]
+    async fn untrusted_media() {
+        let mut export = parse(EXPORT).unwrap();
+        let client = MockClient::new();
+        let options = ImportOptions::builder().media(true).build().unwrap();
+
+        for url in [
+            "file:///etc/passwd",
+            "http://localhost/photo.png",
+            "http://127.0.0.1/photo.png",
+            "http://10.0.0.1/photo.png",
+            "http://169.254.169.254/latest/meta-data/",
+            "http://[::1]/photo.png",
+            "http://[::ffff:192.168.0.1]/photo.png",
+            "test://test/wp-content/uploads/photo.png",
+        ] {
+            export.attachments[0].source_url = url.into();
+            let mut ids = IdMap::default();
+            ids.terms.extend([(3, 13), (4, 14), (7, 17)]);
+
+            let result = import_with(&client, &export, &mut ids, &options).await;
+
+            let err = result.expect_err("expected ApiError::UntrustedMediaUrl");
+            if let ApiError::UntrustedMediaUrl { url: rejected } = err {
+                assert_eq!(rejected.as_str(), Url::parse(url).unwrap().as_str());
+            } else {
+                panic!("unexpected error: {}", err);
+            }
+        }
+    }
+
+    #[test]
+    fn parents_before_children() {
+        let items = [(3, 2), (2, 1), (1, 0), (4, 9)];
+
+        let sorted = parents_first(&items, |item| item.0, |item| item.1);
+
+        assert_eq!(sorted, vec![&(1, 0), &(4, 9), &(2, 1), &(3, 2)]);
+    }
+}
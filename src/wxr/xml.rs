@@ -0,0 +1,310 @@
+//! A minimal XML reader for WXR files.
+//!
+//! Only what exports use is supported: elements with attributes, text, CDATA
+//! sections, comments, the XML declaration and the predefined and numeric
+//! entities. Namespace prefixes are kept as part of the names, such as
+//! `wp:post_id`.
+
+use super::WxrError;
+
+/// An element and its content.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct Element {
+    pub(super) name: String,
+    pub(super) attrs: Vec<(String, String)>,
+    pub(super) children: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Node {
+    Element(Element),
+    Text(String),
+}
+
+impl Element {
+    /// The first child element with a name.
+    pub(super) fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find_map(|node| match node {
+            Node::Element(element) if element.name == name => Some(element),
+            _ => None,
+        })
+    }
+
+    /// The child elements with a name.
+    pub(super) fn elements<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter_map(move |node| match node {
+            Node::Element(element) if element.name == name => Some(element),
+            _ => None,
+        })
+    }
+
+    /// The value of an attribute.
+    pub(super) fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The text directly within the element.
+    pub(super) fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|node| match node {
+                Node::Text(text) => Some(text.as_str()),
+                Node::Element(_) => None,
+            })
+            .collect()
+    }
+
+    /// The text of the first child element with a name, or an empty string.
+    pub(super) fn child_text(&self, name: &str) -> String {
+        self.child(name).map(Element::text).unwrap_or_default()
+    }
+}
+
+/// Parse a document into its root element.
+pub(super) fn parse(input: &str) -> Result<Element, WxrError> {
+    let mut reader = Reader { input, pos: 0 };
+    let mut stack: Vec<Element> = Vec::new();
+    let mut root = None;
+
+    while reader.pos < input.len() {
+        let rest = reader.rest();
+        if rest.starts_with("<?") {
+            reader.skip_past("?>")?;
+        } else if rest.starts_with("<!--") {
+            reader.skip_past("-->")?;
+        } else if rest.starts_with("<![CDATA[") {
+            reader.pos += "<![CDATA[".len();
+            let text = reader.take_until("]]>")?.to_owned();
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(Node::Text(text)),
+                None => return Err(reader.error("text outside of the root element")),
+            }
+        } else if rest.starts_with("<!") {
+            reader.skip_past(">")?;
+        } else if rest.starts_with("</") {
+            let start = reader.pos;
+            reader.pos += 2;
+            let name = reader.take_until(">")?.trim();
+            let element = match stack.pop() {
+                Some(element) if element.name == name => element,
+                _ => {
+                    return Err(WxrError::Xml {
+                        position: start,
+                        message: "mismatched closing tag",
+                    })
+                }
+            };
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(Node::Element(element)),
+                None => root = Some(element),
+            }
+        } else if rest.starts_with('<') {
+            reader.pos += 1;
+            let (element, closed) = reader.start_tag()?;
+            if root.is_some() {
+                return Err(reader.error("more than one root element"));
+            }
+            match (closed, stack.last_mut()) {
+                (false, _) => stack.push(element),
+                (true, Some(parent)) => parent.children.push(Node::Element(element)),
+                (true, None) => root = Some(element),
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = &rest[..end];
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(Node::Text(unescape(text))),
+                None if text.trim().is_empty() => {}
+                None => return Err(reader.error("text outside of the root element")),
+            }
+            reader.pos += end;
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err(reader.error("unclosed element"));
+    }
+    root.ok_or_else(|| reader.error("no root element"))
+}
+
+struct Reader<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn error(&self, message: &'static str) -> WxrError {
+        WxrError::Xml {
+            position: self.pos,
+            message,
+        }
+    }
+
+    /// Take the input up to a delimiter, moving past it.
+    fn take_until(&mut self, delimiter: &str) -> Result<&'a str, WxrError> {
+        let rest = self.rest();
+        let end = rest
+            .find(delimiter)
+            .ok_or_else(|| self.error("unexpected end of input"))?;
+        self.pos += end + delimiter.len();
+        Ok(&rest[..end])
+    }
+
+    fn skip_past(&mut self, delimiter: &str) -> Result<(), WxrError> {
+        self.take_until(delimiter).map(drop)
+    }
+
+    /// Read a start tag after its `<`, returning whether it is self-closing.
+    fn start_tag(&mut self) -> Result<(Element, bool), WxrError> {
+        let mut element = Element {
+            name: self.name()?.to_owned(),
+            ..Element::default()
+        };
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok((element, true));
+            }
+            if rest.starts_with('>') {
+                self.pos += 1;
+                return Ok((element, false));
+            }
+
+            let key = self.name()?.to_owned();
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(self.error("expected `=` after attribute name"));
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(self.error("expected quoted attribute value")),
+            };
+            self.pos += 1;
+            let value = self.take_until(if quote == '"' { "\"" } else { "'" })?;
+            element.attrs.push((key, unescape(value)));
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, WxrError> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '>' | '/' | '='))
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return Err(self.error("expected a name"));
+        }
+        self.pos += end;
+        Ok(&rest[..end])
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+}
+
+/// Replace the entities of text, keeping unknown entities as they are.
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let replacement = entity.and_then(|(entity, end)| {
+            let c = match entity {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                _ => {
+                    let code = match entity.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+        match replacement {
+            Some((c, end)) => {
+                unescaped.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn elements() {
+        let root = parse(
+            r#"<?xml version="1.0" encoding="UTF-8" ?>
+            <!-- generator="WordPress/6.4" -->
+            <rss version="2.0" xmlns:wp="http://wordpress.org/export/1.2/">
+                <item>
+                    <title>Fish &amp; Chips &#8211; &#x263A;</title>
+                    <category domain='category' nicename="news"><![CDATA[News & <b>views</b>]]></category>
+                    <wp:post_id>5</wp:post_id>
+                    <wp:postmeta />
+                </item>
+            </rss>"#,
+        )
+        .unwrap();
+
+        assert_eq!(root.name, "rss");
+        assert_eq!(
+            root.attr("xmlns:wp"),
+            Some("http://wordpress.org/export/1.2/")
+        );
+        let item = root.child("item").unwrap();
+        assert_eq!(item.child_text("title"), "Fish & Chips \u{2013} \u{263A}");
+        let category = item.child("category").unwrap();
+        assert_eq!(category.attr("domain"), Some("category"));
+        assert_eq!(category.text(), "News & <b>views</b>");
+        assert_eq!(item.child_text("wp:post_id"), "5");
+        assert!(item.child("wp:postmeta").is_some());
+        assert_eq!(item.child_text("missing"), "");
+    }
+
+    #[test]
+    fn unknown_entities() {
+        assert_eq!(unescape("a &nbsp; b & c &#xZZ;"), "a &nbsp; b & c &#xZZ;");
+    }
+
+    #[test]
+    fn mismatched_tags() {
+        let err = parse("<rss><channel></rss>").unwrap_err();
+        assert!(matches!(err, WxrError::Xml { position: 14, .. }));
+    }
+
+    #[test]
+    fn unclosed() {
+        assert!(parse("<rss><channel>").is_err());
+        assert!(parse("<rss><![CDATA[text</rss>").is_err());
+    }
+}
@@ -0,0 +1,100 @@
+//! Endpoints for Yoast SEO.
+//!
+//! Yoast SEO adds its meta tags to posts and pages as
+//! [`yoast_head_json`](crate::types::Post::yoast_head_json). The `yoast/v1`
+//! endpoint here generates them for any URL of the site, such as archives
+//! and the home page, which headless frontends need as well.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::prelude::*, types::YoastHead};
+
+/// Retrieve the meta tags Yoast SEO generates for a URL of the site.
+#[derive(Builder)]
+pub struct RetrieveHead {
+    /// The URL of the page, which must belong to the site.
+    #[builder(setter(into))]
+    url: String,
+}
+
+impl RetrieveHead {
+    pub fn builder() -> RetrieveHeadBuilder {
+        RetrieveHeadBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveHead {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn route(&self) -> Cow<'static, str> {
+        "/yoast/v1/get_head".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("url", self.url.as_str());
+        params
+    }
+}
+
+/// The meta tags of a page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PageHead {
+    /// The meta tags, as HTML for the `<head>` of the page.
+    #[serde(default)]
+    pub html: String,
+
+    /// The meta tags.
+    #[serde(default)]
+    pub json: YoastHead,
+
+    /// The HTTP status the page should be served with, which is 404 for
+    /// URLs without content.
+    #[serde(default)]
+    pub status: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test::{MockClient, MockResponse},
+        Query,
+    };
+
+    #[tokio::test]
+    async fn head() {
+        let endpoint = RetrieveHead::builder()
+            .url("http://example.com/category/news/")
+            .build()
+            .unwrap();
+        let response = MockResponse::builder()
+            .route("/yoast/v1/get_head")
+            .query("url=http%3A%2F%2Fexample.com%2Fcategory%2Fnews%2F")
+            .json(json!({
+                "html": "<title>News - Example</title>",
+                "json": {
+                    "title": "News - Example",
+                    "robots": { "index": "index", "follow": "follow" },
+                    "og_type": "website",
+                },
+                "status": 200,
+            }))
+            .build()
+            .unwrap();
+        let client = MockClient::with_response(response);
+
+        let head: PageHead = endpoint.query(&client).await.unwrap();
+
+        assert_eq!(head.status, 200);
+        assert_eq!(head.html, "<title>News - Example</title>");
+        assert_eq!(head.json.title.as_deref(), Some("News - Example"));
+        assert_eq!(head.json.og_type.as_deref(), Some("website"));
+    }
+}